@@ -0,0 +1,39 @@
+//! Parsing, serializing and generating code from synthetic enums of 10, 1,000
+//! and 10,000 variants
+//!
+//! Run with `cargo bench`. The reports are for spotting regressions; the
+//! time budgets are checked by `tests/perf.rs`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use enum_editor_plugin::{synthetic_enum, CodegenTarget, SerializationFormat, FIXTURE_SIZES};
+
+fn serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json");
+    for size in FIXTURE_SIZES {
+        let asset = synthetic_enum("Bench", size);
+        let text = SerializationFormat::Json.serialize(&asset).unwrap();
+        group.bench_with_input(BenchmarkId::new("serialize", size), &asset, |b, asset| {
+            b.iter(|| SerializationFormat::Json.serialize(black_box(asset)))
+        });
+        group.bench_with_input(BenchmarkId::new("parse", size), &text, |b, text| {
+            b.iter(|| SerializationFormat::Json.parse(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
+fn codegen(c: &mut Criterion) {
+    for target in CodegenTarget::ALL {
+        let mut group = c.benchmark_group(format!("codegen/{}", target.id()));
+        for size in FIXTURE_SIZES {
+            let asset = synthetic_enum("Bench", size);
+            group.bench_with_input(BenchmarkId::from_parameter(size), &asset, |b, asset| {
+                b.iter(|| target.generate(black_box(asset)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, serialization, codegen);
+criterion_main!(benches);
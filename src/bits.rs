@@ -0,0 +1,20 @@
+//! Bit usage of bitflags enums
+//!
+//! Values are read as their two's complement bit pattern, so a negative value
+//! sets the top bit.
+
+/// Number of bits shown per variant: enough for the highest set bit of any
+/// value, in whole bytes
+pub fn grid_width(values: &[i64]) -> u32 {
+    let highest = values.iter().map(|value| 64 - (*value as u64).leading_zeros()).max().unwrap_or(0);
+    (highest.div_ceil(8) * 8).max(8)
+}
+
+pub fn is_set(value: i64, bit: u32) -> bool {
+    bit < 64 && (value as u64 >> bit) & 1 == 1
+}
+
+/// How many of `values` set each bit, lowest bit first
+pub fn overlap(values: &[i64], width: u32) -> Vec<usize> {
+    (0..width).map(|bit| values.iter().filter(|value| is_set(**value, bit)).count()).collect()
+}
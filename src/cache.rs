@@ -0,0 +1,52 @@
+//! Parsed definitions shared by the plugin's editors
+//!
+//! Entries are keyed by path and reused while the file's modification time
+//! and size are unchanged, so opening, validating and scanning the same
+//! enums from several editors parses each file once.
+
+use crate::error::Result;
+use crate::storage;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use ui_types_common::EnumAsset;
+
+/// Modification time and size a cached definition was read at
+type Stamp = (SystemTime, u64);
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Cheap to clone; clones share the same entries
+#[derive(Clone, Default)]
+pub struct DefinitionCache {
+    entries: Arc<parking_lot::Mutex<HashMap<PathBuf, (Stamp, Arc<EnumAsset>)>>>,
+}
+
+impl DefinitionCache {
+    /// The definition at `path`, parsed again only if the file changed
+    pub fn load(&self, path: &Path) -> Result<Arc<EnumAsset>> {
+        let stamp = stamp(path);
+        if let Some((cached, asset)) = self.entries.lock().get(path) {
+            if Some(*cached) == stamp {
+                return Ok(asset.clone());
+            }
+        }
+        let asset = Arc::new(storage::load_asset(path)?);
+        match stamp {
+            Some(stamp) => self.entries.lock().insert(path.to_path_buf(), (stamp, asset.clone())),
+            None => self.entries.lock().remove(path),
+        };
+        Ok(asset)
+    }
+
+    /// Record a definition that was just written to `path`
+    pub fn insert(&self, path: &Path, asset: EnumAsset) {
+        if let Some(stamp) = stamp(path) {
+            self.entries.lock().insert(path.to_path_buf(), (stamp, Arc::new(asset)));
+        }
+    }
+}
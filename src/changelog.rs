@@ -0,0 +1,212 @@
+//! Human-readable changelog between two versions of an enum
+
+use crate::metadata::{comparison_order, variant_values, EnumMeta, VariantMeta};
+use crate::type_check;
+use std::collections::{HashMap, HashSet};
+use ui_types_common::EnumAsset;
+
+/// One difference between two versions of an enum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    Added { name: String, value: i64 },
+    Removed { name: String, value: i64 },
+    /// `aliased` if the old name is still accepted as a serde alias
+    Renamed { from: String, to: String, aliased: bool },
+    ValueChanged { name: String, from: i64, to: i64 },
+    PayloadChanged { name: String },
+    Deprecated { name: String },
+    /// Variants kept from the old version compare in a different order
+    OrderChanged,
+}
+
+impl Change {
+    /// Heading the change is listed under
+    fn section(&self) -> &'static str {
+        match self {
+            Change::Added { .. } => "Added",
+            Change::Removed { .. } => "Removed",
+            Change::Renamed { .. } => "Renamed",
+            Change::ValueChanged { .. } | Change::PayloadChanged { .. } | Change::OrderChanged => "Changed",
+            Change::Deprecated { .. } => "Deprecated",
+        }
+    }
+
+    /// True if code or data written against the old version may break
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Change::Removed { .. } | Change::ValueChanged { .. } | Change::PayloadChanged { .. } | Change::OrderChanged => true,
+            Change::Renamed { aliased, .. } => !aliased,
+            Change::Added { .. } | Change::Deprecated { .. } => false,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Change::Added { name, value } => format!("`{}` (= {})", name, value),
+            Change::Removed { name, value } => format!("`{}` (was {})", name, value),
+            Change::Renamed { from, to, aliased: false } => format!("`{}` is now `{}`", from, to),
+            Change::Renamed { from, to, aliased: true } => format!("`{}` is now `{}` (old name kept as alias)", from, to),
+            Change::ValueChanged { name, from, to } => format!("`{}` changed value from {} to {}", name, from, to),
+            Change::PayloadChanged { name } => format!("`{}` changed its payload", name),
+            Change::Deprecated { name } => format!("`{}`", name),
+            Change::OrderChanged => "Variants compare in a different order (`<`, `sort`)".to_string(),
+        }
+    }
+}
+
+/// Semantic version bump a set of changes calls for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Bump {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Bump::None => "none",
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+}
+
+/// Major for breaking changes, minor for additions and deprecations, and
+/// patch for anything else that differs, such as docs or display names
+pub fn suggest_bump(old: &EnumAsset, new: &EnumAsset, changes: &[Change]) -> Bump {
+    if changes.iter().any(Change::is_breaking) {
+        Bump::Major
+    } else if !changes.is_empty() {
+        Bump::Minor
+    } else if serde_json::to_value(old).ok() != serde_json::to_value(new).ok() {
+        Bump::Patch
+    } else {
+        Bump::None
+    }
+}
+
+const SECTIONS: [&str; 5] = ["Added", "Changed", "Renamed", "Deprecated", "Removed"];
+
+/// Compare `old` with `new`.
+///
+/// A variant is considered renamed if a removed variant had the same UUID,
+/// if it lists the old name as an alias, or if a removed and an added
+/// variant share the same value and payload.
+pub fn diff(old: &EnumAsset, new: &EnumAsset) -> Vec<Change> {
+    let old_meta = EnumMeta::from_asset(old);
+    let new_meta = EnumMeta::from_asset(new);
+    let old_values: HashMap<&str, i64> =
+        old.variants.iter().map(|v| v.name.as_str()).zip(variant_values(old, &old_meta)).collect();
+    let new_values: HashMap<&str, i64> =
+        new.variants.iter().map(|v| v.name.as_str()).zip(variant_values(new, &new_meta)).collect();
+    let payload = |asset: &EnumAsset, name: &str| {
+        asset.variants.iter().find(|v| v.name == name).and_then(|v| serde_json::to_value(&v.payload).ok())
+    };
+
+    let mut removed: Vec<&str> = old.variants.iter().map(|v| v.name.as_str()).filter(|n| !new_values.contains_key(n)).collect();
+    let mut renamed_from: HashMap<&str, (&str, bool)> = HashMap::new();
+    for variant in new.variants.iter().filter(|v| !old_values.contains_key(v.name.as_str())) {
+        let VariantMeta { aliases, uuid, .. } = new_meta.variant(&variant.name);
+        let matched = removed
+            .iter()
+            .position(|old_name| uuid.is_some() && old_meta.variant(old_name).uuid == uuid)
+            .or_else(|| removed.iter().position(|old_name| aliases.iter().any(|a| a == old_name)))
+            .or_else(|| {
+                removed.iter().position(|old_name| {
+                    old_values[old_name] == new_values[variant.name.as_str()]
+                        && payload(old, old_name) == payload(new, &variant.name)
+                })
+            });
+        if let Some(position) = matched {
+            let old_name = removed.remove(position);
+            renamed_from.insert(variant.name.as_str(), (old_name, aliases.iter().any(|a| a == old_name)));
+        }
+    }
+
+    let mut changes = Vec::new();
+    for variant in &new.variants {
+        let name = variant.name.as_str();
+        let value = new_values[name];
+        let old_name = match renamed_from.get(name) {
+            Some(&(old_name, aliased)) => {
+                changes.push(Change::Renamed { from: old_name.to_string(), to: name.to_string(), aliased });
+                old_name
+            }
+            None if old_values.contains_key(name) => name,
+            None => {
+                changes.push(Change::Added { name: name.to_string(), value });
+                continue;
+            }
+        };
+
+        if old_values[old_name] != value {
+            changes.push(Change::ValueChanged { name: name.to_string(), from: old_values[old_name], to: value });
+        }
+        if payload(old, old_name) != payload(new, name) {
+            changes.push(Change::PayloadChanged { name: name.to_string() });
+        }
+        if new_meta.variant(name).deprecated && !old_meta.variant(old_name).deprecated {
+            changes.push(Change::Deprecated { name: name.to_string() });
+        }
+    }
+    // Reordering variants, or changing their values or what they're
+    // compared by, changes the results of comparisons
+    if new_meta.derives.iter().any(|d| type_check::derive_name(d) == "PartialOrd") {
+        let new_names: HashMap<&str, &str> = renamed_from.iter().map(|(new, (old, _))| (*old, *new)).collect();
+        let before: Vec<&str> = comparison_order(old, &old_meta)
+            .into_iter()
+            .filter_map(|name| new_names.get(name).copied().or(Some(name).filter(|name| new_values.contains_key(name))))
+            .collect();
+        let after: Vec<&str> = comparison_order(new, &new_meta).into_iter().filter(|name| before.contains(name)).collect();
+        if before != after {
+            changes.push(Change::OrderChanged);
+        }
+    }
+
+    let listed: HashSet<&str> = removed.iter().copied().collect();
+    for variant in old.variants.iter().filter(|v| listed.contains(v.name.as_str())) {
+        changes.push(Change::Removed { name: variant.name.clone(), value: old_values[variant.name.as_str()] });
+    }
+    changes
+}
+
+/// Markdown release notes for the changes, grouped by kind
+pub fn to_markdown(enum_name: &str, since: &str, changes: &[Change], bump: Bump) -> String {
+    let mut out = format!("## {}\n\nChanges since {}. Suggested version bump: **{}**.\n", enum_name, since, bump.label());
+    if changes.is_empty() {
+        out.push_str("\nNo changes.\n");
+        return out;
+    }
+    for section in SECTIONS {
+        let entries: Vec<_> = changes.iter().filter(|c| c.section() == section).collect();
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n### {}\n\n", section));
+        for change in entries {
+            out.push_str(&format!("- {}\n", change.describe()));
+        }
+    }
+    out
+}
+
+/// Version of the enum a changelog is computed against
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChangelogBase {
+    /// A git revision such as `HEAD~3` or `v1.2.0`
+    Revision(String),
+    /// The definition as last saved to disk
+    Saved,
+}
+
+impl ChangelogBase {
+    pub fn label(&self) -> String {
+        match self {
+            ChangelogBase::Revision(revision) => format!("`{}`", revision),
+            ChangelogBase::Saved => "the last save".to_string(),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! "Generate All": run every enabled target and report what changed on disk
+
+use super::header::{add_header_from, is_manually_edited};
+use super::index::rust_index;
+use super::CodegenTarget;
+use crate::metadata::EnumMeta;
+use crate::naming::snake_case;
+use crate::project::{ExportConfig, ExportLayout};
+use crate::storage;
+use similar::{ChangeTag, TextDiff};
+use std::path::{Path, PathBuf};
+use ui_types_common::EnumAsset;
+
+/// How an output file changed compared to its previous contents
+#[derive(Clone, Debug)]
+pub enum FileChange {
+    Created,
+    Unchanged,
+    Changed {
+        added: usize,
+        removed: usize,
+        /// Unified diff, empty for binary outputs
+        diff: String,
+    },
+}
+
+impl FileChange {
+    pub fn summary(&self) -> String {
+        match self {
+            FileChange::Created => "created".to_string(),
+            FileChange::Unchanged => "unchanged".to_string(),
+            FileChange::Changed { diff, .. } if diff.is_empty() => "changed (binary)".to_string(),
+            FileChange::Changed { added, removed, .. } => format!("+{} −{}", added, removed),
+        }
+    }
+}
+
+/// Outcome of one target
+#[derive(Clone, Debug)]
+pub struct TargetReport {
+    pub target: CodegenTarget,
+    pub path: PathBuf,
+    pub result: Result<FileChange, String>,
+}
+
+/// Compare new contents with what is currently on disk
+fn file_change(previous: Option<Vec<u8>>, contents: &[u8]) -> FileChange {
+    let Some(previous) = previous else {
+        return FileChange::Created;
+    };
+    if previous == contents {
+        return FileChange::Unchanged;
+    }
+
+    match (std::str::from_utf8(&previous), std::str::from_utf8(contents)) {
+        (Ok(old), Ok(new)) => {
+            let diff = TextDiff::from_lines(old, new);
+            let (mut added, mut removed) = (0, 0);
+            for change in diff.iter_all_changes() {
+                match change.tag() {
+                    ChangeTag::Insert => added += 1,
+                    ChangeTag::Delete => removed += 1,
+                    ChangeTag::Equal => {}
+                }
+            }
+            let diff = diff.unified_diff().context_radius(2).header("previous", "generated").to_string();
+            FileChange::Changed { added, removed, diff }
+        }
+        _ => FileChange::Changed { added: 0, removed: 0, diff: String::new() },
+    }
+}
+
+/// A generated file that has not been written yet
+#[derive(Clone, Debug)]
+pub struct PlannedOutput {
+    pub target: CodegenTarget,
+    pub path: PathBuf,
+    /// Contents and how they differ from the file on disk, or the generation error
+    pub result: Result<(Vec<u8>, FileChange), String>,
+    /// The existing file was edited by hand since it was generated
+    pub manually_edited: bool,
+}
+
+impl PlannedOutput {
+    /// Whether writing this output would touch the file on disk
+    pub fn writes(&self) -> bool {
+        matches!(self.result, Ok((_, FileChange::Created | FileChange::Changed { .. })))
+    }
+
+    /// Write the output if it changed; unchanged files are not rewritten.
+    /// Hand edits are overwritten, so callers must have confirmed them.
+    pub fn write(self) -> TargetReport {
+        let result = self.result.and_then(|(contents, change)| {
+            if !matches!(change, FileChange::Unchanged) {
+                super::write_output(&self.path, &contents).map_err(|e| e.to_string())?;
+            }
+            Ok(change)
+        });
+        TargetReport { target: self.target, path: self.path, result }
+    }
+}
+
+/// Generate every target enabled in the enum's codegen settings without
+/// writing anything. Failing targets don't stop the others.
+pub fn plan_all(definition_path: &Path, asset: &EnumAsset) -> Vec<PlannedOutput> {
+    CodegenTarget::enabled(asset)
+        .into_iter()
+        .map(|target| {
+            let path = target.output_path(definition_path, asset);
+            let previous = std::fs::read(&path).ok();
+            let manually_edited = previous.as_deref().is_some_and(|previous| target.is_hand_edited(asset, previous));
+            let result = target.generate_file(definition_path, asset).map(|contents| {
+                let change = file_change(previous, &contents);
+                (contents, change)
+            });
+            PlannedOutput { target, path, result, manually_edited }
+        })
+        .collect()
+}
+
+/// Module path of a definition: the snake_case folders between the source
+/// root and the enum, e.g. `["combat", "status"]`
+pub fn module_path(root: &Path, config: &ExportConfig, definition_path: &Path) -> Vec<String> {
+    let source_root = root.join(config.source_root.as_deref().unwrap_or_default());
+    let container = storage::asset_container_dir(definition_path);
+    container
+        .strip_prefix(&source_root)
+        .map(|relative| {
+            relative
+                .components()
+                .map(|component| snake_case(&component.as_os_str().to_string_lossy()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Where "Export All" writes a target of an enum, and whether the file is
+/// shared with the other enums of its module. A target's own output
+/// setting wins over the project's layout.
+fn export_path(root: &Path, config: &ExportConfig, target: CodegenTarget, definition_path: &Path, asset: &EnumAsset) -> (PathBuf, bool) {
+    let Some(dir) = &config.dir else {
+        return (target.output_path(definition_path, asset), false);
+    };
+    if target.injects() || EnumMeta::from_asset(asset).target(target.id()).output.is_some() {
+        return (target.output_path(definition_path, asset), false);
+    }
+    let dir = root.join(dir);
+    let module = module_path(root, config, definition_path);
+    match (config.layout, module.split_last()) {
+        (ExportLayout::Flat, _) => (dir.join(target.module_file_name(asset)), false),
+        (ExportLayout::PerModule, Some((last, parents))) if target.joins() => {
+            let file = parents.iter().fold(dir, |dir, segment| dir.join(segment));
+            (file.join(format!("{}.{}", last, target.file_extension())), true)
+        }
+        _ => {
+            let folder = module.iter().fold(dir, |dir, segment| dir.join(segment));
+            (folder.join(target.module_file_name(asset)), false)
+        }
+    }
+}
+
+/// Generate every enabled target of every enum for "Export All", laid out by
+/// the project's conventions, without writing anything. Enums sharing a
+/// module file are joined in the order given; two enums landing on the same
+/// file otherwise is reported as an error. With an output folder, a `mod.rs`
+/// declaring the Rust files in it is planned last.
+pub fn plan_export(root: &Path, config: &ExportConfig, enums: &[(PathBuf, EnumAsset)]) -> Vec<PlannedOutput> {
+    // Outputs by file, in the order they are first written
+    let mut files: Vec<(PathBuf, CodegenTarget, bool, Vec<&(PathBuf, EnumAsset)>)> = Vec::new();
+    for entry in enums {
+        let (definition_path, asset) = entry;
+        for target in CodegenTarget::enabled(asset) {
+            let (path, shared) = export_path(root, config, target, definition_path, asset);
+            match files.iter_mut().find(|(existing, other, ..)| *existing == path && *other == target) {
+                Some((_, _, _, sources)) => sources.push(entry),
+                None => files.push((path, target, shared, vec![entry])),
+            }
+        }
+    }
+
+    let index = config.dir.as_ref().and_then(|dir| {
+        let dir = root.join(dir);
+        let rust_files: Vec<(PathBuf, Vec<String>)> = files
+            .iter()
+            .filter(|(_, target, ..)| *target == CodegenTarget::Rust)
+            .map(|(path, _, _, sources)| (path.clone(), sources.iter().map(|(_, asset)| asset.name.clone()).collect()))
+            .collect();
+        let source = config.source_root.clone().unwrap_or_else(|| ".".to_string());
+        rust_index(&dir, &rust_files).map(|body| (dir.join("mod.rs"), add_header_from(CodegenTarget::Rust, &source, body.into_bytes())))
+    });
+
+    let mut plans: Vec<PlannedOutput> = files
+        .into_iter()
+        .map(|(path, target, shared, sources)| {
+            let previous = std::fs::read(&path).ok();
+            let result = match (shared, sources.as_slice()) {
+                (false, [(definition_path, asset)]) => target.generate_file(definition_path, asset),
+                (false, sources) => Err(format!(
+                    "{} would all be written to this file; set an output for the target or change the export layout",
+                    sources.iter().map(|(_, asset)| asset.name.as_str()).collect::<Vec<_>>().join(", ")
+                )),
+                (true, sources) => joined(root, target, &path, sources),
+            };
+            let manually_edited = match (&previous, sources.first()) {
+                (Some(previous), Some((_, asset))) => target.is_hand_edited(asset, previous),
+                _ => false,
+            };
+            let result = result.map(|contents| {
+                let change = file_change(previous, &contents);
+                (contents, change)
+            });
+            PlannedOutput { target, path, result, manually_edited }
+        })
+        .collect();
+    if let Some((path, contents)) = index {
+        let previous = std::fs::read(&path).ok();
+        let manually_edited = previous.as_deref().is_some_and(is_manually_edited);
+        let change = file_change(previous, &contents);
+        plans.push(PlannedOutput { target: CodegenTarget::Rust, path, result: Ok((contents, change)), manually_edited });
+    }
+    plans
+}
+
+/// The outputs of a module's enums joined under one generation header
+fn joined(root: &Path, target: CodegenTarget, path: &Path, sources: &[&(PathBuf, EnumAsset)]) -> Result<Vec<u8>, String> {
+    let mut body = Vec::new();
+    for (_, asset) in sources {
+        let contents = target.generate(asset).map_err(|e| format!("{}: {}", asset.name, e))?;
+        if !body.is_empty() {
+            body.push(b'\n');
+        }
+        body.extend(contents);
+    }
+    let folder = sources
+        .first()
+        .map(|(definition_path, _)| storage::asset_container_dir(definition_path))
+        .unwrap_or_else(|| path.to_path_buf());
+    let source = folder.strip_prefix(root).unwrap_or(&folder).display().to_string().replace('\\', "/");
+    Ok(add_header_from(target, &source, body))
+}
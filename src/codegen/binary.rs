@@ -0,0 +1,66 @@
+//! Compact binary metadata table for runtime loading
+//!
+//! Lets the game runtime read names, values, display names and colors without
+//! a JSON parser. All integers are little-endian; strings are a `u32` byte
+//! length followed by UTF-8 bytes.
+//!
+//! ```text
+//! magic          [u8; 4] = b"PENM"
+//! version        u8      = 1
+//! name           string
+//! display_name   string
+//! variant_count  u32
+//! variants       variant_count × {
+//!     name          string
+//!     value         i64
+//!     display_name  string   (generated or the variant name when unset)
+//!     flags         u8       (bit 0: has color)
+//!     color         u32      (0xRRGGBBAA, 0 when absent)
+//! }
+//! ```
+
+use crate::metadata::{variant_values, EnumMeta};
+use ui_types_common::EnumAsset;
+
+pub const MAGIC: &[u8; 4] = b"PENM";
+pub const VERSION: u8 = 1;
+
+const FLAG_HAS_COLOR: u8 = 1 << 0;
+
+/// Encode the enum's metadata table
+pub fn generate_binary(asset: &EnumAsset) -> Result<Vec<u8>, String> {
+    let meta = EnumMeta::from_asset(asset);
+    let values = variant_values(asset, &meta);
+    let mut out = Vec::new();
+
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    write_str(&mut out, &asset.name)?;
+    write_str(&mut out, &asset.display_name)?;
+    write_u32(&mut out, asset.variants.len())?;
+
+    for (variant, value) in asset.variants.iter().zip(values) {
+        let variant_meta = meta.variant(&variant.name);
+        let color = variant_meta.color_rgba();
+
+        write_str(&mut out, &variant.name)?;
+        out.extend_from_slice(&value.to_le_bytes());
+        write_str(&mut out, &meta.display_name(&variant.name))?;
+        out.push(if color.is_some() { FLAG_HAS_COLOR } else { 0 });
+        out.extend_from_slice(&color.unwrap_or(0).to_le_bytes());
+    }
+
+    Ok(out)
+}
+
+fn write_u32(out: &mut Vec<u8>, value: usize) -> Result<(), String> {
+    let value = u32::try_from(value).map_err(|_| format!("{} does not fit in a u32", value))?;
+    out.extend_from_slice(&value.to_le_bytes());
+    Ok(())
+}
+
+fn write_str(out: &mut Vec<u8>, value: &str) -> Result<(), String> {
+    write_u32(out, value.len())?;
+    out.extend_from_slice(value.as_bytes());
+    Ok(())
+}
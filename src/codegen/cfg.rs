@@ -0,0 +1,235 @@
+//! Per-variant `cfg` gating
+//!
+//! Gates are written as Rust `cfg` predicates (`feature = "debug_tools"`,
+//! `any(target_os = "windows", target_os = "linux")`) and translated into
+//! preprocessor conditions for C targets.
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Eq,
+    Comma,
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '=' => {
+                chars.next();
+                tokens.push(Token::Eq);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => value.push(c),
+                        None => return Err("unterminated string".to_string()),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                    ident.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            other => return Err(format!("unexpected `{}`", other)),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parsed predicate
+#[derive(Debug)]
+enum Cfg {
+    Flag(String),
+    KeyValue(String, String),
+    Any(Vec<Cfg>),
+    All(Vec<Cfg>),
+    Not(Box<Cfg>),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if *token == expected => Ok(()),
+            other => Err(format!("expected {:?}, found {:?}", expected, other)),
+        }
+    }
+
+    fn predicate(&mut self) -> Result<Cfg, String> {
+        let name = match self.next() {
+            Some(Token::Ident(name)) => name.clone(),
+            other => return Err(format!("expected a name, found {:?}", other)),
+        };
+        match (name.as_str(), self.peek()) {
+            ("any" | "all" | "not", Some(Token::Open)) => {
+                self.pos += 1;
+                let mut list = Vec::new();
+                while self.peek() != Some(&Token::Close) {
+                    list.push(self.predicate()?);
+                    if self.peek() == Some(&Token::Comma) {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(Token::Close)?;
+                Ok(match name.as_str() {
+                    "any" => Cfg::Any(list),
+                    "all" => Cfg::All(list),
+                    _ => {
+                        let mut list = list.into_iter();
+                        match (list.next(), list.next()) {
+                            (Some(inner), None) => Cfg::Not(Box::new(inner)),
+                            _ => return Err("not() takes exactly one predicate".to_string()),
+                        }
+                    }
+                })
+            }
+            (_, Some(Token::Eq)) => {
+                self.pos += 1;
+                match self.next() {
+                    Some(Token::Str(value)) => Ok(Cfg::KeyValue(name, value.clone())),
+                    other => Err(format!("expected a string, found {:?}", other)),
+                }
+            }
+            _ => Ok(Cfg::Flag(name)),
+        }
+    }
+}
+
+fn parse(predicate: &str) -> Result<Cfg, String> {
+    let mut parser = Parser { tokens: tokenize(predicate)?, pos: 0 };
+    let cfg = parser.predicate()?;
+    if parser.pos < parser.tokens.len() {
+        return Err("unexpected input after the predicate".to_string());
+    }
+    Ok(cfg)
+}
+
+/// Check that a gate is a well-formed `cfg` predicate
+pub fn validate_cfg(predicate: &str) -> Result<(), String> {
+    parse(predicate).map(|_| ())
+}
+
+fn macro_name(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .map(|part| part.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' }).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn to_c(cfg: &Cfg) -> String {
+    let join = |list: &[Cfg], op: &str, empty: &str| {
+        if list.is_empty() {
+            empty.to_string()
+        } else {
+            format!("({})", list.iter().map(to_c).collect::<Vec<_>>().join(op))
+        }
+    };
+    match cfg {
+        Cfg::Flag(flag) => match flag.as_str() {
+            "debug_assertions" => "!defined(NDEBUG)".to_string(),
+            "windows" => "defined(_WIN32)".to_string(),
+            "unix" => "defined(__unix__)".to_string(),
+            other => format!("defined({})", macro_name(&[other])),
+        },
+        Cfg::KeyValue(key, value) => match (key.as_str(), value.as_str()) {
+            ("target_os", "windows") => "defined(_WIN32)".to_string(),
+            ("target_os", "linux") => "defined(__linux__)".to_string(),
+            ("target_os", "macos") => "defined(__APPLE__)".to_string(),
+            ("target_os", "android") => "defined(__ANDROID__)".to_string(),
+            ("feature", feature) => format!("defined({})", macro_name(&["feature", feature])),
+            (key, value) => format!("defined({})", macro_name(&[key, value])),
+        },
+        Cfg::Any(list) => join(list, " || ", "0"),
+        Cfg::All(list) => join(list, " && ", "1"),
+        Cfg::Not(inner) => format!("!{}", to_c(inner)),
+    }
+}
+
+/// Translate a `cfg` predicate into a C preprocessor condition.
+///
+/// Well-known platforms map to the usual compiler macros; features become
+/// `FEATURE_<NAME>` and any other flag its uppercased name.
+pub fn cfg_to_c(predicate: &str) -> Result<String, String> {
+    parse(predicate).map(|cfg| to_c(&cfg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_and_platforms_become_macros() {
+        assert_eq!(cfg_to_c(r#"feature = "debug-tools""#), Ok("defined(FEATURE_DEBUG_TOOLS)".to_string()));
+        assert_eq!(cfg_to_c("windows"), Ok("defined(_WIN32)".to_string()));
+        assert_eq!(cfg_to_c("debug_assertions"), Ok("!defined(NDEBUG)".to_string()));
+        assert_eq!(cfg_to_c(r#"target_arch = "x86_64""#), Ok("defined(TARGET_ARCH_X86_64)".to_string()));
+    }
+
+    #[test]
+    fn combinators_nest() {
+        assert_eq!(
+            cfg_to_c(r#"any(target_os = "windows", target_os = "linux")"#),
+            Ok("(defined(_WIN32) || defined(__linux__))".to_string())
+        );
+        assert_eq!(
+            cfg_to_c(r#"all(unix, not(feature = "headless"))"#),
+            Ok("(defined(__unix__) && !defined(FEATURE_HEADLESS))".to_string())
+        );
+        assert_eq!(cfg_to_c("any()"), Ok("0".to_string()));
+        assert_eq!(cfg_to_c("all()"), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn malformed_predicates_are_rejected() {
+        assert!(cfg_to_c("feature =").is_err());
+        assert!(cfg_to_c("any(unix").is_err());
+        assert!(cfg_to_c("not(unix, windows)").is_err());
+        assert!(cfg_to_c("unix windows").is_err());
+        assert!(cfg_to_c(r#"feature = "x"#).is_err());
+    }
+}
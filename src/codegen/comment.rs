@@ -0,0 +1,26 @@
+//! Doc comment rendering shared by the backends
+//!
+//! Docs are written in markdown. Targets whose doc tooling reads markdown
+//! (rustdoc, DocC, KDoc, Doxygen) get it verbatim, one comment line per line.
+
+/// Line comments such as `/// ` or `# `, one per line of `text`
+pub(crate) fn line_comment(text: &str, indent: &str, marker: &str) -> String {
+    text.lines()
+        .map(|line| match line.trim_end() {
+            "" => format!("{}{}", indent, marker).trim_end().to_string() + "\n",
+            line => format!("{}{}{}\n", indent, marker, line),
+        })
+        .collect()
+}
+
+/// `/** ... */` block comment; single-line text stays on one line
+pub(crate) fn block_comment(text: &str, indent: &str) -> String {
+    let text = text.replace("*/", "*&#47;");
+    if !text.contains('\n') {
+        return format!("{}/** {} */\n", indent, text);
+    }
+    let mut out = format!("{}/**\n", indent);
+    out.push_str(&line_comment(&text, indent, " * "));
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
@@ -0,0 +1,102 @@
+//! Config file backends: a document showing how each variant is written in
+//! JSON, RON and TOML, and a Rust module reading the enum from those formats
+
+use super::rust_visibility;
+use crate::metadata::EnumMeta;
+use crate::naming::snake_case;
+use crate::samples::{self, SampleFormat};
+use ui_types_common::EnumAsset;
+
+/// Path the loader module imports the enum from when the target has no
+/// `enum_path` option
+fn default_enum_path(asset: &EnumAsset) -> String {
+    format!("super::{}", asset.name)
+}
+
+/// Generate a markdown document with a sample of every variant in each
+/// format, for the people writing the config files
+pub fn generate_config_samples(asset: &EnumAsset) -> String {
+    let meta = EnumMeta::from_asset(asset);
+    let mut out = format!("# {} in config files\n\n", asset.display_name);
+    out.push_str(&format!("Written {}.\n", meta.serde_repr.label().to_lowercase()));
+
+    for variant in &asset.variants {
+        out.push_str(&format!("\n## {}\n\n", variant.name));
+        if let Some(summary) = variant.doc.as_deref().and_then(|doc| doc.lines().next()).filter(|line| !line.trim().is_empty()) {
+            out.push_str(&format!("{}\n\n", summary.trim()));
+        }
+        for format in SampleFormat::ALL {
+            match samples::variant_text(asset, variant, &meta, format) {
+                Ok(text) => out.push_str(&format!(
+                    "{}:\n\n```{}\n{}\n```\n\n",
+                    format.label(),
+                    format.label().to_lowercase(),
+                    text.trim_end()
+                )),
+                Err(e) => out.push_str(&format!("{}: can't be written ({})\n\n", format.label(), e)),
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+    }
+    out
+}
+
+/// Doc comment lines showing the first variant in `format`, if it can be written
+fn example(asset: &EnumAsset, meta: &EnumMeta, format: SampleFormat) -> String {
+    let Some(text) = asset.variants.iter().find_map(|variant| samples::variant_text(asset, variant, meta, format).ok()) else {
+        return String::new();
+    };
+    let mut out = format!("///\n/// ```{}\n", format.label().to_lowercase());
+    for line in text.trim_end().lines() {
+        match line {
+            "" => out.push_str("///\n"),
+            line => out.push_str(&format!("/// {}\n", line)),
+        }
+    }
+    out.push_str("/// ```\n");
+    out
+}
+
+/// Generate a Rust module with a function per format reading the enum from
+/// config text. Each function needs its format's crate (`serde_json`, `ron`
+/// or `toml`) and the enum must derive `Deserialize`.
+///
+/// The enum is imported from the target's `enum_path` option, by default
+/// `super::<Enum>`.
+pub fn generate_config_loader(asset: &EnumAsset) -> String {
+    let meta = EnumMeta::from_asset(asset);
+    let options = meta.target("config-loader").options;
+    let enum_path = options
+        .get("enum_path")
+        .and_then(|path| path.as_str())
+        .map(str::to_string)
+        .unwrap_or_else(|| default_enum_path(asset));
+    let visibility = rust_visibility(&asset.visibility);
+    let name = &asset.name;
+    let key = snake_case(name);
+
+    let mut out = format!("//! Reading `{}` from config files\n\n", name);
+    out.push_str(&format!("use {};\n", enum_path));
+
+    out.push_str(&format!("\n/// Parse a `{}` written in JSON, e.g.\n", name));
+    out.push_str(&example(asset, &meta, SampleFormat::Json));
+    out.push_str(&format!("{}fn {}_from_json(text: &str) -> Result<{}, String> {{\n", visibility, key, name));
+    out.push_str("    serde_json::from_str(text).map_err(|e| e.to_string())\n}\n");
+
+    out.push_str(&format!("\n/// Parse a `{}` written in RON, e.g.\n", name));
+    out.push_str(&example(asset, &meta, SampleFormat::Ron));
+    out.push_str(&format!("{}fn {}_from_ron(text: &str) -> Result<{}, String> {{\n", visibility, key, name));
+    out.push_str("    ron::from_str(text).map_err(|e| e.to_string())\n}\n");
+
+    out.push_str(&format!("\n/// Parse the `{}` key of a TOML document, e.g.\n", key));
+    out.push_str(&example(asset, &meta, SampleFormat::Toml));
+    out.push_str(&format!("{}fn {}_from_toml(text: &str) -> Result<{}, String> {{\n", visibility, key, name));
+    out.push_str("    #[derive(serde::Deserialize)]\n");
+    out.push_str(&format!("    struct Document {{\n        {}: {},\n    }}\n", key, name));
+    out.push_str(&format!(
+        "    toml::from_str::<Document>(text).map(|document| document.{}).map_err(|e| e.to_string())\n}}\n",
+        key
+    ));
+    out
+}
@@ -0,0 +1,131 @@
+//! Diagram backends: Mermaid and Graphviz DOT
+//!
+//! The enum is drawn as one node listing its variants and values. Payloads
+//! add an edge from their variant to every type they mention that isn't a
+//! standard one, which shows references to other enums, and composite
+//! flags of a bitflags enum get an edge to each flag they combine.
+
+use super::model::{EnumModel, VariantModel};
+use crate::type_check;
+use ui_types_common::EnumAsset;
+
+/// Types that are drawn as part of the payload text rather than as nodes
+const STANDARD_TYPES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+    "isize", "f32", "f64", "String", "Option", "Result", "Vec", "VecDeque", "HashMap", "BTreeMap", "HashSet",
+    "BTreeSet", "Box", "Rc", "Arc", "Cow", "PathBuf", "Duration",
+];
+
+/// Payload of the variant as written, e.g. `(f32)` or `{ x: i32, y: i32 }`
+fn payload_text(variant: &VariantModel) -> String {
+    match variant.kind {
+        "tuple" => format!("({})", variant.payload_type.as_deref().unwrap_or_default()),
+        "struct" => format!(
+            " {{ {} }}",
+            variant.fields.iter().map(|field| format!("{}: {}", field.name, field.type_name)).collect::<Vec<_>>().join(", ")
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Types the variant's payload mentions that get a node of their own, in
+/// the order they appear
+fn referenced_types(variant: &VariantModel) -> Vec<String> {
+    let texts = variant.payload_type.iter().chain(variant.fields.iter().map(|field| &field.type_name));
+    let mut names: Vec<String> = Vec::new();
+    for text in texts {
+        let Ok(ty) = type_check::parse_type(text) else {
+            continue;
+        };
+        for name in type_check::type_names(&ty) {
+            if !STANDARD_TYPES.contains(&name.as_str()) && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Generate a Mermaid class diagram
+pub fn generate_mermaid(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    // Mermaid writes generics with tildes and reads parentheses as methods
+    let escape = |text: &str| text.replace(['<', '>'], "~").replace('"', "'");
+    let mut out = format!("---\ntitle: \"{}\"\n---\nclassDiagram\n", escape(&model.display_name));
+    out.push_str(&format!("    class {} {{\n        <<enumeration>>\n", model.name));
+    for variant in &model.variants {
+        let value = match variant.combines.as_slice() {
+            [] => variant.value.to_string(),
+            flags => flags.join(" | "),
+        };
+        out.push_str(&format!("        {}{} = {}\n", variant.name, escape(&payload_text(variant)), value));
+    }
+    out.push_str("    }\n");
+    for variant in &model.variants {
+        for name in referenced_types(variant) {
+            out.push_str(&format!("    {} ..> {} : {}\n", model.name, name, variant.name));
+        }
+    }
+    out
+}
+
+/// Text escaped for an HTML-like Graphviz label
+fn dot_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Generate a Graphviz DOT graph. Each variant is a row of the enum's node,
+/// so edges start at the variant they belong to.
+pub fn generate_dot(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = format!("digraph {} {{\n", model.name);
+    out.push_str("    rankdir=LR;\n    node [shape=plaintext, fontname=\"Helvetica\"];\n\n");
+
+    out.push_str(&format!("    {} [label=<\n", model.name));
+    out.push_str("        <table border=\"0\" cellborder=\"1\" cellspacing=\"0\" cellpadding=\"4\">\n");
+    out.push_str(&format!(
+        "            <tr><td colspan=\"2\" bgcolor=\"lightgrey\"><b>{}</b></td></tr>\n",
+        dot_html(&model.name)
+    ));
+    for variant in &model.variants {
+        let name = match &variant.color {
+            Some(color) => format!("<font color=\"{}\">{}</font>", dot_html(color), dot_html(&variant.name)),
+            None => dot_html(&variant.name),
+        };
+        let name = if variant.deprecated { format!("<s>{}</s>", name) } else { name };
+        out.push_str(&format!(
+            "            <tr><td port=\"{}\" align=\"left\">{}{}</td><td align=\"right\">{}</td></tr>\n",
+            variant.name,
+            name,
+            dot_html(&payload_text(variant)),
+            variant.value
+        ));
+    }
+    out.push_str("        </table>\n    >];\n");
+
+    let mut types: Vec<String> = Vec::new();
+    let mut edges = String::new();
+    for variant in &model.variants {
+        for flag in &variant.combines {
+            edges.push_str(&format!("    {}:{} -> {}:{} [style=dashed];\n", model.name, variant.name, model.name, flag));
+        }
+        for name in referenced_types(variant) {
+            edges.push_str(&format!("    {}:{} -> {};\n", model.name, variant.name, name));
+            if !types.contains(&name) {
+                types.push(name);
+            }
+        }
+    }
+    if !types.is_empty() {
+        out.push('\n');
+        for name in &types {
+            out.push_str(&format!("    {} [shape=box];\n", name));
+        }
+    }
+    if !edges.is_empty() {
+        out.push('\n');
+        out.push_str(&edges);
+    }
+    out.push_str("}\n");
+    out
+}
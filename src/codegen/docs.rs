@@ -0,0 +1,284 @@
+//! Documentation page backends: standalone HTML, mdBook chapters and the
+//! review sheet
+
+use super::model::{EnumModel, VariantModel};
+use crate::markdown::{escape_html, to_html};
+use crate::metadata::{EnumMeta, Stage};
+use ui_types_common::EnumAsset;
+
+/// Human-readable payload signature, e.g. `(f32)` or `{ x: f32, y: f32 }`
+pub fn payload_signature(variant: &VariantModel) -> String {
+    match variant.kind {
+        "tuple" => format!("({})", variant.payload_type.as_deref().unwrap_or("")),
+        "struct" => {
+            let fields: Vec<_> = variant.fields.iter().map(|f| format!("{}: {}", f.name, f.type_name)).collect();
+            format!("{{ {} }}", fields.join(", "))
+        }
+        _ => String::new(),
+    }
+}
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:60rem;margin:2rem auto;padding:0 1rem;color:#222}\
+table{border-collapse:collapse;width:100%}th,td{border:1px solid #ccc;padding:.4rem .6rem;text-align:left;vertical-align:top}\
+th{background:#f4f4f4}code{font-family:ui-monospace,monospace}\
+.swatch{display:inline-block;width:1em;height:1em;border:1px solid #888;border-radius:2px;vertical-align:middle;margin-right:.3em}\
+.tag{display:inline-block;background:#eef;border-radius:3px;padding:0 .4em;margin-right:.3em}";
+
+/// Render a self-contained HTML page
+pub fn generate_html(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = String::new();
+
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", escape_html(&model.display_name)));
+    out.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", STYLE));
+    out.push_str(&format!(
+        "<h1>{} <small><code>{}</code></small></h1>\n",
+        escape_html(&model.display_name),
+        escape_html(&model.name)
+    ));
+    if let Some(description) = &model.description {
+        out.push_str(&format!("{}\n", to_html(description)));
+    }
+    if !model.categories.is_empty() {
+        out.push_str("<p>");
+        for category in &model.categories {
+            out.push_str(&format!("<span class=\"tag\">{}</span>", escape_html(category)));
+        }
+        out.push_str("</p>\n");
+    }
+
+    out.push_str("<table>\n<tr><th>Variant</th><th>Value</th><th>Display Name</th><th>Payload</th><th>Description</th></tr>\n");
+    for variant in &model.variants {
+        let swatch = variant
+            .color
+            .as_ref()
+            .map(|color| format!("<span class=\"swatch\" style=\"background:{}\"></span>", escape_html(color)))
+            .unwrap_or_default();
+
+        let mut description = variant.doc.as_deref().map(to_html).unwrap_or_default();
+        if !variant.fields.is_empty() {
+            description.push_str("<ul>");
+            for field in &variant.fields {
+                description.push_str(&format!(
+                    "<li><code>{}: {}</code>{}{}</li>",
+                    escape_html(&field.name),
+                    escape_html(&field.type_name),
+                    field.default.as_deref().map(|default| format!(" = <code>{}</code>", escape_html(default))).unwrap_or_default(),
+                    field.doc.as_deref().map(|doc| format!(" — {}", to_html(doc))).unwrap_or_default()
+                ));
+            }
+            description.push_str("</ul>");
+        }
+
+        out.push_str(&format!(
+            "<tr id=\"{name}\"><td>{swatch}<code>{name}</code></td><td>{value}</td><td>{display}</td><td><code>{payload}</code></td><td>{description}</td></tr>\n",
+            name = escape_html(&variant.name),
+            swatch = swatch,
+            value = variant.value,
+            display = escape_html(&variant.display_name),
+            payload = escape_html(&payload_signature(variant)),
+            description = description,
+        ));
+    }
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+/// Render a markdown chapter that can be dropped into an mdBook `src/` folder
+pub fn generate_mdbook(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = format!("# {}\n\n", model.display_name);
+
+    out.push_str(&format!("`{}`", model.name));
+    for category in &model.categories {
+        out.push_str(&format!(" · *{}*", category));
+    }
+    out.push_str("\n\n");
+    if let Some(description) = &model.description {
+        out.push_str(&format!("{}\n\n", description));
+    }
+
+    out.push_str("## Variants\n\n");
+    for variant in &model.variants {
+        out.push_str(&format!("### `{}{}`\n\n", variant.name, payload_signature(variant)));
+        if let Some(color) = &variant.color {
+            out.push_str(&format!(
+                "<span style=\"display:inline-block;width:1em;height:1em;background:{};border:1px solid #888\"></span> ",
+                escape_html(color)
+            ));
+        }
+        out.push_str(&format!("**{}** — value `{}`\n\n", variant.display_name, variant.value));
+        if let Some(doc) = &variant.doc {
+            out.push_str(&format!("{}\n\n", doc));
+        }
+        if !variant.fields.is_empty() {
+            out.push_str("| Field | Type | Default | Description |\n| --- | --- | --- | --- |\n");
+            for field in &variant.fields {
+                out.push_str(&format!(
+                    "| `{}` | `{}` | {} | {} |\n",
+                    field.name,
+                    field.type_name,
+                    field.default.as_deref().map(|default| format!("`{}`", default.replace('|', "\\|"))).unwrap_or_default(),
+                    field.doc.as_deref().unwrap_or("").replace('|', "\\|")
+                ));
+            }
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Layout of the review sheet on top of the page style: one card per
+/// variant, kept whole across printed pages
+const REVIEW_STYLE: &str = ".variant{border:1px solid #ccc;border-radius:4px;padding:.6rem 1rem;margin:1rem 0;break-inside:avoid}\
+.variant h3{margin:.2rem 0}.variant dl{display:grid;grid-template-columns:9rem 1fr;gap:.2rem 1rem;margin:.5rem 0}\
+dt{color:#666}dd{margin:0}.removed{opacity:.6}.removed h3{text-decoration:line-through}\
+.notes li.resolved{color:#888;text-decoration:line-through}\
+@media print{body{margin:0;max-width:none;font-size:10pt}a{color:inherit;text-decoration:none}h2{break-after:avoid}}";
+
+/// `<dt>`/`<dd>` row, with `value` already escaped
+fn review_row(out: &mut String, label: &str, value: &str) {
+    out.push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", label, value));
+}
+
+/// Render a self-contained HTML page laying out the whole definition for a
+/// design review: the enum's settings, then every variant with its docs,
+/// value and metadata, removed ones included. Print styles keep each variant
+/// on one page, so printing it from a browser gives the PDF version.
+pub fn generate_review(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let meta = EnumMeta::from_asset(asset);
+    let code = |text: &str| format!("<code>{}</code>", escape_html(text));
+    let mut out = String::new();
+
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{} — Review</title>\n", escape_html(&model.display_name)));
+    out.push_str(&format!("<style>{}{}</style>\n</head>\n<body>\n", STYLE, REVIEW_STYLE));
+    out.push_str(&format!(
+        "<h1>{} <small><code>{}</code></small></h1>\n",
+        escape_html(&model.display_name),
+        escape_html(&model.name)
+    ));
+    if let Some(description) = &model.description {
+        out.push_str(&format!("{}\n", to_html(description)));
+    }
+
+    out.push_str("<h2>Definition</h2>\n<dl>\n");
+    review_row(&mut out, "Visibility", model.visibility);
+    review_row(&mut out, "Mode", meta.mode.label());
+    if let Some(repr) = &meta.repr {
+        review_row(&mut out, "Representation", &code(repr));
+    }
+    if !meta.derives.is_empty() {
+        review_row(&mut out, "Derives", &meta.derives.iter().map(|derive| code(derive)).collect::<Vec<_>>().join(", "));
+    }
+    review_row(&mut out, "Serialization", meta.serde_repr.label());
+    review_row(&mut out, "Naming", meta.naming.label());
+    review_row(&mut out, "Export profile", meta.profile.label());
+    review_row(&mut out, "Published", if meta.published { "Yes" } else { "No" });
+    if let Some(default) = &meta.default_variant {
+        review_row(&mut out, "Default", &code(default));
+    }
+    if !model.categories.is_empty() {
+        let tags: String =
+            model.categories.iter().map(|category| format!("<span class=\"tag\">{}</span>", escape_html(category))).collect();
+        review_row(&mut out, "Categories", &tags);
+    }
+    if !meta.constants.is_empty() {
+        let constants: Vec<String> = meta.constants.iter().map(|(name, value)| code(&format!("{} = {}", name, value))).collect();
+        review_row(&mut out, "Constants", &constants.join(", "));
+    }
+    if !meta.reserved.is_empty() {
+        let reserved: Vec<String> = meta.reserved.iter().map(|reserved| code(&reserved.label())).collect();
+        review_row(&mut out, "Reserved", &reserved.join(", "));
+    }
+    out.push_str("</dl>\n");
+
+    out.push_str(&format!("<h2>Variants ({})</h2>\n", model.variants.len()));
+    for variant in &model.variants {
+        let variant_meta = meta.variant(&variant.name);
+        let removed = variant_meta.stage == Stage::Removed;
+        out.push_str(&format!(
+            "<section class=\"variant{}\" id=\"{}\">\n",
+            if removed { " removed" } else { "" },
+            escape_html(&variant.name)
+        ));
+        let swatch = variant
+            .color
+            .as_ref()
+            .map(|color| format!("<span class=\"swatch\" style=\"background:{}\"></span>", escape_html(color)))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "<h3>{}<code>{}{}</code></h3>\n",
+            swatch,
+            escape_html(&variant.name),
+            escape_html(&payload_signature(variant))
+        ));
+        if let Some(doc) = &variant.doc {
+            out.push_str(&format!("{}\n", to_html(doc)));
+        }
+
+        out.push_str("<dl>\n");
+        let value = match (variant.combines.as_slice(), &variant.expr) {
+            ([], Some(expr)) => format!("{} = {}", code(expr), variant.value),
+            ([], None) if variant.has_explicit_value => variant.value.to_string(),
+            ([], None) => format!("{} (implicit)", variant.value),
+            (flags, _) => flags.iter().map(|flag| code(flag)).collect::<Vec<_>>().join(" | "),
+        };
+        review_row(&mut out, "Value", &value);
+        review_row(&mut out, "Display name", &escape_html(&variant.display_name));
+        for field in &variant.fields {
+            let mut text = code(&format!("{}: {}", field.name, field.type_name));
+            if let Some(default) = &field.default {
+                text.push_str(&format!(" = {}", code(default)));
+            }
+            if let Some(doc) = &field.doc {
+                text.push_str(&format!(" — {}", escape_html(doc)));
+            }
+            review_row(&mut out, "Field", &text);
+        }
+        let mut stage = variant_meta.stage.label().to_string();
+        if variant.deprecated {
+            stage.push_str(", deprecated");
+        }
+        review_row(&mut out, "Stage", &stage);
+        if let Some(owner) = &variant_meta.owner {
+            review_row(&mut out, "Owner", &escape_html(owner));
+        }
+        if let Some(cfg) = &variant.cfg {
+            review_row(&mut out, "Only with", &code(&format!("cfg({})", cfg)));
+        }
+        if !variant_meta.aliases.is_empty() {
+            review_row(&mut out, "Old names", &variant_meta.aliases.iter().map(|alias| code(alias)).collect::<Vec<_>>().join(", "));
+        }
+        if !variant.target_names.is_empty() {
+            let names: Vec<String> =
+                variant.target_names.iter().map(|(target, name)| format!("{} {}", escape_html(target), code(name))).collect();
+            review_row(&mut out, "Emitted as", &names.join(", "));
+        }
+        if let Some(stable_id) = &variant_meta.stable_id {
+            review_row(&mut out, "Stable ID", &code(stable_id));
+        }
+        out.push_str("</dl>\n");
+
+        if !variant_meta.notes.is_empty() {
+            out.push_str("<ul class=\"notes\">\n");
+            for note in &variant_meta.notes {
+                let author = note.author.as_deref().map(|author| format!(" — {}", escape_html(author))).unwrap_or_default();
+                out.push_str(&format!(
+                    "<li{}>{}{}</li>\n",
+                    if note.resolved { " class=\"resolved\"" } else { "" },
+                    escape_html(&note.text),
+                    author
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</section>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
@@ -0,0 +1,137 @@
+//! C FFI backends: a `#[repr(C)]` Rust enum and the matching C header
+//!
+//! Both files are generated from the same model so they stay in lockstep.
+//! Each exposes the same extern function table:
+//!
+//! - `<enum>_name(raw)`: variant name of a raw `int32_t` as a NUL-terminated
+//!   string, or NULL if it is not a known value
+//! - `<enum>_is_valid(raw)`: whether a raw `int32_t` is a known value
+
+use super::cfg::cfg_to_c;
+use super::comment::{block_comment, line_comment};
+use super::model::{EnumModel, VariantModel};
+use super::{rust_visibility, CodegenTarget};
+use crate::naming::{screaming_snake_case, snake_case};
+use ui_types_common::EnumAsset;
+
+/// Check the enum can cross the C boundary and build its model
+fn ffi_model(asset: &EnumAsset) -> Result<EnumModel, String> {
+    let model = EnumModel::from_asset(asset);
+    let mut problems = Vec::new();
+    for variant in &model.variants {
+        if variant.kind != "unit" {
+            problems.push(format!("{} has a payload, which C enums can't carry", variant.name));
+        }
+        if i32::try_from(variant.value).is_err() {
+            problems.push(format!("{} = {} does not fit in a C int", variant.name, variant.value));
+        }
+    }
+    if problems.is_empty() {
+        Ok(model)
+    } else {
+        Err(problems.join("\n"))
+    }
+}
+
+fn cfg_attribute(variant: &VariantModel, indent: &str) -> String {
+    variant
+        .cfg
+        .as_ref()
+        .map(|cfg| format!("{}#[cfg({})]\n", indent, cfg))
+        .unwrap_or_default()
+}
+
+fn c_string_literal(text: &str) -> String {
+    format!("{}\\0", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Generate the `#[repr(C)]` enum and its `extern "C"` functions
+pub fn generate_ffi_rust(asset: &EnumAsset) -> Result<String, String> {
+    let model = ffi_model(asset)?;
+    let prefix = snake_case(&model.name);
+    let visibility = rust_visibility(&asset.visibility);
+    let mut out = String::new();
+
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(description, "", "/// "));
+    }
+    out.push_str("#[repr(C)]\n#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    out.push_str(&format!("{}enum {} {{\n", visibility, model.name));
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        out.push_str(&cfg_attribute(variant, "    "));
+        out.push_str(&format!("    {} = {},\n", variant.emitted_name(CodegenTarget::FfiRust, &variant.name), variant.value));
+    }
+    out.push_str("}\n\n");
+
+    // Raw values, since C can pass any int where the enum is expected.
+    // One arm per variant so gated variants are only known when compiled in.
+    out.push_str(&format!(
+        "/// Name of the variant with value `raw` as a NUL-terminated string, or\n/// null if `raw` is not the value of a known variant\n#[no_mangle]\npub extern \"C\" fn {}_name(raw: i32) -> *const std::os::raw::c_char {{\n    let name: &'static [u8] = match raw {{\n",
+        prefix
+    ));
+    for variant in &model.variants {
+        out.push_str(&cfg_attribute(variant, "        "));
+        out.push_str(&format!("        {} => b\"{}\",\n", variant.value, c_string_literal(&variant.name)));
+    }
+    out.push_str("        _ => return std::ptr::null(),\n    };\n    name.as_ptr().cast()\n}\n\n");
+
+    out.push_str(&format!(
+        "/// Whether `raw` is the value of a known variant\n#[no_mangle]\npub extern \"C\" fn {}_is_valid(raw: i32) -> bool {{\n    match raw {{\n",
+        prefix
+    ));
+    for variant in &model.variants {
+        out.push_str(&cfg_attribute(variant, "        "));
+        out.push_str(&format!("        {} => true,\n", variant.value));
+    }
+    out.push_str("        _ => false,\n    }\n}\n");
+    Ok(out)
+}
+
+/// Generate the C header declaring the enum and the extern function table
+pub fn generate_c_header(asset: &EnumAsset) -> Result<String, String> {
+    let model = ffi_model(asset)?;
+    let prefix = snake_case(&model.name);
+    let constant_prefix = screaming_snake_case(&model.name);
+    let guard = format!("{}_H", constant_prefix);
+    let mut out = String::new();
+
+    out.push_str(&format!("#ifndef {guard}\n#define {guard}\n\n", guard = guard));
+    out.push_str("#include <stdbool.h>\n#include <stdint.h>\n\n");
+    out.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+
+    if let Some(description) = &model.description {
+        out.push_str(&block_comment(description, ""));
+    }
+    out.push_str(&format!("typedef enum {} {{\n", model.name));
+    for variant in &model.variants {
+        let guard = variant.cfg.as_deref().map(cfg_to_c).transpose()?;
+        if let Some(condition) = &guard {
+            out.push_str(&format!("#if {}\n", condition));
+        }
+        if let Some(doc) = &variant.doc {
+            out.push_str(&block_comment(doc, "    "));
+        }
+        out.push_str(&format!(
+            "    {}_{} = {},\n",
+            constant_prefix,
+            variant.emitted_name(CodegenTarget::CHeader, &screaming_snake_case(&variant.name)),
+            variant.value
+        ));
+        if guard.is_some() {
+            out.push_str("#endif\n");
+        }
+    }
+    out.push_str(&format!("}} {};\n\n", model.name));
+
+    out.push_str("/** Name of the variant with value `raw`, or NULL if it is not a known value */\n");
+    out.push_str(&format!("const char *{}_name(int32_t raw);\n\n", prefix));
+    out.push_str("/** Whether `raw` is the value of a known variant */\n");
+    out.push_str(&format!("bool {}_is_valid(int32_t raw);\n\n", prefix));
+
+    out.push_str("#ifdef __cplusplus\n}\n#endif\n\n");
+    out.push_str(&format!("#endif /* {} */\n", guard));
+    Ok(out)
+}
@@ -0,0 +1,42 @@
+//! GraphQL schema backend
+
+use super::comment::line_comment;
+use super::model::EnumModel;
+use super::CodegenTarget;
+use crate::naming::screaming_snake_case;
+use ui_types_common::EnumAsset;
+
+/// GraphQL block string, escaping the only sequence it can't contain.
+/// Descriptions are markdown in GraphQL, so the text is kept as-is.
+fn description(text: &str, indent: &str) -> String {
+    let text = text.replace("\"\"\"", "\\\"\"\"");
+    format!("{indent}\"\"\"\n{}{indent}\"\"\"\n", line_comment(&text, indent, ""), indent = indent)
+}
+
+/// Generate a GraphQL `enum` type.
+///
+/// Values are written in SCREAMING_SNAKE_CASE as is conventional for GraphQL.
+/// Payloads can't be expressed in a GraphQL enum and are left out.
+pub fn generate_graphql(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = String::new();
+
+    if let Some(desc) = &model.description {
+        out.push_str(&description(desc, ""));
+    }
+    out.push_str(&format!("enum {} {{\n", model.name));
+
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&description(doc, "  "));
+        }
+        out.push_str(&format!("  {}", variant.emitted_name(CodegenTarget::GraphQl, &screaming_snake_case(&variant.name))));
+        if variant.deprecated {
+            out.push_str(" @deprecated");
+        }
+        out.push('\n');
+    }
+
+    out.push_str("}\n");
+    out
+}
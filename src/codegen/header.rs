@@ -0,0 +1,82 @@
+//! `@generated` header and hand-edit guard for generated files
+//!
+//! Text outputs start with a comment naming the source definition and the
+//! generator version, plus a hash of the rest of the file. If the hash no
+//! longer matches, the file was edited by hand and exports refuse to
+//! overwrite it unless forced.
+//!
+//! ```text
+//! // @generated by Pulsar Enum Editor 0.1.4 from FireMode.enum/enum.json
+//! // Do not edit by hand. content-hash: 8c1f2e0a9b7d6c54
+//! ```
+
+use super::CodegenTarget;
+use std::path::Path;
+
+const HASH_MARKER: &str = "content-hash: ";
+
+/// FNV-1a, stable across platforms and releases
+pub(crate) fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Prepend the header to generated contents. Targets without a comment
+/// syntax (binary outputs) are returned unchanged.
+pub fn add_header(target: CodegenTarget, definition_path: &Path, body: Vec<u8>) -> Vec<u8> {
+    let container = crate::storage::asset_container_dir(definition_path);
+    let source = definition_path.strip_prefix(&container).unwrap_or(definition_path);
+    add_header_from(target, &source.display().to_string().replace('\\', "/"), body)
+}
+
+/// Prepend the header naming `source` as what the contents were generated
+/// from, e.g. a folder of enums joined into one file
+pub(crate) fn add_header_from(target: CodegenTarget, source: &str, body: Vec<u8>) -> Vec<u8> {
+    let Some((open, close)) = target.comment_syntax() else {
+        return body;
+    };
+
+    // Match the body's line endings
+    let newline = if body.windows(2).any(|w| w == b"\r\n") { "\r\n" } else { "\n" };
+    let header = format!(
+        "{open}@generated by Pulsar Enum Editor {} from {}{close}{newline}{open}Do not edit by hand. {}{:016x}{close}{newline}",
+        env!("CARGO_PKG_VERSION"),
+        source,
+        HASH_MARKER,
+        content_hash(&body),
+        open = open,
+        close = close,
+        newline = newline,
+    );
+    let mut out = header.into_bytes();
+    out.extend_from_slice(&body);
+    out
+}
+
+/// True if the file has a generation header whose hash doesn't match its
+/// contents. Files without a header are not considered generated.
+pub fn is_manually_edited(contents: &[u8]) -> bool {
+    let Ok(text) = std::str::from_utf8(contents) else {
+        return false;
+    };
+
+    let mut offset = 0;
+    for line in text.split_inclusive('\n').take(2) {
+        offset += line.len();
+        if let Some(start) = line.find(HASH_MARKER) {
+            let hex: String = line[start + HASH_MARKER.len()..]
+                .chars()
+                .take_while(char::is_ascii_hexdigit)
+                .collect();
+            return match u64::from_str_radix(&hex, 16) {
+                Ok(expected) => content_hash(text[offset..].as_bytes()) != expected,
+                Err(_) => false,
+            };
+        }
+    }
+    false
+}
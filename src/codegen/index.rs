@@ -0,0 +1,76 @@
+//! `mod.rs` declaring every Rust file of an Export All run and re-exporting
+//! its enums, so a crate can mount the output folder as one module and
+//! `use generated::*`
+//!
+//! Folders are declared as inline modules, so the whole tree lives in the one
+//! file. A folder that also has a file of its own (the `per-module` layout
+//! writes `combat.rs` next to `combat/`) includes it into its inline module.
+
+use crate::validation::is_identifier;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Default)]
+struct Module {
+    /// Path of the module's own file relative to the output folder
+    file: Option<String>,
+    children: BTreeMap<String, Module>,
+}
+
+/// Module path of a Rust file relative to the output folder, e.g.
+/// `["combat", "damage_kind"]`, if every part is an identifier
+fn module_of(dir: &Path, path: &Path) -> Option<Vec<String>> {
+    let relative = path.strip_prefix(dir).ok()?;
+    let mut segments: Vec<String> = relative.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect();
+    let file = segments.pop()?;
+    segments.push(file.strip_suffix(".rs")?.to_string());
+    segments.iter().all(|segment| is_identifier(segment) && segment != "mod").then_some(segments)
+}
+
+fn render(module: &Module, indent: usize, out: &mut String) {
+    let pad = "    ".repeat(indent);
+    for (name, child) in &module.children {
+        if child.children.is_empty() {
+            out.push_str(&format!("{}pub mod {};\n", pad, name));
+            continue;
+        }
+        out.push_str(&format!("{}pub mod {} {{\n", pad, name));
+        if let Some(file) = &child.file {
+            out.push_str(&format!("{}    include!(\"{}\");\n\n", pad, file));
+        }
+        render(child, indent + 1, out);
+        out.push_str(&format!("{}}}\n", pad));
+    }
+}
+
+/// Contents of `<dir>/mod.rs` for the Rust files written under `dir` and the
+/// enums each holds; `None` if there are none. Enums are re-exported unless
+/// another module has one of the same name.
+pub(super) fn rust_index(dir: &Path, files: &[(PathBuf, Vec<String>)]) -> Option<String> {
+    let mut root = Module::default();
+    let mut exports: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (path, enums) in files {
+        let Some(segments) = module_of(dir, path) else {
+            continue;
+        };
+        let module = segments.iter().fold(&mut root, |module, segment| module.children.entry(segment.clone()).or_default());
+        module.file = Some(format!("{}.rs", segments.join("/")));
+        for name in enums {
+            exports.entry(name.clone()).or_default().push(segments.join("::"));
+        }
+    }
+    if root.children.is_empty() {
+        return None;
+    }
+
+    let mut out = String::new();
+    render(&root, 0, &mut out);
+    out.push('\n');
+    for (name, modules) in &exports {
+        match modules.as_slice() {
+            [module] => out.push_str(&format!("pub use {}::{};\n", module, name)),
+            modules => out.push_str(&format!("// `{}` is declared in {}, so it isn't re-exported\n", name, modules.join(", "))),
+        }
+    }
+    Some(out)
+}
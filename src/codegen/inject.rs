@@ -0,0 +1,91 @@
+//! Partial generation into hand-written files
+//!
+//! Targets that inject (see [`CodegenTarget::injects`]) don't own their output
+//! file. Their output replaces a marked region of an existing file, which is
+//! appended if the file has none yet:
+//!
+//! ```text
+//! // @enum-editor:begin FireMode content-hash: 8c1f2e0a9b7d6c54
+//! impl std::fmt::Display for FireMode { ... }
+//! // @enum-editor:end FireMode
+//! ```
+//!
+//! The hash covers the region's contents, so hand edits inside it are caught
+//! like edits to a fully generated file.
+//!
+//! [`CodegenTarget::injects`]: super::CodegenTarget::injects
+
+use super::header::content_hash;
+
+const BEGIN: &str = "// @enum-editor:begin ";
+const END: &str = "// @enum-editor:end ";
+const HASH_MARKER: &str = "content-hash: ";
+
+/// Byte range of the region's begin line and of its end line
+fn find_region(text: &str, enum_name: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut offset = 0;
+    let mut begin = None;
+    for line in text.split_inclusive('\n') {
+        let range = (offset, offset + line.len());
+        offset += line.len();
+        let trimmed = line.trim();
+        match begin {
+            None if trimmed.strip_prefix(BEGIN).and_then(|rest| rest.split_whitespace().next()) == Some(enum_name) => {
+                begin = Some(range);
+            }
+            Some(begin) if trimmed == format!("{}{}", END, enum_name) => return Some((begin, range)),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `existing` with the enum's region replaced by `body`, or with a new region
+/// appended
+pub fn inject(existing: &str, enum_name: &str, body: &str) -> String {
+    // Match the file's line endings
+    let newline = if existing.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut body = body.replace("\r\n", "\n");
+    if !body.ends_with('\n') {
+        body.push('\n');
+    }
+    let body = body.replace('\n', newline);
+    let region = format!(
+        "{}{} {}{:016x}{newline}{}{}{}{newline}",
+        BEGIN,
+        enum_name,
+        HASH_MARKER,
+        content_hash(body.as_bytes()),
+        body,
+        END,
+        enum_name,
+        newline = newline,
+    );
+    match find_region(existing, enum_name) {
+        Some(((begin, _), (_, end))) => format!("{}{}{}", &existing[..begin], region, &existing[end..]),
+        None if existing.is_empty() => region,
+        None => {
+            let separator = if existing.ends_with('\n') { newline.to_string() } else { newline.repeat(2) };
+            format!("{}{}{}", existing, separator, region)
+        }
+    }
+}
+
+/// True if the enum's region no longer matches the hash it was written with
+pub fn is_region_edited(existing: &[u8], enum_name: &str) -> bool {
+    let Ok(text) = std::str::from_utf8(existing) else {
+        return false;
+    };
+    let Some(((begin_start, begin_end), (end_start, _))) = find_region(text, enum_name) else {
+        return false;
+    };
+    let begin_line = &text[begin_start..begin_end];
+    let Some(start) = begin_line.find(HASH_MARKER) else {
+        return false;
+    };
+    let hex: String = begin_line[start + HASH_MARKER.len()..].chars().take_while(char::is_ascii_hexdigit).collect();
+    match u64::from_str_radix(&hex, 16) {
+        Ok(expected) => content_hash(text[begin_end..end_start].as_bytes()) != expected,
+        Err(_) => false,
+    }
+}
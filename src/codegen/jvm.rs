@@ -0,0 +1,131 @@
+//! Java and Kotlin enum backends
+//!
+//! Both carry the per-variant metadata (value, display name, color) as
+//! constructor fields and offer a `fromValue` lookup.
+
+use super::comment::block_comment;
+use super::model::{EnumModel, VariantModel};
+use super::CodegenTarget;
+use crate::markdown::to_html;
+use crate::naming::screaming_snake_case;
+use ui_types_common::EnumAsset;
+
+fn string_literal(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Javadoc is HTML rather than markdown
+fn javadoc(text: &str, indent: &str) -> String {
+    block_comment(&to_html(text), indent)
+}
+
+/// Kotlin strings also treat `$` as the start of a template
+fn kotlin_string_literal(text: &str) -> String {
+    string_literal(text).replace('$', "\\$")
+}
+
+fn constructor_args(variant: &VariantModel, literal: fn(&str) -> String) -> String {
+    format!(
+        "{}L, {}, {}",
+        variant.value,
+        literal(&variant.display_name),
+        variant.color.as_deref().map(literal).unwrap_or_else(|| "null".to_string())
+    )
+}
+
+/// Generate a Java enum
+pub fn generate_java(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = String::new();
+
+    if let Some(description) = &model.description {
+        out.push_str(&javadoc(description, ""));
+    }
+    out.push_str(&format!("public enum {} {{\n", model.name));
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&javadoc(doc, "    "));
+        }
+        if variant.deprecated {
+            out.push_str("    @Deprecated\n");
+        }
+        let name = variant.emitted_name(CodegenTarget::Java, &screaming_snake_case(&variant.name));
+        out.push_str(&format!("    {}({}),\n", name, constructor_args(variant, string_literal)));
+    }
+    out.push_str("    ;\n\n");
+
+    out.push_str(&format!(
+        "    private final long value;
+    private final String displayName;
+    private final String color;
+
+    {name}(long value, String displayName, String color) {{
+        this.value = value;
+        this.displayName = displayName;
+        this.color = color;
+    }}
+
+    public long getValue() {{
+        return value;
+    }}
+
+    public String getDisplayName() {{
+        return displayName;
+    }}
+
+    /** Color as {{@code #RRGGBB}} or {{@code #RRGGBBAA}}, or null */
+    public String getColor() {{
+        return color;
+    }}
+
+    /** The variant with the given value, or null */
+    public static {name} fromValue(long value) {{
+        for ({name} variant : values()) {{
+            if (variant.value == value) {{
+                return variant;
+            }}
+        }}
+        return null;
+    }}
+}}
+",
+        name = model.name
+    ));
+    out
+}
+
+/// Generate a Kotlin enum class
+pub fn generate_kotlin(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = String::new();
+
+    if let Some(description) = &model.description {
+        out.push_str(&block_comment(description, ""));
+    }
+    out.push_str(&format!(
+        "enum class {}(val value: Long, val displayName: String, val color: String?) {{\n",
+        model.name
+    ));
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&block_comment(doc, "    "));
+        }
+        if variant.deprecated {
+            out.push_str("    @Deprecated(\"No longer used\")\n");
+        }
+        let name = variant.emitted_name(CodegenTarget::Kotlin, &screaming_snake_case(&variant.name));
+        out.push_str(&format!("    {}({}),\n", name, constructor_args(variant, kotlin_string_literal)));
+    }
+    out.push_str(&format!(
+        "    ;
+
+    companion object {{
+        /** The variant with the given value, or null */
+        fun fromValue(value: Long): {}? = values().firstOrNull {{ it.value == value }}
+    }}
+}}
+",
+        model.name
+    ));
+    out
+}
@@ -0,0 +1,109 @@
+//! Reserved words of the languages variants are emitted into
+//!
+//! Names are checked exactly as each target writes them: the variant's name
+//! override if it has one, otherwise the name after the target's case
+//! conversion. Every checked language is case-sensitive, so keywords are
+//! matched with their case. Targets that emit SCREAMING_SNAKE_CASE (Python,
+//! Java, Kotlin, GraphQL) can't produce a lowercase keyword or `None` on
+//! their own; for them only an override like `python = None` collides.
+//! Swift and WIT escape keywords themselves, and the C header prefixes every
+//! name, so they aren't checked. C#, TypeScript and C++ have no target here,
+//! so they aren't checked either.
+
+use super::CodegenTarget;
+use crate::metadata::EnumMeta;
+use crate::naming::screaming_snake_case;
+use ui_types_common::EnumAsset;
+
+const RUST: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do",
+    "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static",
+    "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+const PYTHON: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def",
+    "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda",
+    "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+const JAVA: &[&str] = &[
+    "_", "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "false", "final", "finally", "float",
+    "for", "goto", "if", "implements", "import", "instanceof", "int", "interface", "long", "native", "new",
+    "null", "package", "private", "protected", "public", "return", "short", "static", "strictfp", "super",
+    "switch", "synchronized", "this", "throw", "throws", "transient", "true", "try", "void", "volatile",
+    "while",
+];
+
+const KOTLIN: &[&str] = &[
+    "as", "break", "class", "continue", "do", "else", "false", "for", "fun", "if", "in", "interface", "is",
+    "null", "object", "package", "return", "super", "this", "throw", "true", "try", "typealias", "typeof",
+    "val", "var", "when", "while",
+];
+
+const GRAPHQL: &[&str] = &["true", "false", "null"];
+
+const FLATBUFFERS: &[&str] = &[
+    "attribute", "enum", "false", "file_extension", "file_identifier", "include", "namespace", "root_type",
+    "rpc_service", "struct", "table", "true", "union",
+];
+
+/// Identifier a target writes for a variant without an override, as the
+/// generators compute it, and the words it can't be; `None` for targets
+/// that can't collide
+fn rules(target: CodegenTarget) -> Option<(fn(&str) -> String, &'static [&'static str])> {
+    let verbatim: fn(&str) -> String = str::to_string;
+    let screaming: fn(&str) -> String = screaming_snake_case;
+    match target {
+        CodegenTarget::Rust | CodegenTarget::FfiRust => Some((verbatim, RUST)),
+        CodegenTarget::Python => Some((screaming, PYTHON)),
+        CodegenTarget::Java => Some((screaming, JAVA)),
+        CodegenTarget::Kotlin => Some((screaming, KOTLIN)),
+        CodegenTarget::GraphQl => Some((screaming, GRAPHQL)),
+        CodegenTarget::FlatBuffers => Some((verbatim, FLATBUFFERS)),
+        _ => None,
+    }
+}
+
+/// A variant emitted as a reserved word of a target's language
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeywordCollision {
+    /// Index of the variant
+    pub variant: usize,
+    pub target: CodegenTarget,
+    /// Identifier the target emits
+    pub identifier: String,
+    /// Name override for the target that avoids the keyword
+    pub suggestion: String,
+}
+
+/// Variants whose emitted identifier is a keyword, in the Rust output (which
+/// is always checked) or in an enabled target
+pub fn keyword_collisions(asset: &EnumAsset, meta: &EnumMeta) -> Vec<KeywordCollision> {
+    let targets: Vec<CodegenTarget> = CodegenTarget::ALL
+        .into_iter()
+        .filter(|target| *target == CodegenTarget::Rust || meta.target(target.id()).enabled)
+        .collect();
+    let mut collisions = Vec::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        let variant_meta = meta.variant(&variant.name);
+        for target in &targets {
+            let Some((usual, reserved)) = rules(*target) else {
+                continue;
+            };
+            let identifier = variant_meta.emitted_name(*target, &usual(&variant.name));
+            if reserved.contains(&identifier.as_str()) {
+                collisions.push(KeywordCollision {
+                    variant: index,
+                    target: *target,
+                    suggestion: format!("{}_", identifier),
+                    identifier,
+                });
+            }
+        }
+    }
+    collisions
+}
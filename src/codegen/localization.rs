@@ -0,0 +1,46 @@
+//! Fluent (`.ftl`) localization backend
+//!
+//! One message per variant holding its display name, as the source strings
+//! translators start from. Message IDs are the enum and variant names in
+//! kebab-case, e.g. `damage-kind-fire-ball`.
+
+use super::model::EnumModel;
+use crate::naming::kebab_case;
+use ui_types_common::EnumAsset;
+
+/// A message value with Fluent's special characters escaped
+fn fluent_value(text: &str) -> String {
+    let escaped: String = text
+        .chars()
+        .map(|c| match c {
+            '{' => "{\"{\"}".to_string(),
+            '}' => "{\"}\"}".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+    // A value can't start with a character Fluent reads as syntax
+    match escaped.chars().next() {
+        Some('[' | '*' | '.') => format!("{{\"{}\"}}{}", &escaped[..1], &escaped[1..]),
+        _ => escaped,
+    }
+}
+
+/// Generate a Fluent resource with the display name of every variant
+pub fn generate_fluent(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let prefix = kebab_case(&model.name);
+    let mut out = format!("## {}\n", model.display_name);
+    for variant in &model.variants {
+        out.push('\n');
+        if let Some(summary) = variant.doc.as_deref().and_then(|doc| doc.lines().next()).filter(|line| !line.trim().is_empty()) {
+            out.push_str(&format!("# {}\n", summary.trim()));
+        }
+        out.push_str(&format!(
+            "{}-{} = {}\n",
+            prefix,
+            kebab_case(&variant.name),
+            fluent_value(variant.display_name.lines().next().unwrap_or_default().trim())
+        ));
+    }
+    out
+}
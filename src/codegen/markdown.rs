@@ -0,0 +1,99 @@
+//! Markdown table backend, for design documents and wikis
+
+use super::model::EnumModel;
+use ui_types_common::EnumAsset;
+
+/// Columns that can be included in the table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkdownColumn {
+    Name,
+    Value,
+    DisplayName,
+    Docs,
+    Color,
+}
+
+impl MarkdownColumn {
+    pub const ALL: [MarkdownColumn; 5] = [
+        MarkdownColumn::Name,
+        MarkdownColumn::Value,
+        MarkdownColumn::DisplayName,
+        MarkdownColumn::Docs,
+        MarkdownColumn::Color,
+    ];
+
+    /// Columns used when exporting to a file
+    pub const DEFAULT: [MarkdownColumn; 3] = [MarkdownColumn::Name, MarkdownColumn::Value, MarkdownColumn::Docs];
+
+    /// Identifier used in the target options
+    pub fn id(&self) -> &'static str {
+        match self {
+            MarkdownColumn::Name => "name",
+            MarkdownColumn::Value => "value",
+            MarkdownColumn::DisplayName => "display_name",
+            MarkdownColumn::Docs => "docs",
+            MarkdownColumn::Color => "color",
+        }
+    }
+
+    /// Columns from the `columns` option of the target, or the defaults
+    pub fn from_options(options: &serde_json::Map<String, serde_json::Value>) -> Vec<MarkdownColumn> {
+        match options.get("columns").and_then(|v| v.as_array()) {
+            Some(ids) => MarkdownColumn::ALL
+                .into_iter()
+                .filter(|column| ids.iter().any(|id| id.as_str() == Some(column.id())))
+                .collect(),
+            None => MarkdownColumn::DEFAULT.to_vec(),
+        }
+    }
+
+    /// Store the columns as the `columns` option of the target
+    pub fn write_options(columns: &[MarkdownColumn], options: &mut serde_json::Map<String, serde_json::Value>) {
+        let ids = columns.iter().map(|c| serde_json::Value::from(c.id())).collect();
+        options.insert("columns".to_string(), serde_json::Value::Array(ids));
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MarkdownColumn::Name => "Name",
+            MarkdownColumn::Value => "Value",
+            MarkdownColumn::DisplayName => "Display Name",
+            MarkdownColumn::Docs => "Docs",
+            MarkdownColumn::Color => "Color",
+        }
+    }
+}
+
+/// Make text safe to put in a table cell
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Generate a table of the variants with the given columns, in the given order
+pub fn generate_markdown_table(asset: &EnumAsset, columns: &[MarkdownColumn]) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = format!("### {}\n\n", model.display_name);
+    if let Some(description) = &model.description {
+        out.push_str(&format!("{}\n\n", description));
+    }
+
+    let header: Vec<_> = columns.iter().map(|c| c.label()).collect();
+    out.push_str(&format!("| {} |\n", header.join(" | ")));
+    out.push_str(&format!("|{}\n", " --- |".repeat(columns.len())));
+
+    for variant in &model.variants {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| match column {
+                MarkdownColumn::Name => format!("`{}`", variant.name),
+                MarkdownColumn::Value => variant.value.to_string(),
+                MarkdownColumn::DisplayName => cell(&variant.display_name),
+                MarkdownColumn::Docs => cell(variant.doc.as_deref().unwrap_or("")),
+                MarkdownColumn::Color => variant.color.clone().unwrap_or_default(),
+            })
+            .collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+
+    out
+}
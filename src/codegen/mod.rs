@@ -0,0 +1,461 @@
+//! Code generation and export backends
+//!
+//! Every backend turns an `EnumAsset` (plus the editor metadata stored in its
+//! `meta` object) into the contents of a single output file. Besides the
+//! built-in [`CodegenTarget`]s, users can add their own targets as Handlebars
+//! templates (see [`TemplateTarget`]).
+
+mod batch;
+mod binary;
+mod cfg;
+mod comment;
+mod config;
+mod diagram;
+mod docs;
+mod ffi;
+mod graphql;
+mod header;
+mod index;
+mod inject;
+mod jvm;
+mod keywords;
+mod localization;
+mod markdown;
+mod model;
+mod python;
+mod rust;
+mod schema;
+mod sql;
+mod style;
+mod swift;
+mod template;
+mod wit;
+
+pub use batch::{module_path, plan_all, plan_export, FileChange, PlannedOutput, TargetReport};
+pub use binary::generate_binary;
+pub use cfg::{cfg_to_c, validate_cfg};
+pub use config::{generate_config_loader, generate_config_samples};
+pub use diagram::{generate_dot, generate_mermaid};
+pub use docs::{generate_html, generate_mdbook, generate_review, payload_signature};
+pub use ffi::{generate_c_header, generate_ffi_rust};
+pub use graphql::generate_graphql;
+pub use header::{add_header, is_manually_edited};
+pub use jvm::{generate_java, generate_kotlin};
+pub use keywords::{keyword_collisions, KeywordCollision};
+pub use localization::generate_fluent;
+pub use markdown::{generate_markdown_table, MarkdownColumn};
+pub use model::{EnumModel, FieldModel, VariantModel};
+pub use python::generate_python;
+pub use inject::{inject, is_region_edited};
+pub use rust::{generate_rust, generate_rust_impls, has_discriminants, generate_rust_mapped, generate_rust_variant, generate_rust_variants, SourceMap};
+pub use schema::{generate_capnp, generate_flatbuffers};
+pub use sql::{generate_sql, SqlStyle};
+pub use swift::generate_swift;
+pub use template::{discover as discover_templates, export_template, TemplateTarget};
+pub use wit::generate_wit;
+
+use crate::error::{EnumEditorError, Result};
+use crate::metadata::{variant_values, BuildProfile, EnumMeta};
+use std::path::{Path, PathBuf};
+use ui_types_common::{EnumAsset, TypeRef, Visibility};
+
+/// Output formats the editor can generate from an enum definition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CodegenTarget {
+    Rust,
+    Binary,
+    Markdown,
+    Html,
+    MdBook,
+    GraphQl,
+    SqlCheck,
+    SqlLookup,
+    FlatBuffers,
+    CapnProto,
+    Python,
+    Java,
+    Kotlin,
+    Swift,
+    Wit,
+    FfiRust,
+    CHeader,
+    RustImpls,
+    ConfigSamples,
+    ConfigLoader,
+    Fluent,
+    Mermaid,
+    Dot,
+}
+
+impl CodegenTarget {
+    pub const ALL: [CodegenTarget; 23] = [
+        CodegenTarget::Rust,
+        CodegenTarget::Binary,
+        CodegenTarget::Markdown,
+        CodegenTarget::Html,
+        CodegenTarget::MdBook,
+        CodegenTarget::GraphQl,
+        CodegenTarget::SqlCheck,
+        CodegenTarget::SqlLookup,
+        CodegenTarget::FlatBuffers,
+        CodegenTarget::CapnProto,
+        CodegenTarget::Python,
+        CodegenTarget::Java,
+        CodegenTarget::Kotlin,
+        CodegenTarget::Swift,
+        CodegenTarget::Wit,
+        CodegenTarget::FfiRust,
+        CodegenTarget::CHeader,
+        CodegenTarget::RustImpls,
+        CodegenTarget::ConfigSamples,
+        CodegenTarget::ConfigLoader,
+        CodegenTarget::Fluent,
+        CodegenTarget::Mermaid,
+        CodegenTarget::Dot,
+    ];
+
+    /// Stable identifier used in settings and enum metadata
+    pub fn id(&self) -> &'static str {
+        match self {
+            CodegenTarget::Rust => "rust",
+            CodegenTarget::Binary => "binary",
+            CodegenTarget::Markdown => "markdown",
+            CodegenTarget::Html => "html",
+            CodegenTarget::MdBook => "mdbook",
+            CodegenTarget::GraphQl => "graphql",
+            CodegenTarget::SqlCheck => "sql-check",
+            CodegenTarget::SqlLookup => "sql-lookup",
+            CodegenTarget::FlatBuffers => "flatbuffers",
+            CodegenTarget::CapnProto => "capnp",
+            CodegenTarget::Python => "python",
+            CodegenTarget::Java => "java",
+            CodegenTarget::Kotlin => "kotlin",
+            CodegenTarget::Swift => "swift",
+            CodegenTarget::Wit => "wit",
+            CodegenTarget::FfiRust => "ffi-rust",
+            CodegenTarget::CHeader => "c-header",
+            CodegenTarget::RustImpls => "rust-impls",
+            CodegenTarget::ConfigSamples => "config-samples",
+            CodegenTarget::ConfigLoader => "config-loader",
+            CodegenTarget::Fluent => "fluent",
+            CodegenTarget::Mermaid => "mermaid",
+            CodegenTarget::Dot => "dot",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CodegenTarget::Rust => "Rust",
+            CodegenTarget::Binary => "Binary",
+            CodegenTarget::Markdown => "Markdown",
+            CodegenTarget::Html => "HTML Docs",
+            CodegenTarget::MdBook => "mdBook",
+            CodegenTarget::GraphQl => "GraphQL",
+            CodegenTarget::SqlCheck => "SQL Check",
+            CodegenTarget::SqlLookup => "SQL Lookup Table",
+            CodegenTarget::FlatBuffers => "FlatBuffers",
+            CodegenTarget::CapnProto => "Cap'n Proto",
+            CodegenTarget::Python => "Python",
+            CodegenTarget::Java => "Java",
+            CodegenTarget::Kotlin => "Kotlin",
+            CodegenTarget::Swift => "Swift",
+            CodegenTarget::Wit => "WIT",
+            CodegenTarget::FfiRust => "Rust FFI",
+            CodegenTarget::CHeader => "C Header",
+            CodegenTarget::RustImpls => "Rust Impls (injected)",
+            CodegenTarget::ConfigSamples => "Config Samples",
+            CodegenTarget::ConfigLoader => "Config Loader",
+            CodegenTarget::Fluent => "Localization (Fluent)",
+            CodegenTarget::Mermaid => "Diagram (Mermaid)",
+            CodegenTarget::Dot => "Diagram (Graphviz)",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            CodegenTarget::Rust => "rs",
+            CodegenTarget::Binary => "enumbin",
+            CodegenTarget::Markdown => "md",
+            CodegenTarget::Html => "html",
+            // Keeps the chapter from clobbering the Markdown table export
+            CodegenTarget::MdBook => "docs.md",
+            CodegenTarget::GraphQl => "graphql",
+            CodegenTarget::SqlCheck => "check.sql",
+            CodegenTarget::SqlLookup => "sql",
+            CodegenTarget::FlatBuffers => "fbs",
+            CodegenTarget::CapnProto => "capnp",
+            CodegenTarget::Python => "py",
+            CodegenTarget::Java => "java",
+            CodegenTarget::Kotlin => "kt",
+            CodegenTarget::Swift => "swift",
+            CodegenTarget::Wit => "wit",
+            CodegenTarget::FfiRust => "ffi.rs",
+            CodegenTarget::CHeader => "h",
+            CodegenTarget::RustImpls => "impls.rs",
+            CodegenTarget::ConfigSamples => "config.md",
+            CodegenTarget::ConfigLoader => "config.rs",
+            CodegenTarget::Fluent => "ftl",
+            CodegenTarget::Mermaid => "mmd",
+            CodegenTarget::Dot => "dot",
+        }
+    }
+
+    /// Line comment delimiters of the output format, if it has comments
+    pub fn comment_syntax(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            CodegenTarget::Binary => None,
+            CodegenTarget::Markdown | CodegenTarget::Html | CodegenTarget::MdBook | CodegenTarget::ConfigSamples => {
+                Some(("<!-- ", " -->"))
+            }
+            CodegenTarget::GraphQl | CodegenTarget::Python | CodegenTarget::CapnProto | CodegenTarget::Fluent => {
+                Some(("# ", ""))
+            }
+            CodegenTarget::SqlCheck | CodegenTarget::SqlLookup => Some(("-- ", "")),
+            CodegenTarget::Mermaid => Some(("%% ", "")),
+            CodegenTarget::Rust
+            | CodegenTarget::FlatBuffers
+            | CodegenTarget::Java
+            | CodegenTarget::Kotlin
+            | CodegenTarget::Swift
+            | CodegenTarget::Wit
+            | CodegenTarget::FfiRust
+            | CodegenTarget::CHeader
+            | CodegenTarget::RustImpls
+            | CodegenTarget::ConfigLoader
+            | CodegenTarget::Dot => Some(("// ", "")),
+        }
+    }
+
+    /// Whether the output goes into a marked region of an existing,
+    /// otherwise hand-written file instead of a file of its own
+    pub fn injects(&self) -> bool {
+        matches!(self, CodegenTarget::RustImpls)
+    }
+
+    /// Whether the outputs of several enums still make a valid file when
+    /// joined one after the other
+    pub fn joins(&self) -> bool {
+        matches!(
+            self,
+            CodegenTarget::Rust
+                | CodegenTarget::Markdown
+                | CodegenTarget::GraphQl
+                | CodegenTarget::SqlCheck
+                | CodegenTarget::SqlLookup
+                | CodegenTarget::Python
+                | CodegenTarget::CHeader
+                | CodegenTarget::Fluent
+        )
+    }
+
+    /// File name of the enum's output when exported into a module tree:
+    /// snake_case where the file name is the module name, as in Rust and
+    /// Python, otherwise the enum name
+    pub fn module_file_name(&self, asset: &EnumAsset) -> String {
+        let stem = match self {
+            CodegenTarget::Rust | CodegenTarget::FfiRust | CodegenTarget::ConfigLoader | CodegenTarget::Python => {
+                crate::naming::snake_case(&asset.name)
+            }
+            _ => asset.name.clone(),
+        };
+        format!("{}.{}", stem, self.file_extension())
+    }
+
+    /// Generate the output file as written to disk, with its generation header.
+    /// Injecting targets return the existing output file with their region
+    /// replaced.
+    pub fn generate_file(&self, definition_path: &Path, asset: &EnumAsset) -> Result<Vec<u8>, String> {
+        if !self.injects() {
+            return self.generate(asset).map(|body| add_header(*self, definition_path, body));
+        }
+        let path = self.output_path(definition_path, asset);
+        let existing = std::fs::read_to_string(&path)
+            .map_err(|e| format!("{} must be an existing source file to generate into: {}", path.display(), e))?;
+        let body = String::from_utf8_lossy(&self.generate(asset)?).into_owned();
+        Ok(inject(&existing, &asset.name, &body).into_bytes())
+    }
+
+    /// True if the file on disk was edited by hand since this target wrote it
+    pub(crate) fn is_hand_edited(&self, asset: &EnumAsset, existing: &[u8]) -> bool {
+        if self.injects() {
+            is_region_edited(existing, &asset.name)
+        } else {
+            is_manually_edited(existing)
+        }
+    }
+
+    /// Text shown in the code preview: the output for the variants `profile`
+    /// includes, without the file header. Binary output has no text form.
+    pub fn preview(&self, asset: &EnumAsset, profile: BuildProfile) -> Result<String, String> {
+        if *self == CodegenTarget::Binary {
+            return Err("Binary output can't be previewed as text".to_string());
+        }
+        let body = self.generate_body(&staged(asset, profile))?;
+        String::from_utf8(body).map_err(|e| e.to_string())
+    }
+
+    /// Generate the contents of the output file for the enum's export profile,
+    /// laid out in the enum's codegen style
+    pub fn generate(&self, asset: &EnumAsset) -> Result<Vec<u8>, String> {
+        let meta = EnumMeta::from_asset(asset);
+        let body = self.generate_body(&staged(asset, meta.profile))?;
+        // Indentation is meaningful in documents, so only source files are restyled
+        let is_document = matches!(
+            self,
+            CodegenTarget::Binary
+                | CodegenTarget::Markdown
+                | CodegenTarget::Html
+                | CodegenTarget::MdBook
+                | CodegenTarget::ConfigSamples
+                | CodegenTarget::Fluent
+                | CodegenTarget::Mermaid
+                | CodegenTarget::Dot
+        );
+        if is_document || meta.style.is_default() {
+            return Ok(body);
+        }
+        match String::from_utf8(body) {
+            Ok(text) => Ok(style::apply(&text, &meta.style).into_bytes()),
+            Err(e) => Ok(e.into_bytes()),
+        }
+    }
+
+    fn generate_body(&self, asset: &EnumAsset) -> Result<Vec<u8>, String> {
+        match self {
+            CodegenTarget::Rust => Ok(generate_rust(asset).into_bytes()),
+            CodegenTarget::Binary => generate_binary(asset),
+            CodegenTarget::Markdown => {
+                let options = EnumMeta::from_asset(asset).target(self.id()).options;
+                Ok(generate_markdown_table(asset, &MarkdownColumn::from_options(&options)).into_bytes())
+            }
+            CodegenTarget::Html => Ok(generate_html(asset).into_bytes()),
+            CodegenTarget::MdBook => Ok(generate_mdbook(asset).into_bytes()),
+            CodegenTarget::GraphQl => Ok(generate_graphql(asset).into_bytes()),
+            CodegenTarget::SqlCheck => Ok(generate_sql(asset, SqlStyle::Check).into_bytes()),
+            CodegenTarget::SqlLookup => Ok(generate_sql(asset, SqlStyle::LookupTable).into_bytes()),
+            CodegenTarget::FlatBuffers => generate_flatbuffers(asset).map(String::into_bytes),
+            CodegenTarget::CapnProto => Ok(generate_capnp(asset).into_bytes()),
+            CodegenTarget::Python => Ok(generate_python(asset).into_bytes()),
+            CodegenTarget::Java => Ok(generate_java(asset).into_bytes()),
+            CodegenTarget::Kotlin => Ok(generate_kotlin(asset).into_bytes()),
+            CodegenTarget::Swift => generate_swift(asset).map(String::into_bytes),
+            CodegenTarget::Wit => Ok(generate_wit(asset).into_bytes()),
+            CodegenTarget::FfiRust => generate_ffi_rust(asset).map(String::into_bytes),
+            CodegenTarget::CHeader => generate_c_header(asset).map(String::into_bytes),
+            CodegenTarget::RustImpls => Ok(generate_rust_impls(asset).into_bytes()),
+            CodegenTarget::ConfigSamples => Ok(generate_config_samples(asset).into_bytes()),
+            CodegenTarget::ConfigLoader => Ok(generate_config_loader(asset).into_bytes()),
+            CodegenTarget::Fluent => Ok(generate_fluent(asset).into_bytes()),
+            CodegenTarget::Mermaid => Ok(generate_mermaid(asset).into_bytes()),
+            CodegenTarget::Dot => Ok(generate_dot(asset).into_bytes()),
+        }
+    }
+
+    /// File name used when no output path is configured
+    pub fn default_file_name(&self, asset: &EnumAsset) -> String {
+        format!("{}.{}", asset.name, self.file_extension())
+    }
+
+    /// Default file the target is written to, next to the enum asset
+    pub fn default_output_path(&self, definition_path: &Path, asset: &EnumAsset) -> PathBuf {
+        let dir = crate::storage::asset_container_dir(definition_path);
+        dir.join(self.default_file_name(asset))
+    }
+
+    /// Configured output path, resolved against the enum's folder, or the default
+    pub fn output_path(&self, definition_path: &Path, asset: &EnumAsset) -> PathBuf {
+        match EnumMeta::from_asset(asset).target(self.id()).output {
+            Some(output) => crate::storage::asset_container_dir(definition_path).join(output),
+            None => self.default_output_path(definition_path, asset),
+        }
+    }
+
+    /// Targets enabled in the enum's codegen settings
+    pub fn enabled(asset: &EnumAsset) -> Vec<CodegenTarget> {
+        let meta = EnumMeta::from_asset(asset);
+        CodegenTarget::ALL.into_iter().filter(|t| meta.target(t.id()).enabled).collect()
+    }
+}
+
+/// Copy of the asset with only the variants the profile includes.
+///
+/// Values of the remaining variants are pinned where needed so that leaving
+/// a variant out never shifts the implicit values after it.
+pub fn staged(asset: &EnumAsset, profile: BuildProfile) -> EnumAsset {
+    let mut meta = EnumMeta::from_asset(asset);
+    let values = variant_values(asset, &meta);
+    let mut staged = asset.clone();
+    if asset.variants.iter().all(|v| profile.includes(meta.variant(&v.name).stage)) {
+        return staged;
+    }
+
+    staged.variants.clear();
+    let mut next = 0i64;
+    for (variant, value) in asset.variants.iter().zip(values) {
+        let mut variant_meta = meta.variant(&variant.name);
+        if !profile.includes(variant_meta.stage) {
+            continue;
+        }
+        staged.variants.push(variant.clone());
+        // Composite flags follow from the flags they combine
+        if variant_meta.is_composite() {
+            continue;
+        }
+        if variant_meta.value.is_none() && variant_meta.expr.is_none() && value != next {
+            variant_meta.value = Some(value);
+            meta.set_variant(&variant.name, variant_meta);
+        }
+        next = value.wrapping_add(1);
+    }
+    meta.write_to(&mut staged);
+    staged
+}
+
+/// Generate a target and write it to its configured output path.
+///
+/// Refuses to overwrite a file that was edited by hand since it was
+/// generated, unless `force` is set.
+pub fn export(target: CodegenTarget, definition_path: &Path, asset: &EnumAsset, force: bool) -> Result<PathBuf> {
+    let contents = target.generate_file(definition_path, asset).map_err(|message| EnumEditorError::Codegen {
+        target: target.label().to_string(),
+        message,
+    })?;
+    let output_path = target.output_path(definition_path, asset);
+    if !force && std::fs::read(&output_path).is_ok_and(|existing| target.is_hand_edited(asset, &existing)) {
+        return Err(EnumEditorError::ManuallyEdited { path: output_path });
+    }
+    write_output(&output_path, &contents)?;
+    Ok(output_path)
+}
+
+/// Write the review sheet next to the definition as `<Enum>.review.html`
+pub fn export_review(definition_path: &Path, asset: &EnumAsset) -> Result<PathBuf> {
+    let path = crate::storage::asset_container_dir(definition_path).join(format!("{}.review.html", asset.name));
+    write_output(&path, generate_review(asset).as_bytes())?;
+    Ok(path)
+}
+
+/// Write a generated file, creating its directory if needed
+pub(crate) fn write_output(path: &Path, contents: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| EnumEditorError::io(parent, e))?;
+    }
+    std::fs::write(path, contents).map_err(|e| EnumEditorError::io(path, e))
+}
+
+pub(crate) fn type_ref_to_string(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Primitive { name } => name.clone(),
+        TypeRef::Path { path } => path.clone(),
+        TypeRef::AliasRef { alias } => alias.clone(),
+    }
+}
+
+pub(crate) fn rust_visibility(visibility: &Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "pub ",
+        Visibility::Private => "",
+        Visibility::Crate => "pub(crate) ",
+        Visibility::Super => "pub(super) ",
+    }
+}
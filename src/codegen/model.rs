@@ -0,0 +1,125 @@
+//! Flattened view of an enum for exporters and user templates
+//!
+//! Resolves the editor metadata (effective values, display names, colors) so
+//! backends don't each have to re-derive them from `EnumAsset::meta`.
+
+use super::{type_ref_to_string, CodegenTarget};
+use crate::metadata::{variant_values, EnumMeta};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use ui_types_common::{EnumAsset, VariantPayload, Visibility};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EnumModel {
+    pub name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub visibility: &'static str,
+    pub categories: Vec<String>,
+    pub variants: Vec<VariantModel>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct VariantModel {
+    pub name: String,
+    pub value: i64,
+    pub has_explicit_value: bool,
+    /// Discriminant expression as written, e.g. `BASE + 5`
+    pub expr: Option<String>,
+    pub display_name: String,
+    pub doc: Option<String>,
+    /// Color as written in the definition, e.g. `#FF8800`
+    pub color: Option<String>,
+    /// `unit`, `tuple` or `struct`
+    pub kind: &'static str,
+    /// Type of a tuple payload
+    pub payload_type: Option<String>,
+    pub fields: Vec<FieldModel>,
+    pub deprecated: bool,
+    /// `cfg` predicate gating the variant
+    pub cfg: Option<String>,
+    /// Flags a composite variant is the union of
+    pub combines: Vec<String>,
+    /// Identifiers overridden per codegen target, by target id
+    pub target_names: BTreeMap<String, String>,
+}
+
+impl VariantModel {
+    /// Identifier `target` emits the variant as: its override, or `usual`
+    pub fn emitted_name(&self, target: CodegenTarget, usual: &str) -> String {
+        self.target_names.get(target.id()).cloned().unwrap_or_else(|| usual.to_string())
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FieldModel {
+    pub name: String,
+    pub type_name: String,
+    pub doc: Option<String>,
+    /// Default value as a Rust expression
+    pub default: Option<String>,
+}
+
+impl EnumModel {
+    pub fn from_asset(asset: &EnumAsset) -> Self {
+        let meta = EnumMeta::from_asset(asset);
+        let values = variant_values(asset, &meta);
+
+        let variants = asset
+            .variants
+            .iter()
+            .zip(values)
+            .map(|(variant, value)| {
+                let variant_meta = meta.variant(&variant.name);
+                let (kind, payload_type, fields) = match &variant.payload {
+                    VariantPayload::Unit => ("unit", None, Vec::new()),
+                    VariantPayload::Single(type_ref) => ("tuple", Some(type_ref_to_string(type_ref)), Vec::new()),
+                    VariantPayload::Struct(fields) => (
+                        "struct",
+                        None,
+                        fields
+                            .iter()
+                            .map(|field| FieldModel {
+                                name: field.name.clone(),
+                                type_name: type_ref_to_string(&field.type_ref),
+                                doc: field.doc.clone(),
+                                default: variant_meta.field_defaults.get(&field.name).cloned(),
+                            })
+                            .collect(),
+                    ),
+                };
+
+                VariantModel {
+                    name: variant.name.clone(),
+                    value,
+                    has_explicit_value: variant_meta.value.is_some() || variant_meta.expr.is_some(),
+                    expr: variant_meta.expr.clone(),
+                    display_name: meta.display_name(&variant.name),
+                    doc: variant.doc.clone(),
+                    color: variant_meta.color.clone(),
+                    kind,
+                    payload_type,
+                    fields,
+                    deprecated: variant_meta.deprecated,
+                    cfg: variant_meta.cfg.clone(),
+                    combines: variant_meta.combines.clone(),
+                    target_names: variant_meta.target_names.clone(),
+                }
+            })
+            .collect();
+
+        Self {
+            name: asset.name.clone(),
+            display_name: asset.display_name.clone(),
+            description: asset.description.clone(),
+            visibility: match asset.visibility {
+                Visibility::Public => "public",
+                Visibility::Private => "private",
+                Visibility::Crate => "crate",
+                Visibility::Super => "super",
+            },
+            categories: meta.categories,
+            variants,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Python `enum` backend
+
+use super::model::{EnumModel, VariantModel};
+use super::CodegenTarget;
+use crate::metadata::{EnumMeta, EnumMode};
+use crate::naming::screaming_snake_case;
+use ui_types_common::EnumAsset;
+
+fn docstring(text: &str, indent: &str) -> String {
+    let text = text.replace("\"\"\"", "\\\"\\\"\\\"");
+    if !text.contains('\n') {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, text);
+    }
+    // Continuation lines are indented to match the opening quotes
+    let mut lines = text.lines();
+    let mut out = format!("{}\"\"\"{}\n", indent, lines.next().unwrap_or(""));
+    for line in lines {
+        match line.trim_end() {
+            "" => out.push('\n'),
+            line => out.push_str(&format!("{}{}\n", indent, line)),
+        }
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+/// Member name of the variant: its `python` override or SCREAMING_SNAKE_CASE
+fn member_name(variant: &VariantModel) -> String {
+    variant.emitted_name(CodegenTarget::Python, &screaming_snake_case(&variant.name))
+}
+
+/// Generate a Python enum class.
+///
+/// Bitflags enums become `enum.Flag`, plain enums `enum.IntEnum`. Payloads
+/// have no Python equivalent, so enums with payload variants fall back to
+/// `enum.Enum` keyed by their discriminants.
+pub fn generate_python(asset: &EnumAsset) -> String {
+    let meta = EnumMeta::from_asset(asset);
+    let model = EnumModel::from_asset(asset);
+    let base = if meta.mode == EnumMode::Flags {
+        "Flag"
+    } else if model.variants.iter().all(|v| v.kind == "unit") {
+        "IntEnum"
+    } else {
+        "Enum"
+    };
+
+    let mut out = "import enum\n\n\n".to_string();
+    // Expressions name the constants, so they come first
+    if !meta.constants.is_empty() {
+        for (name, value) in &meta.constants {
+            out.push_str(&format!("{} = {}\n", name, value));
+        }
+        out.push_str("\n\n");
+    }
+    out.push_str(&format!("class {}(enum.{}):\n", model.name, base));
+    if let Some(description) = &model.description {
+        out.push_str(&docstring(description, "    "));
+        out.push('\n');
+    }
+    if model.variants.is_empty() {
+        out.push_str("    pass\n");
+    }
+
+    for variant in &model.variants {
+        if variant.deprecated {
+            out.push_str("    # Deprecated\n");
+        }
+        let name = member_name(variant);
+        if !variant.combines.is_empty() {
+            let flags: Vec<String> = variant
+                .combines
+                .iter()
+                .map(|flag| match model.variants.iter().find(|v| &v.name == flag) {
+                    Some(flag) => member_name(flag),
+                    None => screaming_snake_case(flag),
+                })
+                .collect();
+            out.push_str(&format!("    {} = {}\n", name, flags.join(" | ")));
+        } else if let Some(expr) = variant.expr.as_ref().filter(|expr| !expr.contains(['/', '%'])) {
+            // Python spells bitwise not `~` only
+            out.push_str(&format!("    {} = {}\n", name, expr.replace('!', "~")));
+        } else if let Some(expr) = &variant.expr {
+            // Python's `/` and `%` round differently from the editor's, so the value is spelled out
+            out.push_str(&format!("    {} = {}  # {}\n", name, variant.value, expr));
+        } else {
+            out.push_str(&format!("    {} = {}\n", name, variant.value));
+        }
+        if let Some(doc) = &variant.doc {
+            out.push_str(&docstring(doc, "    "));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::synthetic_enum;
+
+    fn with_exprs(exprs: &[&str]) -> EnumAsset {
+        let mut asset = synthetic_enum("Values", exprs.len());
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.constants.insert("BASE".to_string(), -7);
+        for (index, expr) in exprs.iter().enumerate() {
+            let name = format!("Variant{}", index);
+            let mut variant = meta.variant(&name);
+            variant.expr = Some(expr.to_string());
+            meta.set_variant(&name, variant);
+        }
+        meta.write_to(&mut asset);
+        asset
+    }
+
+    #[test]
+    fn division_and_remainder_are_spelled_out() {
+        let code = generate_python(&with_exprs(&["BASE / 2", "-7 % 4"]));
+        // Python would give -4 and 1
+        assert!(code.contains("VARIANT0 = -3  # BASE / 2\n"), "{}", code);
+        assert!(code.contains("VARIANT1 = -3  # -7 % 4\n"), "{}", code);
+    }
+
+    #[test]
+    fn other_expressions_are_kept() {
+        let code = generate_python(&with_exprs(&["BASE << 2", "!BASE"]));
+        assert!(code.contains("VARIANT0 = BASE << 2\n"), "{}", code);
+        assert!(code.contains("VARIANT1 = ~BASE\n"), "{}", code);
+    }
+}
@@ -0,0 +1,583 @@
+//! Rust enum declaration backend
+
+use super::comment::line_comment;
+use super::model::EnumModel;
+use super::{rust_visibility, type_ref_to_string, CodegenTarget};
+use crate::metadata::{sort_key_order, values_ascend, variant_values, EnumMeta, OrderBy};
+use crate::naming;
+use crate::type_check;
+use std::collections::HashMap;
+use std::ops::Range;
+use ui_types_common::{EnumAsset, EnumVariant, VariantPayload};
+
+/// Lines of the generated code each variant was written to, by variant name;
+/// lines are counted from 0
+pub type SourceMap = Vec<(String, Range<usize>)>;
+
+/// Generate the Rust declaration of the enum
+pub fn generate_rust(asset: &EnumAsset) -> String {
+    generate_rust_mapped(asset).0
+}
+
+/// Like [`generate_rust`], with the lines of each variant
+pub fn generate_rust_mapped(asset: &EnumAsset) -> (String, SourceMap) {
+    let meta = EnumMeta::from_asset(asset);
+    let mut code = String::new();
+
+    // Constants named by discriminant expressions, typed like the discriminants
+    if has_discriminants(asset, &meta) && !meta.constants.is_empty() {
+        let ty = meta.repr.as_deref().unwrap_or("isize");
+        for (name, value) in &meta.constants {
+            code.push_str(&format!("{}const {}: {} = {};\n", rust_visibility(&asset.visibility), name, ty, value));
+        }
+        code.push('\n');
+    }
+
+    if let Some(desc) = &asset.description {
+        code.push_str(&line_comment(desc, "", "/// "));
+    }
+
+    let mut derives = vec!["Debug".to_string(), "Clone".to_string()];
+    let manual_ordering = manual_ordering(asset, &meta);
+    for derive in &meta.derives {
+        // Comparing by value is written out below instead
+        let ordering = matches!(type_check::derive_name(derive), "PartialOrd" | "Ord");
+        if !derives.contains(derive) && !(manual_ordering && ordering) {
+            derives.push(derive.clone());
+        }
+    }
+    // Aliases only make sense if the enum goes through serde
+    let has_aliases = meta.variants.values().any(|v| !v.aliases.is_empty());
+    if has_aliases {
+        for derive in ["serde::Serialize", "serde::Deserialize"] {
+            // `Serialize` from the defaults covers `serde::Serialize`
+            if !derives.iter().any(|d| d.rsplit("::").next() == derive.rsplit("::").next()) {
+                derives.push(derive.to_string());
+            }
+        }
+    }
+    code.push_str(&format!("#[derive({})]\n", derives.join(", ")));
+    let serde = derives.iter().any(|d| matches!(type_check::derive_name(d), "Serialize" | "Deserialize"));
+    if let Some(attribute) = meta.serde_repr.attribute().filter(|_| serde) {
+        code.push_str(&format!("#[serde({})]\n", attribute));
+    }
+    if let Some(repr) = &meta.repr {
+        code.push_str(&format!("#[repr({})]\n", repr));
+    }
+
+    code.push_str(&format!("{}enum {} {{\n", rust_visibility(&asset.visibility), asset.name));
+    let offset = code.matches('\n').count();
+    let (lines, mut map) = variant_lines(asset, &meta);
+    for (_, range) in &mut map {
+        *range = range.start + offset..range.end + offset;
+    }
+    code.push_str(&lines);
+    code.push_str("}\n");
+    let ordering = manual_ordering.then(|| ordering_impls(asset, &meta));
+    let blocks = [
+        ordering,
+        constructors(asset, &meta),
+        stable_ids_block(asset, &meta),
+        sort_keys_block(asset, &meta),
+        display_names_block(asset, &meta),
+        impl_block(asset, &meta),
+    ];
+    for block in blocks.into_iter().flatten() {
+        code.push('\n');
+        code.push_str(&block);
+    }
+    (code, map)
+}
+
+/// Identifier the Rust declaration uses for a variant: its name, or its
+/// override for the Rust target
+fn rust_name(meta: &EnumMeta, name: &str) -> String {
+    meta.variant(name).emitted_name(CodegenTarget::Rust, name)
+}
+
+/// Explicit discriminants need a repr unless the enum is field-less
+pub fn has_discriminants(asset: &EnumAsset, meta: &EnumMeta) -> bool {
+    meta.repr.is_some() || asset.variants.iter().all(|v| matches!(v.payload, VariantPayload::Unit))
+}
+
+/// The lines between the braces of the Rust declaration
+pub fn generate_rust_variants(asset: &EnumAsset) -> String {
+    variant_lines(asset, &EnumMeta::from_asset(asset)).0
+}
+
+/// The lines declaring one variant, unindented, with its docs and attributes
+pub fn generate_rust_variant(asset: &EnumAsset, name: &str) -> Option<String> {
+    let (code, map) = variant_lines(asset, &EnumMeta::from_asset(asset));
+    let (_, lines) = map.into_iter().find(|(variant, _)| variant == name)?;
+    let lines: Vec<&str> =
+        code.lines().skip(lines.start).take(lines.len()).map(|line| line.strip_prefix("    ").unwrap_or(line)).collect();
+    Some(lines.join("\n"))
+}
+
+fn variant_lines(asset: &EnumAsset, meta: &EnumMeta) -> (String, SourceMap) {
+    let discriminants = has_discriminants(asset, meta);
+    let mut code = String::new();
+    let mut map = SourceMap::new();
+    let mut line = 0;
+    let values: HashMap<&str, i64> =
+        asset.variants.iter().map(|v| v.name.as_str()).zip(variant_values(asset, meta)).collect();
+    // `#[default]` doesn't compile without the derive
+    let default_variant = meta
+        .default_variant
+        .as_deref()
+        .filter(|_| meta.derives.iter().any(|d| type_check::derive_name(d) == "Default"));
+    // Overridden names keep the definition's name in serialized data
+    let serde = meta.variants.values().any(|v| !v.aliases.is_empty())
+        || meta.derives.iter().any(|d| matches!(type_check::derive_name(d), "Serialize" | "Deserialize"));
+
+    // Once a variant may be compiled out, implicit values after it would
+    // shift, so they are pinned to the values the other targets use
+    let mut after_gated = false;
+    for variant in &asset.variants {
+        let start = code.len();
+        let name = rust_name(meta, &variant.name);
+        if let Some(doc) = &variant.doc {
+            code.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        if let Some(cfg) = &meta.variant(&variant.name).cfg {
+            code.push_str(&format!("    #[cfg({})]\n", cfg));
+        }
+        if meta.variant(&variant.name).deprecated {
+            code.push_str("    #[deprecated]\n");
+        }
+        if default_variant == Some(variant.name.as_str()) {
+            code.push_str("    #[default]\n");
+        }
+        if serde && name != variant.name {
+            code.push_str(&format!("    #[serde(rename = \"{}\")]\n", variant.name));
+        }
+        for alias in &meta.variant(&variant.name).aliases {
+            code.push_str(&format!("    #[serde(alias = \"{}\")]\n", alias));
+        }
+
+        match &variant.payload {
+            VariantPayload::Unit if discriminants && meta.variant(&variant.name).is_composite() => {
+                // Discriminants can't name other variants, so the flags' values are spelled out
+                let combines = meta.variant(&variant.name).combines;
+                let flags: Vec<String> =
+                    combines.iter().filter_map(|name| values.get(name.as_str())).map(i64::to_string).collect();
+                let expression = if flags.is_empty() { "0".to_string() } else { flags.join(" | ") };
+                code.push_str(&format!("    {} = {}, // {}\n", name, expression, combines.join(" | ")));
+            }
+            VariantPayload::Unit if discriminants && meta.variant(&variant.name).expr.is_some() => {
+                // Rust spells bitwise not `!` only
+                let expr = meta.variant(&variant.name).expr.unwrap_or_default().replace('~', "!");
+                code.push_str(&format!("    {} = {},\n", name, expr));
+            }
+            VariantPayload::Unit => {
+                let pinned = values.get(variant.name.as_str()).copied().filter(|_| after_gated);
+                match meta.variant(&variant.name).value.or(pinned).filter(|_| discriminants) {
+                    Some(value) => code.push_str(&format!("    {} = {},\n", name, value)),
+                    None => code.push_str(&format!("    {},\n", name)),
+                }
+            }
+            VariantPayload::Single(type_ref) => {
+                let type_str = type_ref_to_string(type_ref);
+                code.push_str(&format!("    {}({}),\n", name, type_str));
+            }
+            VariantPayload::Struct(fields) => {
+                let defaults = meta.variant(&variant.name).field_defaults;
+                code.push_str(&format!("    {} {{\n", name));
+                for field in fields {
+                    if let Some(doc) = &field.doc {
+                        code.push_str(&line_comment(doc, "        ", "/// "));
+                    }
+                    if let Some(default) = defaults.get(&field.name) {
+                        if field.doc.is_some() {
+                            code.push_str("        ///\n");
+                        }
+                        code.push_str(&format!("        /// Defaults to `{}`\n", default));
+                    }
+                    let type_str = type_ref_to_string(&field.type_ref);
+                    code.push_str(&format!(
+                        "        {}{}: {},\n",
+                        rust_visibility(&field.visibility),
+                        field.name,
+                        type_str
+                    ));
+                }
+                code.push_str("    },\n");
+            }
+        }
+        after_gated |= meta.variant(&variant.name).cfg.is_some();
+        let lines = code[start..].matches('\n').count();
+        map.push((variant.name.clone(), line..line + lines));
+        line += lines;
+    }
+    (code, map)
+}
+
+/// Helper impls for an enum declared in hand-written code: `Display` from
+/// the display names, `TryFrom` the discriminant and an `ALL` table for
+/// field-less enums, and the associated constants and methods
+pub fn generate_rust_impls(asset: &EnumAsset) -> String {
+    let meta = EnumMeta::from_asset(asset);
+    let model = EnumModel::from_asset(asset);
+    let visibility = rust_visibility(&asset.visibility);
+    let cfg = |name: &str, indent: &str| {
+        meta.variant(name).cfg.map(|cfg| format!("{}#[cfg({})]\n", indent, cfg)).unwrap_or_default()
+    };
+    let mut code = String::new();
+
+    code.push_str(&format!("impl std::fmt::Display for {} {{\n", asset.name));
+    code.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    code.push_str("        f.write_str(match self {\n");
+    for (variant, model) in asset.variants.iter().zip(&model.variants) {
+        let pattern = match variant.payload {
+            VariantPayload::Unit => "",
+            VariantPayload::Single(_) => "(..)",
+            VariantPayload::Struct(_) => " { .. }",
+        };
+        code.push_str(&cfg(&variant.name, "            "));
+        code.push_str(&format!("            Self::{}{} => {:?},\n", rust_name(&meta, &variant.name), pattern, model.display_name));
+    }
+    code.push_str("        })\n    }\n}\n");
+
+    if asset.variants.iter().all(|v| matches!(v.payload, VariantPayload::Unit)) {
+        let ty = meta.repr.as_deref().unwrap_or("i64");
+        code.push_str(&format!("\nimpl TryFrom<{}> for {} {{\n", ty, asset.name));
+        code.push_str(&format!("    type Error = {};\n\n", ty));
+        code.push_str(&format!("    fn try_from(value: {}) -> Result<Self, Self::Error> {{\n", ty));
+        code.push_str("        match value {\n");
+        for variant in &model.variants {
+            code.push_str(&cfg(&variant.name, "            "));
+            code.push_str(&format!("            {} => Ok(Self::{}),\n", variant.value, rust_name(&meta, &variant.name)));
+        }
+        code.push_str("            _ => Err(value),\n        }\n    }\n}\n");
+
+        code.push_str(&format!("\nimpl {} {{\n", asset.name));
+        code.push_str("    /// Every variant in declaration order\n");
+        code.push_str(&format!("    {}const ALL: &'static [Self] = &[\n", visibility));
+        for variant in &model.variants {
+            code.push_str(&cfg(&variant.name, "        "));
+            code.push_str(&format!("        Self::{},\n", rust_name(&meta, &variant.name)));
+        }
+        code.push_str("    ];\n}\n");
+    }
+
+    let blocks = [
+        constructors(asset, &meta),
+        stable_ids_block(asset, &meta),
+        sort_keys_block(asset, &meta),
+        display_names_block(asset, &meta),
+        impl_block(asset, &meta),
+    ];
+    for block in blocks.into_iter().flatten() {
+        code.push('\n');
+        code.push_str(&block);
+    }
+    code
+}
+
+/// True if `PartialOrd` is derived, variants compare by value and the values
+/// don't ascend, so the derives would compare them differently
+fn manual_ordering(asset: &EnumAsset, meta: &EnumMeta) -> bool {
+    meta.order_by == OrderBy::Value
+        && meta.derives.iter().any(|d| type_check::derive_name(d) == "PartialOrd")
+        && !values_ascend(asset, meta)
+}
+
+/// The variant's pattern binding its payload to `prefix`ed names, e.g.
+/// `Self::Move { x: a0, y: a1 }`, with the names
+fn binding_pattern(variant: &EnumVariant, meta: &EnumMeta, prefix: &str) -> (String, Vec<String>) {
+    let name = rust_name(meta, &variant.name);
+    match &variant.payload {
+        VariantPayload::Unit => (format!("Self::{}", name), Vec::new()),
+        VariantPayload::Single(_) => (format!("Self::{}({})", name, prefix), vec![prefix.to_string()]),
+        VariantPayload::Struct(fields) => {
+            let names: Vec<String> = (0..fields.len()).map(|i| format!("{}{}", prefix, i)).collect();
+            let bindings: Vec<String> = fields.iter().zip(&names).map(|(field, name)| format!("{}: {}", field.name, name)).collect();
+            (format!("Self::{} {{ {} }}", name, bindings.join(", ")), names)
+        }
+    }
+}
+
+/// `PartialOrd`, and `Ord` if derived, comparing variants by value, then
+/// by declaration order for equal values, then by payload
+fn ordering_impls(asset: &EnumAsset, meta: &EnumMeta) -> String {
+    let total = meta.derives.iter().any(|d| type_check::derive_name(d) == "Ord");
+    let cfg = |name: &str, indent: &str| {
+        meta.variant(name).cfg.map(|cfg| format!("{}#[cfg({})]\n", indent, cfg)).unwrap_or_default()
+    };
+    let mut rank = "        let rank = |value: &Self| -> (i64, usize) {\n            match value {\n".to_string();
+    for (index, (variant, value)) in asset.variants.iter().zip(variant_values(asset, meta)).enumerate() {
+        let pattern = match variant.payload {
+            VariantPayload::Unit => "",
+            VariantPayload::Single(_) => "(..)",
+            VariantPayload::Struct(_) => " { .. }",
+        };
+        rank.push_str(&cfg(&variant.name, "                "));
+        let name = rust_name(meta, &variant.name);
+        rank.push_str(&format!("                Self::{}{} => ({}, {}),\n", name, pattern, value, index));
+    }
+    rank.push_str("            }\n        };\n");
+
+    // Payloads of the same variant, compared field by field
+    let method = if total { "cmp" } else { "partial_cmp" };
+    let mut arms = Vec::new();
+    for variant in asset.variants.iter().filter(|v| !matches!(v.payload, VariantPayload::Unit)) {
+        let (left, left_names) = binding_pattern(variant, meta, "a");
+        let (right, right_names) = binding_pattern(variant, meta, "b");
+        let compared = match left_names.len() {
+            0 => continue,
+            1 => format!("{}.{}({})", left_names[0], method, right_names[0]),
+            _ => format!("({}).{}(&({}))", left_names.join(", "), method, right_names.join(", ")),
+        };
+        arms.push((variant.name.as_str(), format!("({}, {}) => {},\n", left, right, compared)));
+    }
+    let payloads = |indent: &str| -> String {
+        arms.iter().map(|(name, arm)| format!("{}{}{}", cfg(name, indent), indent, arm)).collect()
+    };
+
+    let name = &asset.name;
+    let mut code = format!("impl PartialOrd for {} {{\n", name);
+    code.push_str("    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {\n");
+    if total {
+        code.push_str("        Some(self.cmp(other))\n");
+    } else {
+        code.push_str(&rank);
+        if arms.is_empty() {
+            code.push_str("        Some(rank(self).cmp(&rank(other)))\n");
+        } else {
+            code.push_str("        match rank(self).cmp(&rank(other)) {\n");
+            code.push_str("            std::cmp::Ordering::Equal => match (self, other) {\n");
+            code.push_str(&payloads("                "));
+            code.push_str("                _ => Some(std::cmp::Ordering::Equal),\n            },\n");
+            code.push_str("            ordering => Some(ordering),\n        }\n");
+        }
+    }
+    code.push_str("    }\n}\n");
+
+    if total {
+        code.push_str(&format!("\nimpl Ord for {} {{\n", name));
+        code.push_str("    fn cmp(&self, other: &Self) -> std::cmp::Ordering {\n");
+        code.push_str(&rank);
+        if arms.is_empty() {
+            code.push_str("        rank(self).cmp(&rank(other))\n");
+        } else {
+            code.push_str("        rank(self).cmp(&rank(other)).then_with(|| match (self, other) {\n");
+            code.push_str(&payloads("            "));
+            code.push_str("            _ => std::cmp::Ordering::Equal,\n        })\n");
+        }
+        code.push_str("    }\n}\n");
+    }
+    code
+}
+
+/// Constructors for the struct variants that have field defaults, taking the
+/// other fields, e.g. `new_move(x)` for `Move { x, y }` where `y` defaults
+fn constructors(asset: &EnumAsset, meta: &EnumMeta) -> Option<String> {
+    let visibility = rust_visibility(&asset.visibility);
+    let mut functions = Vec::new();
+    for variant in &asset.variants {
+        let VariantPayload::Struct(fields) = &variant.payload else {
+            continue;
+        };
+        let variant_meta = meta.variant(&variant.name);
+        let defaults = &variant_meta.field_defaults;
+        if !fields.iter().any(|field| defaults.contains_key(&field.name)) {
+            continue;
+        }
+        let parameters: Vec<String> = fields
+            .iter()
+            .filter(|field| !defaults.contains_key(&field.name))
+            .map(|field| format!("{}: {}", field.name, type_ref_to_string(&field.type_ref)))
+            .collect();
+
+        let mut function = format!("    /// `{}` with its fields' defaults\n", variant.name);
+        if let Some(cfg) = &variant_meta.cfg {
+            function.push_str(&format!("    #[cfg({})]\n", cfg));
+        }
+        if variant_meta.deprecated {
+            function.push_str("    #[deprecated]\n");
+        }
+        function.push_str(&format!(
+            "    {}fn new_{}({}) -> Self {{\n",
+            visibility,
+            naming::snake_case(&variant.name),
+            parameters.join(", ")
+        ));
+        function.push_str(&format!("        Self::{} {{\n", rust_name(meta, &variant.name)));
+        for field in fields {
+            match defaults.get(&field.name) {
+                Some(default) => function.push_str(&format!("            {}: {},\n", field.name, default)),
+                None => function.push_str(&format!("            {},\n", field.name)),
+            }
+        }
+        function.push_str("        }\n    }\n");
+        functions.push(function);
+    }
+    if functions.is_empty() {
+        return None;
+    }
+    Some(format!("impl {} {{\n{}}}\n", asset.name, functions.join("\n")))
+}
+
+/// `stable_id()` method returning each variant's stable ID, if the enum uses
+/// them; variants not saved yet get the ID they will be given
+fn stable_ids_block(asset: &EnumAsset, meta: &EnumMeta) -> Option<String> {
+    if !meta.stable_ids {
+        return None;
+    }
+    let mut meta = meta.clone();
+    meta.assign_stable_ids(asset);
+    let mut code = format!("impl {} {{\n", asset.name);
+    code.push_str("    /// Identifier for analytics; never changes, even when the variant is renamed\n");
+    code.push_str(&format!("    {}fn stable_id(&self) -> &'static str {{\n", rust_visibility(&asset.visibility)));
+    code.push_str("        match self {\n");
+    for variant in &asset.variants {
+        let variant_meta = meta.variant(&variant.name);
+        let pattern = match variant.payload {
+            VariantPayload::Unit => "",
+            VariantPayload::Single(_) => "(..)",
+            VariantPayload::Struct(_) => " { .. }",
+        };
+        if let Some(cfg) = &variant_meta.cfg {
+            code.push_str(&format!("            #[cfg({})]\n", cfg));
+        }
+        let id = variant_meta.stable_id.unwrap_or_default();
+        code.push_str(&format!("            Self::{}{} => {:?},\n", rust_name(meta, &variant.name), pattern, id));
+    }
+    code.push_str("        }\n    }\n}\n");
+    Some(code)
+}
+
+/// `display_name()` returning each variant's display name, if the enum
+/// generates them
+fn display_names_block(asset: &EnumAsset, meta: &EnumMeta) -> Option<String> {
+    meta.display_style?;
+    let mut code = format!("impl {} {{\n", asset.name);
+    code.push_str("    /// Name shown to players\n");
+    code.push_str(&format!("    {}fn display_name(&self) -> &'static str {{\n", rust_visibility(&asset.visibility)));
+    code.push_str("        match self {\n");
+    for variant in &asset.variants {
+        let pattern = match variant.payload {
+            VariantPayload::Unit => "",
+            VariantPayload::Single(_) => "(..)",
+            VariantPayload::Struct(_) => " { .. }",
+        };
+        if let Some(cfg) = &meta.variant(&variant.name).cfg {
+            code.push_str(&format!("            #[cfg({})]\n", cfg));
+        }
+        let display_name = meta.display_name(&variant.name);
+        code.push_str(&format!("            Self::{}{} => {:?},\n", rust_name(meta, &variant.name), pattern, display_name));
+    }
+    code.push_str("        }\n    }\n}\n");
+    Some(code)
+}
+
+/// `sort_key()` returning each variant's sort key, if any variant has one,
+/// and for field-less enums `sorted()` listing the variants by it
+fn sort_keys_block(asset: &EnumAsset, meta: &EnumMeta) -> Option<String> {
+    if !asset.variants.iter().any(|variant| meta.variant(&variant.name).sort_key.is_some()) {
+        return None;
+    }
+    let visibility = rust_visibility(&asset.visibility);
+    let order = sort_key_order(asset, meta);
+    let cfg = |name: &str, indent: &str| {
+        meta.variant(name).cfg.map(|cfg| format!("{}#[cfg({})]\n", indent, cfg)).unwrap_or_default()
+    };
+
+    let mut code = format!("impl {} {{\n", asset.name);
+    code.push_str("    /// Position in designer-ordered lists, lowest first\n");
+    code.push_str(&format!("    {}fn sort_key(&self) -> i64 {{\n", visibility));
+    code.push_str("        match self {\n");
+    for variant in &asset.variants {
+        let pattern = match variant.payload {
+            VariantPayload::Unit => "",
+            VariantPayload::Single(_) => "(..)",
+            VariantPayload::Struct(_) => " { .. }",
+        };
+        let key = order.iter().find(|(name, _)| *name == variant.name).map_or(0, |(_, key)| *key);
+        code.push_str(&cfg(&variant.name, "            "));
+        code.push_str(&format!("            Self::{}{} => {},\n", rust_name(meta, &variant.name), pattern, key));
+    }
+    code.push_str("        }\n    }\n");
+
+    if asset.variants.iter().all(|v| matches!(v.payload, VariantPayload::Unit)) {
+        code.push_str("\n    /// Every variant by sort key\n");
+        code.push_str(&format!("    {}fn sorted() -> impl Iterator<Item = Self> {{\n", visibility));
+        code.push_str("        [\n");
+        for (name, _) in &order {
+            code.push_str(&cfg(name, "            "));
+            code.push_str(&format!("            Self::{},\n", rust_name(meta, name)));
+        }
+        code.push_str("        ]\n        .into_iter()\n    }\n");
+    }
+    code.push_str("}\n");
+    Some(code)
+}
+
+/// `impl` block with the associated constants and methods, if there are any
+fn impl_block(asset: &EnumAsset, meta: &EnumMeta) -> Option<String> {
+    if meta.associated_consts.is_empty() && meta.methods.is_empty() {
+        return None;
+    }
+    let visibility = rust_visibility(&asset.visibility);
+    let mut code = format!("impl {} {{\n", asset.name);
+    for constant in &meta.associated_consts {
+        if let Some(doc) = &constant.doc {
+            code.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        code.push_str(&format!("    {}const {}: {} = {};\n", visibility, constant.name, constant.ty, constant.value));
+    }
+    for (i, method) in meta.methods.iter().enumerate() {
+        if i > 0 || !meta.associated_consts.is_empty() {
+            code.push('\n');
+        }
+        if let Some(doc) = &method.doc {
+            code.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        code.push_str(&format!("    {} {{\n", method.signature.trim()));
+        for line in method.body.trim_end().lines() {
+            match line.trim_end() {
+                "" => code.push('\n'),
+                line => code.push_str(&format!("        {}\n", line)),
+            }
+        }
+        code.push_str("    }\n");
+    }
+    code.push_str("}\n");
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::synthetic_enum;
+
+    #[test]
+    fn bitwise_not_is_spelled_for_rust() {
+        let mut asset = synthetic_enum("Masks", 2);
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.constants.insert("MASK".to_string(), 3);
+        let mut variant = meta.variant("Variant1");
+        variant.expr = Some("~MASK".to_string());
+        meta.set_variant("Variant1", variant);
+        meta.write_to(&mut asset);
+
+        let code = generate_rust(&asset);
+        assert!(code.contains("Variant1 = !MASK,"), "{}", code);
+        assert!(!code.contains('~'), "{}", code);
+    }
+
+    #[test]
+    fn values_after_a_gated_variant_are_pinned() {
+        let mut asset = synthetic_enum("Gated", 3);
+        let mut meta = EnumMeta::from_asset(&asset);
+        let mut gated = meta.variant("Variant1");
+        gated.cfg = Some("feature = \"x\"".to_string());
+        meta.set_variant("Variant1", gated);
+        meta.write_to(&mut asset);
+
+        let code = generate_rust(&asset);
+        assert!(code.contains("    Variant0,\n"), "{}", code);
+        assert!(code.contains("    Variant1,\n"), "{}", code);
+        assert!(code.contains("    Variant2 = 2,\n"), "{}", code);
+    }
+}
@@ -0,0 +1,96 @@
+//! Serialization schema backends: FlatBuffers and Cap'n Proto
+
+use super::comment::line_comment;
+use super::header::content_hash;
+use super::model::EnumModel;
+use super::CodegenTarget;
+use crate::markdown::to_plain_text;
+use crate::naming::camel_case;
+use ui_types_common::EnumAsset;
+
+/// Smallest FlatBuffers integer type holding every value
+fn flatbuffers_underlying_type(values: impl Iterator<Item = i64> + Clone) -> &'static str {
+    let min = values.clone().min().unwrap_or(0);
+    let max = values.max().unwrap_or(0);
+    let fits = |lo: i64, hi: i64| min >= lo && max <= hi;
+
+    if fits(0, u8::MAX as i64) {
+        "ubyte"
+    } else if fits(i16::MIN as i64, i16::MAX as i64) {
+        "short"
+    } else if fits(i32::MIN as i64, i32::MAX as i64) {
+        "int"
+    } else {
+        "long"
+    }
+}
+
+/// Generate a FlatBuffers `enum`. Payloads are not representable and are left out.
+pub fn generate_flatbuffers(asset: &EnumAsset) -> Result<String, String> {
+    let model = EnumModel::from_asset(asset);
+
+    // flatc rejects enums whose values are not strictly ascending
+    if let Some(pair) = model.variants.windows(2).find(|pair| pair[1].value <= pair[0].value) {
+        return Err(format!(
+            "FlatBuffers requires ascending values, but {} ({}) follows {} ({})",
+            pair[1].name, pair[1].value, pair[0].name, pair[0].value
+        ));
+    }
+
+    let mut out = String::new();
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(description, "", "/// "));
+    }
+    out.push_str(&format!(
+        "enum {} : {} {{\n",
+        model.name,
+        flatbuffers_underlying_type(model.variants.iter().map(|v| v.value))
+    ));
+    for (i, variant) in model.variants.iter().enumerate() {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&line_comment(doc, "  ", "/// "));
+        }
+        let separator = if i + 1 < model.variants.len() { "," } else { "" };
+        out.push_str(&format!(
+            "  {} = {}{}\n",
+            variant.emitted_name(CodegenTarget::FlatBuffers, &variant.name),
+            variant.value,
+            separator
+        ));
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// Stable 64-bit file ID derived from the enum name (top bit set as Cap'n
+/// Proto requires)
+fn capnp_file_id(name: &str) -> u64 {
+    content_hash(name.as_bytes()) | (1 << 63)
+}
+
+/// Generate a Cap'n Proto schema file with the enum.
+///
+/// Cap'n Proto enumerants are numbered by declaration order, so any explicit
+/// values are recorded in comments, and names are converted to camelCase.
+pub fn generate_capnp(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let mut out = format!("@0x{:016x};\n\n", capnp_file_id(&model.name));
+
+    out.push_str(&format!("enum {} {{\n", model.name));
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(&to_plain_text(description), "  ", "# "));
+    }
+    for (ordinal, variant) in model.variants.iter().enumerate() {
+        out.push_str(&format!("  {} @{};", variant.emitted_name(CodegenTarget::CapnProto, &camel_case(&variant.name)), ordinal));
+        if variant.value != ordinal as i64 {
+            out.push_str(&format!(" # value = {}", variant.value));
+        }
+        out.push('\n');
+        // Cap'n Proto doc comments follow the declaration they describe
+        if let Some(doc) = &variant.doc {
+            out.push_str(&line_comment(&to_plain_text(doc), "    ", "# "));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
@@ -0,0 +1,71 @@
+//! SQL backends for keeping database schemas in line with the enum
+
+use super::comment::line_comment;
+use super::model::EnumModel;
+use crate::markdown::to_plain_text;
+use crate::naming::snake_case;
+use ui_types_common::EnumAsset;
+
+/// Shape of the generated SQL
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlStyle {
+    /// A `CHECK` constraint listing the allowed values
+    Check,
+    /// A lookup table with one row per variant
+    LookupTable,
+}
+
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Generate a SQL snippet for the enum. Columns and tables are named after the
+/// enum in snake_case.
+pub fn generate_sql(asset: &EnumAsset, style: SqlStyle) -> String {
+    let model = EnumModel::from_asset(asset);
+    let name = snake_case(&model.name);
+    let mut out = format!("-- {} ({})\n", model.display_name, model.name);
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(&to_plain_text(description), "", "-- "));
+    }
+
+    match style {
+        SqlStyle::Check => {
+            for variant in &model.variants {
+                out.push_str(&format!("--   {} = {}\n", variant.value, variant.name));
+            }
+            let values: Vec<_> = model.variants.iter().map(|v| v.value.to_string()).collect();
+            out.push_str(&format!(
+                "CONSTRAINT {name}_check CHECK ({name} IN ({}))\n",
+                values.join(", "),
+                name = name
+            ));
+        }
+        SqlStyle::LookupTable => {
+            out.push_str(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (\n    value BIGINT PRIMARY KEY,\n    name TEXT NOT NULL UNIQUE,\n    display_name TEXT NOT NULL,\n    description TEXT\n);\n\n",
+                name
+            ));
+            if !model.variants.is_empty() {
+                out.push_str(&format!("INSERT INTO {} (value, name, display_name, description) VALUES\n", name));
+                let rows: Vec<_> = model
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        format!(
+                            "    ({}, {}, {}, {})",
+                            variant.value,
+                            quote(&variant.name),
+                            quote(&variant.display_name),
+                            variant.doc.as_deref().map(|doc| quote(&to_plain_text(doc))).unwrap_or_else(|| "NULL".to_string())
+                        )
+                    })
+                    .collect();
+                out.push_str(&rows.join(",\n"));
+                out.push_str(";\n");
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,32 @@
+//! Indentation and line endings of generated source files
+//!
+//! Backends indent with four spaces and end lines with `\n`; the enum's
+//! [`CodegenStyle`] is applied to their output afterwards.
+
+use crate::metadata::CodegenStyle;
+
+/// Indentation level the backends emit
+const BACKEND_INDENT: usize = 4;
+
+/// Re-indent `text` and convert its line endings
+pub(crate) fn apply(text: &str, style: &CodegenStyle) -> String {
+    let unit = if style.tabs { "\t".to_string() } else { " ".repeat(style.indent_width) };
+    let newline = if style.crlf { "\r\n" } else { "\n" };
+
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (line, ends) = match line.strip_suffix('\n') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let body = line.trim_start_matches(' ');
+        let spaces = line.len() - body.len();
+        out.push_str(&unit.repeat(spaces / BACKEND_INDENT));
+        out.push_str(&" ".repeat(spaces % BACKEND_INDENT));
+        out.push_str(body);
+        if ends {
+            out.push_str(newline);
+        }
+    }
+    out
+}
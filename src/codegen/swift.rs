@@ -0,0 +1,71 @@
+//! Swift enum backend
+
+use super::comment::line_comment;
+use super::model::{EnumModel, VariantModel};
+use super::CodegenTarget;
+use crate::naming::camel_case;
+use ui_types_common::EnumAsset;
+
+/// Swift keywords that need backticks when used as a case name
+const SWIFT_KEYWORDS: &[&str] = &[
+    "as", "break", "case", "catch", "class", "continue", "default", "defer", "do", "else", "enum",
+    "extension", "fallthrough", "false", "for", "func", "guard", "if", "import", "in", "init", "inout",
+    "internal", "is", "let", "nil", "operator", "private", "protocol", "public", "repeat", "return",
+    "self", "static", "struct", "subscript", "super", "switch", "throw", "throws", "true", "try", "typealias",
+    "var", "where", "while",
+];
+
+/// Case name of the variant, its `swift` override or camelCase, escaped
+/// when it's a keyword
+fn swift_name(variant: &VariantModel) -> String {
+    let name = variant.emitted_name(CodegenTarget::Swift, &camel_case(&variant.name));
+    if SWIFT_KEYWORDS.contains(&name.as_str()) {
+        format!("`{}`", name)
+    } else {
+        name
+    }
+}
+
+fn string_literal(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Generate a Swift enum with `Int64` raw values and `CaseIterable` conformance.
+///
+/// Raw values can't be combined with associated values, so payload variants
+/// are rejected.
+pub fn generate_swift(asset: &EnumAsset) -> Result<String, String> {
+    let model = EnumModel::from_asset(asset);
+    if let Some(variant) = model.variants.iter().find(|v| v.kind != "unit") {
+        return Err(format!(
+            "Swift enums with raw values can't have payloads, but {} has one",
+            variant.name
+        ));
+    }
+
+    let mut out = String::new();
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(description, "", "/// "));
+    }
+    out.push_str(&format!("public enum {}: Int64, CaseIterable {{\n", model.name));
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        if variant.deprecated {
+            out.push_str("    @available(*, deprecated)\n");
+        }
+        out.push_str(&format!("    case {} = {}\n", swift_name(variant), variant.value));
+    }
+
+    out.push_str("\n    public var displayName: String {\n        switch self {\n");
+    for variant in &model.variants {
+        out.push_str(&format!(
+            "        case .{}: return {}\n",
+            swift_name(variant),
+            string_literal(&variant.display_name)
+        ));
+    }
+    out.push_str("        }\n    }\n}\n");
+    Ok(out)
+}
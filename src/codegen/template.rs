@@ -0,0 +1,127 @@
+//! User-defined codegen targets rendered from Handlebars templates
+//!
+//! Templates are picked up from two places:
+//!
+//! - `*.hbs` files inside the `.enum` folder, applying to that enum only
+//! - `*.hbs` files in an `enum-templates/` directory in the asset's directory
+//!   or any of its parents, applying to every enum in the project
+//!
+//! A template named `csharp.cs.hbs` renders to `<EnumName>.cs` next to the
+//! asset. Templates are rendered with an [`EnumModel`] and HTML escaping is
+//! disabled, since the output is source code.
+
+use super::model::EnumModel;
+use crate::error::{EnumEditorError, Result};
+use crate::metadata::EnumMeta;
+use crate::storage::{self, EnumFileFormat};
+use handlebars::Handlebars;
+use std::path::{Path, PathBuf};
+use ui_types_common::EnumAsset;
+
+/// Directory searched for project-wide templates
+pub const PROJECT_TEMPLATES_DIR: &str = "enum-templates";
+
+/// Extension of template files
+pub const TEMPLATE_EXTENSION: &str = "hbs";
+
+/// A template discovered on disk
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateTarget {
+    /// Template name, e.g. `csharp` for `csharp.cs.hbs`
+    pub name: String,
+    /// Extension of the rendered file, e.g. `cs`
+    pub output_extension: String,
+    pub path: PathBuf,
+}
+
+impl TemplateTarget {
+    fn from_path(path: PathBuf) -> Option<Self> {
+        if path.extension().and_then(|e| e.to_str()) != Some(TEMPLATE_EXTENSION) {
+            return None;
+        }
+        let stem = path.file_stem()?.to_str()?;
+        let (name, output_extension) = stem.rsplit_once('.').unwrap_or((stem, "txt"));
+        Some(Self {
+            name: name.to_string(),
+            output_extension: output_extension.to_string(),
+            path,
+        })
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} (.{})", self.name, self.output_extension)
+    }
+
+    /// File the template is rendered to, next to the enum asset
+    pub fn output_path(&self, definition_path: &Path, asset: &EnumAsset) -> PathBuf {
+        storage::asset_container_dir(definition_path)
+            .join(format!("{}.{}", asset.name, self.output_extension))
+    }
+
+    pub fn render(&self, asset: &EnumAsset) -> Result<String> {
+        let source = std::fs::read_to_string(&self.path).map_err(|e| EnumEditorError::io(&self.path, e))?;
+        let asset = &super::staged(asset, EnumMeta::from_asset(asset).profile);
+        render_template(&source, asset).map_err(|message| EnumEditorError::Codegen {
+            target: self.name.clone(),
+            message,
+        })
+    }
+}
+
+/// Render a template source with the enum model
+pub fn render_template(source: &str, asset: &EnumAsset) -> Result<String, String> {
+    let mut handlebars = Handlebars::new();
+    handlebars.register_escape_fn(handlebars::no_escape);
+    handlebars
+        .render_template(source, &EnumModel::from_asset(asset))
+        .map_err(|e| e.to_string())
+}
+
+/// Templates applying to the enum at `definition_path`. Templates in the
+/// `.enum` folder shadow project templates of the same name.
+pub fn discover(definition_path: &Path) -> Vec<TemplateTarget> {
+    let mut templates = Vec::new();
+
+    if EnumFileFormat::detect(definition_path) == EnumFileFormat::Folder {
+        if let Some(asset_dir) = definition_path.parent() {
+            templates.extend(templates_in(asset_dir));
+        }
+    }
+
+    let container = storage::asset_container_dir(definition_path);
+    for dir in container.ancestors() {
+        let project_dir = dir.join(PROJECT_TEMPLATES_DIR);
+        if project_dir.is_dir() {
+            for template in templates_in(&project_dir) {
+                if !templates.iter().any(|t: &TemplateTarget| t.name == template.name) {
+                    templates.push(template);
+                }
+            }
+            break;
+        }
+    }
+
+    templates
+}
+
+fn templates_in(dir: &Path) -> Vec<TemplateTarget> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut templates: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(TemplateTarget::from_path)
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Render a template and write it to its output path
+pub fn export_template(template: &TemplateTarget, definition_path: &Path, asset: &EnumAsset) -> Result<PathBuf> {
+    let contents = template.render(asset)?;
+    let output_path = template.output_path(definition_path, asset);
+    std::fs::write(&output_path, contents).map_err(|e| EnumEditorError::io(&output_path, e))?;
+    Ok(output_path)
+}
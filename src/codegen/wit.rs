@@ -0,0 +1,102 @@
+//! WIT (WebAssembly Interface Types) backend for wasm component plugins
+
+use super::comment::line_comment;
+use super::model::EnumModel;
+use super::CodegenTarget;
+use crate::naming::kebab_case;
+use ui_types_common::EnumAsset;
+
+/// WIT keywords that must be escaped with `%` when used as identifiers
+const WIT_KEYWORDS: &[&str] = &[
+    "as", "bool", "borrow", "char", "constructor", "enum", "export", "f32", "f64", "flags", "func",
+    "import", "include", "interface", "list", "option", "own", "package", "record", "resource", "result",
+    "s16", "s32", "s64", "s8", "static", "string", "tuple", "type", "u16", "u32", "u64", "u8", "use",
+    "variant", "with", "world",
+];
+
+fn identifier(name: &str) -> String {
+    let name = kebab_case(name);
+    if WIT_KEYWORDS.contains(&name.as_str()) {
+        format!("%{}", name)
+    } else {
+        name
+    }
+}
+
+/// Translate a Rust type name into its WIT equivalent.
+///
+/// Unknown types are referenced by the kebab-case name of their last path
+/// segment and are expected to be defined elsewhere in the interface.
+fn wit_type(rust_type: &str) -> String {
+    let rust_type = rust_type.trim();
+    if let Some((outer, inner)) = rust_type.strip_suffix('>').and_then(|t| t.split_once('<')) {
+        let outer = outer.rsplit("::").next().unwrap_or(outer);
+        return match outer {
+            "Vec" => format!("list<{}>", wit_type(inner)),
+            "Option" => format!("option<{}>", wit_type(inner)),
+            "Box" | "Arc" | "Rc" => wit_type(inner),
+            _ => identifier(outer),
+        };
+    }
+
+    match rust_type {
+        "bool" => "bool".to_string(),
+        "i8" => "s8".to_string(),
+        "i16" => "s16".to_string(),
+        "i32" => "s32".to_string(),
+        "i64" | "isize" => "s64".to_string(),
+        "u8" => "u8".to_string(),
+        "u16" => "u16".to_string(),
+        "u32" => "u32".to_string(),
+        "u64" | "usize" => "u64".to_string(),
+        "f32" => "f32".to_string(),
+        "f64" => "f64".to_string(),
+        "char" => "char".to_string(),
+        "String" | "str" | "&str" => "string".to_string(),
+        other => identifier(other.rsplit("::").next().unwrap_or(other)),
+    }
+}
+
+/// Generate a WIT `enum`, or a `variant` (with a `record` per struct payload)
+/// when any variant carries data.
+///
+/// WIT cases have no discriminant, so values are recorded in the docs.
+pub fn generate_wit(asset: &EnumAsset) -> String {
+    let model = EnumModel::from_asset(asset);
+    let type_name = identifier(&model.name);
+    let has_payloads = model.variants.iter().any(|v| v.kind != "unit");
+    let mut out = String::new();
+
+    // Struct payloads become records named `<enum>-<variant>`
+    for variant in model.variants.iter().filter(|v| v.kind == "struct") {
+        out.push_str(&format!("record {}-{} {{\n", kebab_case(&model.name), kebab_case(&variant.name)));
+        for field in &variant.fields {
+            if let Some(doc) = &field.doc {
+                out.push_str(&line_comment(doc, "    ", "/// "));
+            }
+            out.push_str(&format!("    {}: {},\n", identifier(&field.name), wit_type(&field.type_name)));
+        }
+        out.push_str("}\n\n");
+    }
+
+    if let Some(description) = &model.description {
+        out.push_str(&line_comment(description, "", "/// "));
+    }
+    out.push_str(&format!("{} {} {{\n", if has_payloads { "variant" } else { "enum" }, type_name));
+    for variant in &model.variants {
+        if let Some(doc) = &variant.doc {
+            out.push_str(&line_comment(doc, "    ", "/// "));
+        }
+        out.push_str(&format!("    /// Value: {}\n", variant.value));
+
+        let payload = match variant.kind {
+            "tuple" => format!("({})", wit_type(variant.payload_type.as_deref().unwrap_or(""))),
+            "struct" => format!("({}-{})", kebab_case(&model.name), kebab_case(&variant.name)),
+            _ => String::new(),
+        };
+        let name = variant.emitted_name(CodegenTarget::Wit, &identifier(&variant.name));
+        out.push_str(&format!("    {}{},\n", name, payload));
+    }
+    out.push_str("}\n");
+    out
+}
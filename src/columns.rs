@@ -0,0 +1,137 @@
+//! Columns of the compact variants list
+//!
+//! The layout is a view setting: it is kept next to the definition for the
+//! editor to restore, and never written into the definition itself.
+
+use serde::{Deserialize, Serialize};
+
+/// Narrowest a column can be made, in pixels
+pub const MIN_COLUMN_WIDTH: f32 = 40.0;
+
+/// Widest a column can be made, in pixels
+pub const MAX_COLUMN_WIDTH: f32 = 600.0;
+
+/// What a column of the compact list shows about each variant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantColumn {
+    /// Discriminant, in the list's value format
+    Value,
+    Payload,
+    /// First line of the docs
+    Docs,
+    /// Flags a composite flag combines
+    Flags,
+    DisplayName,
+    Color,
+    Stage,
+    Cfg,
+    Owner,
+    Aliases,
+    /// Date of the last edit
+    Modified,
+}
+
+impl VariantColumn {
+    pub const ALL: [VariantColumn; 11] = [
+        VariantColumn::Value,
+        VariantColumn::Payload,
+        VariantColumn::Docs,
+        VariantColumn::Flags,
+        VariantColumn::DisplayName,
+        VariantColumn::Color,
+        VariantColumn::Stage,
+        VariantColumn::Cfg,
+        VariantColumn::Owner,
+        VariantColumn::Aliases,
+        VariantColumn::Modified,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VariantColumn::Value => "Value",
+            VariantColumn::Payload => "Payload",
+            VariantColumn::Docs => "Docs",
+            VariantColumn::Flags => "Flags",
+            VariantColumn::DisplayName => "Display Name",
+            VariantColumn::Color => "Color",
+            VariantColumn::Stage => "Stage",
+            VariantColumn::Cfg => "Cfg",
+            VariantColumn::Owner => "Owner",
+            VariantColumn::Aliases => "Aliases",
+            VariantColumn::Modified => "Modified",
+        }
+    }
+
+    /// Width the column gets when it is shown
+    pub fn default_width(&self) -> f32 {
+        match self {
+            VariantColumn::Docs => 240.0,
+            VariantColumn::Payload | VariantColumn::Flags | VariantColumn::DisplayName | VariantColumn::Cfg => 140.0,
+            VariantColumn::Value | VariantColumn::Stage | VariantColumn::Modified => 90.0,
+            VariantColumn::Color | VariantColumn::Owner | VariantColumn::Aliases => 110.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Column {
+    pub kind: VariantColumn,
+    pub width: f32,
+}
+
+impl Column {
+    fn new(kind: VariantColumn) -> Self {
+        Self { kind, width: kind.default_width() }
+    }
+}
+
+/// Columns shown after the variant name, in order
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnLayout {
+    pub columns: Vec<Column>,
+}
+
+impl Default for ColumnLayout {
+    fn default() -> Self {
+        Self {
+            columns: [VariantColumn::Payload, VariantColumn::Docs, VariantColumn::Value]
+                .into_iter()
+                .map(Column::new)
+                .collect(),
+        }
+    }
+}
+
+impl ColumnLayout {
+    pub fn is_shown(&self, kind: VariantColumn) -> bool {
+        self.columns.iter().any(|column| column.kind == kind)
+    }
+
+    /// Show a hidden column after the others, or hide a shown one
+    pub fn toggle(&mut self, kind: VariantColumn) {
+        match self.columns.iter().position(|column| column.kind == kind) {
+            Some(position) => {
+                self.columns.remove(position);
+            }
+            None => self.columns.push(Column::new(kind)),
+        }
+    }
+
+    /// Move a shown column `offset` places, stopping at either end
+    pub fn move_column(&mut self, kind: VariantColumn, offset: isize) {
+        if let Some(position) = self.columns.iter().position(|column| column.kind == kind) {
+            let target = position.saturating_add_signed(offset).min(self.columns.len() - 1);
+            let column = self.columns.remove(position);
+            self.columns.insert(target, column);
+        }
+    }
+
+    /// Widen or narrow a shown column by `delta` pixels, within the limits
+    pub fn resize(&mut self, kind: VariantColumn, delta: f32) {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.kind == kind) {
+            column.width = (column.width + delta).clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        }
+    }
+}
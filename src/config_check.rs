@@ -0,0 +1,268 @@
+//! Checks on the values of an enum written in game config files
+//!
+//! Values are found under the keys the enum is stored at (by default its
+//! name in snake_case) and read according to its serde representation.
+//! JSON files are parsed; RON files are scanned, which is enough for the
+//! usual `key: Variant` and `key: (type: "Variant", ..)` forms. Untagged
+//! enums can't be told apart from other values and aren't checked.
+
+use crate::metadata::{EnumMeta, SerdeRepr, Stage};
+use crate::output::Severity;
+use crate::references::{is_ident_char, SKIPPED_DIRS};
+use crate::storage::FOLDER_EXTENSION;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use ui_types_common::EnumAsset;
+
+/// Config formats that are checked, by extension
+const CONFIG_EXTENSIONS: [&str; 2] = ["json", "ron"];
+
+/// A value in a config file that doesn't match the definition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigFinding {
+    pub severity: Severity,
+    /// `line 12` in RON, a JSON pointer like `/enemies/3/weapon` in JSON
+    pub location: String,
+    pub message: String,
+}
+
+/// Outcome of checking one config file
+#[derive(Clone, Debug)]
+pub struct ConfigFileCheck {
+    pub path: PathBuf,
+    /// Findings, or why the file couldn't be read
+    pub result: Result<Vec<ConfigFinding>, String>,
+}
+
+impl ConfigFileCheck {
+    pub fn problems(&self) -> usize {
+        match &self.result {
+            Ok(findings) => findings.len(),
+            Err(_) => 1,
+        }
+    }
+}
+
+/// JSON and RON files under `dir`, sorted by path. Enum definitions are
+/// left out.
+pub fn config_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if path.is_dir() {
+                if !SKIPPED_DIRS.contains(&name.as_str()) && !name.ends_with(&format!(".{}", FOLDER_EXTENSION)) {
+                    pending.push(path);
+                }
+            } else if CONFIG_EXTENSIONS.iter().any(|extension| name.ends_with(&format!(".{}", extension)))
+                && !name.contains(&format!(".{}.", FOLDER_EXTENSION))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// What's wrong with `name` as a value of the enum, if anything
+fn classify(asset: &EnumAsset, meta: &EnumMeta, name: &str) -> Option<(Severity, String)> {
+    if asset.variants.iter().any(|variant| variant.name == name) {
+        let variant_meta = meta.variant(name);
+        return match variant_meta.stage {
+            Stage::Removed => Some((Severity::Error, format!("`{}` was removed", name))),
+            _ if variant_meta.deprecated => Some((Severity::Warning, format!("`{}` is deprecated", name))),
+            _ => None,
+        };
+    }
+    if let Some(variant) = asset.variants.iter().find(|variant| meta.variant(&variant.name).aliases.iter().any(|a| a == name)) {
+        return Some((Severity::Warning, format!("`{}` is an old name of `{}`", name, variant.name)));
+    }
+    if meta.reserved.iter().any(|reserved| reserved.name.as_deref() == Some(name)) {
+        return Some((Severity::Error, format!("`{}` was retired from `{}`", name, asset.name)));
+    }
+    Some((Severity::Error, format!("Unknown `{}` value `{}`", asset.name, name)))
+}
+
+/// Variant name written in a JSON value under the representation
+fn json_variant_name<'a>(value: &'a Value, repr: &SerdeRepr) -> Option<&'a str> {
+    match (repr, value) {
+        (SerdeRepr::External, Value::String(name)) => Some(name),
+        (SerdeRepr::External, Value::Object(entries)) if entries.len() == 1 => entries.keys().next().map(String::as_str),
+        (SerdeRepr::Internal { tag } | SerdeRepr::Adjacent { tag, .. }, Value::Object(entries)) => {
+            entries.get(tag).and_then(Value::as_str)
+        }
+        _ => None,
+    }
+}
+
+/// Enum values stored under `keys` anywhere in the document, with their
+/// JSON pointer; lists of values are looked into
+fn json_values<'a>(value: &'a Value, pointer: &str, keys: &[String], repr: &SerdeRepr, found: &mut Vec<(String, &'a str)>) {
+    match value {
+        Value::Object(entries) => {
+            for (key, value) in entries {
+                let pointer = format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+                if keys.contains(key) {
+                    let items: Vec<(String, &Value)> = match value {
+                        Value::Array(items) => {
+                            items.iter().enumerate().map(|(index, item)| (format!("{}/{}", pointer, index), item)).collect()
+                        }
+                        value => vec![(pointer.clone(), value)],
+                    };
+                    for (pointer, item) in items {
+                        if let Some(name) = json_variant_name(item, repr) {
+                            found.push((pointer, name));
+                        }
+                    }
+                }
+                json_values(value, &pointer, keys, repr, found);
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                json_values(item, &format!("{}/{}", pointer, index), keys, repr, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Identifier starting at `start`, if there is one
+fn ident_at(text: &str, start: usize) -> Option<&str> {
+    let rest = &text[start..];
+    let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+    Some(&rest[..end]).filter(|ident| ident.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+}
+
+/// Offset of the first non-whitespace character from `start`
+fn skip_space(text: &str, start: usize) -> usize {
+    start + text[start..].find(|c: char| !c.is_whitespace()).unwrap_or(text.len() - start)
+}
+
+/// Offset just past the bracket closing the one at `open`
+fn closing(text: &str, open: usize) -> usize {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (offset, c) in text[open..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return open + offset + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Offsets where the elements of the list opened at `open` start: after
+/// the `[` and after each comma at its top level
+fn list_elements(text: &str, open: usize) -> Vec<usize> {
+    let mut starts = vec![open + 1];
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (offset, c) in text[open..closing(text, open)].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 1 => starts.push(open + offset + 1),
+            _ => {}
+        }
+    }
+    starts
+}
+
+/// Variant name of the RON value at `start`, with its offset
+fn ron_variant_name<'a>(text: &'a str, start: usize, repr: &SerdeRepr) -> Option<(usize, &'a str)> {
+    let mut start = skip_space(text, start);
+    // Optional values are wrapped in `Some(..)`
+    if text[start..].starts_with("Some(") {
+        start = skip_space(text, start + "Some(".len());
+    }
+    match repr {
+        SerdeRepr::External => ident_at(text, start).map(|ident| (start, ident)),
+        SerdeRepr::Internal { tag } | SerdeRepr::Adjacent { tag, .. } if text[start..].starts_with('(') => {
+            let body = &text[start..closing(text, start)];
+            let at = body.match_indices(tag.as_str()).find_map(|(offset, _)| {
+                let before = body[..offset].chars().next_back();
+                let colon = skip_space(body, offset + tag.len());
+                if before.is_some_and(is_ident_char) || !body[colon..].starts_with(':') {
+                    return None;
+                }
+                let quote = skip_space(body, colon + 1);
+                body[quote..].starts_with('"').then_some(quote + 1)
+            })?;
+            let name = ident_at(body, at)?;
+            Some((start + at, name))
+        }
+        _ => None,
+    }
+}
+
+/// Enum values stored under `keys` in a RON document, with their offset;
+/// lists of values are looked into
+fn ron_values<'a>(text: &'a str, keys: &[String], repr: &SerdeRepr) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    for key in keys {
+        for (offset, _) in text.match_indices(key.as_str()) {
+            let before = text[..offset].chars().next_back();
+            let colon = skip_space(text, offset + key.len());
+            if before.is_some_and(is_ident_char) || !text[colon..].starts_with(':') {
+                continue;
+            }
+            let value = skip_space(text, colon + 1);
+            if !text[value..].starts_with('[') {
+                found.extend(ron_variant_name(text, value, repr));
+                continue;
+            }
+            for element in list_elements(text, value) {
+                found.extend(ron_variant_name(text, element, repr));
+            }
+        }
+    }
+    found.sort_by_key(|(offset, _)| *offset);
+    found.dedup();
+    found
+}
+
+/// Check the enum's values in one config file
+pub fn check_file(asset: &EnumAsset, keys: &[String], path: PathBuf) -> ConfigFileCheck {
+    let meta = EnumMeta::from_asset(asset);
+    let result = std::fs::read_to_string(&path).map_err(|e| e.to_string()).and_then(|text| {
+        let values: Vec<(String, String)> = if path.extension().is_some_and(|extension| extension == "ron") {
+            ron_values(&text, keys, &meta.serde_repr)
+                .into_iter()
+                .map(|(offset, name)| (format!("line {}", text[..offset].matches('\n').count() + 1), name.to_string()))
+                .collect()
+        } else {
+            let document: Value = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+            let mut found = Vec::new();
+            json_values(&document, "", keys, &meta.serde_repr, &mut found);
+            found.into_iter().map(|(pointer, name)| (pointer, name.to_string())).collect()
+        };
+        Ok(values
+            .into_iter()
+            .filter_map(|(location, name)| {
+                classify(asset, &meta, &name).map(|(severity, message)| ConfigFinding { severity, location, message })
+            })
+            .collect())
+    });
+    ConfigFileCheck { path, result }
+}
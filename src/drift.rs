@@ -0,0 +1,114 @@
+//! Drift between the definition and the enum's hand-written Rust declaration
+//!
+//! Variants are matched by name. Values are only compared when both sides
+//! can have discriminants, and payloads by their types with the spacing
+//! normalized.
+
+use crate::codegen::{generate_rust_variants, type_ref_to_string};
+use crate::error::{EnumEditorError, Result};
+use crate::import::{rust, SourceEnum};
+use crate::metadata::{variant_values, EnumMeta};
+use std::collections::HashMap;
+use std::path::Path;
+use ui_types_common::{EnumAsset, TypeRef, VariantPayload};
+
+/// One difference between the definition and the source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Drift {
+    /// Declared in the definition but not in the source
+    MissingFromSource { name: String },
+    /// Declared in the source but not in the definition
+    MissingFromDefinition { name: String },
+    Value { name: String, definition: i64, source: i64 },
+    Payload { name: String, definition: String, source: String },
+    /// Both declare the same variants in a different order
+    Order,
+}
+
+impl Drift {
+    pub fn describe(&self) -> String {
+        match self {
+            Drift::MissingFromSource { name } => format!("`{}` is missing from the source", name),
+            Drift::MissingFromDefinition { name } => format!("`{}` is missing from the definition", name),
+            Drift::Value { name, definition, source } => {
+                format!("`{}` is {} in the definition but {} in the source", name, definition, source)
+            }
+            Drift::Payload { name, definition, source } => {
+                let show = |payload: &str| if payload.is_empty() { "no payload".to_string() } else { format!("`{}`", payload) };
+                format!("`{}` has {} in the definition but {} in the source", name, show(definition), show(source))
+            }
+            Drift::Order => "The variants are declared in a different order".to_string(),
+        }
+    }
+}
+
+/// Payload types as they would be written after the variant's name
+fn signature(payload: &VariantPayload) -> String {
+    let ty = |type_ref: &TypeRef| rust::normalize_spacing(&type_ref_to_string(type_ref));
+    match payload {
+        VariantPayload::Unit => String::new(),
+        VariantPayload::Single(type_ref) => format!("({})", ty(type_ref)),
+        VariantPayload::Struct(fields) => {
+            let fields: Vec<String> = fields.iter().map(|field| format!("{}: {}", field.name, ty(&field.type_ref))).collect();
+            format!(" {{ {} }}", fields.join(", "))
+        }
+    }
+}
+
+/// Differences between `asset` and `source`, in the definition's order
+pub fn check(asset: &EnumAsset, source: &SourceEnum) -> Vec<Drift> {
+    let meta = EnumMeta::from_asset(asset);
+    let compare_values = source.has_discriminants()
+        && (meta.repr.is_some() || asset.variants.iter().all(|v| matches!(v.payload, VariantPayload::Unit)));
+    let source_variants: HashMap<&str, (usize, Option<i64>)> = source
+        .variants
+        .iter()
+        .zip(source.values())
+        .enumerate()
+        .map(|(index, (variant, value))| (variant.variant.name.as_str(), (index, value)))
+        .collect();
+
+    let mut drift = Vec::new();
+    let mut order = Vec::new();
+    for (variant, value) in asset.variants.iter().zip(variant_values(asset, &meta)) {
+        let Some((index, source_value)) = source_variants.get(variant.name.as_str()) else {
+            drift.push(Drift::MissingFromSource { name: variant.name.clone() });
+            continue;
+        };
+        order.push(*index);
+        let source_variant = &source.variants[*index].variant;
+        match source_value {
+            Some(source_value) if compare_values && *source_value != value => {
+                drift.push(Drift::Value { name: variant.name.clone(), definition: value, source: *source_value });
+            }
+            _ => {}
+        }
+        let (ours, theirs) = (signature(&variant.payload), signature(&source_variant.payload));
+        if ours != theirs {
+            drift.push(Drift::Payload {
+                name: variant.name.clone(),
+                definition: ours.trim().to_string(),
+                source: theirs.trim().to_string(),
+            });
+        }
+    }
+    for variant in &source.variants {
+        if !asset.variants.iter().any(|v| v.name == variant.variant.name) {
+            drift.push(Drift::MissingFromDefinition { name: variant.variant.name.clone() });
+        }
+    }
+    if order.windows(2).any(|pair| pair[0] > pair[1]) {
+        drift.push(Drift::Order);
+    }
+    drift
+}
+
+/// Rewrite the body of the enum's declaration in the file at `path` from the
+/// definition
+pub fn update_source(path: &Path, asset: &EnumAsset) -> Result<()> {
+    let text = std::fs::read_to_string(path).map_err(|e| EnumEditorError::io(path, e))?;
+    let updated = rust::replace_body(&text, &asset.name, &generate_rust_variants(asset)).ok_or_else(|| {
+        EnumEditorError::Parse { path: path.to_path_buf(), message: format!("no enum `{}` is declared", asset.name) }
+    })?;
+    std::fs::write(path, updated).map_err(|e| EnumEditorError::io(path, e))
+}
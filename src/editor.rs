@@ -6,19 +6,201 @@ use ui::{
     button::{Button, ButtonVariants},
     divider::Divider,
     input::{InputState, TextInput},
+    notification::Notification,
+    ContextModal as _,
 };
 use ui_types_common::{EnumAsset, EnumVariant, TypeRef, Visibility, TypeKind, VariantPayload};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use crate::cache::DefinitionCache;
+use crate::changelog::{self, ChangelogBase};
+use crate::config_check;
+use crate::codegen::{self, CodegenTarget, PlannedOutput, TargetReport, TemplateTarget};
+use crate::drift::{self, Drift};
+use crate::error::{EnumEditorError, Result};
+use crate::export_preview::ExportPreviewView;
+use crate::find_replace::{self, Replacement};
+use crate::fixtures;
+use crate::git;
+use crate::hooks::{self, HookContext, HookStage};
+use crate::import;
+use crate::metadata::{BatchEdit, EnumMeta};
+use crate::naming;
+use crate::output::{OutputLog, OutputSource, Severity};
+use crate::project::{self, EditorConfig, ImportConfig, Project, ProjectConfig};
+use crate::references;
+use crate::renumber;
+use crate::spellcheck::SpellChecker;
+use crate::type_suggestions::TypeSuggestions;
+use crate::storage::{self, EnumFileFormat, SerializationFormat};
+use crate::validation;
 use crate::variant_editor::{VariantEditorView, VariantEditorEvent};
-use crate::workspace_panels::{PropertiesPanel, VariantsPanel, CodePreviewPanel};
+use crate::panels::{
+    PropertiesPanel, PropertiesPanelEvent, VariantsPanel, CodePreviewPanel, CodePreviewPanelEvent,
+    OutputPanel, FindReplacePanel, FindReplacePanelEvent, GenerateReportPanel, DocsPanel, ReviewPanel, ReviewPanelEvent, SamplesPanel,
+    ChangelogPanel, ChangelogPanelEvent, VariantsPanelEvent, SettingsPanel, SettingsPanelEvent,
+    ValidationReportPanel, ValidationReportPanelEvent, TypeRenamePanel, TypeRenamePanelEvent,
+    RenumberPanel, RenumberPanelEvent, MethodsPanel, MethodsPanelEvent, DriftPanel, DriftPanelEvent,
+    ImportPanel, ImportPanelEvent, InspectorPanel, InspectorPanelEvent,
+    ConfigCheckPanel, ConfigCheckPanelEvent,
+};
+use crate::workspace_scan;
 
 actions!(enum_editor, [
     Save,
     AddVariant,
     TogglePreview,
+    ConvertFormat,
+    Undo,
+    GenerateAll,
+    ExportAll,
+    ValidateAll,
+    CheckConfigs,
+    PasteVariants,
+    LoadStressFixture,
+    RevealInFolder,
 ]);
 
+/// How long an editor goes without focus before its cached previews are freed
+const IDLE_TRIM_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// How often editors check whether they have gone idle
+const IDLE_POLL: Duration = Duration::from_secs(30);
+
+/// Variants of the fixture loaded by `LoadStressFixture`
+const STRESS_VARIANTS: usize = 10_000;
+
+/// Snapshots kept for undo
+const MAX_HISTORY: usize = 50;
+
+/// Revisions searched when blaming variants
+const BLAME_DEPTH: usize = 50;
+
+/// How often the enum's Rust source is checked for changes
+const RUST_SOURCE_POLL: Duration = Duration::from_secs(2);
+
+/// Definitions validated per background batch; the report panel is updated
+/// after each
+const VALIDATION_BATCH: usize = 16;
+
+/// How often unsaved edits are written to the recovery file
+const RECOVERY_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A save's file IO and hooks, run off the UI thread
+struct SaveJob {
+    file_path: PathBuf,
+    asset: EnumAsset,
+    /// Write breaking changes to a published enum without asking
+    force: bool,
+    /// Write the definition in this serialization instead, replacing the file
+    serialization: Option<SerializationFormat>,
+    output: OutputLog,
+    definitions: DefinitionCache,
+    /// Order the save was started in
+    generation: u64,
+    /// Generation of the last save written, shared by the editor's saves;
+    /// held while writing so a slower, older save can't overwrite a newer one
+    written: Arc<parking_lot::Mutex<u64>>,
+}
+
+impl SaveJob {
+    fn run(self) -> Result<PathBuf> {
+        self.check_stable_ids()?;
+        if !self.force {
+            self.check_breaking_changes()?;
+        }
+        self.run_pre_save_hooks()?;
+        let mut written = self.written.lock();
+        if *written > self.generation {
+            // A later save already wrote newer contents
+            return Ok(self.file_path);
+        }
+        let file_path = match self.serialization {
+            Some(serialization) => storage::convert_serialization(&self.file_path, &self.asset, serialization)?,
+            None => {
+                storage::save_asset(&self.file_path, &self.asset)?;
+                self.file_path
+            }
+        };
+        *written = self.generation;
+        self.definitions.insert(&file_path, self.asset);
+        Ok(file_path)
+    }
+
+    /// Fail if a stable ID changed since the last save
+    fn check_stable_ids(&self) -> Result<()> {
+        let Ok(saved) = self.definitions.load(&self.file_path) else {
+            return Ok(());
+        };
+        let messages = validation::stable_id_changes(&saved, &self.asset);
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(EnumEditorError::Validation { messages })
+        }
+    }
+
+    /// Fail if the enum is published and the edits since the last save break it
+    fn check_breaking_changes(&self) -> Result<()> {
+        if !EnumMeta::from_asset(&self.asset).published {
+            return Ok(());
+        }
+        let Ok(saved) = self.definitions.load(&self.file_path) else {
+            return Ok(());
+        };
+        let changes: Vec<String> = changelog::diff(&saved, &self.asset)
+            .into_iter()
+            .filter(changelog::Change::is_breaking)
+            .map(|change| change.describe())
+            .collect();
+        if changes.is_empty() {
+            Ok(())
+        } else {
+            Err(EnumEditorError::BreakingChanges { changes })
+        }
+    }
+
+    /// Run the project's pre-save hooks, failing if any command fails
+    fn run_pre_save_hooks(&self) -> Result<()> {
+        let project = Project::find(&self.file_path).unwrap_or_else(|e| {
+            self.output.warn(OutputSource::Editor, e.to_string());
+            None
+        });
+        let Some(project) = project else {
+            return Ok(());
+        };
+        let context = HookContext::new(&project.root, &self.asset.name, &self.file_path);
+        let outputs = hooks::run_all(HookStage::PreSave, &project.config.hooks.pre_save, &context, &self.output);
+        match outputs.into_iter().find(|output| !output.success) {
+            Some(failed) => Err(EnumEditorError::Hook { command: failed.command }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Approximate memory held by an editor, in bytes. Definitions are measured
+/// by their serialized size.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MemoryUsage {
+    pub model: usize,
+    /// Undo snapshots
+    pub history: usize,
+    /// Generated code shown in the preview
+    pub preview: usize,
+}
+
+impl MemoryUsage {
+    pub fn total(&self) -> usize {
+        self.model + self.history + self.preview
+    }
+}
+
+fn serialized_size(asset: &EnumAsset) -> usize {
+    serde_json::to_vec(asset).map_or(0, |bytes| bytes.len())
+}
+
 #[derive(Clone, Debug)]
 pub enum EnumEditorEvent {
     Modified,
@@ -33,38 +215,171 @@ pub struct EnumEditor {
 
     // Workspace for dock panels
     workspace: Option<Entity<Workspace>>,
+    properties_panel: Option<Entity<PropertiesPanel>>,
+    code_preview_panel: Option<Entity<CodePreviewPanel>>,
+    variants_panel: Option<Entity<VariantsPanel>>,
+    generate_report_panel: Option<Entity<GenerateReportPanel>>,
+    settings_panel: Option<Entity<SettingsPanel>>,
+    validation_report_panel: Option<Entity<ValidationReportPanel>>,
+    config_check_panel: Option<Entity<ConfigCheckPanel>>,
+
+    // Messages shown in the Output panel
+    output: OutputLog,
+
+    // Dictionaries and ignore list of the project, shared with the variant editors
+    spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+    // Types the variant editors' type pickers offer
+    type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
+
+    // Snapshots taken before bulk edits, most recent last
+    history: Vec<EnumAsset>,
+
+    // Modified flag, also set by the panels
+    modified: Arc<AtomicBool>,
+
+    // Parsed definitions shared with the plugin's other editors
+    definitions: DefinitionCache,
+
+    // Periodic autosave, if the project enables it
+    autosave_task: Option<Task<()>>,
+    // Last autosave failure, so it is only reported once
+    autosave_error: Option<String>,
+    // A save is being written in the background
+    saving: bool,
+    // Saves started so far, and the generation of the last one written
+    save_generation: u64,
+    written_generation: Arc<parking_lot::Mutex<u64>>,
+    // Serialization the next save converts the definition to
+    convert_on_save: Option<SerializationFormat>,
+    // Save asked for while another was running, as (force, autosave); it
+    // starts once the running save finishes
+    queued_save: Option<(bool, bool)>,
+    // Validate All run in progress; replacing it cancels the run
+    validation_task: Option<Task<()>>,
+    // Config file check in progress
+    config_check_task: Option<Task<()>>,
+    // Export All being planned
+    export_task: Option<Task<()>>,
+    // Search for a variant's references in the project's sources
+    references_task: Option<Task<()>>,
+    // Per-variant blame being read from git for the Variants panel
+    blame_task: Option<Task<()>>,
+
+    // Polls the enum's Rust source (`meta.rust_source`) for changes
+    rust_source_watch: Option<Task<()>>,
+    // Modification time of the Rust source when it was last looked at
+    rust_source_seen: Option<SystemTime>,
 
-    // Modified flag
-    modified: bool,
+    // Periodically writes unsaved edits to the recovery file
+    recovery_task: Option<Task<()>>,
+    // Last failure writing the recovery file, so it is only reported once
+    recovery_error: Option<String>,
+    // Unsaved edits found when the definition was opened, until restored or discarded
+    recovered: Option<EnumAsset>,
+
+    // When focus last entered the editor, and whether its previews were
+    // freed since
+    last_focused: Instant,
+    trimmed: bool,
+    _focus_subscription: Option<Subscription>,
+    idle_task: Option<Task<()>>,
 }
 
 impl EnumEditor {
-    pub fn new_with_file(file_path: PathBuf, window: &mut Window, cx: &mut Context<Self>) -> Self {
+    pub fn new_with_file(file_path: PathBuf, definitions: DefinitionCache, window: &mut Window, cx: &mut Context<Self>) -> Self {
         // Try to load the enum data
-        let (asset, error_message) = match std::fs::read_to_string(&file_path) {
-            Ok(json_content) => {
-                match serde_json::from_str::<EnumAsset>(&json_content) {
-                    Ok(asset) => (asset, None),
-                    Err(e) => (
-                        Self::create_empty_asset(),
-                        Some(format!("Failed to parse enum: {}", e))
-                    ),
-                }
-            }
-            Err(_) => (Self::create_empty_asset(), None),
+        let (asset, error, is_new) = match definitions.load(&file_path) {
+            Ok(asset) => ((*asset).clone(), None, false),
+            // A definition that doesn't exist yet starts out empty
+            Err(e) if e.is_not_found() => (Self::create_empty_asset(), None, true),
+            Err(e) => (Self::create_empty_asset(), Some(e), false),
         };
 
+        let output = OutputLog::new();
+        let error_message = error.as_ref().map(ToString::to_string);
+        match error {
+            Some(e) => {
+                output.error(OutputSource::Editor, e.to_string());
+                window.push_notification(Notification::error(e.to_string()).title(e.title()), cx);
+            }
+            None => output.info(OutputSource::Editor, format!("Opened {}", file_path.display())),
+        }
+        if let Err(message) = EnumMeta::read(&asset) {
+            let message = format!(
+                "The metadata of {} can't be read, so it is left as it is and edits to it are not saved: {}",
+                file_path.display(),
+                message
+            );
+            output.error(OutputSource::Editor, message.clone());
+            window.push_notification(Notification::error(message).title("Parse error"), cx);
+        }
+
         let mut editor = Self {
             file_path: Some(file_path),
             asset: Arc::new(parking_lot::RwLock::new(asset)),
             error_message,
             focus_handle: cx.focus_handle(),
             workspace: None,
-            modified: false,
+            properties_panel: None,
+            code_preview_panel: None,
+            generate_report_panel: None,
+            variants_panel: None,
+            settings_panel: None,
+            validation_report_panel: None,
+            config_check_panel: None,
+            output,
+            spell_checker: Arc::new(parking_lot::RwLock::new(SpellChecker::default())),
+            type_suggestions: Arc::new(parking_lot::RwLock::new(TypeSuggestions::default())),
+            history: Vec::new(),
+            modified: Arc::new(AtomicBool::new(false)),
+            definitions,
+            autosave_task: None,
+            autosave_error: None,
+            saving: false,
+            save_generation: 0,
+            written_generation: Arc::new(parking_lot::Mutex::new(0)),
+            convert_on_save: None,
+            queued_save: None,
+            validation_task: None,
+            config_check_task: None,
+            references_task: None,
+            blame_task: None,
+            export_task: None,
+            rust_source_watch: None,
+            rust_source_seen: None,
+            recovery_task: None,
+            recovery_error: None,
+            recovered: None,
+            last_focused: Instant::now(),
+            trimmed: false,
+            _focus_subscription: None,
+            idle_task: None,
         };
 
+        // Enums without a file yet start with the project's defaults; files
+        // the host creates already hold them
+        let project = editor.file_path.as_deref().and_then(|path| editor.project(path));
+        if let Some(project) = project.as_ref().filter(|_| is_new) {
+            project.config.defaults.apply(&mut editor.asset.write());
+            // The first save writes the project's serialization
+            let serialization = project.config.defaults.serialization;
+            if let (Some(path), Some(serialization)) = (&editor.file_path, serialization) {
+                if EnumFileFormat::detect(path) == EnumFileFormat::Flat && storage::serialization_of(path) != serialization {
+                    editor.convert_on_save = Some(serialization);
+                }
+            }
+            editor.modified.store(true, Ordering::Relaxed);
+            editor.output.info(OutputSource::Editor, "Applied project defaults");
+        }
+
         // Initialize workspace with panels
         editor.initialize_workspace(window, cx);
+        editor.load_settings(project, window, cx);
+        editor.watch_rust_source(window, cx);
+        editor.check_recovery(cx);
+        editor.start_recovery(window, cx);
+        editor.load_columns(cx);
+        editor.watch_idle(window, cx);
 
         editor
     }
@@ -86,41 +401,423 @@ impl EnumEditor {
 
         let asset_clone = self.asset.clone();
 
-        workspace.update(cx, |workspace, cx| {
-            let dock_area = workspace.dock_area().downgrade();
+        // Create Code Preview Panel (right) first, so edits in the other
+        // panels can mark it stale
+        let code_preview_panel = cx.new(|cx| {
+            let mut panel = CodePreviewPanel::new(asset_clone.clone(), window, cx);
+            if let Some(file_path) = &self.file_path {
+                panel.set_templates(codegen::discover_templates(file_path), cx);
+            }
+            panel
+        });
+        let preview_trigger = code_preview_panel.read(cx).trigger();
 
-            // Create Properties Panel (left)
-            let properties_panel = cx.new(|cx| {
-                PropertiesPanel::new(asset_clone.clone(), window, cx)
+        // Create Properties Panel (left)
+        let properties_panel = cx.new(|cx| {
+            let mut panel = PropertiesPanel::new(asset_clone.clone(), window, cx);
+            let modified = self.modified.clone();
+            let preview_trigger = preview_trigger.clone();
+            panel.set_on_modified(move || {
+                modified.store(true, Ordering::Relaxed);
+                preview_trigger.edited();
             });
+            if let Some(file_path) = &self.file_path {
+                panel.set_file_format(
+                    EnumFileFormat::detect(file_path),
+                    storage::serialization_of(file_path),
+                    cx,
+                );
+            }
+            panel
+        });
+        cx.subscribe_in(&properties_panel, window, |this, _, event: &PropertiesPanelEvent, window, cx| {
+            match event {
+                PropertiesPanelEvent::ConvertFormatRequested => {
+                    this.convert_format(&ConvertFormat, window, cx);
+                }
+                PropertiesPanelEvent::SerializationChangeRequested(serialization) => {
+                    this.change_serialization(*serialization, window, cx);
+                }
+                PropertiesPanelEvent::ModeChanged(_) => {
+                    if let Some(panel) = &this.variants_panel {
+                        panel.update(cx, |panel, cx| panel.sync_flags_mode(cx));
+                    }
+                }
+                PropertiesPanelEvent::DerivesChanged => this.revalidate_in_report(cx),
+                PropertiesPanelEvent::VariantMetaChanged => {
+                    if let Some(panel) = &this.variants_panel {
+                        panel.update(cx, |panel, cx| panel.rebuild(window, cx));
+                    }
+                }
+            }
+        }).detach();
 
-            // Create Variants Panel (center)
-            let variants_panel = cx.new(|cx| {
-                VariantsPanel::new(asset_clone.clone(), window, cx)
+        // Create Variants Panel (center)
+        let search_root = self.file_path.as_deref().map(|path| self.search_root(path));
+        if let Some(file_path) = &self.file_path {
+            *self.spell_checker.write() = self.load_spell_checker(file_path);
+        }
+        let spell_checker = self.spell_checker.clone();
+        let type_suggestions = self.type_suggestions.clone();
+        let variants_panel = cx.new(|cx| {
+            let mut panel = VariantsPanel::new(asset_clone.clone(), spell_checker, type_suggestions, window, cx);
+            let modified = self.modified.clone();
+            panel.set_on_modified(move || {
+                modified.store(true, Ordering::Relaxed);
+                preview_trigger.edited();
             });
+            panel.set_user(search_root.as_deref().and_then(project::current_user));
+            panel.set_search_root(search_root);
+            panel
+        });
 
-            // Create Code Preview Panel (right)
-            let code_preview_panel = cx.new(|cx| {
-                CodePreviewPanel::new(asset_clone.clone(), window, cx)
-            });
+        // Inspector for the variant selected in the compact list (tabbed with the properties)
+        let inspector_panel = cx.new(|cx| InspectorPanel::new(window, cx));
+
+        let inspector = inspector_panel.clone();
+        cx.subscribe_in(&variants_panel, window, move |this, panel, event: &VariantsPanelEvent, window, cx| {
+            match event {
+                VariantsPanelEvent::BlameRequested => {
+                    this.load_blame(window, cx);
+                }
+                VariantsPanelEvent::ReimportRequested => {
+                    let path = EnumMeta::from_asset(&this.asset.read()).rust_source;
+                    if let Some(path) = path {
+                        if let Err(e) = this.update_definition_from_source(&path, window, cx) {
+                            this.report_error(OutputSource::Editor, &e, window, cx);
+                        }
+                    }
+                }
+                VariantsPanelEvent::PasteRequested { source, replace } => {
+                    this.apply_paste(source, *replace, window, cx);
+                }
+                VariantsPanelEvent::RestoreRecoveryRequested => {
+                    this.restore_recovery(window, cx);
+                }
+                VariantsPanelEvent::DiscardRecoveryRequested => {
+                    this.discard_recovery(window, cx);
+                }
+                VariantsPanelEvent::VariantSelected(name) => {
+                    if let Some(preview) = &this.code_preview_panel {
+                        preview.update(cx, |preview, cx| preview.highlight_variant(name, window, cx));
+                    }
+                }
+                VariantsPanelEvent::InspectedChanged => {
+                    let variants = panel.read(cx).inspected();
+                    inspector.update(cx, |inspector, cx| inspector.set_variants(variants, cx));
+                }
+                VariantsPanelEvent::FindReferencesRequested(name) => {
+                    this.find_references(name.clone(), window, cx);
+                }
+                VariantsPanelEvent::ColumnsChanged(columns) => {
+                    if let Some(file_path) = this.file_path.clone() {
+                        if let Err(e) = storage::save_columns(&file_path, columns) {
+                            this.report_error(OutputSource::Editor, &e, window, cx);
+                        }
+                    }
+                }
+            }
+        }).detach();
+
+        cx.subscribe_in(&inspector_panel, window, |this, _, event: &InspectorPanelEvent, window, cx| {
+            match event {
+                InspectorPanelEvent::BatchEditRequested { names, edit } => {
+                    this.apply_batch_edit(names, edit, window, cx);
+                }
+            }
+        }).detach();
+
+        // Subscribe to the Code Preview Panel (right)
+        cx.subscribe_in(&code_preview_panel, window, |this, _, event: &CodePreviewPanelEvent, window, cx| {
+            match event {
+                CodePreviewPanelEvent::ExportRequested(target) => {
+                    this.export(*target, false, window, cx);
+                }
+                CodePreviewPanelEvent::ExportTemplateRequested(template) => {
+                    this.export_template(template, window, cx);
+                }
+                CodePreviewPanelEvent::GenerateAllRequested => {
+                    this.generate_all(&GenerateAll, window, cx);
+                }
+                CodePreviewPanelEvent::ExportAllRequested => {
+                    this.export_all(&ExportAll, window, cx);
+                }
+                CodePreviewPanelEvent::CompareRequested => {
+                    this.compare_with_saved(window, cx);
+                }
+                CodePreviewPanelEvent::VariantClicked(name) => {
+                    let index = this.asset.read().variants.iter().position(|v| v.name == *name);
+                    if let Some(index) = index {
+                        this.reveal_variant(index, window, cx);
+                    }
+                }
+            }
+        }).detach();
+
+        // Create Find & Replace Panel (tabbed with the properties)
+        let find_replace_panel = cx.new(|cx| {
+            FindReplacePanel::new(asset_clone.clone(), window, cx)
+        });
+        cx.subscribe_in(&find_replace_panel, window, |this, panel, event: &FindReplacePanelEvent, window, cx| {
+            match event {
+                FindReplacePanelEvent::ApplyRequested(replacements) => {
+                    this.apply_replacements(replacements, window, cx);
+                }
+                FindReplacePanelEvent::UndoRequested => {
+                    this.undo(&Undo, window, cx);
+                }
+                FindReplacePanelEvent::RevealRequested(index) => {
+                    this.reveal_variant(*index, window, cx);
+                }
+            }
+            panel.update(cx, |panel, cx| panel.refresh(cx));
+        }).detach();
+
+        // Create Rename Type Panel (tabbed with the properties)
+        let type_rename_panel = cx.new(|cx| {
+            TypeRenamePanel::new(window, cx)
+        });
+        cx.subscribe_in(&type_rename_panel, window, |this, panel, event: &TypeRenamePanelEvent, window, cx| {
+            match event {
+                TypeRenamePanelEvent::PreviewRequested { old, new } => {
+                    if let Some((root, plans)) = this.plan_type_rename(old, new) {
+                        panel.update(cx, |panel, cx| panel.set_plans(root, plans, cx));
+                    }
+                }
+                TypeRenamePanelEvent::ApplyRequested { old, new, plans } => {
+                    this.apply_type_rename(old, new, plans, window, cx);
+                }
+            }
+        }).detach();
+
+        // Create Docs Panel (tabbed with the variants)
+        let docs_panel = cx.new(|cx| {
+            DocsPanel::new(asset_clone.clone(), window, cx)
+        });
+
+        // Create Review Panel (tabbed with the variants)
+        let review_panel = cx.new(|cx| {
+            ReviewPanel::new(asset_clone.clone(), window, cx)
+        });
+        cx.subscribe_in(&review_panel, window, |this, _, event: &ReviewPanelEvent, window, cx| {
+            match event {
+                ReviewPanelEvent::ExportRequested => this.export_review(window, cx),
+            }
+        }).detach();
+
+        // Create Samples Panel (tabbed with the variants)
+        let samples_panel = cx.new(|cx| {
+            SamplesPanel::new(asset_clone.clone(), window, cx)
+        });
+
+        // Create Renumber Panel (tabbed with the variants)
+        let renumber_panel = cx.new(|cx| {
+            RenumberPanel::new(asset_clone.clone(), window, cx)
+        });
+        cx.subscribe_in(&renumber_panel, window, |this, _, event: &RenumberPanelEvent, window, cx| {
+            match event {
+                RenumberPanelEvent::ApplyRequested(changes) => {
+                    this.push_history();
+                    renumber::apply(&mut this.asset.write(), changes);
+                    this.modified.store(true, Ordering::Relaxed);
+                    this.refresh_panels(window, cx);
+                    this.output.info(OutputSource::Editor, format!("Renumbered {} variant(s)", changes.len()));
+                    cx.emit(EnumEditorEvent::Modified);
+                    cx.notify();
+                }
+            }
+        }).detach();
+
+        // Create Methods Panel (tabbed with the variants)
+        let methods_panel = cx.new(|cx| {
+            MethodsPanel::new(asset_clone.clone(), window, cx)
+        });
+        cx.subscribe_in(&methods_panel, window, |this, _, event: &MethodsPanelEvent, window, cx| {
+            match event {
+                MethodsPanelEvent::MethodsChanged(methods) => {
+                    this.push_history();
+                    let mut asset = this.asset.write();
+                    let mut meta = EnumMeta::from_asset(&asset);
+                    meta.methods = methods.clone();
+                    meta.write_to(&mut asset);
+                    drop(asset);
+                    this.modified.store(true, Ordering::Relaxed);
+                    this.refresh_panels(window, cx);
+                    cx.emit(EnumEditorEvent::Modified);
+                    cx.notify();
+                }
+            }
+        }).detach();
+
+        // Create Drift Panel (tabbed with the variants)
+        let drift_panel = cx.new(|cx| {
+            DriftPanel::new(asset_clone.clone(), window, cx)
+        });
+        cx.subscribe_in(&drift_panel, window, |this, panel, event: &DriftPanelEvent, window, cx| {
+            let result = match event {
+                DriftPanelEvent::CheckRequested(path) => this.check_drift(path, window, cx),
+                DriftPanelEvent::UpdateDefinitionRequested(path) => this.update_definition_from_source(path, window, cx),
+                DriftPanelEvent::UpdateSourceRequested(path) => this.update_source_from_definition(path, window, cx),
+            };
+            if let Err(e) = &result {
+                this.report_error(OutputSource::Editor, e, window, cx);
+            }
+            panel.update(cx, |panel, cx| panel.set_result(result.map_err(|e| e.to_string()), cx));
+            cx.notify();
+        }).detach();
+
+        // Create Import Panel (tabbed with the properties)
+        let import_panel = cx.new(|cx| {
+            ImportPanel::new(window, cx)
+        });
+        cx.subscribe_in(&import_panel, window, |this, panel, event: &ImportPanelEvent, window, cx| {
+            match event {
+                ImportPanelEvent::ScanRequested(path) => {
+                    let rules = this.import_rules();
+                    let result = this.resolve_source_path(path).and_then(|path| import::parse_file_conformed(&path, &rules));
+                    if let Err(e) = &result {
+                        this.report_error(OutputSource::Editor, e, window, cx);
+                    }
+                    let path = path.clone();
+                    panel.update(cx, |panel, cx| panel.set_enums(path, result.map_err(|e| e.to_string()), cx));
+                }
+                ImportPanelEvent::ImportRequested { path, source } => {
+                    this.import_enum(path, source, window, cx);
+                }
+                ImportPanelEvent::CreateRequested(sources) => {
+                    this.create_definitions(sources, window, cx);
+                }
+            }
+            cx.notify();
+        }).detach();
+
+        // Create Output Panel (tabbed with the code preview)
+        let output_panel = cx.new(|cx| {
+            OutputPanel::new(self.output.clone(), window, cx)
+        });
+
+        // Create Generate Report Panel (tabbed with the code preview)
+        let generate_report_panel = cx.new(|cx| {
+            GenerateReportPanel::new(window, cx)
+        });
+        self.generate_report_panel = Some(generate_report_panel.clone());
+
+        // Create Changelog Panel (tabbed with the code preview)
+        let changelog_panel = cx.new(|cx| {
+            ChangelogPanel::new(window, cx)
+        });
+        cx.subscribe_in(&changelog_panel, window, |this, panel, event: &ChangelogPanelEvent, window, cx| {
+            match event {
+                ChangelogPanelEvent::CompareRequested(base) => {
+                    if let Some(changelog) = this.changelog(base, window, cx) {
+                        panel.update(cx, |panel, cx| panel.set_changelog(changelog, cx));
+                    }
+                }
+            }
+        }).detach();
+
+        // Create Validation Report Panel (tabbed with the code preview)
+        let validation_report_panel = cx.new(|cx| {
+            ValidationReportPanel::new(window, cx)
+        });
+        cx.subscribe_in(&validation_report_panel, window, |this, _, event: &ValidationReportPanelEvent, window, cx| {
+            match event {
+                ValidationReportPanelEvent::ValidateAllRequested => {
+                    this.validate_all(&ValidateAll, window, cx);
+                }
+                ValidationReportPanelEvent::IssueSelected { path, variant } => {
+                    if this.file_path.as_ref() == Some(path) {
+                        this.reveal_variant(*variant, window, cx);
+                    } else {
+                        this.output.info(OutputSource::Validation, format!("Open {} to see the variant", path.display()));
+                    }
+                }
+            }
+        }).detach();
+        self.validation_report_panel = Some(validation_report_panel.clone());
+
+        // Create Config Check Panel (tabbed with the code preview)
+        let default_key = naming::snake_case(&asset_clone.read().name);
+        let config_check_panel = cx.new(|cx| {
+            ConfigCheckPanel::new(&default_key, window, cx)
+        });
+        cx.subscribe_in(&config_check_panel, window, |this, _, event: &ConfigCheckPanelEvent, window, cx| {
+            match event {
+                ConfigCheckPanelEvent::CheckRequested { dir, keys } => {
+                    this.check_configs(dir, keys, window, cx);
+                }
+            }
+        }).detach();
+        self.config_check_panel = Some(config_check_panel.clone());
+
+        // Create Settings Panel (tabbed with the properties)
+        let settings_panel = cx.new(|cx| {
+            SettingsPanel::new(window, cx)
+        });
+        cx.subscribe_in(&settings_panel, window, |this, _, event: &SettingsPanelEvent, window, cx| {
+            match event {
+                SettingsPanelEvent::SaveRequested(config) => {
+                    this.save_settings(config.clone(), window, cx);
+                }
+                SettingsPanelEvent::ApplyDefaultsRequested(defaults) => {
+                    this.push_history();
+                    defaults.apply(&mut this.asset.write());
+                    this.modified.store(true, Ordering::Relaxed);
+                    this.refresh_panels(window, cx);
+                    this.output.info(OutputSource::Editor, "Applied project defaults");
+                    cx.emit(EnumEditorEvent::Modified);
+                    cx.notify();
+                }
+            }
+        }).detach();
+        self.settings_panel = Some(settings_panel.clone());
+
+        self.properties_panel = Some(properties_panel.clone());
+        self.code_preview_panel = Some(code_preview_panel.clone());
+        self.variants_panel = Some(variants_panel.clone());
+
+        workspace.update(cx, |workspace, cx| {
+            let dock_area = workspace.dock_area().downgrade();
 
             // Setup dock layout - all panels in tabs for consistency
             let center = DockItem::tabs(
-                vec![Arc::new(variants_panel) as Arc<dyn ui::dock::PanelView>],
+                vec![
+                    Arc::new(variants_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(docs_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(review_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(samples_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(renumber_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(methods_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(drift_panel) as Arc<dyn ui::dock::PanelView>,
+                ],
                 Some(0),
                 &dock_area,
                 window,
                 cx,
             );
             let left = DockItem::tabs(
-                vec![Arc::new(properties_panel) as Arc<dyn ui::dock::PanelView>],
+                vec![
+                    Arc::new(properties_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(inspector_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(find_replace_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(type_rename_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(import_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(settings_panel) as Arc<dyn ui::dock::PanelView>,
+                ],
                 Some(0),
                 &dock_area,
                 window,
                 cx,
             );
             let right = DockItem::tabs(
-                vec![Arc::new(code_preview_panel) as Arc<dyn ui::dock::PanelView>],
+                vec![
+                    Arc::new(code_preview_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(output_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(generate_report_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(changelog_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(validation_report_panel) as Arc<dyn ui::dock::PanelView>,
+                    Arc::new(config_check_panel) as Arc<dyn ui::dock::PanelView>,
+                ],
                 Some(0),
                 &dock_area,
                 window,
@@ -155,150 +852,1566 @@ impl EnumEditor {
         self.file_path.clone()
     }
 
-    fn save(&mut self, _: &Save, _window: &mut Window, cx: &mut Context<Self>) {
-        if let Some(file_path) = &self.file_path {
-            let asset = self.asset.read();
-            match serde_json::to_string_pretty(&*asset) {
-                Ok(json) => {
-                    drop(asset); // Release the read lock before writing
-                    if let Err(e) = std::fs::write(file_path, json) {
-                        self.error_message = Some(format!("Failed to save: {}", e));
-                    } else {
-                        self.error_message = None;
-                        self.modified = false;
-                        eprintln!("✅ Saved enum to {:?}", file_path);
-                        cx.emit(EnumEditorEvent::Saved);
-                    }
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to serialize: {}", e));
-                }
+    /// Categories assigned to this enum, used for grouping in the file drawer
+    pub fn categories(&self) -> Vec<String> {
+        EnumMeta::from_asset(&self.asset.read()).categories
+    }
+
+    /// Switch the definition between the folder-based and flat single-file layouts
+    fn convert_format(&mut self, _: &ConvertFormat, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| {
+            storage::convert(&file_path).map(|new_path| (file_path, new_path))
+        });
+        match result {
+            Ok((file_path, new_path)) => {
+                self.output.info(OutputSource::Editor, format!("Converted {} to {}", file_path.display(), new_path.display()));
+                self.set_file_path(new_path, cx);
+                self.error_message = None;
             }
+            Err(e) => self.report_error(OutputSource::Editor, &e, window, cx),
         }
         cx.notify();
     }
-}
 
-impl EventEmitter<EnumEditorEvent> for EnumEditor {}
-impl EventEmitter<PanelEvent> for EnumEditor {}
-
-impl Render for EnumEditor {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        if let Some(ref workspace) = self.workspace {
-            workspace.clone().into_any_element()
-        } else {
-            div()
-                .size_full()
-                .flex()
-                .items_center()
-                .justify_center()
-                .child("Initializing workspace...")
-                .into_any_element()
+    /// Rewrite the definition file using another serialization (JSON, YAML,
+    /// TOML). The rewrite is a save, with its checks and hooks.
+    fn change_serialization(&mut self, serialization: SerializationFormat, window: &mut Window, cx: &mut Context<Self>) {
+        self.convert_on_save = Some(serialization);
+        if let Err(e) = self.start_save(false, false, window, cx) {
+            self.convert_on_save = None;
+            self.save_failed(e, window, cx);
         }
+        cx.notify();
     }
-}
 
-impl Focusable for EnumEditor {
-    fn focus_handle(&self, _cx: &App) -> FocusHandle {
-        self.focus_handle.clone()
+    /// Generate an export target and write it next to the enum asset
+    fn export(&mut self, target: CodegenTarget, force: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| {
+            let asset = self.asset.read();
+            validation::ensure_valid(&asset)?;
+            codegen::export(target, &file_path, &asset, force).map(|output_path| (file_path, output_path))
+        });
+        match result {
+            Ok((file_path, output_path)) => {
+                self.output.info(OutputSource::Export, format!("Exported {} to {}", target.label(), output_path.display()));
+                self.error_message = None;
+                self.spawn_post_hooks(HookStage::PostExport, &file_path, Some(&output_path));
+            }
+            Err(EnumEditorError::ManuallyEdited { path }) => {
+                self.confirm_overwrite(target, &path, window, cx);
+            }
+            Err(e) => self.report_error(OutputSource::Export, &e, window, cx),
+        }
+        cx.notify();
     }
-}
 
-impl Panel for EnumEditor {
-    fn panel_name(&self) -> &'static str {
-        "Enum Editor"
+    /// Write the review sheet next to the enum asset
+    fn export_review(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| codegen::export_review(&file_path, &self.asset.read()));
+        match result {
+            Ok(path) => {
+                self.output.info(OutputSource::Export, format!("Wrote review sheet to {}", path.display()));
+                self.error_message = None;
+            }
+            Err(e) => self.report_error(OutputSource::Export, &e, window, cx),
+        }
+        cx.notify();
     }
 
-    fn title(&self, _window: &Window, _cx: &App) -> gpui::AnyElement {
-        let asset = self.asset.read();
-        format!(
-            "{}{}",
-            asset.display_name,
-            if self.modified { " •" } else { "" }
-        )
-        .into_any_element()
-    }
+    /// Ask before an export overwrites a generated file that was edited by hand
+    fn confirm_overwrite(&mut self, target: CodegenTarget, path: &std::path::Path, window: &mut Window, cx: &mut Context<Self>) {
+        let message = EnumEditorError::ManuallyEdited { path: path.to_path_buf() }.to_string();
+        self.output.warn(OutputSource::Export, message.clone());
 
-    fn dump(&self, _cx: &App) -> ui::dock::PanelState {
-        let info = self.file_path.as_ref().map(|p| {
-            serde_json::json!({
-                "file_path": p.to_string_lossy().to_string()
-            })
-        }).unwrap_or(serde_json::Value::Null);
+        let editor = cx.entity().downgrade();
+        window.open_modal(cx, move |modal, _window, _cx| {
+            let editor = editor.clone();
+            modal
+                .title("Overwrite hand-edited file?")
+                .child(message.clone())
+                .confirm()
+                .on_ok(move |_, window, cx| {
+                    editor.update(cx, |editor, cx| editor.export(target, true, window, cx)).ok();
+                    true
+                })
+        });
+    }
 
-        ui::dock::PanelState {
-            panel_name: self.panel_name().to_string(),
-            info: ui::dock::PanelInfo::Panel(info),
-            ..Default::default()
+    /// Render a user template and write it next to the enum asset
+    fn export_template(&mut self, template: &TemplateTarget, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| {
+            let asset = self.asset.read();
+            validation::ensure_valid(&asset)?;
+            codegen::export_template(template, &file_path, &asset).map(|output_path| (file_path, output_path))
+        });
+        match result {
+            Ok((file_path, output_path)) => {
+                self.output.info(OutputSource::Export, format!("Rendered template {} to {}", template.name, output_path.display()));
+                self.error_message = None;
+                self.spawn_post_hooks(HookStage::PostExport, &file_path, Some(&output_path));
+            }
+            Err(e) => self.report_error(OutputSource::Export, &e, window, cx),
         }
+        cx.notify();
     }
-}
 
-// Plugin-related methods (called by EnumEditorWrapper)
-impl EnumEditor {
-    pub fn plugin_save(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> Result<(), plugin_editor_api::PluginError> {
-        if let Some(file_path) = &self.file_path {
+    /// Generate every enabled codegen target and preview the files before writing them
+    fn generate_all(&mut self, _: &GenerateAll, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| {
             let asset = self.asset.read();
-            match serde_json::to_string_pretty(&*asset) {
-                Ok(json) => {
-                    drop(asset); // Release the read lock before writing
-                    std::fs::write(file_path, json)
-                        .map_err(|e| plugin_editor_api::PluginError::FileSaveError {
-                            path: file_path.clone(),
-                            message: e.to_string(),
-                        })?;
-                    self.error_message = None;
-                    self.modified = false;
-                    cx.emit(EnumEditorEvent::Saved);
-                    cx.notify();
-                    Ok(())
-                }
-                Err(e) => {
-                    Err(plugin_editor_api::PluginError::FileSaveError {
-                        path: file_path.clone(),
-                        message: format!("Failed to serialize: {}", e),
-                    })
-                }
+            validation::ensure_valid(&asset)?;
+            Ok(codegen::plan_all(&file_path, &asset))
+        });
+        let plans = match result {
+            Ok(plans) => plans,
+            Err(e) => {
+                self.report_error(OutputSource::Export, &e, window, cx);
+                cx.notify();
+                return;
             }
-        } else {
-            Err(plugin_editor_api::PluginError::Other {
-                message: "No file path set".into(),
-            })
-        }
+        };
+        self.preview_plans("Generate All", plans, window, cx);
     }
 
-    pub fn plugin_reload(&mut self, window: &mut Window, cx: &mut Context<Self>) -> Result<(), plugin_editor_api::PluginError> {
-        if let Some(file_path) = &self.file_path {
-            match std::fs::read_to_string(&file_path) {
-                Ok(json_content) => {
-                    match serde_json::from_str::<ui_types_common::EnumAsset>(&json_content) {
-                        Ok(asset) => {
-                            *self.asset.write() = asset;
-                            self.error_message = None;
-                            self.modified = false;
-                            self.initialize_workspace(window, cx);
-                            cx.notify();
-                            Ok(())
-                        }
-                        Err(e) => {
-                            Err(plugin_editor_api::PluginError::FileLoadError {
-                                path: file_path.clone(),
-                                message: format!("Failed to parse: {}", e),
-                            })
+    /// Generate every enum in the project into the layout of the project's
+    /// export settings, and preview the files before writing them. Enums
+    /// that don't pass validation or contain each other are left out.
+    fn export_all(&mut self, _: &ExportAll, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(file_path) = self.require_file_path() else {
+            return;
+        };
+        let (root, config) = match self.project(&file_path) {
+            Some(project) => (project.root, project.config.export),
+            None => (storage::asset_container_dir(&file_path), Default::default()),
+        };
+        let definitions = self.definitions.clone();
+        // This enum is exported as edited, the others as saved
+        let current = (file_path, self.asset.read().clone());
+        self.export_task = Some(cx.spawn_in(window, async move |editor, cx| {
+            let (plans, skipped) = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut enums = Vec::new();
+                    let mut skipped = Vec::new();
+                    for path in workspace_scan::enum_files(&root) {
+                        let asset = if path == current.0 {
+                            Ok(current.1.clone())
+                        } else {
+                            definitions.load(&path).map(|asset| (*asset).clone())
+                        };
+                        match asset.and_then(|asset| validation::ensure_valid(&asset).map(|_| asset)) {
+                            Ok(asset) => enums.push((path, asset)),
+                            Err(e) => skipped.push(format!("Not exporting {}: {}", path.display(), e)),
                         }
                     }
+                    // Dependencies first, so joined files declare them first
+                    let (order, cycles, blocked) = workspace_scan::export_order(&enums);
+                    for cycle in cycles {
+                        let names: Vec<&str> = cycle.iter().map(|&index| enums[index].1.name.as_str()).collect();
+                        skipped.push(format!(
+                            "Not exporting {}: they hold each other by value, so none has a finite size; put one behind a Box",
+                            names.join(", ")
+                        ));
+                    }
+                    for (index, dependency) in blocked {
+                        skipped.push(format!(
+                            "Not exporting {}: it mentions {}, which is not exported",
+                            enums[index].1.name, enums[dependency].1.name
+                        ));
+                    }
+                    let enums: Vec<_> = order.into_iter().map(|index| enums[index].clone()).collect();
+                    (codegen::plan_export(&root, &config, &enums), skipped)
+                })
+                .await;
+            editor
+                .update_in(cx, |editor, window, cx| {
+                    if !skipped.is_empty() {
+                        let summary = match skipped.as_slice() {
+                            [message] => message.clone(),
+                            _ => format!("{} problems left enums out, see the Output panel", skipped.len()),
+                        };
+                        window.push_notification(Notification::warning(summary).title("Export All"), cx);
+                    }
+                    for message in skipped {
+                        editor.output.warn(OutputSource::Export, message);
+                    }
+                    editor.preview_plans("Export All", plans, window, cx);
+                })
+                .ok();
+        }));
+    }
+
+    /// Show the files a batch would write and write the ones kept on confirmation
+    fn preview_plans(&mut self, title: &'static str, plans: Vec<PlannedOutput>, window: &mut Window, cx: &mut Context<Self>) {
+        if plans.is_empty() {
+            window.push_notification(Notification::info("No codegen targets are enabled"), cx);
+            return;
+        }
+
+        let preview = cx.new(|_| ExportPreviewView::new(plans));
+        let editor = cx.entity().downgrade();
+        window.open_modal(cx, move |modal, _window, _cx| {
+            let preview = preview.clone();
+            let editor = editor.clone();
+            modal
+                .title(title)
+                .child(preview.clone())
+                .confirm()
+                .on_ok(move |_, window, cx| {
+                    let plans = preview.update(cx, |preview, _| preview.take_included());
+                    editor
+                        .update(cx, |editor, cx| editor.write_generated(plans, window, cx))
+                        .ok();
+                    true
+                })
+        });
+    }
+
+    /// Validate every enum in the project on a background thread, streaming
+    /// the problems into the report panel
+    fn validate_all(&mut self, _: &ValidateAll, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(file_path) = self.require_file_path() else {
+            return;
+        };
+        let root = self.search_root(&file_path);
+        let definitions = self.definitions.clone();
+        self.validation_task = Some(cx.spawn_in(window, async move |editor, cx| {
+            let files = cx
+                .background_executor()
+                .spawn({
+                    let root = root.clone();
+                    async move { workspace_scan::enum_files(&root) }
+                })
+                .await;
+            let total = files.len();
+            let begun = editor.update(cx, |editor, cx| {
+                if let Some(panel) = &editor.validation_report_panel {
+                    panel.update(cx, |panel, cx| panel.begin(root, total, cx));
                 }
-                Err(e) => {
-                    Err(plugin_editor_api::PluginError::FileLoadError {
-                        path: file_path.clone(),
-                        message: e.to_string(),
+            });
+            if begun.is_err() {
+                return;
+            }
+
+            let (mut errors, mut warnings) = (0, 0);
+            for batch in files.chunks(VALIDATION_BATCH) {
+                let batch = batch.to_vec();
+                let results = cx
+                    .background_executor()
+                    .spawn({
+                        let definitions = definitions.clone();
+                        async move {
+                            batch.into_iter().map(|path| workspace_scan::validate_file(&definitions, path)).collect::<Vec<_>>()
+                        }
                     })
+                    .await;
+                errors += results.iter().map(workspace_scan::FileValidation::errors).sum::<usize>();
+                warnings += results.iter().map(workspace_scan::FileValidation::warnings).sum::<usize>();
+                let appended = editor.update(cx, |editor, cx| {
+                    if let Some(panel) = &editor.validation_report_panel {
+                        panel.update(cx, |panel, cx| panel.append(results, cx));
+                    }
+                });
+                if appended.is_err() {
+                    return;
+                }
+            }
+            editor
+                .update_in(cx, |editor, window, cx| editor.finish_validation(total, errors, warnings, window, cx))
+                .ok();
+        }));
+    }
+
+    /// Run the check of the Config Check panel
+    fn check_configs_action(&mut self, _: &CheckConfigs, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(panel) = &self.config_check_panel {
+            panel.update(cx, |panel, cx| panel.request_check(cx));
+        }
+    }
+
+    /// Check this enum's values in the config files under `dir` on a
+    /// background thread, showing the problems in the Config Check panel
+    fn check_configs(&mut self, dir: &str, keys: &[String], window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(file_path) = self.require_file_path() else {
+            return;
+        };
+        let root = self.search_root(&file_path).join(dir);
+        if !root.is_dir() {
+            self.output.warn(OutputSource::Validation, format!("{} is not a folder", root.display()));
+            return;
+        }
+        let asset = self.asset.read().clone();
+        let keys = if keys.is_empty() { vec![naming::snake_case(&asset.name)] } else { keys.to_vec() };
+        if let Some(panel) = &self.config_check_panel {
+            panel.update(cx, |panel, cx| panel.begin(root.clone(), cx));
+        }
+        self.config_check_task = Some(cx.spawn_in(window, async move |editor, cx| {
+            let results = cx
+                .background_executor()
+                .spawn(async move {
+                    config_check::config_files(&root)
+                        .into_iter()
+                        .map(|path| config_check::check_file(&asset, &keys, path))
+                        .collect::<Vec<_>>()
+                })
+                .await;
+            editor
+                .update(cx, |editor, cx| {
+                    let problems: usize = results.iter().map(config_check::ConfigFileCheck::problems).sum();
+                    editor.output.info(
+                        OutputSource::Validation,
+                        format!("{} config files checked, {} problems", results.len(), problems),
+                    );
+                    if let Some(panel) = &editor.config_check_panel {
+                        panel.update(cx, |panel, cx| panel.finish(results, cx));
+                    }
+                })
+                .ok();
+        }));
+    }
+
+    /// List the places in the project's Rust sources that use a variant, on
+    /// a background thread
+    fn find_references(&mut self, variant: String, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(file_path) = self.require_file_path() else {
+            return;
+        };
+        let root = self.search_root(&file_path);
+        let enum_name = self.asset.read().name.clone();
+        self.references_task = Some(cx.spawn_in(window, async move |editor, cx| {
+            let found = {
+                let variant = variant.clone();
+                cx.background_executor()
+                    .spawn(async move { references::find_references(&root, &enum_name, &variant) })
+                    .await
+            };
+            editor
+                .update_in(cx, |editor, window, cx| {
+                    for reference in &found {
+                        editor.output.info(
+                            OutputSource::Editor,
+                            format!("{}:{}: {}", reference.path.display(), reference.line, reference.text.trim()),
+                        );
+                    }
+                    let summary = format!("{} reference(s) to `{}`", found.len(), variant);
+                    editor.output.info(OutputSource::Editor, summary.clone());
+                    window.push_notification(Notification::info(summary), cx);
+                })
+                .ok();
+        }));
+    }
+
+    /// Show the definition file in the system's file manager
+    fn reveal_in_folder(&mut self, _: &RevealInFolder, window: &mut Window, cx: &mut Context<Self>) {
+        match self.require_file_path() {
+            Ok(file_path) => cx.reveal_path(&file_path),
+            Err(e) => self.report_error(OutputSource::Editor, &e, window, cx),
+        }
+    }
+
+    /// Bring this enum's entry in the validation report up to date
+    fn revalidate_in_report(&mut self, cx: &mut Context<Self>) {
+        let (Some(panel), Some(path)) = (&self.validation_report_panel, &self.file_path) else {
+            return;
+        };
+        let validation = workspace_scan::FileValidation {
+            path: path.clone(),
+            result: Ok(validation::validate(&self.asset.read())),
+        };
+        panel.update(cx, |panel, cx| panel.update_file(validation, cx));
+    }
+
+    fn finish_validation(&mut self, total: usize, errors: usize, warnings: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let summary = format!("{} enums checked, {} errors, {} warnings", total, errors, warnings);
+        self.output.info(OutputSource::Validation, summary.clone());
+        if errors > 0 {
+            window.push_notification(Notification::error(summary).title("Validate All"), cx);
+        } else if warnings > 0 {
+            window.push_notification(Notification::warning(summary).title("Validate All"), cx);
+        } else {
+            window.push_notification(Notification::success(summary), cx);
+        }
+        if let Some(panel) = &self.validation_report_panel {
+            panel.update(cx, |panel, cx| panel.finish(cx));
+        }
+        cx.notify();
+    }
+
+    /// Scroll the Variants panel to the variant at `index` and highlight it
+    pub fn reveal_variant(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let revealed = self
+            .variants_panel
+            .as_ref()
+            .is_some_and(|panel| panel.update(cx, |panel, cx| panel.reveal_variant(index, window, cx)));
+        if !revealed {
+            self.output.warn(OutputSource::Editor, format!("Variant {} no longer exists", index + 1));
+        }
+        cx.notify();
+    }
+
+    /// Files of the project that use a type, with the type renamed. The open
+    /// enum is planned from the editor's copy rather than from disk.
+    fn plan_type_rename(&self, old: &str, new: &str) -> Option<(PathBuf, Vec<workspace_scan::TypeRenamePlan>)> {
+        let file_path = self.require_file_path().ok()?;
+        let root = self.search_root(&file_path);
+        let mut plans = workspace_scan::plan_type_rename(&self.definitions, &root, old, new);
+        plans.retain(|plan| plan.path != file_path);
+
+        let mut asset = self.asset.read().clone();
+        let changes = workspace_scan::rename_type(&mut asset, old, new);
+        if !changes.is_empty() {
+            plans.push(workspace_scan::TypeRenamePlan { path: file_path, asset, changes });
+            plans.sort_by(|a, b| a.path.cmp(&b.path));
+        }
+        Some((root, plans))
+    }
+
+    /// Write the renamed definitions. The open enum is renamed in the editor
+    /// as an undoable edit instead, and saved with the rest of its changes.
+    fn apply_type_rename(&mut self, old: &str, new: &str, plans: &[workspace_scan::TypeRenamePlan], window: &mut Window, cx: &mut Context<Self>) {
+        let file_path = self.file_path.clone();
+        let mut renamed = 0;
+        for plan in plans {
+            if Some(&plan.path) == file_path.as_ref() {
+                self.push_history();
+                workspace_scan::rename_type(&mut self.asset.write(), old, new);
+                self.modified.store(true, Ordering::Relaxed);
+                self.refresh_panels(window, cx);
+                cx.emit(EnumEditorEvent::Modified);
+                renamed += 1;
+                continue;
+            }
+            match plan.write() {
+                Ok(()) => {
+                    renamed += 1;
+                    self.output.info(OutputSource::Editor, format!("Renamed `{}` to `{}` in {}", old, new, plan.path.display()));
                 }
+                Err(e) => self.report_error(OutputSource::Editor, &e, window, cx),
+            }
+        }
+        window.push_notification(
+            Notification::success(format!("Renamed `{}` to `{}` in {} files", old, new, renamed)),
+            cx,
+        );
+        cx.notify();
+    }
+
+    /// Path of the enum's Rust source, resolved against the definition's
+    /// folder. The path is remembered in the metadata and watched.
+    fn rust_source_path(&mut self, path: &str, window: &mut Window, cx: &mut Context<Self>) -> Result<PathBuf> {
+        let source_path = self.resolve_source_path(path)?;
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if meta.rust_source.as_deref() != Some(path) {
+            meta.rust_source = Some(path.to_string());
+            meta.write_to(&mut asset);
+            drop(asset);
+            self.modified.store(true, Ordering::Relaxed);
+            self.watch_rust_source(window, cx);
+            cx.emit(EnumEditorEvent::Modified);
+        }
+        Ok(source_path)
+    }
+
+    /// A source path entered in a panel, relative to the definition's folder
+    fn resolve_source_path(&self, path: &str) -> Result<PathBuf> {
+        let file_path = self.require_file_path()?;
+        Ok(storage::asset_container_dir(&file_path).join(path))
+    }
+
+    /// Merge an enum read from source into the definition as an undoable
+    /// edit. An enum imported from Rust stays linked to its file.
+    fn import_enum(&mut self, path: &str, source: &import::SourceEnum, window: &mut Window, cx: &mut Context<Self>) {
+        self.push_history();
+        import::merge(&mut self.asset.write(), source);
+        let linked = import::SourceLanguage::detect(std::path::Path::new(path)) == Some(import::SourceLanguage::Rust);
+        if linked {
+            if let Err(e) = self.rust_source_path(path, window, cx) {
+                self.report_error(OutputSource::Editor, &e, window, cx);
+            }
+        }
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        self.output.info(OutputSource::Editor, format!("Imported {} variant(s) of `{}` from {}", source.variants.len(), source.name, path));
+        cx.emit(EnumEditorEvent::Modified);
+    }
+
+    /// Write enums read from source as new definitions next to this one
+    fn create_definitions(&mut self, sources: &[import::SourceEnum], window: &mut Window, cx: &mut Context<Self>) {
+        let dir = match self.resolve_source_path("") {
+            Ok(dir) => dir,
+            Err(e) => {
+                self.report_error(OutputSource::Editor, &e, window, cx);
+                return;
             }
+        };
+        let mut created = 0;
+        for result in import::create_definitions(&dir, sources) {
+            match result {
+                Ok(path) => {
+                    created += 1;
+                    self.output.info(OutputSource::Editor, format!("Created {}", path.display()));
+                }
+                Err(e) => self.report_error(OutputSource::Editor, &e, window, cx),
+            }
+        }
+        if created > 0 {
+            window.push_notification(Notification::success(format!("Created {} definitions", created)), cx);
+        }
+    }
+
+    /// Start polling the Rust source named in the metadata, replacing any
+    /// previous watch
+    fn watch_rust_source(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.rust_source_watch = None;
+        let Some(path) = self.watched_rust_source() else {
+            return;
+        };
+        self.rust_source_seen = std::fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+        self.rust_source_watch = Some(cx.spawn_in(window, async move |editor, cx| loop {
+            cx.background_executor().timer(RUST_SOURCE_POLL).await;
+            if editor.update_in(cx, |editor, window, cx| editor.poll_rust_source(window, cx)).is_err() {
+                break;
+            }
+        }));
+    }
+
+    fn watched_rust_source(&self) -> Option<PathBuf> {
+        let path = EnumMeta::from_asset(&self.asset.read()).rust_source?;
+        self.resolve_source_path(&path).ok()
+    }
+
+    /// Offer a re-import in the variants panel if the Rust source changed
+    /// since it was last looked at and no longer matches the definition
+    fn poll_rust_source(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let Some(path) = self.watched_rust_source() else {
+            return;
+        };
+        let modified = std::fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+        if modified.is_none() || modified == self.rust_source_seen {
+            return;
+        }
+        self.rust_source_seen = modified;
+
+        let name = self.asset.read().name.clone();
+        let changes = match import::find_enum(&path, &name) {
+            Ok(source) => drift::check(&self.asset.read(), &source),
+            Err(e) => {
+                self.output.warn(OutputSource::Editor, e.to_string());
+                return;
+            }
+        };
+        if !changes.is_empty() {
+            self.output.info(OutputSource::Editor, format!("{} changed: {} difference(s) from the definition", path.display(), changes.len()));
+        }
+        let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| {
+                panel.set_source_changes((!changes.is_empty()).then_some((file_name, changes)), cx);
+            });
+        }
+    }
+
+    fn check_drift(&mut self, path: &str, window: &mut Window, cx: &mut Context<Self>) -> Result<Vec<Drift>> {
+        let source_path = self.rust_source_path(path, window, cx)?;
+        let source = import::find_enum(&source_path, &self.asset.read().name)?;
+        Ok(drift::check(&self.asset.read(), &source))
+    }
+
+    /// Make the definition match the Rust declaration as an undoable edit
+    fn update_definition_from_source(&mut self, path: &str, window: &mut Window, cx: &mut Context<Self>) -> Result<Vec<Drift>> {
+        let source_path = self.rust_source_path(path, window, cx)?;
+        let source = import::find_enum(&source_path, &self.asset.read().name)?;
+        self.push_history();
+        import::merge(&mut self.asset.write(), &source);
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.set_source_changes(None, cx));
+        }
+        self.output.info(OutputSource::Editor, format!("Updated the definition from {}", source_path.display()));
+        cx.emit(EnumEditorEvent::Modified);
+        Ok(drift::check(&self.asset.read(), &source))
+    }
+
+    fn update_source_from_definition(&mut self, path: &str, window: &mut Window, cx: &mut Context<Self>) -> Result<Vec<Drift>> {
+        let source_path = self.rust_source_path(path, window, cx)?;
+        drift::update_source(&source_path, &self.asset.read())?;
+        self.output.info(OutputSource::Export, format!("Updated the declaration in {}", source_path.display()));
+        self.check_drift(path, window, cx)
+    }
+
+    /// Write the outputs confirmed in the preview and show the results in the report panel
+    fn write_generated(&mut self, plans: Vec<PlannedOutput>, window: &mut Window, cx: &mut Context<Self>) {
+        let Ok(file_path) = self.require_file_path() else {
+            return;
+        };
+        let reports: Vec<_> = plans.into_iter().map(PlannedOutput::write).collect();
+        let count = reports.len();
+
+        let failed = self.record_reports(reports, &file_path, cx);
+        if failed > 0 {
+            window.push_notification(
+                Notification::error(format!("{} of {} targets failed", failed, count)).title("Generate All"),
+                cx,
+            );
         } else {
-            Err(plugin_editor_api::PluginError::Other {
-                message: "No file path set".into(),
+            window.push_notification(Notification::success(format!("Generated {} targets", count)), cx);
+        }
+        self.error_message = None;
+        cx.notify();
+    }
+
+    /// Regenerate the enabled targets after a save, skipping files edited by hand
+    fn regenerate_on_save(&mut self, file_path: &std::path::Path, cx: &mut Context<Self>) {
+        let plans = codegen::plan_all(file_path, &self.asset.read());
+        let (skipped, plans): (Vec<_>, Vec<_>) = plans.into_iter().partition(|plan| plan.manually_edited);
+        for plan in skipped {
+            self.output.warn(OutputSource::Export, format!(
+                "Not regenerating {}: {}",
+                plan.target.label(),
+                EnumEditorError::ManuallyEdited { path: plan.path }
+            ));
+        }
+        let reports = plans.into_iter().map(PlannedOutput::write).collect();
+        self.record_reports(reports, file_path, cx);
+    }
+
+    /// Log codegen results, run post-export hooks for written files and show
+    /// the results in the report panel. Returns the number of failed targets.
+    fn record_reports(&mut self, reports: Vec<TargetReport>, file_path: &std::path::Path, cx: &mut Context<Self>) -> usize {
+        let mut failed = 0;
+        for report in &reports {
+            match &report.result {
+                Ok(change) => {
+                    self.output.info(OutputSource::Export, format!(
+                        "{}: {} ({})",
+                        report.target.label(),
+                        report.path.display(),
+                        change.summary()
+                    ));
+                    if !matches!(change, codegen::FileChange::Unchanged) {
+                        self.spawn_post_hooks(HookStage::PostExport, file_path, Some(&report.path));
+                    }
+                }
+                Err(message) => {
+                    failed += 1;
+                    self.output.error(OutputSource::Export, format!("{}: {}", report.target.label(), message));
+                }
+            }
+        }
+
+        if let Some(panel) = &self.generate_report_panel {
+            panel.update(cx, |panel, cx| panel.set_reports(reports, cx));
+        }
+        failed
+    }
+
+    fn require_file_path(&self) -> Result<PathBuf> {
+        self.file_path.clone().ok_or(EnumEditorError::NoFilePath)
+    }
+
+    /// Show an error in the Output panel and as a toast
+    fn report_error(&mut self, source: OutputSource, error: &EnumEditorError, window: &mut Window, cx: &mut Context<Self>) {
+        let message = error.to_string();
+        self.output.error(source, format!("{}: {}", error.title(), message));
+        window.push_notification(Notification::error(message.clone()).title(error.title()), cx);
+        self.error_message = Some(message);
+    }
+
+    /// Project configuration for the open file, if any
+    fn project(&self, file_path: &std::path::Path) -> Option<Project> {
+        Project::find(file_path).unwrap_or_else(|e| {
+            self.output.warn(OutputSource::Editor, e.to_string());
+            None
+        })
+    }
+
+    /// Directory scanned for references: the project root, or the asset's directory
+    fn search_root(&self, file_path: &std::path::Path) -> PathBuf {
+        match self.project(file_path) {
+            Some(project) => project.root,
+            None => storage::asset_container_dir(file_path),
+        }
+    }
+
+    /// The project's renaming rules for imported enums
+    fn import_rules(&self) -> ImportConfig {
+        self.file_path
+            .as_deref()
+            .and_then(|file_path| self.project(file_path))
+            .map(|project| project.config.import)
+            .unwrap_or_default()
+    }
+
+    /// Spell checker using the project's dictionaries and ignore list
+    fn load_spell_checker(&self, file_path: &std::path::Path) -> SpellChecker {
+        match self.project(file_path) {
+            Some(project) => SpellChecker::load(&project.root, &project.config.spellcheck),
+            None => SpellChecker::load(&storage::asset_container_dir(file_path), &Default::default()),
+        }
+    }
+
+    /// Start the project's post-save or post-export hooks in the background
+    fn spawn_post_hooks(&self, stage: HookStage, file_path: &std::path::Path, output_path: Option<&std::path::Path>) {
+        let Some(project) = self.project(file_path) else {
+            return;
+        };
+        let commands = match stage {
+            HookStage::PreSave => return,
+            HookStage::PostSave => project.config.hooks.post_save,
+            HookStage::PostExport => project.config.hooks.post_export,
+        };
+        let mut context = HookContext::new(&project.root, &self.asset.read().name, file_path);
+        if let Some(output_path) = output_path {
+            context = context.with_output(output_path);
+        }
+        hooks::spawn_all(stage, commands, context, self.output.clone());
+    }
+
+    fn set_file_path(&mut self, file_path: PathBuf, cx: &mut Context<Self>) {
+        let layout = EnumFileFormat::detect(&file_path);
+        let serialization = storage::serialization_of(&file_path);
+        if let Some(panel) = &self.properties_panel {
+            panel.update(cx, |panel, cx| panel.set_file_format(layout, serialization, cx));
+        }
+        if let Some(panel) = &self.code_preview_panel {
+            let templates = codegen::discover_templates(&file_path);
+            panel.update(cx, |panel, cx| panel.set_templates(templates, cx));
+        }
+        if let Some(panel) = &self.variants_panel {
+            let search_root = self.search_root(&file_path);
+            panel.update(cx, |panel, _cx| {
+                panel.set_user(project::current_user(&search_root));
+                panel.set_search_root(Some(search_root));
+            });
+        }
+        *self.spell_checker.write() = self.load_spell_checker(&file_path);
+        self.file_path = Some(file_path);
+    }
+
+    /// Show the project settings and start autosaving if they enable it
+    fn load_settings(&mut self, project: Option<Project>, window: &mut Window, cx: &mut Context<Self>) {
+        let (config, config_path) = match project {
+            Some(project) => (project.config, Some(project.root.join(project::CONFIG_FILE))),
+            None => (ProjectConfig::default(), None),
+        };
+        self.start_autosave(&config.editor, window, cx);
+        self.load_type_suggestions(config.editor.payload_types.clone(), cx);
+        self.apply_appearance(&config.editor, cx);
+        if let Some(panel) = &self.settings_panel {
+            panel.update(cx, |panel, cx| panel.set_config(config, config_path, window, cx));
+        }
+    }
+
+    /// Apply the configured density and code font size to the panels
+    fn apply_appearance(&mut self, config: &EditorConfig, cx: &mut Context<Self>) {
+        let density = config.density;
+        if let Some(panel) = &self.properties_panel {
+            panel.update(cx, |panel, cx| panel.set_density(density.properties, cx));
+        }
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.set_density(density.variants, cx));
+        }
+        if let Some(panel) = &self.code_preview_panel {
+            panel.update(cx, |panel, cx| panel.set_appearance(density.code_preview, config.code_font_size, cx));
+        }
+    }
+
+    /// Write the settings to the project's `pulsar-enum.toml`, creating one
+    /// next to the asset if the project has none
+    fn save_settings(&mut self, config: ProjectConfig, window: &mut Window, cx: &mut Context<Self>) {
+        let result = self.require_file_path().and_then(|file_path| {
+            let root = self.search_root(&file_path);
+            let project = Project { root, config };
+            project.save().map(|path| (file_path, project, path))
+        });
+        match result {
+            Ok((file_path, project, config_path)) => {
+                self.output.info(OutputSource::Editor, format!("Saved settings to {}", config_path.display()));
+                window.push_notification(Notification::success("Saved project settings"), cx);
+                *self.spell_checker.write() = self.load_spell_checker(&file_path);
+                self.load_settings(Some(project), window, cx);
+            }
+            Err(e) => self.report_error(OutputSource::Editor, &e, window, cx),
+        }
+        cx.notify();
+    }
+
+    /// Collect the workspace's enums and the project's types for the type
+    /// pickers on a background thread
+    fn load_type_suggestions(&mut self, project_types: Vec<String>, cx: &mut Context<Self>) {
+        let Some(file_path) = self.file_path.clone() else {
+            *self.type_suggestions.write() = TypeSuggestions::default();
+            return;
+        };
+        let root = self.search_root(&file_path);
+        let definitions = self.definitions.clone();
+        let current = self.asset.read().name.clone();
+        let suggestions = self.type_suggestions.clone();
+        cx.background_executor()
+            .spawn(async move {
+                *suggestions.write() = TypeSuggestions::load(&definitions, &root, &project_types, &current);
             })
+            .detach();
+    }
+
+    /// Replace the autosave task according to the editor settings
+    fn start_autosave(&mut self, config: &EditorConfig, window: &mut Window, cx: &mut Context<Self>) {
+        self.autosave_task = None;
+        if !config.autosave {
+            return;
+        }
+        let delay = Duration::from_secs(config.autosave_delay_secs.max(1));
+        self.autosave_task = Some(cx.spawn_in(window, async move |editor, cx| loop {
+            cx.background_executor().timer(delay).await;
+            if editor.update_in(cx, |editor, window, cx| editor.autosave(window, cx)).is_err() {
+                break;
+            }
+        }));
+    }
+
+    /// Save if modified. Failures are logged rather than shown as toasts, and
+    /// breaking changes to a published enum wait for an explicit save.
+    fn autosave(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.modified.load(Ordering::Relaxed) || self.saving {
+            return;
+        }
+        if let Err(e) = self.start_save(false, true, window, cx) {
+            self.autosave_failed(&e);
         }
+        cx.notify();
+    }
+
+    fn autosave_failed(&mut self, e: &EnumEditorError) {
+        let message = format!("Autosave skipped: {}", e);
+        if self.autosave_error.as_ref() != Some(&message) {
+            self.output.warn(OutputSource::Editor, message.clone());
+            self.autosave_error = Some(message);
+        }
+    }
+
+    /// Look for unsaved edits a previous session left behind and offer them
+    /// in the variants panel
+    fn check_recovery(&mut self, cx: &mut Context<Self>) {
+        let Some(file_path) = self.file_path.clone() else {
+            return;
+        };
+        let recovered = match storage::load_recovery(&file_path) {
+            Ok(Some(recovered)) => recovered,
+            Ok(None) => return,
+            Err(e) => {
+                self.output.warn(OutputSource::Editor, format!("Recovery file ignored: {}", e));
+                return;
+            }
+        };
+        // Edits that were saved after all need no recovery
+        if serde_json::to_value(&recovered).ok() == serde_json::to_value(&*self.asset.read()).ok() {
+            let _ = storage::discard_recovery(&file_path);
+            return;
+        }
+        let written = std::fs::metadata(storage::recovery_path(&file_path))
+            .and_then(|metadata| metadata.modified())
+            .map(|time| chrono::DateTime::<chrono::Local>::from(time).format(" from %Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        let message = format!("Found unsaved changes{}", written);
+        self.output.warn(OutputSource::Editor, message.clone());
+        self.recovered = Some(recovered);
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.set_recovery(Some(message), cx));
+        }
+    }
+
+    /// Restore the variant list columns last used for this definition
+    fn load_columns(&mut self, cx: &mut Context<Self>) {
+        let Some(file_path) = self.file_path.clone() else {
+            return;
+        };
+        match storage::load_columns(&file_path) {
+            Ok(Some(columns)) => {
+                if let Some(panel) = &self.variants_panel {
+                    panel.update(cx, |panel, cx| panel.set_columns(columns, cx));
+                }
+            }
+            Ok(None) => {}
+            Err(e) => self.output.warn(OutputSource::Editor, format!("Column settings ignored: {}", e)),
+        }
+    }
+
+    /// Write unsaved edits to the recovery file every few seconds
+    fn start_recovery(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.recovery_task = Some(cx.spawn_in(window, async move |editor, cx| loop {
+            cx.background_executor().timer(RECOVERY_INTERVAL).await;
+            let Ok(snapshot) = editor.update(cx, |editor, _cx| editor.recovery_snapshot()) else {
+                break;
+            };
+            let Some((file_path, asset)) = snapshot else {
+                continue;
+            };
+            let result = cx
+                .background_executor()
+                .spawn(async move { storage::save_recovery(&file_path, &asset) })
+                .await;
+            if editor.update(cx, |editor, _cx| editor.recovery_written(result)).is_err() {
+                break;
+            }
+        }));
+    }
+
+    /// Track focus, and free the cached previews of an editor that hasn't
+    /// been focused for `IDLE_TRIM_AFTER`
+    fn watch_idle(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self._focus_subscription = Some(cx.on_focus_in(&self.focus_handle, window, |editor, _window, _cx| {
+            editor.last_focused = Instant::now();
+            editor.trimmed = false;
+        }));
+        self.idle_task = Some(cx.spawn_in(window, async move |editor, cx| loop {
+            cx.background_executor().timer(IDLE_POLL).await;
+            let checked = editor.update_in(cx, |editor, window, cx| {
+                if !editor.trimmed && editor.last_focused.elapsed() >= IDLE_TRIM_AFTER {
+                    editor.trim(window, cx);
+                }
+            });
+            if checked.is_err() {
+                break;
+            }
+        }));
+    }
+
+    /// Approximate memory held by the editor
+    pub fn memory_usage(&self, cx: &App) -> MemoryUsage {
+        MemoryUsage {
+            model: serialized_size(&self.asset.read()),
+            history: self.history.iter().map(serialized_size).sum(),
+            preview: self.code_preview_panel.as_ref().map_or(0, |panel| panel.read(cx).buffer_len(cx)),
+        }
+    }
+
+    /// Free the generated preview and its highlighting until the editor is
+    /// used again
+    fn trim(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.trimmed = true;
+        let usage = self.memory_usage(cx);
+        let freed = match &self.code_preview_panel {
+            Some(panel) => panel.update(cx, |panel, cx| panel.trim(window, cx)),
+            None => 0,
+        };
+        log::debug!(
+            "Idle enum editor {}: freed {} bytes of preview, {} bytes still held",
+            self.asset.read().name,
+            freed,
+            usage.total() - freed
+        );
+    }
+
+    /// Definition to write to the recovery file, if it has unsaved edits
+    fn recovery_snapshot(&self) -> Option<(PathBuf, EnumAsset)> {
+        // Not overwritten until the user decided on the recovered edits
+        if !self.modified.load(Ordering::Relaxed) || self.recovered.is_some() {
+            return None;
+        }
+        let file_path = self.file_path.clone()?;
+        Some((file_path, self.asset.read().clone()))
+    }
+
+    /// Report a failed recovery write, once per distinct failure
+    fn recovery_written(&mut self, result: Result<()>) {
+        match result {
+            Ok(()) => self.recovery_error = None,
+            Err(e) => {
+                let message = format!("Could not write the recovery file: {}", e);
+                if self.recovery_error.as_ref() != Some(&message) {
+                    self.output.warn(OutputSource::Editor, message.clone());
+                    self.recovery_error = Some(message);
+                }
+            }
+        }
+    }
+
+    /// Replace the definition with the recovered edits as an undoable edit
+    fn restore_recovery(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(recovered) = self.recovered.take() else {
+            return;
+        };
+        self.push_history();
+        *self.asset.write() = recovered;
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.set_recovery(None, cx));
+        }
+        self.output.info(OutputSource::Editor, "Restored unsaved changes");
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    fn discard_recovery(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.recovered = None;
+        if let Some(file_path) = self.file_path.clone() {
+            if let Err(e) = storage::discard_recovery(&file_path) {
+                self.report_error(OutputSource::Editor, &e, window, cx);
+            }
+        }
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.set_recovery(None, cx));
+        }
+        self.output.info(OutputSource::Editor, "Discarded unsaved changes from the last session");
+        cx.notify();
+    }
+
+    /// Update every panel after the asset was replaced wholesale
+    fn refresh_panels(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(panel) = &self.properties_panel {
+            panel.update(cx, |panel, cx| panel.reload(window, cx));
+        }
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.rebuild(window, cx));
+        }
+        if let Some(panel) = &self.code_preview_panel {
+            panel.update(cx, |panel, cx| {
+                panel.request_update();
+                cx.notify();
+            });
+        }
+    }
+
+    /// Remember the current asset so the next bulk edit can be undone
+    fn push_history(&mut self) {
+        self.history.push(self.asset.read().clone());
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    fn undo(&mut self, _: &Undo, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(snapshot) = self.history.pop() else {
+            return;
+        };
+        *self.asset.write() = snapshot;
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        self.output.info(OutputSource::Editor, "Undid last change");
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    fn paste_variants(&mut self, _: &PasteVariants, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(panel) = &self.variants_panel {
+            panel.update(cx, |panel, cx| panel.paste(cx));
+        }
+    }
+
+    /// Replace the model with a synthetic enum of `STRESS_VARIANTS` variants,
+    /// as an undoable edit, and log how long the panels took to refresh
+    fn load_stress_fixture(&mut self, _: &LoadStressFixture, window: &mut Window, cx: &mut Context<Self>) {
+        self.push_history();
+        let name = self.asset.read().name.clone();
+        *self.asset.write() = fixtures::synthetic_enum(&name, STRESS_VARIANTS);
+        self.modified.store(true, Ordering::Relaxed);
+        let started = Instant::now();
+        self.refresh_panels(window, cx);
+        self.output.info(
+            OutputSource::Editor,
+            format!("Loaded a {}-variant stress fixture; panels refreshed in {:?}", STRESS_VARIANTS, started.elapsed()),
+        );
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    /// Add pasted variants, or replace the variants with them, as a single
+    /// undoable edit
+    fn apply_paste(&mut self, source: &import::SourceEnum, replace: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.push_history();
+        let message = if replace {
+            import::merge(&mut self.asset.write(), source);
+            format!("Replaced the variants with {} pasted ones", source.variants.len())
+        } else {
+            let added = import::append(&mut self.asset.write(), source);
+            format!("Added {} pasted variant(s)", added)
+        };
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        self.output.info(OutputSource::Editor, message);
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    /// Make the same metadata change to each named variant as a single
+    /// undoable edit
+    fn apply_batch_edit(&mut self, names: &[String], edit: &BatchEdit, window: &mut Window, cx: &mut Context<Self>) {
+        self.push_history();
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        for name in names {
+            let mut variant_meta = meta.variant(name);
+            edit.apply(&mut variant_meta);
+            variant_meta.touch();
+            meta.set_variant(name, variant_meta);
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        self.output.info(OutputSource::Editor, format!("{} on {} variant(s)", edit.describe(), names.len()));
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    /// Apply a find & replace preview as a single undoable edit
+    fn apply_replacements(&mut self, replacements: &[Replacement], window: &mut Window, cx: &mut Context<Self>) {
+        let mut asset = self.asset.read().clone();
+        if let Err(message) = find_replace::apply(&mut asset, replacements) {
+            self.output.warn(OutputSource::Editor, format!("Replace not applied: {}", message));
+            window.push_notification(Notification::warning(message).title("Replace not applied"), cx);
+            return;
+        }
+        self.push_history();
+        *self.asset.write() = asset;
+        self.modified.store(true, Ordering::Relaxed);
+        self.refresh_panels(window, cx);
+        self.output.info(OutputSource::Editor, format!("Replaced {} occurrence(s)", replacements.len()));
+        cx.emit(EnumEditorEvent::Modified);
+        cx.notify();
+    }
+
+    /// Check for breaking changes, run pre-save hooks and write the
+    /// definition on a background thread. Definitions with errors are saved
+    /// too, so work in progress isn't lost; only exports require validity.
+    /// Breaking changes to a published enum fail unless `force` is set;
+    /// autosaves only log failures. While another save is running the save
+    /// is queued behind it, so edits made after that save took its snapshot
+    /// are still written.
+    fn start_save(&mut self, force: bool, autosave: bool, window: &mut Window, cx: &mut Context<Self>) -> Result<()> {
+        if self.saving {
+            let (queued_force, queued_autosave) = self.queued_save.unwrap_or((false, true));
+            self.queued_save = Some((force || queued_force, autosave && queued_autosave));
+            if !autosave {
+                self.output.info(OutputSource::Editor, "Saving again once the running save finishes");
+            }
+            cx.notify();
+            return Ok(());
+        }
+        let job = self.save_job(force)?;
+        self.saving = true;
+        let task = cx.background_executor().spawn(async move { job.run() });
+        cx.spawn_in(window, async move |editor, cx| {
+            let result = task.await;
+            editor
+                .update_in(cx, |editor, window, cx| {
+                    editor.saving = false;
+                    editor.finish_save(result, autosave, window, cx);
+                })
+                .ok();
+        })
+        .detach();
+        cx.notify();
+        Ok(())
+    }
+
+    /// Snapshot the definition for a save. Edits made while the save runs
+    /// mark the editor modified again.
+    fn save_job(&mut self, force: bool) -> Result<SaveJob> {
+        let file_path = self.require_file_path()?;
+        self.assign_ids();
+        self.save_generation += 1;
+        self.modified.store(false, Ordering::Relaxed);
+        Ok(SaveJob {
+            file_path,
+            asset: self.asset.read().clone(),
+            force,
+            serialization: self.convert_on_save.take(),
+            output: self.output.clone(),
+            definitions: self.definitions.clone(),
+            generation: self.save_generation,
+            written: self.written_generation.clone(),
+        })
+    }
+
+    /// Give new variants their UUIDs, and their stable IDs if the enum uses them
+    fn assign_ids(&mut self) {
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        let uuids = meta.assign_uuids(&asset);
+        let stable_ids = if meta.stable_ids { meta.assign_stable_ids(&asset) } else { 0 };
+        if uuids + stable_ids > 0 {
+            meta.write_to(&mut asset);
+        }
+        drop(asset);
+        if stable_ids > 0 {
+            self.output.info(OutputSource::Editor, format!("Assigned {} stable IDs", stable_ids));
+        }
+    }
+
+    fn finish_save(&mut self, result: Result<PathBuf>, autosave: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let failed = result.is_err();
+        match result {
+            Ok(file_path) => {
+                if let Some(old_path) = self.file_path.clone().filter(|old_path| *old_path != file_path) {
+                    self.output.info(OutputSource::Editor, format!("Converted {} to {}", old_path.display(), file_path.display()));
+                    self.set_file_path(file_path.clone(), cx);
+                }
+                self.error_message = None;
+                self.autosave_error = None;
+                if let Err(e) = storage::discard_recovery(&file_path) {
+                    self.output.warn(OutputSource::Editor, e.to_string());
+                }
+                self.output.info(OutputSource::Editor, format!("Saved {}", file_path.display()));
+                self.spawn_post_hooks(HookStage::PostSave, &file_path, None);
+                if EnumMeta::from_asset(&self.asset.read()).regenerate_on_save {
+                    self.regenerate_on_save(&file_path, cx);
+                }
+                cx.emit(EnumEditorEvent::Saved);
+                self.revalidate_in_report(cx);
+                self.refresh_saved_comparison(window, cx);
+                if !autosave {
+                    self.report_saved(&file_path, window, cx);
+                    self.refresh_blame(window, cx);
+                }
+            }
+            Err(e) => {
+                self.modified.store(true, Ordering::Relaxed);
+                if autosave {
+                    self.autosave_failed(&e);
+                } else {
+                    self.save_failed(e, window, cx);
+                }
+            }
+        }
+        // After a failure only a forced save could do better; the failure
+        // was already reported
+        if let Some((force, autosave)) = self.queued_save.take() {
+            if !failed || force {
+                if let Err(e) = self.start_save(force, autosave, window, cx) {
+                    if autosave {
+                        self.autosave_failed(&e);
+                    } else {
+                        self.save_failed(e, window, cx);
+                    }
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    /// Ask about breaking changes, or report why the save failed
+    fn save_failed(&mut self, e: EnumEditorError, window: &mut Window, cx: &mut Context<Self>) {
+        match e {
+            EnumEditorError::BreakingChanges { changes } => self.confirm_breaking_save(changes, window, cx),
+            e => self.report_error(OutputSource::Editor, &e, window, cx),
+        }
+    }
+
+    /// Ask before saving breaking changes to a published enum
+    fn confirm_breaking_save(&mut self, changes: Vec<String>, window: &mut Window, cx: &mut Context<Self>) {
+        self.output.warn(OutputSource::Validation, EnumEditorError::BreakingChanges { changes: changes.clone() }.to_string());
+
+        let editor = cx.entity().downgrade();
+        window.open_modal(cx, move |modal, _window, cx| {
+            let editor = editor.clone();
+            modal
+                .title("Save breaking changes?")
+                .child(
+                    v_flex()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().warning)
+                                .child("This enum is published. These changes may break code and data that use it; a major version bump is suggested.")
+                        )
+                        .children(changes.iter().map(|change| {
+                            div().text_sm().child(format!("• {}", change.replace('`', "")))
+                        }))
+                )
+                .confirm()
+                .on_ok(move |_, window, cx| {
+                    editor.update(cx, |editor, cx| editor.save_confirmed(window, cx)).ok();
+                    true
+                })
+        });
+    }
+
+    fn save_confirmed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Err(e) = self.start_save(true, false, window, cx) {
+            self.report_error(OutputSource::Editor, &e, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Load the saved definition on a background thread and show its code
+    /// next to the preview
+    fn compare_with_saved(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let file_path = match self.require_file_path() {
+            Ok(file_path) => file_path,
+            Err(e) => return self.report_error(OutputSource::Editor, &e, window, cx),
+        };
+        let definitions = self.definitions.clone();
+        let task = cx.background_executor().spawn(async move { definitions.load(&file_path) });
+        cx.spawn_in(window, async move |editor, cx| {
+            let result = task.await;
+            editor.update_in(cx, |editor, window, cx| match result {
+                Ok(saved) => {
+                    if let Some(panel) = &editor.code_preview_panel {
+                        panel.update(cx, |panel, cx| panel.set_saved(Some((*saved).clone()), window, cx));
+                    }
+                }
+                Err(e) => editor.report_error(OutputSource::Editor, &e, window, cx),
+            }).ok();
+        })
+        .detach();
+    }
+
+    /// Keep the saved column of the preview in step with the file
+    fn refresh_saved_comparison(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let comparing = self.code_preview_panel.as_ref().is_some_and(|panel| panel.read(cx).is_comparing());
+        if comparing {
+            self.compare_with_saved(window, cx);
+        }
+    }
+
+    /// Read the definition on disk on a background thread, then replace the
+    /// model with it
+    fn start_reload(&mut self, window: &mut Window, cx: &mut Context<Self>) -> Result<()> {
+        let file_path = self.require_file_path()?;
+        let task = cx.background_executor().spawn({
+            let file_path = file_path.clone();
+            let definitions = self.definitions.clone();
+            async move { definitions.load(&file_path).map(|asset| (*asset).clone()) }
+        });
+        cx.spawn_in(window, async move |editor, cx| {
+            let result = task.await;
+            editor.update_in(cx, |editor, window, cx| {
+                match result {
+                    Ok(asset) => editor.finish_reload(file_path, asset, window, cx),
+                    Err(e) => editor.report_error(OutputSource::Editor, &e, window, cx),
+                }
+                cx.notify();
+            }).ok();
+        })
+        .detach();
+        Ok(())
+    }
+
+    /// Replace the model with the definition read from disk
+    fn finish_reload(&mut self, file_path: PathBuf, asset: EnumAsset, window: &mut Window, cx: &mut Context<Self>) {
+        *self.asset.write() = asset;
+        self.error_message = None;
+        self.modified.store(false, Ordering::Relaxed);
+        self.history.clear();
+        // Reloading throws the unsaved edits away
+        self.recovered = None;
+        if let Err(e) = storage::discard_recovery(&file_path) {
+            self.output.warn(OutputSource::Editor, e.to_string());
+        }
+        self.initialize_workspace(window, cx);
+        self.refresh_panels(window, cx);
+        self.watch_rust_source(window, cx);
+        self.refresh_saved_comparison(window, cx);
+        self.output.info(OutputSource::Editor, format!("Reloaded {}", file_path.display()));
+    }
+
+    /// Last commit per variant for the blame gutter, reporting failures
+    /// Read the per-variant blame from git on a background thread and show it
+    /// in the Variants panel, reporting failures
+    fn load_blame(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let file_path = match self.require_file_path() {
+            Ok(file_path) => file_path,
+            Err(e) => return self.report_error(OutputSource::Editor, &e, window, cx),
+        };
+        let asset = self.asset.read().clone();
+        self.blame_task = Some(cx.spawn_in(window, async move |editor, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { git::variant_blame(&file_path, &asset, BLAME_DEPTH) })
+                .await;
+            editor
+                .update_in(cx, |editor, window, cx| {
+                    let blame = match result {
+                        Ok(blame) => Some(blame),
+                        Err(e) => {
+                            editor.report_error(OutputSource::Editor, &e, window, cx);
+                            None
+                        }
+                    };
+                    if let Some(panel) = &editor.variants_panel {
+                        panel.update(cx, |panel, cx| panel.set_blame(blame, cx));
+                    }
+                    editor.blame_task = None;
+                })
+                .ok();
+        }));
+    }
+
+    /// Release notes for the changes since `base`, reporting failures
+    fn changelog(&mut self, base: &ChangelogBase, window: &mut Window, cx: &mut Context<Self>) -> Option<String> {
+        let previous = self.require_file_path().and_then(|file_path| match base {
+            ChangelogBase::Revision(revision) => git::load_revision(&file_path, revision),
+            ChangelogBase::Saved => self.definitions.load(&file_path).map(|asset| (*asset).clone()),
+        });
+        match previous {
+            Ok(previous) => {
+                let asset = self.asset.read();
+                let changes = changelog::diff(&previous, &asset);
+                let bump = changelog::suggest_bump(&previous, &asset, &changes);
+                let text = changelog::to_markdown(&asset.name, &base.label(), &changes, bump);
+                drop(asset);
+                self.output.info(OutputSource::Editor, format!("{} changes since {}", changes.len(), base.label()));
+                Some(text)
+            }
+            Err(e) => {
+                self.report_error(OutputSource::Editor, &e, window, cx);
+                None
+            }
+        }
+    }
+
+    /// Confirm a save with a toast, and warn about remaining validation issues
+    fn report_saved(&mut self, file_path: &std::path::Path, window: &mut Window, cx: &mut Context<Self>) {
+        let asset = self.asset.read();
+        let (errors, warnings): (Vec<_>, Vec<_>) = validation::validate(&asset)
+            .into_iter()
+            .chain(validation::check_spelling(&asset, &self.spell_checker.read()))
+            .partition(|issue| issue.severity == Severity::Error);
+        drop(asset);
+        for issue in &errors {
+            self.output.error(OutputSource::Validation, issue.message.clone());
+        }
+        for issue in &warnings {
+            self.output.warn(OutputSource::Validation, issue.message.clone());
+        }
+
+        let name = file_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        window.push_notification(Notification::success(format!("Saved {}", name)), cx);
+        if !errors.is_empty() {
+            let message = format!("{} error(s) must be fixed before exporting, see the Output panel", errors.len());
+            window.push_notification(Notification::error(message).title("Saved with errors"), cx);
+        } else if !warnings.is_empty() {
+            let message = match warnings.as_slice() {
+                [issue] => issue.message.clone(),
+                _ => format!("{} warnings, see the Output panel", warnings.len()),
+            };
+            window.push_notification(Notification::warning(message).title("Validation warnings"), cx);
+        }
+    }
+
+    fn save(&mut self, _: &Save, window: &mut Window, cx: &mut Context<Self>) {
+        if let Err(e) = self.start_save(false, false, window, cx) {
+            self.save_failed(e, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Recompute the blame gutter, if it is shown
+    fn refresh_blame(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(panel) = self.variants_panel.clone() else {
+            return;
+        };
+        if panel.read(cx).is_blame_shown() {
+            self.load_blame(window, cx);
+        }
+    }
+}
+
+impl EventEmitter<EnumEditorEvent> for EnumEditor {}
+impl EventEmitter<PanelEvent> for EnumEditor {}
+
+impl Render for EnumEditor {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if let Some(ref workspace) = self.workspace {
+            div()
+                .size_full()
+                .key_context("EnumEditor")
+                .track_focus(&self.focus_handle)
+                .on_action(cx.listener(Self::save))
+                .on_action(cx.listener(Self::convert_format))
+                .on_action(cx.listener(Self::undo))
+                .on_action(cx.listener(Self::generate_all))
+                .on_action(cx.listener(Self::export_all))
+                .on_action(cx.listener(Self::validate_all))
+                .on_action(cx.listener(Self::check_configs_action))
+                .on_action(cx.listener(Self::paste_variants))
+                .on_action(cx.listener(Self::load_stress_fixture))
+                .on_action(cx.listener(Self::reveal_in_folder))
+                .child(workspace.clone())
+                .into_any_element()
+        } else {
+            div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child("Initializing workspace...")
+                .into_any_element()
+        }
+    }
+}
+
+impl Focusable for EnumEditor {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for EnumEditor {
+    fn panel_name(&self) -> &'static str {
+        "Enum Editor"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> gpui::AnyElement {
+        let asset = self.asset.read();
+        format!(
+            "{}{}",
+            asset.display_name,
+            if self.saving {
+                " (saving…)"
+            } else if self.modified.load(Ordering::Relaxed) {
+                " •"
+            } else {
+                ""
+            }
+        )
+        .into_any_element()
+    }
+
+    fn dump(&self, _cx: &App) -> ui::dock::PanelState {
+        let info = self.file_path.as_ref().map(|p| {
+            serde_json::json!({
+                "file_path": p.to_string_lossy().to_string()
+            })
+        }).unwrap_or(serde_json::Value::Null);
+
+        ui::dock::PanelState {
+            panel_name: self.panel_name().to_string(),
+            info: ui::dock::PanelInfo::Panel(info),
+            ..Default::default()
+        }
+    }
+}
+
+// Plugin-related methods (called by EnumEditorWrapper)
+impl EnumEditor {
+    /// Save on the calling thread, so the host only hears of success once
+    /// the definition is written. A background save still running when this
+    /// is called can't overwrite it afterwards.
+    /// Save for the host. The save runs in the background, queued behind one
+    /// already running; the host only learns whether it could be started, and
+    /// later failures are reported in the editor.
+    pub fn plugin_save(&mut self, window: &mut Window, cx: &mut Context<Self>) -> Result<(), plugin_editor_api::PluginError> {
+        let result = self.start_save(false, false, window, cx);
+        if let Err(e) = result {
+            let host_error = e.to_save_error();
+            self.save_failed(e, window, cx);
+            return Err(host_error);
+        }
+        Ok(())
+    }
+
+    pub fn plugin_reload(&mut self, window: &mut Window, cx: &mut Context<Self>) -> Result<(), plugin_editor_api::PluginError> {
+        let result = self.start_reload(window, cx);
+        if let Err(e) = &result {
+            self.report_error(OutputSource::Editor, e, window, cx);
+        }
+        cx.notify();
+        result.map_err(EnumEditorError::into_load_error)
     }
 }
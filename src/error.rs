@@ -0,0 +1,123 @@
+//! Errors surfaced by the editor
+//!
+//! Every fallible editor operation returns an [`EnumEditorError`]. The editor
+//! reports them in the Output panel and as a toast; plugin entry points convert
+//! them into the host's `PluginError`.
+
+use plugin_editor_api::PluginError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T, E = EnumEditorError> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum EnumEditorError {
+    /// Reading or writing a file failed
+    Io { path: PathBuf, source: io::Error },
+    /// A definition or configuration file could not be parsed
+    Parse { path: PathBuf, message: String },
+    /// The definition could not be serialized
+    Serialize { path: PathBuf, message: String },
+    /// The definition has errors that prevent exporting
+    Validation { messages: Vec<String> },
+    /// A codegen target or template failed to generate its output
+    Codegen { target: String, message: String },
+    /// A generated file was edited by hand and would be overwritten
+    ManuallyEdited { path: PathBuf },
+    /// Saving would make breaking changes to a published enum
+    BreakingChanges { changes: Vec<String> },
+    /// A pre-save hook command failed
+    Hook { command: String },
+    /// A git command failed
+    Git { message: String },
+    /// The editor has no file to operate on
+    NoFilePath,
+}
+
+impl EnumEditorError {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        EnumEditorError::Io { path: path.into(), source }
+    }
+
+    /// True if the error is a missing file
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, EnumEditorError::Io { source, .. } if source.kind() == io::ErrorKind::NotFound)
+    }
+
+    /// Short title for toasts
+    pub fn title(&self) -> &'static str {
+        match self {
+            EnumEditorError::Io { .. } => "File error",
+            EnumEditorError::Parse { .. } => "Parse error",
+            EnumEditorError::Serialize { .. } => "Serialization error",
+            EnumEditorError::Validation { .. } => "Validation failed",
+            EnumEditorError::Codegen { .. } => "Code generation failed",
+            EnumEditorError::ManuallyEdited { .. } => "File edited by hand",
+            EnumEditorError::BreakingChanges { .. } => "Breaking changes",
+            EnumEditorError::Hook { .. } => "Hook failed",
+            EnumEditorError::Git { .. } => "Git error",
+            EnumEditorError::NoFilePath => "No file",
+        }
+    }
+}
+
+impl fmt::Display for EnumEditorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnumEditorError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            EnumEditorError::Parse { path, message } => {
+                write!(f, "Failed to parse {}: {}", path.display(), message)
+            }
+            EnumEditorError::Serialize { path, message } => {
+                write!(f, "Failed to serialize {}: {}", path.display(), message)
+            }
+            EnumEditorError::Validation { messages } => write!(f, "{}", messages.join("; ")),
+            EnumEditorError::Codegen { target, message } => write!(f, "{}: {}", target, message),
+            EnumEditorError::ManuallyEdited { path } => {
+                write!(f, "{} was edited by hand since it was generated", path.display())
+            }
+            EnumEditorError::BreakingChanges { changes } => {
+                write!(f, "Saving would break the published enum: {}", changes.join("; "))
+            }
+            EnumEditorError::Hook { command } => write!(f, "Pre-save hook `{}` failed", command),
+            EnumEditorError::Git { message } => write!(f, "git: {}", message),
+            EnumEditorError::NoFilePath => write!(f, "No file path set"),
+        }
+    }
+}
+
+impl std::error::Error for EnumEditorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EnumEditorError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl EnumEditorError {
+    /// Convert for the host, reporting file errors as load failures
+    pub fn into_load_error(self) -> PluginError {
+        match self {
+            EnumEditorError::Io { ref path, .. }
+            | EnumEditorError::Parse { ref path, .. } => PluginError::FileLoadError {
+                path: path.clone(),
+                message: self.to_string(),
+            },
+            other => PluginError::Other { message: other.to_string() },
+        }
+    }
+
+    /// Convert for the host, reporting file errors as save failures
+    pub fn to_save_error(&self) -> PluginError {
+        match self {
+            EnumEditorError::Io { path, .. }
+            | EnumEditorError::Serialize { path, .. } => PluginError::FileSaveError {
+                path: path.clone(),
+                message: self.to_string(),
+            },
+            other => PluginError::Other { message: other.to_string() },
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Dry-run preview shown before "Generate All" writes anything
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, IconName, Sizable,
+    button::{Button, ButtonVariants},
+};
+use std::collections::HashSet;
+use crate::codegen::{FileChange, PlannedOutput};
+use crate::panels::render_diff;
+
+/// Lists the files a run would create or overwrite, with per-file diffs and
+/// a toggle to exclude each one
+pub struct ExportPreviewView {
+    plans: Vec<PlannedOutput>,
+    excluded: HashSet<usize>,
+    expanded: HashSet<usize>,
+}
+
+impl ExportPreviewView {
+    /// Files edited by hand start out excluded
+    pub fn new(plans: Vec<PlannedOutput>) -> Self {
+        let excluded = plans
+            .iter()
+            .enumerate()
+            .filter(|(_, plan)| plan.manually_edited)
+            .map(|(index, _)| index)
+            .collect();
+        Self {
+            plans,
+            excluded,
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Outputs the user kept selected; failed ones are included so they get reported
+    pub fn take_included(&mut self) -> Vec<PlannedOutput> {
+        let excluded = std::mem::take(&mut self.excluded);
+        std::mem::take(&mut self.plans)
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !excluded.contains(index))
+            .map(|(_, plan)| plan)
+            .collect()
+    }
+
+    fn toggle(set: &mut HashSet<usize>, index: usize) {
+        if !set.remove(&index) {
+            set.insert(index);
+        }
+    }
+}
+
+impl Render for ExportPreviewView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let pending = self.plans.iter().filter(|p| p.writes()).count();
+
+        v_flex()
+            .id("enum-export-preview")
+            .gap_2()
+            .max_h(px(480.0))
+            .overflow_scroll()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("{} of {} files would be written", pending, self.plans.len()))
+            )
+            .children(self.plans.iter().enumerate().map(|(index, plan)| {
+                let included = !self.excluded.contains(&index);
+                let expanded = self.expanded.contains(&index);
+                let (status, color, diff) = match &plan.result {
+                    Ok((_, change @ FileChange::Changed { diff, .. })) => (
+                        format!("overwrite ({})", change.summary()),
+                        cx.theme().warning,
+                        Some(diff.clone()).filter(|d| !d.is_empty()),
+                    ),
+                    Ok((_, change)) => (change.summary(), cx.theme().foreground, None),
+                    Err(message) => (message.clone(), cx.theme().danger, None),
+                };
+                let status = if plan.manually_edited {
+                    format!("edited by hand, {}", status)
+                } else {
+                    status
+                };
+
+                v_flex()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .text_xs()
+                            .child(
+                                Button::new(("export-preview-include", index))
+                                    .with_size(ui::Size::XSmall)
+                                    .when(included, |this| this.primary().icon(IconName::Check))
+                                    .label(plan.target.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        Self::toggle(&mut this.excluded, index);
+                                        cx.notify();
+                                    }))
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(plan.path.display().to_string())
+                            )
+                            .child(div().text_color(color).child(status))
+                            .when(diff.is_some(), |this| {
+                                this.child(
+                                    Button::new(("export-preview-diff", index))
+                                        .ghost()
+                                        .with_size(ui::Size::XSmall)
+                                        .label(if expanded { "Hide Diff" } else { "Diff" })
+                                        .on_click(cx.listener(move |this, _, _window, cx| {
+                                            Self::toggle(&mut this.expanded, index);
+                                            cx.notify();
+                                        }))
+                                )
+                            })
+                    )
+                    .when_some(diff.filter(|_| expanded), |this, diff| {
+                        this.child(render_diff(&diff, cx))
+                    })
+            }))
+    }
+}
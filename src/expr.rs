@@ -0,0 +1,227 @@
+//! Constant expressions for discriminants
+//!
+//! Supports integer literals (decimal, `0x`, `0b`, `0o`), named constants,
+//! parentheses, unary `-`, `!` and `~`, and the binary operators
+//! `* / % + - << >> & ^ |` with Rust's precedence. Arithmetic is checked;
+//! overflow and division by zero are errors.
+
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(i64),
+    Name(String),
+    Op(&'static str),
+    Open,
+    Close,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Number(value) => format!("`{}`", value),
+            Token::Name(name) => format!("`{}`", name),
+            Token::Op(op) => format!("`{}`", op),
+            Token::Open => "`(`".to_string(),
+            Token::Close => "`)`".to_string(),
+        }
+    }
+}
+
+const OPERATORS: [&str; 12] = ["<<", ">>", "*", "/", "%", "+", "-", "&", "^", "|", "!", "~"];
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+            let word = &rest[..end];
+            if c.is_ascii_digit() {
+                tokens.push(Token::Number(parse_value(word).ok_or_else(|| format!("`{}` is not a number", word))?));
+            } else {
+                tokens.push(Token::Name(word.to_string()));
+            }
+            rest = &rest[end..];
+        } else if c == '(' || c == ')' {
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            rest = &rest[1..];
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Op(*op));
+            rest = &rest[op.len()..];
+        } else {
+            return Err(format!("Unexpected `{}`", c));
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+fn precedence(op: &str) -> u8 {
+    match op {
+        "*" | "/" | "%" => 5,
+        "+" | "-" => 4,
+        "<<" | ">>" => 3,
+        "&" => 2,
+        "^" => 1,
+        _ => 0,
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    constants: &'a BTreeMap<String, i64>,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.tokens.get(self.position) {
+            Some(Token::Op(op)) if !matches!(*op, "!" | "~") => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn operand(&mut self) -> Result<i64, String> {
+        match self.next() {
+            Some(Token::Number(value)) => Ok(value),
+            Some(Token::Name(name)) => {
+                self.constants.get(&name).copied().ok_or_else(|| format!("Unknown constant `{}`", name))
+            }
+            Some(Token::Op("-")) => self.operand()?.checked_neg().ok_or_else(overflow),
+            Some(Token::Op("!" | "~")) => Ok(!self.operand()?),
+            Some(Token::Open) => {
+                let value = self.binary(0)?;
+                match self.next() {
+                    Some(Token::Close) => Ok(value),
+                    _ => Err("Missing `)`".to_string()),
+                }
+            }
+            Some(token) => Err(format!("Unexpected {}", token.describe())),
+            None => Err("Expression ends unexpectedly".to_string()),
+        }
+    }
+
+    /// Precedence climbing over operators binding at least as tightly as `min`
+    fn binary(&mut self, min: u8) -> Result<i64, String> {
+        let mut left = self.operand()?;
+        while let Some(op) = self.peek_op().filter(|op| precedence(op) >= min) {
+            self.position += 1;
+            let right = self.binary(precedence(op) + 1)?;
+            left = apply(op, left, right)?;
+        }
+        Ok(left)
+    }
+}
+
+fn overflow() -> String {
+    "Arithmetic overflow".to_string()
+}
+
+fn apply(op: &str, left: i64, right: i64) -> Result<i64, String> {
+    let shift = || u32::try_from(right).ok().filter(|shift| *shift < 64).ok_or_else(overflow);
+    match op {
+        "*" => left.checked_mul(right).ok_or_else(overflow),
+        "/" | "%" if right == 0 => Err("Division by zero".to_string()),
+        "/" => left.checked_div(right).ok_or_else(overflow),
+        "%" => left.checked_rem(right).ok_or_else(overflow),
+        "+" => left.checked_add(right).ok_or_else(overflow),
+        "-" => left.checked_sub(right).ok_or_else(overflow),
+        "<<" => Ok(left << shift()?),
+        ">>" => Ok(left >> shift()?),
+        "&" => Ok(left & right),
+        "^" => Ok(left ^ right),
+        "|" => Ok(left | right),
+        _ => Err(format!("Unknown operator `{}`", op)),
+    }
+}
+
+/// Parse a value written in decimal, hex (`0xFF`), binary (`0b1010`) or
+/// octal (`0o17`). Underscores between digits are ignored.
+pub fn parse_value(text: &str) -> Option<i64> {
+    let text = text.trim().replace('_', "");
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let prefix = text.get(..2).map(str::to_ascii_lowercase);
+    let (radix, digits) = match prefix.as_deref() {
+        Some("0x") => (16, &text[2..]),
+        Some("0b") => (2, &text[2..]),
+        Some("0o") => (8, &text[2..]),
+        _ => (10, text),
+    };
+    if digits.is_empty() || digits.starts_with(['+', '-']) {
+        return None;
+    }
+    let magnitude = i128::from_str_radix(digits, radix).ok()?;
+    i64::try_from(if negative { -magnitude } else { magnitude }).ok()
+}
+
+/// Value of `text`, resolving names from `constants`
+pub fn evaluate(text: &str, constants: &BTreeMap<String, i64>) -> Result<i64, String> {
+    let mut parser = Parser { tokens: tokenize(text)?, position: 0, constants };
+    let value = parser.binary(0)?;
+    match parser.next() {
+        None => Ok(value),
+        Some(token) => Err(format!("Unexpected {} after the expression", token.describe())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(text: &str) -> Result<i64, String> {
+        evaluate(text, &BTreeMap::new())
+    }
+
+    #[test]
+    fn operators_follow_rust_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), Ok(7));
+        assert_eq!(eval("(1 + 2) * 3"), Ok(9));
+        assert_eq!(eval("1 << 2 + 1"), Ok(8));
+        assert_eq!(eval("6 & 3 | 8"), Ok(10));
+        assert_eq!(eval("1 | 2 ^ 3"), Ok(1));
+        assert_eq!(eval("10 - 4 - 3"), Ok(3));
+    }
+
+    #[test]
+    fn names_resolve_to_constants() {
+        let constants = BTreeMap::from([("BASE".to_string(), 100)]);
+        assert_eq!(evaluate("BASE + 0x5", &constants), Ok(105));
+        assert_eq!(evaluate("OTHER", &constants), Err("Unknown constant `OTHER`".to_string()));
+    }
+
+    #[test]
+    fn unary_operators_bind_tightest() {
+        assert_eq!(eval("-5"), Ok(-5));
+        assert_eq!(eval("- -3"), Ok(3));
+        assert_eq!(eval("-(2 + 3)"), Ok(-5));
+        assert_eq!(eval("!0"), Ok(-1));
+        assert_eq!(eval("~0"), Ok(-1));
+        assert_eq!(eval("!1 & 3"), Ok(2));
+    }
+
+    #[test]
+    fn overflow_and_division_by_zero_are_errors() {
+        assert_eq!(eval("9223372036854775807 + 1"), Err("Arithmetic overflow".to_string()));
+        assert_eq!(eval("-(-9223372036854775807 - 1)"), Err("Arithmetic overflow".to_string()));
+        assert_eq!(eval("1 << 64"), Err("Arithmetic overflow".to_string()));
+        assert_eq!(eval("1 % 0"), Err("Division by zero".to_string()));
+    }
+
+    #[test]
+    fn malformed_expressions_are_errors() {
+        assert!(eval("(1 + 2").is_err());
+        assert!(eval("1 +").is_err());
+        assert!(eval("1 2").is_err());
+        assert!(eval("1 $ 2").is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! Find and replace across the variants of an enum
+
+use crate::metadata::{self, EnumMeta};
+use regex::{Regex, RegexBuilder};
+use ui_types_common::EnumAsset;
+
+/// Variant fields that can be searched
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchField {
+    Name,
+    Doc,
+    DisplayName,
+}
+
+impl MatchField {
+    pub const ALL: [MatchField; 3] = [MatchField::Name, MatchField::Doc, MatchField::DisplayName];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MatchField::Name => "Names",
+            MatchField::Doc => "Docs",
+            MatchField::DisplayName => "Display names",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FindOptions {
+    pub pattern: String,
+    pub replacement: String,
+    pub regex: bool,
+    pub case_sensitive: bool,
+    pub fields: Vec<MatchField>,
+}
+
+impl Default for FindOptions {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            replacement: String::new(),
+            regex: false,
+            case_sensitive: true,
+            fields: MatchField::ALL.to_vec(),
+        }
+    }
+}
+
+/// A field whose value changes when the replacement is applied
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replacement {
+    pub variant_index: usize,
+    pub variant_name: String,
+    pub field: MatchField,
+    pub before: String,
+    pub after: String,
+}
+
+fn build_regex(options: &FindOptions) -> Result<Regex, String> {
+    let pattern = if options.regex {
+        options.pattern.clone()
+    } else {
+        regex::escape(&options.pattern)
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Every change the replacement would make, in variant order
+pub fn find(asset: &EnumAsset, options: &FindOptions) -> Result<Vec<Replacement>, String> {
+    if options.pattern.is_empty() {
+        return Ok(Vec::new());
+    }
+    let regex = build_regex(options)?;
+    // Plain-text replacements must not expand `$1`-style references
+    let replace = |text: &str| -> String {
+        if options.regex {
+            regex.replace_all(text, options.replacement.as_str()).into_owned()
+        } else {
+            regex.replace_all(text, regex::NoExpand(&options.replacement)).into_owned()
+        }
+    };
+
+    let meta = EnumMeta::from_asset(asset);
+    let mut replacements = Vec::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        for field in &options.fields {
+            let value = match field {
+                MatchField::Name => Some(variant.name.clone()),
+                MatchField::Doc => variant.doc.clone(),
+                MatchField::DisplayName => meta.variant(&variant.name).display_name,
+            };
+            let Some(before) = value else {
+                continue;
+            };
+            if !regex.is_match(&before) {
+                continue;
+            }
+            let after = replace(&before);
+            if after != before {
+                replacements.push(Replacement {
+                    variant_index: index,
+                    variant_name: variant.name.clone(),
+                    field: *field,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    Ok(replacements)
+}
+
+/// Apply replacements produced by [`find`] on the same asset. Nothing is
+/// changed if the new names are invalid, clash or were retired; renames go
+/// through [`metadata::rename_variants`] like any other rename.
+pub fn apply(asset: &mut EnumAsset, replacements: &[Replacement]) -> Result<(), String> {
+    let renames: Vec<(usize, String)> = replacements
+        .iter()
+        .filter(|r| r.field == MatchField::Name)
+        .map(|r| (r.variant_index, r.after.clone()))
+        .collect();
+    metadata::check_renames(asset, &renames)?;
+
+    // Metadata is keyed by the current name, so handle display names before renames
+    let mut meta = EnumMeta::from_asset(asset);
+    for replacement in replacements.iter().filter(|r| r.field == MatchField::DisplayName) {
+        let mut variant_meta = meta.variant(&replacement.variant_name);
+        variant_meta.display_name = Some(replacement.after.clone()).filter(|s| !s.is_empty());
+        meta.set_variant(&replacement.variant_name, variant_meta);
+    }
+    meta.write_to(asset);
+
+    for replacement in replacements.iter().filter(|r| r.field == MatchField::Doc) {
+        if let Some(variant) = asset.variants.get_mut(replacement.variant_index) {
+            variant.doc = Some(replacement.after.clone()).filter(|s| !s.is_empty());
+        }
+    }
+
+    metadata::rename_variants(asset, &renames);
+    Ok(())
+}
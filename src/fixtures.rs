@@ -0,0 +1,62 @@
+//! Synthetic definitions for measuring the editor on large enums
+//!
+//! The generated enums are deterministic, so timings taken from them can be
+//! compared between builds.
+
+use crate::metadata::EnumMeta;
+use ui_types_common::{EnumAsset, EnumVariant, StructField, TypeKind, TypeRef, VariantPayload, Visibility};
+
+/// Sizes performance is measured at
+pub const FIXTURE_SIZES: [usize; 3] = [10, 1_000, 10_000];
+
+fn payload(index: usize) -> VariantPayload {
+    let field = |name: &str| StructField {
+        name: name.to_string(),
+        type_ref: TypeRef::Primitive { name: "f32".to_string() },
+        visibility: Visibility::Public,
+        doc: Some(format!("{} coordinate", name.to_uppercase())),
+    };
+    match index % 10 {
+        3 => VariantPayload::Single(TypeRef::Primitive { name: "u32".to_string() }),
+        7 => VariantPayload::Struct(vec![field("x"), field("y")]),
+        _ => VariantPayload::Unit,
+    }
+}
+
+/// An enum named `name` with `variants` documented variants. One in ten
+/// holds a `u32` and another one in ten a struct; all have a display name
+/// and a color, and some are deprecated or have aliases.
+pub fn synthetic_enum(name: &str, variants: usize) -> EnumAsset {
+    let mut asset = EnumAsset {
+        schema_version: 1,
+        type_kind: TypeKind::Enum,
+        name: name.to_string(),
+        display_name: name.to_string(),
+        description: Some(format!("Synthetic enum with {} variants", variants)),
+        variants: (0..variants)
+            .map(|index| EnumVariant {
+                name: format!("Variant{}", index),
+                payload: payload(index),
+                doc: Some(format!("Variant number {} of the fixture", index)),
+            })
+            .collect(),
+        visibility: Visibility::Public,
+        meta: serde_json::Value::Object(serde_json::Map::new()),
+    };
+
+    let mut meta = EnumMeta::from_asset(&asset);
+    meta.repr = Some("u32".to_string());
+    for index in 0..variants {
+        let name = format!("Variant{}", index);
+        let mut variant_meta = meta.variant(&name);
+        variant_meta.display_name = Some(format!("Variant {}", index));
+        variant_meta.color = Some(format!("#{:06X}", index.wrapping_mul(0x9E3779) & 0xFF_FFFF));
+        variant_meta.deprecated = index % 25 == 24;
+        if index % 50 == 0 {
+            variant_meta.aliases.push(format!("OldVariant{}", index));
+        }
+        meta.set_variant(&name, variant_meta);
+    }
+    meta.write_to(&mut asset);
+    asset
+}
@@ -0,0 +1,144 @@
+//! Reading earlier revisions of a definition from git, for changelogs and
+//! per-variant blame that follows variants through renames
+
+use crate::error::{EnumEditorError, Result};
+use crate::metadata::EnumMeta;
+use crate::storage;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use ui_types_common::EnumAsset;
+use uuid::Uuid;
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| EnumEditorError::Git { message: format!("Failed to run git: {}", e) })?;
+    if !output.status.success() {
+        return Err(EnumEditorError::Git {
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Root of the repository containing `path`, if it is in one
+pub fn repo_root(path: &Path) -> Option<PathBuf> {
+    let dir = storage::asset_container_dir(path);
+    git(&dir, &["rev-parse", "--show-toplevel"]).ok().map(|root| PathBuf::from(root.trim()))
+}
+
+/// `path` relative to the repository root, with `/` separators as git expects
+fn repo_path(root: &Path, path: &Path) -> Result<String> {
+    let canonical = path.canonicalize().map_err(|e| EnumEditorError::io(path, e))?;
+    let root = root.canonicalize().map_err(|e| EnumEditorError::io(root, e))?;
+    let relative = canonical.strip_prefix(&root).map_err(|_| EnumEditorError::Git {
+        message: format!("{} is not in the repository", path.display()),
+    })?;
+    Ok(relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
+}
+
+/// The definition at `path` as it was at `revision`
+pub fn load_revision(path: &Path, revision: &str) -> Result<EnumAsset> {
+    let root = repo_root(path).ok_or_else(|| EnumEditorError::Git {
+        message: format!("{} is not in a git repository", path.display()),
+    })?;
+    let spec = format!("{}:{}", revision, repo_path(&root, path)?);
+    let content = git(&root, &["show", &spec])?;
+    storage::serialization_of(path).parse(&content).map_err(|message| EnumEditorError::Parse {
+        path: PathBuf::from(spec),
+        message,
+    })
+}
+
+/// A commit that touched the definition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commit {
+    pub hash: String,
+    pub author: String,
+    /// Author date as `YYYY-MM-DD`
+    pub date: String,
+    pub summary: String,
+}
+
+impl Commit {
+    pub fn short_hash(&self) -> &str {
+        &self.hash[..self.hash.len().min(8)]
+    }
+}
+
+/// The last `limit` commits that changed the definition, newest first
+pub fn log(path: &Path, limit: usize) -> Result<Vec<Commit>> {
+    let root = repo_root(path).ok_or_else(|| EnumEditorError::Git {
+        message: format!("{} is not in a git repository", path.display()),
+    })?;
+    let relative = repo_path(&root, path)?;
+    let limit = format!("-n{}", limit);
+    let output = git(&root, &["log", &limit, "--date=short", "--format=%H%x1f%an%x1f%ad%x1f%s", "--", &relative])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\x1f');
+            Some(Commit {
+                hash: fields.next()?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                summary: fields.next().unwrap_or("").to_string(),
+            })
+        })
+        .collect())
+}
+
+/// What a commit has to change for a variant to be blamed on it. The
+/// modification time is left out since it changes with every edit anyway.
+fn fingerprint(asset: &EnumAsset, name: &str) -> Option<(serde_json::Value, serde_json::Value)> {
+    let variant = asset.variants.iter().find(|v| v.name == name)?;
+    let mut meta = EnumMeta::from_asset(asset).variant(name);
+    meta.modified = None;
+    Some((serde_json::to_value(variant).ok()?, serde_json::to_value(meta).ok()?))
+}
+
+/// Name of the variant with `uuid` in `asset`, if it has one
+fn name_with_uuid(asset: &EnumAsset, uuid: Uuid) -> Option<String> {
+    EnumMeta::from_asset(asset)
+        .variants
+        .into_iter()
+        .find(|(_, meta)| meta.uuid == Some(uuid))
+        .map(|(name, _)| name)
+}
+
+/// The most recent commit that changed each variant, keyed by variant name,
+/// found by comparing up to `limit` earlier revisions. A variant is followed
+/// through renames by its UUID, and by its name in revisions from before it
+/// had one. Variants with uncommitted changes are left out.
+pub fn variant_blame(path: &Path, current: &EnumAsset, limit: usize) -> Result<HashMap<String, Commit>> {
+    let commits = log(path, limit)?;
+    // A revision that fails to parse counts as changing every variant
+    let revisions: Vec<Option<EnumAsset>> = commits.iter().map(|c| load_revision(path, &c.hash).ok()).collect();
+    let meta = EnumMeta::from_asset(current);
+
+    let mut blame = HashMap::new();
+    for variant in &current.variants {
+        let name = variant.name.as_str();
+        let uuid = meta.variants.get(name).and_then(|variant_meta| variant_meta.uuid);
+        let fingerprints: Vec<_> = revisions
+            .iter()
+            .map(|revision| {
+                let asset = revision.as_ref()?;
+                let name_then = uuid.and_then(|uuid| name_with_uuid(asset, uuid)).unwrap_or_else(|| name.to_string());
+                fingerprint(asset, &name_then)
+            })
+            .collect();
+        if fingerprints.first().cloned().flatten() != fingerprint(current, name) {
+            continue;
+        }
+        let introduced = fingerprints
+            .windows(2)
+            .position(|pair| pair[0] != pair[1])
+            .unwrap_or(fingerprints.len() - 1);
+        blame.insert(name.to_string(), commits[introduced].clone());
+    }
+    Ok(blame)
+}
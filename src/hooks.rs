@@ -0,0 +1,149 @@
+//! User commands run before and after saves and exports
+//!
+//! Commands come from the `[hooks]` section of the project configuration and
+//! run through the platform shell with the project root as working directory.
+//! The enum is described to the command through environment variables:
+//!
+//! - `ENUM_NAME`: name of the enum
+//! - `ENUM_FILE`: path of the definition file
+//! - `ENUM_OUTPUT`: path of the exported file (export hooks only)
+
+use crate::output::{OutputLog, OutputSource};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// When a hook runs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookStage {
+    PreSave,
+    PostSave,
+    PostExport,
+}
+
+impl HookStage {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HookStage::PreSave => "pre-save",
+            HookStage::PostSave => "post-save",
+            HookStage::PostExport => "post-export",
+        }
+    }
+}
+
+/// Environment a hook runs in
+#[derive(Clone, Debug)]
+pub struct HookContext {
+    pub project_root: PathBuf,
+    pub enum_name: String,
+    pub definition_path: PathBuf,
+    pub output_path: Option<PathBuf>,
+}
+
+impl HookContext {
+    pub fn new(project_root: &Path, enum_name: &str, definition_path: &Path) -> Self {
+        Self {
+            project_root: project_root.to_path_buf(),
+            enum_name: enum_name.to_string(),
+            definition_path: definition_path.to_path_buf(),
+            output_path: None,
+        }
+    }
+
+    pub fn with_output(mut self, output_path: &Path) -> Self {
+        self.output_path = Some(output_path.to_path_buf());
+        self
+    }
+}
+
+/// Result of running a single hook command
+#[derive(Clone, Debug)]
+pub struct HookOutput {
+    pub stage: HookStage,
+    pub command: String,
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl HookOutput {
+    /// Write the command's result and output to the Output panel
+    pub fn report(&self, output: &OutputLog) {
+        if self.success {
+            output.info(OutputSource::Hook, format!("{} hook `{}` succeeded", self.stage.label(), self.command));
+        } else {
+            output.error(OutputSource::Hook, format!("{} hook `{}` failed", self.stage.label(), self.command));
+        }
+        for line in self.stdout.lines() {
+            output.info(OutputSource::Hook, format!("  {}", line));
+        }
+        for line in self.stderr.lines() {
+            output.warn(OutputSource::Hook, format!("  {}", line));
+        }
+    }
+}
+
+fn shell_command(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+/// Run one command and capture its output
+pub fn run(stage: HookStage, command: &str, context: &HookContext) -> HookOutput {
+    let mut shell = shell_command(command);
+    shell
+        .current_dir(&context.project_root)
+        .env("ENUM_NAME", &context.enum_name)
+        .env("ENUM_FILE", &context.definition_path);
+    if let Some(output_path) = &context.output_path {
+        shell.env("ENUM_OUTPUT", output_path);
+    }
+
+    match shell.output() {
+        Ok(output) => HookOutput {
+            stage,
+            command: command.to_string(),
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        },
+        Err(e) => HookOutput {
+            stage,
+            command: command.to_string(),
+            success: false,
+            stdout: String::new(),
+            stderr: e.to_string(),
+        },
+    }
+}
+
+/// Run commands in order, stopping at the first failure
+pub fn run_all(stage: HookStage, commands: &[String], context: &HookContext, log: &OutputLog) -> Vec<HookOutput> {
+    let mut outputs = Vec::new();
+    for command in commands {
+        let output = run(stage, command, context);
+        output.report(log);
+        let success = output.success;
+        outputs.push(output);
+        if !success {
+            break;
+        }
+    }
+    outputs
+}
+
+/// Run commands on a background thread so slow hooks don't block the editor
+pub fn spawn_all(stage: HookStage, commands: Vec<String>, context: HookContext, log: OutputLog) {
+    if commands.is_empty() {
+        return;
+    }
+    std::thread::spawn(move || {
+        run_all(stage, &commands, &context, &log);
+    });
+}
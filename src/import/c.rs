@@ -0,0 +1,308 @@
+//! Enum declarations in C and C++ headers
+//!
+//! Reads `enum`, `enum class` and `typedef enum` declarations without
+//! running the preprocessor. Values are evaluated against earlier
+//! enumerators and integer `#define`s of the same file; comments before an
+//! enumerator, or after it on the same line, become its doc.
+
+use super::{SourceEnum, SourceVariant};
+use crate::expr;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+use ui_types_common::{EnumVariant, VariantPayload};
+
+static INTEGER_SUFFIX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(0[xX][0-9a-fA-F]+|0[bB][01]+|\d+)[uUlL]+\b").unwrap());
+static INTEGER_CAST: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\(\s*(?:unsigned|signed|int|long|short|char|u?int\d+_t|\s)+\)").unwrap());
+static SCOPE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\w+::").unwrap());
+static DEFINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*#[ \t]*define[ \t]+(\w+)[ \t]+(.+?)[ \t]*$").unwrap());
+static DECLARATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(?:typedef\s+)?enum\s+(?:class\s+|struct\s+)?(?:(\w+)\s*)?(?::\s*([\w:\s]+?)\s*)?\{").unwrap()
+});
+static TYPEDEF_NAME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(\w+)\s*;").unwrap());
+
+/// A comment's byte range in the file and its text without the markers
+pub(super) struct Comment {
+    range: Range<usize>,
+    text: String,
+}
+
+/// `text` with comments and string literals blanked out, keeping every byte
+/// offset, and the comments that were removed
+pub(super) fn strip_comments(text: &str) -> (String, Vec<Comment>) {
+    let bytes = text.as_bytes();
+    let mut code = bytes.to_vec();
+    let mut comments = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = match (bytes[i], bytes.get(i + 1)) {
+            (b'/', Some(b'/')) => text[i..].find('\n').map_or(text.len(), |end| i + end),
+            (b'/', Some(b'*')) => text[i + 2..].find("*/").map_or(text.len(), |end| i + 2 + end + 2),
+            (quote @ (b'"' | b'\''), _) => {
+                let mut end = i + 1;
+                while end < bytes.len() && bytes[end] != quote && bytes[end] != b'\n' {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                // Never stop inside a multi-byte character
+                while end < bytes.len() && bytes[end] & 0xC0 == 0x80 {
+                    end += 1;
+                }
+                for byte in &mut code[i + 1..end.min(bytes.len())] {
+                    *byte = b' ';
+                }
+                i = end + 1;
+                continue;
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        comments.push(Comment { range: i..end, text: comment_text(&text[i..end]) });
+        for byte in &mut code[i..end] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+        i = end;
+    }
+    // Only ASCII bytes were replaced, and only whole comments and literals
+    (String::from_utf8(code).unwrap_or_default(), comments)
+}
+
+/// Text of a `//`, `///<`, `/* */` or `/** */` comment, without the markers
+/// and leading `*`s
+fn comment_text(comment: &str) -> String {
+    let inner = match comment.strip_prefix("/*") {
+        Some(rest) => rest.strip_suffix("*/").unwrap_or(rest),
+        None => comment.trim_start_matches('/'),
+    };
+    let lines: Vec<&str> = inner
+        .lines()
+        .map(|line| line.trim().trim_start_matches(['*', '!', '<']).trim())
+        .collect();
+    lines.join("\n").trim().to_string()
+}
+
+/// Docs of the comments in `range`, joined in order
+fn docs_in(comments: &[Comment], range: Range<usize>) -> Option<String> {
+    let docs: Vec<&str> = comments
+        .iter()
+        .filter(|comment| comment.range.start >= range.start && comment.range.end <= range.end)
+        .map(|comment| comment.text.as_str())
+        .filter(|text| !text.is_empty())
+        .collect();
+    (!docs.is_empty()).then(|| docs.join("\n"))
+}
+
+/// Comments right above `position`, separated from it only by whitespace
+pub(super) fn doc_before(code: &str, comments: &[Comment], position: usize) -> Option<String> {
+    let mut start = position;
+    for comment in comments.iter().rev().filter(|comment| comment.range.end <= position) {
+        if !code[comment.range.end..start].trim().is_empty() {
+            break;
+        }
+        start = comment.range.start;
+    }
+    docs_in(comments, start..position)
+}
+
+/// Byte ranges of the entries of `range` between separators outside
+/// parentheses and brackets
+pub(super) fn split_entries(code: &str, range: Range<usize>, separator: char) -> Vec<Range<usize>> {
+    let mut entries = Vec::new();
+    let mut start = range.start;
+    let mut depth = 0;
+    for (offset, c) in code[range.clone()].char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                entries.push(start..range.start + offset);
+                start = range.start + offset + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(start..range.end);
+    entries
+}
+
+/// Doc of an entry of a list separated by `,` or `;`, ending at `end`:
+/// comments above it, unless they trail the previous entry on its line, then
+/// comments after it up to the end of its line
+pub(super) fn entry_doc(code: &str, comments: &[Comment], entries: &[Range<usize>], index: usize, end: usize) -> Option<String> {
+    let entry = &entries[index];
+    let name_start = entry.start + (code[entry.clone()].len() - code[entry.clone()].trim_start().len());
+    let leading_start = match code[entry.start..name_start].find('\n') {
+        _ if index == 0 => entry.start,
+        Some(line_end) => entry.start + line_end,
+        None => name_start,
+    };
+    let trailing_end = entries
+        .get(index + 1)
+        .map_or(end, |next| next.start + code[next.start..].find('\n').unwrap_or(0))
+        .min(end);
+    docs_in(comments, leading_start..name_start)
+        .into_iter()
+        .chain(docs_in(comments, name_start..trailing_end))
+        .reduce(|a, b| format!("{}\n{}", a, b))
+}
+
+/// Rust integer type for a C underlying type
+fn repr(underlying: &str) -> Option<String> {
+    let words: Vec<&str> = underlying.split_whitespace().collect();
+    let name = words.join(" ");
+    let name = name.trim_start_matches("std::");
+    let repr = match name {
+        "uint8_t" | "unsigned char" => "u8",
+        "int8_t" | "char" | "signed char" => "i8",
+        "uint16_t" | "unsigned short" | "unsigned short int" => "u16",
+        "int16_t" | "short" | "short int" => "i16",
+        "uint32_t" | "unsigned" | "unsigned int" => "u32",
+        "int32_t" | "int" | "signed" | "signed int" => "i32",
+        "uint64_t" | "unsigned long long" | "size_t" => "u64",
+        "int64_t" | "long long" => "i64",
+        _ => return None,
+    };
+    Some(repr.to_string())
+}
+
+/// A C integer expression in the syntax `expr` understands: without integer
+/// suffixes, casts to integer types and scope qualifiers
+fn clean_expression(text: &str) -> String {
+    let text = INTEGER_SUFFIX.replace_all(text, "$1");
+    let text = INTEGER_CAST.replace_all(&text, "");
+    SCOPE.replace_all(&text, "").trim().to_string()
+}
+
+/// Integer `#define`s, in order, so later ones can use earlier ones
+fn defines(code: &str) -> BTreeMap<String, i64> {
+    let mut constants = BTreeMap::new();
+    for captures in DEFINE.captures_iter(code) {
+        if let Ok(value) = expr::evaluate(&clean_expression(&captures[2]), &constants) {
+            constants.insert(captures[1].to_string(), value);
+        }
+    }
+    constants
+}
+
+/// Every named enum declared in `text`
+pub fn parse_enums(text: &str) -> Vec<SourceEnum> {
+    let (code, comments) = strip_comments(text);
+    let constants = defines(&code);
+
+    // Enumerators of unscoped enums are visible to every later enum
+    let mut known = constants.clone();
+    let mut enums = Vec::new();
+    for captures in DECLARATION.captures_iter(&code) {
+        let whole = captures.get(0).unwrap();
+        let body_start = whole.end();
+        let Some(body_end) = code[body_start..].find('}').map(|end| body_start + end) else {
+            continue;
+        };
+        // `typedef enum _Tag { .. } Name;` is known by its typedef
+        let typedef = TYPEDEF_NAME
+            .captures(&code[body_end + 1..])
+            .filter(|_| whole.as_str().starts_with("typedef"))
+            .map(|captures| captures[1].to_string());
+        let name = typedef.or_else(|| captures.get(1).map(|name| name.as_str().to_string()));
+        let Some(name) = name else {
+            continue;
+        };
+
+        let entries = split_entries(&code, body_start..body_end, ',');
+
+        let mut next = 0i64;
+        let mut variants = Vec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            let text = code[entry.clone()].trim();
+            if text.is_empty() {
+                continue;
+            }
+            let (variant_name, discriminant) = match text.split_once('=') {
+                Some((name, value)) => (name.trim().to_string(), Some(clean_expression(value))),
+                None => (text.to_string(), None),
+            };
+            let doc = entry_doc(&code, &comments, &entries, index, body_end);
+
+            let value = match &discriminant {
+                Some(text) => expr::evaluate(text, &known).ok(),
+                None => Some(next),
+            };
+            if let Some(value) = value {
+                known.insert(variant_name.clone(), value);
+                next = value.wrapping_add(1);
+            }
+            variants.push(SourceVariant {
+                variant: EnumVariant { name: variant_name, payload: VariantPayload::Unit, doc },
+                // Values that reference other enumerators are written out
+                discriminant: discriminant.map(|text| value.map_or(text, |value| value.to_string())),
+                alias: None,
+            });
+        }
+
+        enums.push(SourceEnum {
+            name,
+            doc: doc_before(&code, &comments, whole.start()),
+            repr: captures.get(2).and_then(|underlying| repr(underlying.as_str())),
+            variants,
+            constants: constants.clone(),
+            reserved: Vec::new(),
+        });
+    }
+    enums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discriminants(source: &SourceEnum) -> Vec<Option<&str>> {
+        source.variants.iter().map(|variant| variant.discriminant.as_deref()).collect()
+    }
+
+    #[test]
+    fn typedefs_are_known_by_their_typedef_name() {
+        let enums = parse_enums(
+            "typedef enum _Color { RED, GREEN } Color;\n\
+             typedef enum { ON, OFF } Switch;\n\
+             enum class Mode : uint8_t { Idle, Busy };\n",
+        );
+        let names: Vec<&str> = enums.iter().map(|source| source.name.as_str()).collect();
+        assert_eq!(names, vec!["Color", "Switch", "Mode"]);
+        assert_eq!(enums[2].repr.as_deref(), Some("u8"));
+    }
+
+    #[test]
+    fn comments_become_docs() {
+        let enums = parse_enums(
+            "/** Damage kinds */\n\
+             enum Damage {\n\
+                 /// Physical hits\n\
+                 DAMAGE_PHYSICAL,\n\
+                 DAMAGE_FIRE, ///< Burning\n\
+             };\n",
+        );
+        let damage = &enums[0];
+        assert_eq!(damage.doc.as_deref(), Some("Damage kinds"));
+        let docs: Vec<Option<&str>> = damage.variants.iter().map(|variant| variant.variant.doc.as_deref()).collect();
+        assert_eq!(docs, vec![Some("Physical hits"), Some("Burning")]);
+    }
+
+    #[test]
+    fn values_can_use_earlier_enumerators_and_defines() {
+        let enums = parse_enums(
+            "#define BASE 0x10u\n\
+             enum Flags { A = 1, B = A << 1, C = A | B, D };\n\
+             enum Next { E = C + 1, F = (int)BASE + 1 };\n",
+        );
+        assert_eq!(discriminants(&enums[0]), vec![Some("1"), Some("2"), Some("3"), None]);
+        assert_eq!(discriminants(&enums[1]), vec![Some("4"), Some("17")]);
+        assert_eq!(enums[1].constants.get("BASE"), Some(&16));
+    }
+}
@@ -0,0 +1,43 @@
+//! Value sets in JSON files
+//!
+//! A top-level array of strings is one enum named after the file; an object
+//! whose values are arrays of strings holds one enum per key. JSON has no
+//! comments, so the variants have no docs.
+
+use super::{string_variants, SourceEnum};
+use crate::naming;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The strings of `value` if it is an array of nothing else
+fn strings(value: &Value) -> Option<Vec<(String, Option<String>)>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|item| item.as_str().map(|item| (item.to_string(), None)))
+        .collect()
+}
+
+fn source_enum(name: &str, values: Vec<(String, Option<String>)>) -> SourceEnum {
+    SourceEnum {
+        name: if naming::is_identifier(name) { name.to_string() } else { naming::pascal_case(name) },
+        doc: None,
+        repr: None,
+        variants: string_variants(values),
+        constants: BTreeMap::new(),
+        reserved: Vec::new(),
+    }
+}
+
+/// Every array of strings in `text`; `name` is used for a top-level array
+pub fn parse_enums(text: &str, name: &str) -> Result<Vec<SourceEnum>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    if let Some(values) = strings(&value) {
+        return Ok(vec![source_enum(name, values)]);
+    }
+    let object = value.as_object().ok_or("expected an array of strings, or an object of them")?;
+    Ok(object
+        .iter()
+        .filter_map(|(key, value)| strings(value).map(|values| source_enum(key, values)))
+        .collect())
+}
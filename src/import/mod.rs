@@ -0,0 +1,341 @@
+//! Enum declarations read from source files in other languages
+//!
+//! Each importer turns the enums declared in a file into [`SourceEnum`]s,
+//! which [`merge`] writes into a definition.
+
+pub mod c;
+pub mod json;
+pub mod paste;
+pub mod proto;
+pub mod rust;
+pub mod typescript;
+
+use crate::error::{EnumEditorError, Result};
+use crate::expr;
+use crate::metadata::{variant_values, EnumMeta, Reserved, VariantMeta};
+use crate::naming::{self, Case};
+use crate::project::ImportConfig;
+use crate::storage;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use ui_types_common::{EnumAsset, EnumVariant, TypeKind, VariantPayload, Visibility};
+
+/// An enum declared in a source file
+#[derive(Clone, Debug)]
+pub struct SourceEnum {
+    pub name: String,
+    pub doc: Option<String>,
+    /// Integer type of the values, as a Rust `repr`
+    pub repr: Option<String>,
+    pub variants: Vec<SourceVariant>,
+    /// Integer constants declared in the same file, by name
+    pub constants: BTreeMap<String, i64>,
+    /// Names and values the source retired
+    pub reserved: Vec<Reserved>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SourceVariant {
+    pub variant: EnumVariant,
+    /// Explicit discriminant as written, e.g. `BASE + 1`
+    pub discriminant: Option<String>,
+    /// String the source knows the variant by when it differs from its name,
+    /// e.g. `"north-east"` for `NorthEast`; kept as a serde alias
+    pub alias: Option<String>,
+}
+
+/// Variants named after string values, e.g. the members of a TypeScript
+/// string union, with their docs. Values that aren't all identifiers are
+/// converted to PascalCase and kept as aliases.
+fn string_variants(values: Vec<(String, Option<String>)>) -> Vec<SourceVariant> {
+    let values = values.into_iter().map(|(value, doc)| (value, doc, None)).collect();
+    named_variants(values)
+}
+
+/// Like [`string_variants`], with a discriminant per value
+fn named_variants(values: Vec<(String, Option<String>, Option<String>)>) -> Vec<SourceVariant> {
+    let convert = !values.iter().all(|(value, _, _)| naming::is_identifier(value));
+    let mut variants: Vec<SourceVariant> = Vec::new();
+    for (value, doc, discriminant) in values {
+        let name = if convert { naming::pascal_case(&value) } else { value.clone() };
+        let mut name: String = name.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '_').collect();
+        if name.is_empty() {
+            continue;
+        }
+        if !naming::is_identifier(&name) {
+            name = format!("_{}", name);
+        }
+        if variants.iter().any(|v| v.variant.name == name) {
+            name = naming::duplicate_name(&name, variants.iter().map(|v| v.variant.name.as_str()));
+        }
+        variants.push(SourceVariant {
+            alias: (name != value).then_some(value),
+            variant: EnumVariant { name, payload: VariantPayload::Unit, doc },
+            discriminant,
+        });
+    }
+    variants
+}
+
+impl SourceEnum {
+    /// True if the variants' values are meaningful: the enum is field-less or
+    /// has a `repr`
+    pub fn has_discriminants(&self) -> bool {
+        self.repr.is_some() || self.variants.iter().all(|v| matches!(v.variant.payload, VariantPayload::Unit))
+    }
+
+    /// Value of every variant, following Rust's rules. A discriminant that
+    /// isn't a constant expression is unknown, and so are the implicit values
+    /// after it.
+    pub fn values(&self) -> Vec<Option<i64>> {
+        let mut next = Some(0i64);
+        self.variants
+            .iter()
+            .map(|variant| {
+                let value = match &variant.discriminant {
+                    Some(text) => expr::evaluate(text, &self.constants).ok(),
+                    None => next,
+                };
+                next = value.map(|value| value.wrapping_add(1));
+                value
+            })
+            .collect()
+    }
+
+    /// A new definition of the enum. Names are kept as written, with the
+    /// naming convention they follow.
+    pub fn to_asset(&self) -> EnumAsset {
+        let display_name: Vec<String> = naming::words(&self.name).iter().map(|word| naming::pascal_case(word)).collect();
+        let mut asset = EnumAsset {
+            schema_version: 1,
+            type_kind: TypeKind::Enum,
+            name: self.name.clone(),
+            display_name: display_name.join(" "),
+            description: None,
+            variants: Vec::new(),
+            visibility: Visibility::Public,
+            meta: serde_json::Value::Object(serde_json::Map::new()),
+        };
+        let naming = Case::ALL
+            .into_iter()
+            .find(|case| self.variants.iter().all(|v| case.matches(&v.variant.name)))
+            .unwrap_or_default();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.naming = naming;
+        meta.write_to(&mut asset);
+        merge(&mut asset, self);
+        asset
+    }
+}
+
+/// Variant name after the import rules, or `None` if they leave it as is.
+/// Names the rules would leave empty or invalid are kept as written.
+fn conformed_name(name: &str, enum_name: &str, rules: &ImportConfig) -> Option<String> {
+    let mut stripped = name;
+    let enum_prefix = format!("{}_", naming::screaming_snake_case(enum_name));
+    if rules.strip_enum_name {
+        stripped = stripped.strip_prefix(&enum_prefix).unwrap_or(stripped);
+    }
+    if let Some(rest) = rules.strip_prefixes.iter().find_map(|prefix| stripped.strip_prefix(prefix.as_str())) {
+        stripped = rest;
+    }
+    if let Some(rest) = rules.strip_suffixes.iter().find_map(|suffix| stripped.strip_suffix(suffix.as_str())) {
+        stripped = rest;
+    }
+    let converted = match rules.case {
+        Some(case) => case.apply(stripped),
+        None => stripped.to_string(),
+    };
+    (converted != name && naming::is_identifier(&converted)).then_some(converted)
+}
+
+impl SourceEnum {
+    /// Rename the variants following the project's import rules
+    pub fn conform(&mut self, rules: &ImportConfig) {
+        if rules.is_empty() {
+            return;
+        }
+        let mut names: Vec<String> = Vec::new();
+        for source_variant in &mut self.variants {
+            let mut name = conformed_name(&source_variant.variant.name, &self.name, rules)
+                .unwrap_or_else(|| source_variant.variant.name.clone());
+            if names.contains(&name) {
+                name = naming::duplicate_name(&name, names.iter().map(String::as_str));
+            }
+            names.push(name.clone());
+            source_variant.variant.name = name;
+        }
+    }
+}
+
+/// Languages enums can be imported from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SourceLanguage {
+    Rust,
+    C,
+    Proto,
+    TypeScript,
+    Json,
+}
+
+impl SourceLanguage {
+    /// Language of a file, from its extension
+    pub fn detect(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "rs" => Some(SourceLanguage::Rust),
+            "h" | "hh" | "hpp" | "hxx" | "c" | "cc" | "cpp" | "cxx" => Some(SourceLanguage::C),
+            "proto" => Some(SourceLanguage::Proto),
+            "ts" | "mts" | "cts" | "tsx" => Some(SourceLanguage::TypeScript),
+            "json" => Some(SourceLanguage::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Every enum declared in the file at `path`
+pub fn parse_file(path: &Path) -> Result<Vec<SourceEnum>> {
+    let language = SourceLanguage::detect(path).ok_or_else(|| EnumEditorError::Parse {
+        path: path.to_path_buf(),
+        message: "enums can't be imported from this kind of file".to_string(),
+    })?;
+    let text = std::fs::read_to_string(path).map_err(|e| EnumEditorError::io(path, e))?;
+    let parsed = match language {
+        SourceLanguage::Rust => rust::parse_enums(&text),
+        SourceLanguage::C => Ok(c::parse_enums(&text)),
+        SourceLanguage::Proto => Ok(proto::parse_enums(&text)),
+        SourceLanguage::TypeScript => Ok(typescript::parse_enums(&text)),
+        SourceLanguage::Json => {
+            let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+            json::parse_enums(&text, &naming::pascal_case(stem))
+        }
+    };
+    parsed.map_err(|message| EnumEditorError::Parse { path: path.to_path_buf(), message })
+}
+
+/// Like [`parse_file`], with the import rules applied to the enums of C
+/// headers and protobuf files
+pub fn parse_file_conformed(path: &Path, rules: &ImportConfig) -> Result<Vec<SourceEnum>> {
+    let mut enums = parse_file(path)?;
+    if matches!(SourceLanguage::detect(path), Some(SourceLanguage::C | SourceLanguage::Proto)) {
+        enums.iter_mut().for_each(|source| source.conform(rules));
+    }
+    Ok(enums)
+}
+
+/// The enum `name` declared in the file at `path`
+pub fn find_enum(path: &Path, name: &str) -> Result<SourceEnum> {
+    parse_file(path)?.into_iter().find(|e| e.name == name).ok_or_else(|| EnumEditorError::Parse {
+        path: path.to_path_buf(),
+        message: format!("no enum `{}` is declared", name),
+    })
+}
+
+/// Make the definition match the source: its variants, their order, docs,
+/// payloads and values. Variants that remain keep their metadata; removed
+/// ones go to the trash.
+pub fn merge(asset: &mut EnumAsset, source: &SourceEnum) {
+    let mut meta = EnumMeta::from_asset(asset);
+    let old_values: HashMap<String, i64> =
+        asset.variants.iter().map(|v| v.name.clone()).zip(variant_values(asset, &meta)).collect();
+
+    for (index, variant) in asset.variants.clone().into_iter().enumerate().rev() {
+        if !source.variants.iter().any(|v| v.variant.name == variant.name) {
+            meta.trash_variant(variant, index);
+        }
+    }
+
+    for (source_variant, source_value) in source.variants.iter().zip(source.values()) {
+        let name = &source_variant.variant.name;
+        let mut variant_meta = meta.variant(name);
+        // A composite flag stays one as long as it still has the same value
+        let unchanged = source_value.is_some() && old_values.get(name) == source_value.as_ref();
+        if !(variant_meta.is_composite() && unchanged) {
+            variant_meta.combines.clear();
+            set_value(&mut variant_meta, &meta.constants, source_variant, source_value);
+        }
+        add_alias(&mut variant_meta, source_variant);
+        meta.set_variant(name, variant_meta);
+    }
+
+    asset.variants = source.variants.iter().map(|v| v.variant.clone()).collect();
+    if source.doc.is_some() {
+        asset.description = source.doc.clone();
+    }
+    if meta.repr.is_none() {
+        meta.repr = source.repr.clone();
+    }
+    for reserved in &source.reserved {
+        if !meta.reserved.contains(reserved) {
+            meta.reserved.push(reserved.clone());
+        }
+    }
+    meta.write_to(asset);
+}
+
+/// Add the source's variants the definition doesn't declare yet after the
+/// existing ones, with the values the source gives them. Returns how many
+/// were added.
+pub fn append(asset: &mut EnumAsset, source: &SourceEnum) -> usize {
+    let mut meta = EnumMeta::from_asset(asset);
+    let mut added = 0;
+    for (source_variant, source_value) in source.variants.iter().zip(source.values()) {
+        let name = &source_variant.variant.name;
+        if asset.variants.iter().any(|v| v.name == *name) {
+            continue;
+        }
+        let mut variant_meta = meta.variant(name);
+        set_value(&mut variant_meta, &meta.constants, source_variant, source_value);
+        add_alias(&mut variant_meta, source_variant);
+        meta.set_variant(name, variant_meta);
+        asset.variants.push(source_variant.variant.clone());
+        added += 1;
+    }
+    meta.write_to(asset);
+    added
+}
+
+/// The value of a variant as the source writes it: a literal, an expression
+/// over the definition's constants, or else the value it evaluated to
+fn set_value(variant_meta: &mut VariantMeta, constants: &BTreeMap<String, i64>, source_variant: &SourceVariant, source_value: Option<i64>) {
+    variant_meta.value = None;
+    variant_meta.expr = None;
+    if let Some(text) = &source_variant.discriminant {
+        match expr::parse_value(text) {
+            Some(value) => variant_meta.value = Some(value),
+            None if expr::evaluate(text, constants).is_ok() => variant_meta.expr = Some(text.clone()),
+            None => variant_meta.value = source_value,
+        }
+    }
+}
+
+fn add_alias(variant_meta: &mut VariantMeta, source_variant: &SourceVariant) {
+    if let Some(alias) = &source_variant.alias {
+        if !variant_meta.aliases.contains(alias) {
+            variant_meta.aliases.push(alias.clone());
+        }
+    }
+}
+
+/// Write each enum as a new `.enum` folder in `dir`. Existing definitions are
+/// never overwritten. Returns the written paths.
+pub fn create_definitions(dir: &Path, enums: &[SourceEnum]) -> Vec<Result<PathBuf>> {
+    enums
+        .iter()
+        .map(|source| {
+            let path = dir
+                .join(format!("{}.{}", source.name, storage::FOLDER_EXTENSION))
+                .join(storage::MARKER_FILE);
+            if path.exists() {
+                return Err(EnumEditorError::io(
+                    &path,
+                    std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the definition already exists"),
+                ));
+            }
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| EnumEditorError::io(parent, e))?;
+            }
+            storage::save_asset(&path, &source.to_asset())?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+//! Variants pasted as text
+//!
+//! Pasted text is read as the first of: a Rust or C enum declaration, a list
+//! of names, the body of a Rust or C enum, CSV rows (name, then a value or
+//! description) or a list of arbitrary strings.
+
+use super::{c, named_variants, rust, SourceEnum, SourceVariant};
+use crate::expr;
+use crate::naming;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+/// Name given to a pasted enum body
+const PASTED: &str = "Pasted";
+
+static ENUM_KEYWORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\benum\b").unwrap());
+
+/// What pasted text was read as
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PasteFormat {
+    Rust,
+    C,
+    Csv,
+    List,
+}
+
+impl PasteFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PasteFormat::Rust => "Rust enum",
+            PasteFormat::C => "C enum",
+            PasteFormat::Csv => "CSV",
+            PasteFormat::List => "list",
+        }
+    }
+}
+
+/// The first enum, if it has variants and all of them have usable names
+fn usable(enums: Vec<SourceEnum>) -> Option<SourceEnum> {
+    enums
+        .into_iter()
+        .next()
+        .filter(|e| !e.variants.is_empty() && e.variants.iter().all(|v| naming::is_identifier(&v.variant.name)))
+}
+
+fn pasted(variants: Vec<SourceVariant>) -> SourceEnum {
+    SourceEnum {
+        name: PASTED.to_string(),
+        doc: None,
+        repr: None,
+        variants,
+        constants: BTreeMap::new(),
+        reserved: Vec::new(),
+    }
+}
+
+/// Lines, or the items of a single comma separated line, without quotes
+fn items(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    let items = match lines.as_slice() {
+        [line] => line.split(',').collect(),
+        _ => lines,
+    };
+    items
+        .into_iter()
+        .map(|item| item.trim().trim_end_matches(',').trim().trim_matches(['"', '\'']).to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// At least two rows of comma or tab separated cells, each with a name and
+/// at least one more cell. A second cell that is a number is the value,
+/// otherwise the description; a third cell is the description.
+fn csv(text: &str) -> Option<SourceEnum> {
+    let separator = if text.contains('\t') { '\t' } else { ',' };
+    let rows: Vec<Vec<String>> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split(separator).map(|cell| cell.trim().trim_matches('"').trim().to_string()).collect())
+        .collect();
+    if rows.len() < 2 || rows.iter().any(|row| row.len() < 2) {
+        return None;
+    }
+    let header = ["name", "variant"].iter().any(|name| rows[0][0].eq_ignore_ascii_case(name));
+    let values = rows
+        .into_iter()
+        .skip(usize::from(header))
+        .map(|row| {
+            let value = expr::parse_value(&row[1]);
+            let description = match value {
+                Some(_) => row.get(2),
+                None => Some(&row[1]),
+            };
+            let description = description.filter(|text| !text.is_empty()).cloned();
+            (row[0].clone(), description, value.map(|value| value.to_string()))
+        })
+        .collect();
+    Some(pasted(named_variants(values))).filter(|source| !source.variants.is_empty())
+}
+
+/// Variants read from pasted text and the format they were read as
+pub fn detect(text: &str) -> Option<(PasteFormat, SourceEnum)> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if ENUM_KEYWORD.is_match(text) {
+        if let Some(source) = rust::parse_enums(text).ok().and_then(usable) {
+            return Some((PasteFormat::Rust, source));
+        }
+        if let Some(source) = usable(c::parse_enums(text)) {
+            return Some((PasteFormat::C, source));
+        }
+    }
+
+    let items = items(text);
+    if !items.is_empty() && items.iter().all(|item| naming::is_identifier(item)) {
+        let values = items.into_iter().map(|item| (item, None, None)).collect();
+        return Some((PasteFormat::List, pasted(named_variants(values))));
+    }
+
+    let body = format!("enum {} {{\n{}\n}}", PASTED, text);
+    if let Some(source) = rust::parse_enums(&body).ok().and_then(usable) {
+        return Some((PasteFormat::Rust, source));
+    }
+    if let Some(source) = usable(c::parse_enums(&format!("{};", body))) {
+        return Some((PasteFormat::C, source));
+    }
+    if let Some(source) = csv(text) {
+        return Some((PasteFormat::Csv, source));
+    }
+    let values = items.into_iter().map(|item| (item, None, None)).collect();
+    Some((PasteFormat::List, pasted(named_variants(values)))).filter(|(_, source)| !source.variants.is_empty())
+}
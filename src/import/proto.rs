@@ -0,0 +1,96 @@
+//! Enum declarations in Protocol Buffers (`.proto`) files
+//!
+//! Reads top-level and message-nested `enum` blocks with their values,
+//! comments and `reserved` statements. Field options such as
+//! `[deprecated = true]` and `option` statements are skipped.
+
+use super::c::{doc_before, entry_doc, split_entries, strip_comments};
+use super::{SourceEnum, SourceVariant};
+use crate::expr;
+use crate::metadata::Reserved;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use ui_types_common::{EnumVariant, VariantPayload};
+
+static DECLARATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\benum\s+(\w+)\s*\{").unwrap());
+static VALUE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\w+)\s*=\s*(-?\s*\w+)").unwrap());
+
+/// Largest value of a proto enum, the end of `reserved 10 to max`
+const MAX_VALUE: i64 = i32::MAX as i64;
+
+/// Names and values of a `reserved` statement, e.g. `2, 9 to 11` or `"FOO"`
+fn parse_reserved(list: &str) -> Vec<Reserved> {
+    list.split(',')
+        .map(|item| item.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter_map(|item| {
+            if let Some(name) = item.strip_prefix(['"', '\'']).and_then(|rest| rest.strip_suffix(['"', '\''])) {
+                return Some(Reserved { name: Some(name.to_string()), value: None, end: None });
+            }
+            match item.split_once(" to ") {
+                Some((start, end)) => {
+                    let start = expr::parse_value(start)?;
+                    let end = match end.trim() {
+                        "max" => MAX_VALUE,
+                        end => expr::parse_value(end)?,
+                    };
+                    Some(Reserved { name: None, value: Some(start), end: Some(end) })
+                }
+                None => Some(Reserved { name: None, value: Some(expr::parse_value(&item)?), end: None }),
+            }
+        })
+        .collect()
+}
+
+/// Every enum declared in `text`
+pub fn parse_enums(text: &str) -> Vec<SourceEnum> {
+    let (code, comments) = strip_comments(text);
+
+    let mut enums = Vec::new();
+    for captures in DECLARATION.captures_iter(&code) {
+        let whole = captures.get(0).unwrap();
+        let body_start = whole.end();
+        let Some(body_end) = code[body_start..].find('}').map(|end| body_start + end) else {
+            continue;
+        };
+
+        let entries = split_entries(&code, body_start..body_end, ';');
+        let mut variants = Vec::new();
+        let mut reserved = Vec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            let statement = code[entry.clone()].split_whitespace().collect::<Vec<_>>().join(" ");
+            if statement.is_empty() || statement.starts_with("option ") {
+                continue;
+            }
+            if statement.starts_with("reserved ") {
+                // Names are string literals, which are blanked in `code`
+                let start = entry.start + code[entry.clone()].find("reserved").unwrap_or(0) + "reserved".len();
+                reserved.extend(parse_reserved(&text[start..entry.end]));
+                continue;
+            }
+            let Some(value) = VALUE.captures(&statement) else {
+                continue;
+            };
+            variants.push(SourceVariant {
+                variant: EnumVariant {
+                    name: value[1].to_string(),
+                    payload: VariantPayload::Unit,
+                    doc: entry_doc(&code, &comments, &entries, index, body_end),
+                },
+                discriminant: Some(value[2].replace(' ', "")),
+                alias: None,
+            });
+        }
+
+        enums.push(SourceEnum {
+            name: captures[1].to_string(),
+            doc: doc_before(&code, &comments, whole.start()),
+            // Proto enums are 32-bit on the wire
+            repr: Some("i32".to_string()),
+            variants,
+            constants: BTreeMap::new(),
+            reserved,
+        });
+    }
+    enums
+}
@@ -0,0 +1,221 @@
+//! Enum declarations in hand-written Rust source
+//!
+//! Files are parsed with `syn`, so anything that compiles can be read.
+//! Rewriting is textual: only the braces of the enum's body are replaced,
+//! which keeps the surrounding code, attributes and comments as written.
+
+use super::{SourceEnum, SourceVariant};
+use crate::expr;
+use crate::metadata::repr_range;
+use crate::type_suggestions;
+use quote::ToTokens;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use ui_types_common::{EnumVariant, StructField, TypeRef, VariantPayload, Visibility};
+
+/// `tokens` as text, without the spaces `quote` puts between every token
+fn tokens_to_string(tokens: impl ToTokens) -> String {
+    let text = tokens.to_token_stream().to_string();
+    normalize_spacing(&text)
+}
+
+/// Drop whitespace except between two words, and after commas
+pub fn normalize_spacing(text: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut result = String::new();
+    let mut pending_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            let previous = result.chars().next_back();
+            if previous.is_some_and(|p| (is_word(p) && is_word(c)) || p == ',') {
+                result.push(' ');
+            }
+            pending_space = false;
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn doc_of(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(text), .. }),
+                ..
+            }) => Some(text.value()),
+            _ => None,
+        })
+        .collect();
+    // `/// Text` is `#[doc = " Text"]`
+    let lines: Vec<&str> = lines.iter().map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end()).collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+fn repr_of(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().filter(|attr| attr.path().is_ident("repr")).find_map(|attr| {
+        let mut repr = None;
+        let _ = attr.parse_nested_meta(|nested| {
+            let name = tokens_to_string(&nested.path);
+            if repr_range(&name).is_some() {
+                repr = Some(name);
+            }
+            Ok(())
+        });
+        repr
+    })
+}
+
+fn type_ref(ty: &syn::Type) -> TypeRef {
+    type_suggestions::type_ref(&tokens_to_string(ty))
+}
+
+fn visibility(vis: &syn::Visibility) -> Visibility {
+    match vis {
+        syn::Visibility::Public(_) => Visibility::Public,
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("super") => Visibility::Super,
+        syn::Visibility::Restricted(_) => Visibility::Crate,
+        syn::Visibility::Inherited => Visibility::Private,
+    }
+}
+
+fn from_item(item: &syn::ItemEnum, constants: &BTreeMap<String, i64>) -> SourceEnum {
+    let variants = item
+        .variants
+        .iter()
+        .map(|variant| {
+            let payload = match &variant.fields {
+                syn::Fields::Unit => VariantPayload::Unit,
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    VariantPayload::Single(type_ref(&fields.unnamed[0].ty))
+                }
+                // The definition has no tuples, so their types are kept together
+                syn::Fields::Unnamed(fields) => VariantPayload::Single(TypeRef::Path {
+                    path: format!("({})", tokens_to_string(&fields.unnamed)),
+                }),
+                syn::Fields::Named(fields) => VariantPayload::Struct(
+                    fields
+                        .named
+                        .iter()
+                        .map(|field| StructField {
+                            name: field.ident.as_ref().map(ToString::to_string).unwrap_or_default(),
+                            type_ref: type_ref(&field.ty),
+                            visibility: visibility(&field.vis),
+                            doc: doc_of(&field.attrs),
+                        })
+                        .collect(),
+                ),
+            };
+            SourceVariant {
+                variant: EnumVariant { name: variant.ident.to_string(), payload, doc: doc_of(&variant.attrs) },
+                discriminant: variant.discriminant.as_ref().map(|(_, expr)| tokens_to_string(expr)),
+                alias: None,
+            }
+        })
+        .collect();
+    SourceEnum {
+        name: item.ident.to_string(),
+        doc: doc_of(&item.attrs),
+        repr: repr_of(&item.attrs),
+        variants,
+        constants: constants.clone(),
+        reserved: Vec::new(),
+    }
+}
+
+/// Enums of `items` and of their inline modules, and the integer constants
+/// they can use
+fn collect(items: &[syn::Item], constants: &mut BTreeMap<String, i64>, enums: &mut Vec<syn::ItemEnum>) {
+    for item in items {
+        match item {
+            syn::Item::Const(constant) => {
+                if let Ok(value) = expr::evaluate(&tokens_to_string(&constant.expr), constants) {
+                    constants.insert(constant.ident.to_string(), value);
+                }
+            }
+            syn::Item::Enum(item) => enums.push(item.clone()),
+            syn::Item::Mod(module) => {
+                if let Some((_, items)) = &module.content {
+                    collect(items, constants, enums);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Every enum declared in `text`
+pub fn parse_enums(text: &str) -> std::result::Result<Vec<SourceEnum>, String> {
+    let file = syn::parse_file(text).map_err(|e| e.to_string())?;
+    let mut constants = BTreeMap::new();
+    let mut enums = Vec::new();
+    collect(&file.items, &mut constants, &mut enums);
+    Ok(enums.iter().map(|item| from_item(item, &constants)).collect())
+}
+
+/// Byte range of the braces around the body of enum `name`, skipping
+/// comments and string literals
+fn body_range(text: &str, name: &str) -> Option<Range<usize>> {
+    let pattern = regex::Regex::new(&format!(r"(?m)^[^/\n]*\benum\s+{}\b", regex::escape(name))).ok()?;
+    let declaration = pattern.find(text)?.end();
+    let start = declaration + text[declaration..].find('{')?;
+
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i += text[i..].find('\n').unwrap_or(text.len() - i);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += text[i..].find("*/").map_or(text.len() - i, |end| end + 1);
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start..i + 1);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// `text` with the body of enum `name` replaced by `variants`, the variant
+/// lines as generated for a top-level enum. Returns `None` if the enum isn't
+/// found.
+pub fn replace_body(text: &str, name: &str, variants: &str) -> Option<String> {
+    let range = body_range(text, name)?;
+    let line_start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let indent: String = text[line_start..].chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let mut body = format!("{{{}", newline);
+    for line in variants.lines() {
+        if !line.trim().is_empty() {
+            body.push_str(&indent);
+            body.push_str(line);
+        }
+        body.push_str(newline);
+    }
+    body.push_str(&indent);
+    body.push('}');
+    Some(format!("{}{}{}", &text[..range.start], body, &text[range.end..]))
+}
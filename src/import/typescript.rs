@@ -0,0 +1,141 @@
+//! Enum declarations in TypeScript
+//!
+//! Reads `enum` and `const enum` declarations and type aliases of string
+//! unions (`type Direction = "north" | "south";`). String values become
+//! serde aliases of the variants named after them.
+
+use super::c::{doc_before, entry_doc, split_entries, strip_comments, Comment};
+use super::{string_variants, SourceEnum, SourceVariant};
+use crate::expr;
+use crate::naming;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+use ui_types_common::{EnumVariant, VariantPayload};
+
+static QUALIFIED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\w+\.").unwrap());
+static DECLARATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:export\s+)?(?:declare\s+)?(?:const\s+)?enum\s+(\w+)\s*\{").unwrap());
+static STRING_UNION: LazyLock<Regex> = LazyLock::new(|| {
+    let string = r#"(?:"[^"\n]*"|'[^'\n]*')"#;
+    Regex::new(&format!(
+        r"(?m)\b(?:export\s+)?type\s+(\w+)\s*=\s*((?:\|\s*)?{string}(?:\s*\|\s*{string})*)[ \t]*(?:;|$)"
+    ))
+    .unwrap()
+});
+
+/// Contents of the string literal starting at `start`, read from `text`
+/// since literals are blanked in `code`, and the offset after it
+fn literal(code: &str, text: &str, start: usize) -> Option<(String, usize)> {
+    let quote = code[start..].chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let end = start + 1 + code[start + 1..].find(quote)?;
+    Some((text[start + 1..end].to_string(), end + 1))
+}
+
+/// Members of a numeric or string `enum`
+fn enum_variants(code: &str, text: &str, comments: &[Comment], body: Range<usize>) -> Vec<SourceVariant> {
+    let entries = split_entries(code, body.clone(), ',');
+    let mut known = BTreeMap::new();
+    let mut next = Some(0i64);
+    let mut variants = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let start = entry.start + (code[entry.clone()].len() - code[entry.clone()].trim_start().len());
+        if code[start..entry.end].trim().is_empty() {
+            continue;
+        }
+        let (name, rest) = match literal(code, text, start) {
+            Some((name, end)) => (name, end),
+            None => {
+                let end = code[start..entry.end].find(|c: char| !c.is_alphanumeric() && c != '_' && c != '$');
+                let end = end.map_or(entry.end, |end| start + end);
+                (code[start..end].to_string(), end)
+            }
+        };
+        let value = code[rest..entry.end].trim().strip_prefix('=').map(str::trim);
+
+        let mut alias = None;
+        let mut discriminant = None;
+        match value {
+            Some(value) if value.starts_with(['"', '\'']) => {
+                let value_start = rest + code[rest..entry.end].find(value).unwrap_or(0);
+                alias = literal(code, text, value_start).map(|(value, _)| value);
+                next = None;
+            }
+            Some(value) => {
+                let cleaned = QUALIFIED.replace_all(value, "").to_string();
+                let evaluated = expr::evaluate(&cleaned, &known).ok();
+                // Values that reference other members are written out
+                discriminant = Some(evaluated.map_or(cleaned, |value| value.to_string()));
+                next = evaluated;
+            }
+            None => {}
+        }
+        if let Some(value) = next {
+            known.insert(name.clone(), value);
+            next = Some(value.wrapping_add(1));
+        }
+
+        let variant_name = if naming::is_identifier(&name) { name } else { naming::pascal_case(&name) };
+        variants.push(SourceVariant {
+            alias: alias.filter(|alias| *alias != variant_name),
+            variant: EnumVariant {
+                name: variant_name,
+                payload: VariantPayload::Unit,
+                doc: entry_doc(code, comments, &entries, index, body.end),
+            },
+            discriminant,
+        });
+    }
+    variants
+}
+
+/// Every enum and string union declared in `text`
+pub fn parse_enums(text: &str) -> Vec<SourceEnum> {
+    let (code, comments) = strip_comments(text);
+
+    let mut enums = Vec::new();
+    for captures in DECLARATION.captures_iter(&code) {
+        let whole = captures.get(0).unwrap();
+        let body_start = whole.end();
+        let Some(body_end) = code[body_start..].find('}').map(|end| body_start + end) else {
+            continue;
+        };
+        enums.push(SourceEnum {
+            name: captures[1].to_string(),
+            doc: doc_before(&code, &comments, whole.start()),
+            repr: None,
+            variants: enum_variants(&code, text, &comments, body_start..body_end),
+            constants: BTreeMap::new(),
+            reserved: Vec::new(),
+        });
+    }
+
+    for captures in STRING_UNION.captures_iter(&code) {
+        let whole = captures.get(0).unwrap();
+        // `"a" | "b" | number` isn't a string union
+        if code[whole.end()..].trim_start().starts_with('|') {
+            continue;
+        }
+        let members = captures.get(2).unwrap().range();
+        let entries = split_entries(&code, members.clone(), '|');
+        let values = entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let start = entry.start + (code[entry.clone()].len() - code[entry.clone()].trim_start().len());
+                let (value, _) = literal(&code, text, start)?;
+                Some((value, entry_doc(&code, &comments, &entries, index, members.end)))
+            })
+            .collect();
+        enums.push(SourceEnum {
+            name: captures[1].to_string(),
+            doc: doc_before(&code, &comments, whole.start()),
+            repr: None,
+            variants: string_variants(values),
+            constants: BTreeMap::new(),
+            reserved: Vec::new(),
+        });
+    }
+    enums
+}
@@ -1,36 +1,108 @@
 //! # Enum Editor Plugin
 //!
-//! This plugin provides a professional multi-panel editor for creating enum definitions.
-//! It supports .enum files (folder-based) that contain enum metadata and variants.
+//! Editor plugin for enum definitions: `.enum` folders and single-file
+//! `.enum.json`, `.enum.yaml` and `.enum.toml` assets. What the editor does
+//! for users, and the `pulsar-enum.toml` project settings, are described in
+//! the README.
 //!
-//! ## File Types
+//! [`EnumEditorPlugin`] registers the file types and creates an
+//! [`EnumEditor`] per opened asset. The editor owns the definition and its
+//! panels (`panels`, `variant_editor`) and does file work on
+//! background threads through `storage`, with parsed definitions shared via
+//! [`DefinitionCache`]. The rest is UI-independent:
 //!
-//! - **Enum Definition** (.enum folder)
-//!   - Contains `enum.json` with the enum definition
-//!   - Appears as a single file in the file drawer
-//!
-//! ## Editors
-//!
-//! - **Enum Editor**: Multi-panel editor with properties, variants, and code preview
+//! - `metadata` and `validation`: the editor's metadata and the checks run
+//!   on save
+//! - `codegen`: one [`CodegenTarget`] per output language or schema, plus
+//!   Handlebars templates
+//! - `import`: readers for Rust, C, protobuf, TypeScript and JSON enums
+//! - `workspace_scan`: project-wide operations such as [`validate_all`]
+//! - `project` and `hooks`: `pulsar-enum.toml` and the commands it runs
+//! - `fixtures`: synthetic enums ([`synthetic_enum`]) for benches and tests
 
 use plugin_editor_api::*;
 use serde_json::json;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::Mutex;
-use std::collections::HashMap;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::collections::{BTreeSet, HashMap};
 use gpui::*;
 use ui::dock::PanelView;
 
 // Enum Editor modules
+mod bits;
+mod cache;
+mod changelog;
+mod codegen;
+mod columns;
+mod config_check;
+mod drift;
 mod editor;
+mod error;
+mod expr;
+mod export_preview;
+mod find_replace;
+mod fixtures;
+mod git;
+mod hooks;
+mod import;
+mod markdown;
+mod metadata;
+mod naming;
+mod output;
+mod panels;
+mod project;
+mod references;
+mod renumber;
+mod samples;
+mod spellcheck;
+mod storage;
+mod type_check;
+mod type_suggestions;
+mod validation;
 mod variant_editor;
-mod workspace_panels;
+mod workspace_scan;
 
 // Re-export main types
-pub use editor::EnumEditor;
-pub use variant_editor::{VariantEditorView, VariantEditorEvent};
-pub use workspace_panels::{PropertiesPanel, VariantsPanel, CodePreviewPanel};
+pub use cache::DefinitionCache;
+pub use changelog::Change;
+pub use codegen::{CodegenTarget, EnumModel, FileChange, TargetReport, TemplateTarget};
+pub use columns::{Column, ColumnLayout, VariantColumn};
+pub use drift::Drift;
+pub use editor::{EnumEditor, EnumEditorEvent, MemoryUsage};
+pub use error::EnumEditorError;
+pub use expr::parse_value;
+pub use fixtures::{synthetic_enum, FIXTURE_SIZES};
+pub use hooks::{HookOutput, HookStage};
+pub use metadata::{
+    AssociatedConst, BuildProfile, CodegenStyle, EnumMeta, EnumMode, MethodSnippet, Note, OrderBy, Reserved, SerdeRepr,
+    Stage, TargetConfig, VariantMeta,
+};
+pub use naming::{Case, DisplayStyle};
+pub use output::{OutputEntry, OutputLog, OutputSource, Severity};
+pub use project::{
+    Density, EditorConfig, EnumDefaults, ExportConfig, ExportLayout, HooksConfig, ImportConfig, PanelDensity, ProjectConfig,
+    SpellcheckConfig,
+};
+pub use spellcheck::{Misspelling, SpellChecker};
+pub use storage::{EnumFileFormat, SerializationFormat};
+pub use type_suggestions::{TypeSource, TypeSuggestion, TypeSuggestions};
+pub use validation::Issue;
+pub use variant_editor::{ValueFormat, VariantEditorView, VariantEditorEvent};
+pub use panels::{
+    PropertiesPanel, PropertiesPanelEvent, VariantsPanel, CodePreviewPanel, CodePreviewPanelEvent,
+    OutputPanel, FindReplacePanel, FindReplacePanelEvent, GenerateReportPanel, DocsPanel, ReviewPanel, ReviewPanelEvent, SamplesPanel,
+    ChangelogPanel, ChangelogPanelEvent, VariantsPanelEvent, SettingsPanel, SettingsPanelEvent,
+    ValidationReportPanel, ValidationReportPanelEvent, TypeRenamePanel, TypeRenamePanelEvent,
+    RenumberPanel, RenumberPanelEvent, MethodsPanel, MethodsPanelEvent, DriftPanel, DriftPanelEvent,
+    ConfigCheckPanel, ConfigCheckPanelEvent,
+};
+pub use config_check::{check_file as check_config_file, config_files, ConfigFileCheck, ConfigFinding};
+pub use renumber::{Analysis, Strategy, ValueChange};
+pub use workspace_scan::{
+    enum_files, plan_type_rename, rename_type, report_to_text, validate_all, FileValidation, TypeRenamePlan,
+};
 
 /// Storage for editor instances owned by the plugin
 struct EditorStorage {
@@ -40,15 +112,61 @@ struct EditorStorage {
 
 /// The Enum Editor Plugin
 pub struct EnumEditorPlugin {
-    editors: Arc<Mutex<HashMap<usize, EditorStorage>>>,
-    next_editor_id: Arc<Mutex<usize>>,
+    /// Open editors by id. Ids come from a counter rather than the map, so
+    /// the lock is only held for the insert or removal itself.
+    editors: Arc<parking_lot::RwLock<HashMap<usize, EditorStorage>>>,
+    next_editor_id: Arc<AtomicUsize>,
+    /// Categories each open editor's enum declares, by editor id, as last
+    /// opened or saved. Their union is reported through `file_types()`.
+    known_categories: Arc<Mutex<HashMap<usize, Vec<String>>>>,
+    /// Contents of a newly created enum, from the defaults of the project of
+    /// the most recently opened enum. Looked up when an enum is opened rather
+    /// than on every `file_types()` call.
+    default_content: Arc<Mutex<Option<serde_json::Value>>>,
+    /// Parsed definitions shared by every editor the plugin opens
+    definitions: cache::DefinitionCache,
+    /// Set once the panels' key bindings are registered with the app
+    keys_bound: Arc<AtomicBool>,
 }
 
 impl Default for EnumEditorPlugin {
     fn default() -> Self {
         Self {
-            editors: Arc::new(Mutex::new(HashMap::new())),
-            next_editor_id: Arc::new(Mutex::new(0)),
+            editors: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            next_editor_id: Arc::new(AtomicUsize::new(0)),
+            known_categories: Arc::new(Mutex::new(HashMap::new())),
+            default_content: Arc::new(Mutex::new(None)),
+            definitions: cache::DefinitionCache::default(),
+            keys_bound: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl EnumEditorPlugin {
+    /// Contents of a newly created enum: the defaults of the last opened
+    /// project (or of the working directory's), else an empty `NewEnum`
+    fn default_content(&self) -> serde_json::Value {
+        self.default_content
+            .lock()
+            .get_or_insert_with(|| {
+                let project = std::env::current_dir()
+                    .ok()
+                    .and_then(|dir| project::Project::find(&dir).ok().flatten());
+                Self::project_content(project)
+            })
+            .clone()
+    }
+
+    /// Contents of a newly created enum in `project`
+    fn project_content(project: Option<project::Project>) -> serde_json::Value {
+        let content = project.map(|project| project.config.defaults.new_definition(&project.root));
+        match content {
+            Some(Ok(content)) => content,
+            Some(Err(e)) => {
+                log::warn!("Ignoring enum defaults: {}", e);
+                json!({ "name": "NewEnum", "variants": [] })
+            }
+            None => json!({ "name": "NewEnum", "variants": [] }),
         }
     }
 }
@@ -65,7 +183,12 @@ impl EditorPlugin for EnumEditorPlugin {
     }
 
     fn file_types(&self) -> Vec<FileTypeDefinition> {
-        vec![
+        let declared: BTreeSet<String> = self.known_categories.lock().values().flatten().cloned().collect();
+        let mut categories = vec!["Types".to_string()];
+        categories.extend(declared);
+        let default_content = self.default_content();
+
+        [
             FileTypeDefinition {
                 id: FileTypeId::new("enum"),
                 extension: "enum".to_string(),
@@ -73,23 +196,36 @@ impl EditorPlugin for EnumEditorPlugin {
                 icon: ui::IconName::List,
                 color: gpui::rgb(0x673AB7).into(),
                 structure: FileStructure::FolderBased {
-                    marker_file: "enum.json".to_string(),
+                    marker_file: storage::MARKER_FILE.to_string(),
                     template_structure: vec![],
                 },
-                default_content: json!({
-                    "name": "NewEnum",
-                    "variants": []
-                }),
-                categories: vec!["Types".to_string()],
-            }
+                default_content: default_content.clone(),
+                categories: categories.clone(),
+            },
         ]
+        .into_iter()
+        .chain(SerializationFormat::ALL.iter().map(|format| FileTypeDefinition {
+            id: FileTypeId::new(&format!("enum-{}", format.extension())),
+            extension: format!("enum.{}", format.extension()),
+            display_name: format!("Enum Definition ({})", format.label()),
+            icon: ui::IconName::List,
+            color: gpui::rgb(0x673AB7).into(),
+            structure: FileStructure::Standalone,
+            default_content: default_content.clone(),
+            categories: categories.clone(),
+        }))
+        .collect()
     }
 
     fn editors(&self) -> Vec<EditorMetadata> {
         vec![EditorMetadata {
             id: EditorId::new("enum-editor"),
             display_name: "Enum Editor".into(),
-            supported_file_types: vec![FileTypeId::new("enum")],
+            supported_file_types: std::iter::once(FileTypeId::new("enum"))
+                .chain(SerializationFormat::ALL.iter().map(|format| {
+                    FileTypeId::new(&format!("enum-{}", format.extension()))
+                }))
+                .collect(),
         }]
     }
 
@@ -103,27 +239,37 @@ impl EditorPlugin for EnumEditorPlugin {
     ) -> Result<(Arc<dyn PanelView>, Box<dyn EditorInstance>), PluginError> {
         logger.info("ENUM EDITOR LOADED!!");
         if editor_id.as_str() == "enum-editor" {
-            let actual_path = if file_path.is_dir() {
-                file_path.join("enum.json")
-            } else {
-                file_path.clone()
-            };
+            if !self.keys_bound.swap(true, Ordering::Relaxed) {
+                panels::bind_keys(cx);
+            }
+            let actual_path = storage::resolve_definition_path(&file_path);
+            if let Ok(Some(project)) = project::Project::find(&actual_path) {
+                *self.default_content.lock() = Some(Self::project_content(Some(project)));
+            }
+
+            let panel = cx.new(|cx| EnumEditor::new_with_file(actual_path.clone(), self.definitions.clone(), window, cx));
+            let id = self.next_editor_id.fetch_add(1, Ordering::Relaxed);
+
+            // Keep the file drawer's category list in sync with what open enums declare
+            self.known_categories.lock().insert(id, panel.read(cx).categories());
+            let known_categories = self.known_categories.clone();
+            cx.subscribe(&panel, move |panel, event: &EnumEditorEvent, cx| {
+                if let EnumEditorEvent::Saved = event {
+                    known_categories.lock().insert(id, panel.read(cx).categories());
+                }
+            }).detach();
+            let known_categories = self.known_categories.clone();
+            cx.observe_release(&panel, move |_, _| {
+                known_categories.lock().remove(&id);
+            }).detach();
 
-            let panel = cx.new(|cx| EnumEditor::new_with_file(actual_path.clone(), window, cx));
             let panel_arc: Arc<dyn ui::dock::PanelView> = Arc::new(panel.clone());
             let wrapper = Box::new(EnumEditorWrapper {
                 panel: panel.into(),
                 file_path: file_path.clone(),
             });
 
-            let id = {
-                let mut next_id = self.next_editor_id.lock().unwrap();
-                let id = *next_id;
-                *next_id += 1;
-                id
-            };
-
-            self.editors.lock().unwrap().insert(id, EditorStorage {
+            self.editors.write().insert(id, EditorStorage {
                 panel: panel_arc.clone(),
                 wrapper: wrapper.clone(),
             });
@@ -140,9 +286,8 @@ impl EditorPlugin for EnumEditorPlugin {
     }
 
     fn on_unload(&mut self) {
-        let mut editors = self.editors.lock().unwrap();
-        let count = editors.len();
-        editors.clear();
+        let count = std::mem::take(&mut *self.editors.write()).len();
+        self.known_categories.lock().clear();
         log::info!("Enum Editor Plugin unloaded (cleaned up {} editors)", count);
     }
 }
@@ -153,18 +298,31 @@ pub struct EnumEditorWrapper {
     file_path: std::path::PathBuf,
 }
 
+impl EnumEditorWrapper {
+    /// Follow the asset to where the editor last moved it, since converting
+    /// its layout or serialization renames the file
+    fn follow_moves(&mut self, cx: &App) {
+        if let Some(definition_path) = self.panel.read(cx).file_path() {
+            self.file_path = storage::asset_path(&definition_path);
+        }
+    }
+}
+
 impl plugin_editor_api::EditorInstance for EnumEditorWrapper {
     fn file_path(&self) -> &std::path::PathBuf {
         &self.file_path
     }
 
     fn save(&mut self, window: &mut Window, cx: &mut App) -> Result<(), PluginError> {
-        self.panel.update(cx, |panel, cx| {
+        let result = self.panel.update(cx, |panel, cx| {
             panel.plugin_save(window, cx)
-        })
+        });
+        self.follow_moves(cx);
+        result
     }
 
     fn reload(&mut self, window: &mut Window, cx: &mut App) -> Result<(), PluginError> {
+        self.follow_moves(cx);
         self.panel.update(cx, |panel, cx| {
             panel.plugin_reload(window, cx)
         })
@@ -0,0 +1,253 @@
+//! Minimal markdown support for documentation fields
+//!
+//! Covers what enum docs need: paragraphs, headings, bullet lists, fenced
+//! code blocks, and inline code, bold, italics and links. Codegen targets
+//! whose doc syntax isn't markdown get HTML or plain text from here.
+
+use gpui::*;
+use ui::{v_flex, h_flex, ActiveTheme, StyledExt};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Strong(String),
+    Emphasis(String),
+    Link { text: String, url: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Block {
+    Heading(u8, Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    ListItem(Vec<Inline>),
+    Code(String),
+}
+
+/// Split a line into inline spans
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    let closed = |rest: &str, open: &str, close: &str| -> Option<(String, usize)> {
+        let body = rest.strip_prefix(open)?;
+        let end = body.find(close)?;
+        (end > 0).then(|| (body[..end].to_string(), open.len() + end + close.len()))
+    };
+
+    while let Some(c) = rest.chars().next() {
+        let span = match c {
+            '`' => closed(rest, "`", "`").map(|(body, len)| (Inline::Code(body), len)),
+            '*' | '_' if rest.starts_with("**") || rest.starts_with("__") => {
+                closed(rest, &rest[..2], &rest[..2]).map(|(body, len)| (Inline::Strong(body), len))
+            }
+            '*' | '_' => closed(rest, &rest[..1], &rest[..1]).map(|(body, len)| (Inline::Emphasis(body), len)),
+            '[' => rest.find("](").and_then(|mid| {
+                let end = rest[mid..].find(')')? + mid;
+                Some((
+                    Inline::Link { text: rest[1..mid].to_string(), url: rest[mid + 2..end].to_string() },
+                    end + 1,
+                ))
+            }),
+            _ => None,
+        };
+
+        match span {
+            Some((span, len)) => {
+                if !plain.is_empty() {
+                    spans.push(Inline::Text(std::mem::take(&mut plain)));
+                }
+                spans.push(span);
+                rest = &rest[len..];
+            }
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Inline::Text(plain));
+    }
+    spans
+}
+
+/// Parse markdown into blocks. Consecutive lines form one paragraph.
+pub fn parse(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            match code.take() {
+                Some(lines) => blocks.push(Block::Code(lines.join("\n"))),
+                None => {
+                    flush(&mut paragraph, &mut blocks);
+                    code = Some(Vec::new());
+                }
+            }
+            continue;
+        }
+        if let Some(lines) = &mut code {
+            lines.push(line);
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(level as u8, parse_inline(trimmed[level..].trim())));
+        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::ListItem(parse_inline(item)));
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    if let Some(lines) = code {
+        blocks.push(Block::Code(lines.join("\n")));
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn inline_html(spans: &[Inline]) -> String {
+    spans
+        .iter()
+        .map(|span| match span {
+            Inline::Text(text) => escape_html(text),
+            Inline::Code(text) => format!("<code>{}</code>", escape_html(text)),
+            Inline::Strong(text) => format!("<strong>{}</strong>", escape_html(text)),
+            Inline::Emphasis(text) => format!("<em>{}</em>", escape_html(text)),
+            Inline::Link { text, url } => format!("<a href=\"{}\">{}</a>", escape_html(url), escape_html(text)),
+        })
+        .collect()
+}
+
+/// Convert markdown to HTML, e.g. for Javadoc and documentation pages
+pub fn to_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut in_list = false;
+
+    for block in parse(text) {
+        let is_item = matches!(block, Block::ListItem(_));
+        if is_item && !in_list {
+            out.push_str("<ul>");
+        } else if !is_item && in_list {
+            out.push_str("</ul>");
+        }
+        in_list = is_item;
+
+        match block {
+            Block::Heading(level, spans) => out.push_str(&format!("<h{0}>{1}</h{0}>", level, inline_html(&spans))),
+            Block::Paragraph(spans) => out.push_str(&format!("<p>{}</p>", inline_html(&spans))),
+            Block::ListItem(spans) => out.push_str(&format!("<li>{}</li>", inline_html(&spans))),
+            Block::Code(code) => out.push_str(&format!("<pre><code>{}</code></pre>", escape_html(&code))),
+        }
+    }
+    if in_list {
+        out.push_str("</ul>");
+    }
+    out
+}
+
+fn inline_plain(spans: &[Inline]) -> String {
+    spans
+        .iter()
+        .map(|span| match span {
+            Inline::Text(text) | Inline::Code(text) | Inline::Strong(text) | Inline::Emphasis(text) => text.clone(),
+            Inline::Link { text, url } => format!("{} ({})", text, url),
+        })
+        .collect()
+}
+
+/// Strip markdown formatting, for targets whose comments are plain text.
+/// Blocks stay on separate lines.
+pub fn to_plain_text(text: &str) -> String {
+    parse(text)
+        .into_iter()
+        .map(|block| match block {
+            Block::Heading(_, spans) | Block::Paragraph(spans) => inline_plain(&spans),
+            Block::ListItem(spans) => format!("- {}", inline_plain(&spans)),
+            Block::Code(code) => code,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_inline(spans: &[Inline], cx: &App) -> Div {
+    // One element per word so the row wraps like running text
+    h_flex()
+        .flex_wrap()
+        .gap_x(px(4.0))
+        .children(spans.iter().flat_map(|span| {
+            let (text, kind) = match span {
+                Inline::Text(text) => (text.as_str(), 0),
+                Inline::Code(text) => (text.as_str(), 1),
+                Inline::Strong(text) => (text.as_str(), 2),
+                Inline::Emphasis(text) => (text.as_str(), 3),
+                Inline::Link { text, .. } => (text.as_str(), 4),
+            };
+            text.split_whitespace()
+                .map(|word| {
+                    let element = div().child(word.to_string());
+                    match kind {
+                        1 => element.px_1().rounded(px(3.0)).bg(cx.theme().secondary),
+                        2 => element.font_bold(),
+                        3 => element.italic(),
+                        4 => element.text_color(cx.theme().primary),
+                        _ => element,
+                    }
+                })
+                .collect::<Vec<_>>()
+        }))
+}
+
+/// Render markdown as gpui elements
+pub fn render(text: &str, cx: &App) -> Div {
+    v_flex()
+        .gap_2()
+        .text_sm()
+        .text_color(cx.theme().foreground)
+        .children(parse(text).into_iter().map(|block| match block {
+            Block::Heading(level, spans) => {
+                let heading = render_inline(&spans, cx).font_semibold();
+                match level {
+                    1 => heading.text_lg(),
+                    2 => heading.text_base(),
+                    _ => heading,
+                }
+            }
+            Block::Paragraph(spans) => render_inline(&spans, cx),
+            Block::ListItem(spans) => h_flex()
+                .gap_2()
+                .items_start()
+                .child("•")
+                .child(render_inline(&spans, cx).flex_1()),
+            Block::Code(code) => v_flex()
+                .p_2()
+                .rounded(px(4.0))
+                .bg(cx.theme().secondary)
+                .text_xs()
+                .children(code.lines().map(|line| div().child(line.to_string())).collect::<Vec<_>>()),
+        }))
+}
@@ -0,0 +1,954 @@
+//! Plugin-owned metadata stored in `EnumAsset::meta`
+//!
+//! `ui_types_common` owns the core enum model, so everything the editor adds on
+//! top of it lives in the free-form `meta` object of `enum.json`. Unknown keys
+//! are preserved so hand-written or newer metadata survives a round-trip.
+
+use crate::codegen::CodegenTarget;
+use crate::expr;
+use crate::naming::{self, Case, DisplayStyle};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use ui_types_common::{EnumAsset, EnumVariant};
+use uuid::Uuid;
+
+/// Deleted variants kept for recovery; the oldest are dropped beyond this
+pub const MAX_TRASH: usize = 20;
+
+/// Whether variants are exclusive values or combinable bit flags
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnumMode {
+    #[default]
+    Standard,
+    Flags,
+}
+
+impl EnumMode {
+    pub const ALL: [EnumMode; 2] = [EnumMode::Standard, EnumMode::Flags];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnumMode::Standard => "Standard",
+            EnumMode::Flags => "Bitflags",
+        }
+    }
+
+    fn is_standard(&self) -> bool {
+        *self == EnumMode::Standard
+    }
+}
+
+/// Release stage of a variant
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    #[default]
+    Stable,
+    Experimental,
+    /// Kept in the definition but no longer generated
+    Removed,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Stable, Stage::Experimental, Stage::Removed];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stage::Stable => "Stable",
+            Stage::Experimental => "Experimental",
+            Stage::Removed => "Removed",
+        }
+    }
+
+    fn is_stable(&self) -> bool {
+        *self == Stage::Stable
+    }
+}
+
+/// Which stages end up in generated code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildProfile {
+    /// Stable and experimental variants
+    #[default]
+    Development,
+    /// Stable variants only
+    Release,
+}
+
+impl BuildProfile {
+    pub const ALL: [BuildProfile; 2] = [BuildProfile::Development, BuildProfile::Release];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BuildProfile::Development => "Development",
+            BuildProfile::Release => "Release",
+        }
+    }
+
+    pub fn includes(&self, stage: Stage) -> bool {
+        match stage {
+            Stage::Stable => true,
+            Stage::Experimental => *self == BuildProfile::Development,
+            Stage::Removed => false,
+        }
+    }
+
+    fn is_development(&self) -> bool {
+        *self == BuildProfile::Development
+    }
+}
+
+/// What `PartialOrd` and `Ord` compare variants by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderBy {
+    /// Declaration order, as the derives do
+    #[default]
+    Declaration,
+    /// Discriminant value; implemented by hand when the values don't ascend
+    /// in declaration order
+    Value,
+}
+
+impl OrderBy {
+    pub const ALL: [OrderBy; 2] = [OrderBy::Declaration, OrderBy::Value];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OrderBy::Declaration => "By declaration",
+            OrderBy::Value => "By value",
+        }
+    }
+
+    fn is_declaration(&self) -> bool {
+        *self == OrderBy::Declaration
+    }
+}
+
+/// How serde writes variants, i.e. the container's tagging attributes
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SerdeRepr {
+    /// `{"Variant": payload}`, serde's default
+    #[default]
+    External,
+    /// `{"type": "Variant", ..fields}`
+    Internal { tag: String },
+    /// `{"t": "Variant", "c": payload}`
+    Adjacent { tag: String, content: String },
+    /// The payload alone
+    Untagged,
+}
+
+impl SerdeRepr {
+    /// Each representation, with the usual tag names
+    pub fn all() -> [SerdeRepr; 4] {
+        [
+            SerdeRepr::External,
+            SerdeRepr::Internal { tag: "type".to_string() },
+            SerdeRepr::Adjacent { tag: "t".to_string(), content: "c".to_string() },
+            SerdeRepr::Untagged,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SerdeRepr::External => "Externally tagged",
+            SerdeRepr::Internal { .. } => "Internally tagged",
+            SerdeRepr::Adjacent { .. } => "Adjacently tagged",
+            SerdeRepr::Untagged => "Untagged",
+        }
+    }
+
+    /// True if `other` is the same representation, whatever its tag names
+    pub fn same_kind(&self, other: &SerdeRepr) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Arguments of the container's `#[serde(..)]` attribute, if it needs one
+    pub fn attribute(&self) -> Option<String> {
+        match self {
+            SerdeRepr::External => None,
+            SerdeRepr::Internal { tag } => Some(format!("tag = \"{}\"", tag)),
+            SerdeRepr::Adjacent { tag, content } => Some(format!("tag = \"{}\", content = \"{}\"", tag, content)),
+            SerdeRepr::Untagged => Some("untagged".to_string()),
+        }
+    }
+
+    fn is_external(&self) -> bool {
+        *self == SerdeRepr::External
+    }
+}
+
+/// Settings of one codegen target
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TargetConfig {
+    /// Included in "Generate All"
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub enabled: bool,
+
+    /// Output file, relative to the enum's folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+
+    /// Target-specific options
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub options: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TargetConfig {
+    pub fn is_default(&self) -> bool {
+        *self == TargetConfig::default()
+    }
+}
+
+/// Layout of generated source files
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CodegenStyle {
+    /// Spaces per indentation level
+    pub indent_width: usize,
+    /// Indent with tabs instead of spaces
+    pub tabs: bool,
+    /// End lines with `\r\n`
+    pub crlf: bool,
+}
+
+impl Default for CodegenStyle {
+    fn default() -> Self {
+        Self { indent_width: 4, tabs: false, crlf: false }
+    }
+}
+
+impl CodegenStyle {
+    pub fn is_default(&self) -> bool {
+        *self == CodegenStyle::default()
+    }
+}
+
+/// Enum-level metadata managed by the editor
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnumMeta {
+    /// Tags used by the file drawer to group enums
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+
+    /// Per-variant metadata, keyed by variant name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variants: BTreeMap<String, VariantMeta>,
+
+    /// Recently deleted variants, most recent last
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trash: Vec<TrashedVariant>,
+
+    /// Keep a renamed variant's old name as a serde alias
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub alias_on_rename: bool,
+
+    /// How the variant values are meant to be used
+    #[serde(skip_serializing_if = "EnumMode::is_standard")]
+    pub mode: EnumMode,
+
+    /// Rerun the enabled codegen targets whenever the enum is saved
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub regenerate_on_save: bool,
+
+    /// Consumed by other code or data; breaking edits need confirmation
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub published: bool,
+
+    /// Names and values retired from the published enum
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reserved: Vec<Reserved>,
+
+    /// Flag undocumented variants of public enums in validation
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub require_docs: bool,
+
+    /// Convention variant names are checked against
+    #[serde(skip_serializing_if = "Case::is_pascal")]
+    pub naming: Case,
+
+    /// Integer representation of the Rust declaration, e.g. `u8`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repr: Option<String>,
+
+    /// Named values usable in discriminant expressions, e.g. `BASE = 100`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub constants: BTreeMap<String, i64>,
+
+    /// Constants emitted in an `impl` block alongside the Rust declaration
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub associated_consts: Vec<AssociatedConst>,
+
+    /// Helper methods emitted in the same `impl` block
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<MethodSnippet>,
+
+    /// Derives added to the Rust declaration besides `Debug` and `Clone`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub derives: Vec<String>,
+
+    /// What comparisons order variants by, if `PartialOrd` is derived
+    #[serde(skip_serializing_if = "OrderBy::is_declaration")]
+    pub order_by: OrderBy,
+
+    /// Tagging of the serialized enum
+    #[serde(skip_serializing_if = "SerdeRepr::is_external")]
+    pub serde_repr: SerdeRepr,
+
+    /// Give every variant a stable ID and generate `stable_id()`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub stable_ids: bool,
+
+    /// Variant marked `#[default]` for a derived `Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_variant: Option<String>,
+
+    /// Rule generating display names for variants without one; without a
+    /// rule they are shown by their name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_style: Option<DisplayStyle>,
+
+    /// Hand-written Rust file declaring the enum, checked for drift. Relative
+    /// paths are relative to the folder holding the definition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_source: Option<String>,
+
+    /// Indentation and line endings of generated source files
+    #[serde(skip_serializing_if = "CodegenStyle::is_default")]
+    pub style: CodegenStyle,
+
+    /// Build profile used for exports
+    #[serde(skip_serializing_if = "BuildProfile::is_development")]
+    pub profile: BuildProfile,
+
+    /// Codegen settings keyed by target id
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub targets: BTreeMap<String, TargetConfig>,
+
+    /// Keys this version of the plugin does not know about
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl EnumMeta {
+    /// Read the metadata from an asset, falling back to defaults if it is
+    /// malformed. Use [`EnumMeta::read`] where the problem must be reported.
+    pub fn from_asset(asset: &EnumAsset) -> Self {
+        Self::read(asset).unwrap_or_default()
+    }
+
+    /// Read the metadata from an asset. A missing `meta` is the default.
+    pub fn read(asset: &EnumAsset) -> Result<Self, String> {
+        if asset.meta.is_null() {
+            return Ok(Self::default());
+        }
+        serde_json::from_value(asset.meta.clone()).map_err(|e| e.to_string())
+    }
+
+    /// Write the metadata back into the asset's `meta` object. Metadata that
+    /// can't be read is left as it is, so defaults never replace it.
+    pub fn write_to(&self, asset: &mut EnumAsset) {
+        if Self::read(asset).is_err() {
+            return;
+        }
+        if let Ok(value) = serde_json::to_value(self) {
+            asset.meta = value;
+        }
+    }
+
+    /// Add a category, ignoring blanks and duplicates. Returns true if it was added.
+    pub fn add_category(&mut self, category: &str) -> bool {
+        let category = category.trim();
+        if category.is_empty() || self.categories.iter().any(|c| c == category) {
+            return false;
+        }
+        self.categories.push(category.to_string());
+        true
+    }
+
+    pub fn remove_category(&mut self, category: &str) {
+        self.categories.retain(|c| c != category);
+    }
+
+    /// Metadata for a variant, or defaults if none has been recorded
+    pub fn variant(&self, name: &str) -> VariantMeta {
+        self.variants.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Display name of a variant: its own, generated by the display style, or
+    /// its name
+    pub fn display_name(&self, name: &str) -> String {
+        match (self.variant(name).display_name, self.display_style) {
+            (Some(display_name), _) => display_name,
+            (None, Some(style)) => style.apply(name),
+            (None, None) => name.to_string(),
+        }
+    }
+
+    /// Record metadata for a variant, dropping the entry if it is all defaults
+    pub fn set_variant(&mut self, name: &str, meta: VariantMeta) {
+        if meta == VariantMeta::default() {
+            self.variants.remove(name);
+        } else {
+            self.variants.insert(name.to_string(), meta);
+        }
+    }
+
+    /// Settings of a codegen target; defaults if never configured
+    pub fn target(&self, id: &str) -> TargetConfig {
+        self.targets.get(id).cloned().unwrap_or_default()
+    }
+
+    /// Record a target's settings, dropping the entry if it is all defaults
+    pub fn set_target(&mut self, id: &str, config: TargetConfig) {
+        if config.is_default() {
+            self.targets.remove(id);
+        } else {
+            self.targets.insert(id.to_string(), config);
+        }
+    }
+
+    /// Keep a retired name and/or value from being reused
+    pub fn reserve(&mut self, name: Option<String>, value: Option<i64>) {
+        let entry = Reserved { name, value, end: None };
+        if !self.reserved.contains(&entry) {
+            self.reserved.push(entry);
+        }
+    }
+
+    /// Move a variant's metadata to its new name. Existing metadata under the
+    /// new name is kept so a transient collision while typing can't clobber it.
+    pub fn rename_variant(&mut self, old_name: &str, new_name: &str) {
+        if old_name == new_name || self.variants.contains_key(new_name) {
+            return;
+        }
+        if let Some(meta) = self.variants.remove(old_name) {
+            self.variants.insert(new_name.to_string(), meta);
+        }
+        if self.default_variant.as_deref() == Some(old_name) {
+            self.default_variant = Some(new_name.to_string());
+        }
+    }
+
+    /// Record `alias` as an alternative name the variant deserializes from
+    pub fn add_alias(&mut self, name: &str, alias: &str) {
+        let mut meta = self.variant(name);
+        if alias != name && !meta.aliases.iter().any(|a| a == alias) {
+            meta.aliases.push(alias.to_string());
+            self.set_variant(name, meta);
+        }
+    }
+
+    /// Give each variant of `asset` without a stable ID one derived from its
+    /// current name, never reusing an ID still held by a variant or the
+    /// trash. Returns how many were assigned.
+    pub fn assign_stable_ids(&mut self, asset: &EnumAsset) -> usize {
+        let mut taken: Vec<String> = self
+            .variants
+            .values()
+            .chain(self.trash.iter().map(|trashed| &trashed.meta))
+            .filter_map(|meta| meta.stable_id.clone())
+            .collect();
+        let mut assigned = 0;
+        for variant in &asset.variants {
+            let mut meta = self.variant(&variant.name);
+            if meta.stable_id.is_some() {
+                continue;
+            }
+            let base = naming::snake_case(&variant.name);
+            let id = (1..)
+                .map(|n| if n == 1 { base.clone() } else { format!("{}_{}", base, n) })
+                .find(|id| !taken.contains(id))
+                .unwrap_or(base);
+            taken.push(id.clone());
+            meta.stable_id = Some(id);
+            self.set_variant(&variant.name, meta);
+            assigned += 1;
+        }
+        assigned
+    }
+
+    /// Give each variant of `asset` without a UUID, or sharing one with an
+    /// earlier variant, a new one. Returns how many were assigned.
+    pub fn assign_uuids(&mut self, asset: &EnumAsset) -> usize {
+        let mut seen = Vec::new();
+        let mut assigned = 0;
+        for variant in &asset.variants {
+            let mut meta = self.variant(&variant.name);
+            match meta.uuid {
+                Some(uuid) if !seen.contains(&uuid) => seen.push(uuid),
+                _ => {
+                    let uuid = Uuid::new_v4();
+                    seen.push(uuid);
+                    meta.uuid = Some(uuid);
+                    self.set_variant(&variant.name, meta);
+                    assigned += 1;
+                }
+            }
+        }
+        assigned
+    }
+
+    pub fn remove_variant(&mut self, name: &str) {
+        self.variants.remove(name);
+    }
+
+    /// Move a deleted variant and its metadata to the trash
+    pub fn trash_variant(&mut self, variant: EnumVariant, index: usize) {
+        let meta = self.variants.remove(&variant.name).unwrap_or_default();
+        self.trash.push(TrashedVariant { variant, meta, index });
+        if self.trash.len() > MAX_TRASH {
+            self.trash.remove(0);
+        }
+    }
+
+    /// Take a variant out of the trash, restoring its metadata
+    pub fn restore_variant(&mut self, trash_index: usize) -> Option<TrashedVariant> {
+        if trash_index >= self.trash.len() {
+            return None;
+        }
+        let trashed = self.trash.remove(trash_index);
+        self.set_variant(&trashed.variant.name, trashed.meta.clone());
+        Some(trashed)
+    }
+}
+
+/// Integer types accepted as a `repr`, with the values they can hold
+pub fn repr_range(repr: &str) -> Option<(i64, i64)> {
+    Some(match repr {
+        "u8" => (0, u8::MAX as i64),
+        "i8" => (i8::MIN as i64, i8::MAX as i64),
+        "u16" => (0, u16::MAX as i64),
+        "i16" => (i16::MIN as i64, i16::MAX as i64),
+        "u32" => (0, u32::MAX as i64),
+        "i32" => (i32::MIN as i64, i32::MAX as i64),
+        "u64" | "usize" => (0, i64::MAX),
+        "i64" | "isize" => (i64::MIN, i64::MAX),
+        _ => return None,
+    })
+}
+
+/// A name or value of a published enum that must not be reused
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reserved {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
+    /// Last value of a reserved range starting at `value`, inclusive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<i64>,
+}
+
+impl Reserved {
+    /// e.g. `Fireball = 3`, `Fireball`, `3` or `9–11`
+    pub fn label(&self) -> String {
+        let value = match (self.value, self.end) {
+            (Some(start), Some(end)) => Some(format!("{}–{}", start, end)),
+            (value, _) => value.map(|value| value.to_string()),
+        };
+        match (&self.name, value) {
+            (Some(name), Some(value)) => format!("{} = {}", name, value),
+            (Some(name), None) => name.clone(),
+            (None, Some(value)) => value,
+            (None, None) => String::new(),
+        }
+    }
+
+    pub fn reserves_value(&self, value: i64) -> bool {
+        match (self.value, self.end) {
+            (Some(start), Some(end)) => (start..=end).contains(&value),
+            (reserved, _) => reserved == Some(value),
+        }
+    }
+}
+
+/// An associated constant emitted in the enum's `impl` block
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssociatedConst {
+    pub name: String,
+    /// Rust type, e.g. `u32`
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Rust expression, e.g. `100` or `Self::BASE * 2`
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+impl AssociatedConst {
+    /// e.g. `MAX: u32 = 100`
+    pub fn label(&self) -> String {
+        format!("{}: {} = {}", self.name, self.ty, self.value)
+    }
+}
+
+/// A method emitted in the enum's `impl` block
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MethodSnippet {
+    /// e.g. `pub fn is_magic(&self) -> bool`
+    pub signature: String,
+    /// Statements inside the braces
+    pub body: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+impl MethodSnippet {
+    /// Name of the method, taken from the signature
+    pub fn name(&self) -> Option<&str> {
+        let rest = self.signature.split_once("fn ")?.1.trim_start();
+        let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+        (end > 0).then(|| &rest[..end])
+    }
+}
+
+/// A deleted variant kept in `meta.trash`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrashedVariant {
+    pub variant: EnumVariant,
+    #[serde(default, skip_serializing_if = "VariantMeta::is_default")]
+    pub meta: VariantMeta,
+    /// Position the variant had before it was deleted
+    pub index: usize,
+}
+
+/// Variant-level metadata managed by the editor
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VariantMeta {
+    /// Explicit discriminant; implicit variants continue from the previous value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
+
+    /// Discriminant written as a constant expression, e.g. `BASE + 5`;
+    /// takes the place of `value`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expr: Option<String>,
+
+    /// Flags this variant is the union of, e.g. `Read` and `Write` for
+    /// `ReadWrite`; only valid in bitflags mode
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub combines: Vec<String>,
+
+    /// Player-facing name, e.g. for tooltips
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Color as `#RRGGBB` or `#RRGGBBAA`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// Previous names, accepted when deserializing
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+
+    /// Kept for compatibility but should no longer be used
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+
+    /// `cfg` predicate gating the variant, e.g. `feature = "debug_tools"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<String>,
+
+    /// Release stage; decides which build profiles include the variant
+    #[serde(skip_serializing_if = "Stage::is_stable")]
+    pub stage: Stage,
+
+    /// Person responsible for the variant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Identifier for analytics, assigned once and kept through renames
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stable_id: Option<String>,
+
+    /// Position in designer-ordered lists, lowest first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_key: Option<i64>,
+
+    /// Identity the changelog and blame follow through renames; not shown in
+    /// the editor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<Uuid>,
+
+    /// Identifier emitted by a codegen target instead of its usual one, by
+    /// target id, e.g. `Self_` for `rust` where the name is a keyword
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub target_names: BTreeMap<String, String>,
+
+    /// Default of a struct payload field, by field name, as a Rust
+    /// expression, e.g. `1.0`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_defaults: BTreeMap<String, String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+
+    /// Last edit made in the editor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified: Option<DateTime<Utc>>,
+
+    /// Review notes and TODOs
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<Note>,
+
+    /// Keys this version of the plugin does not know about
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl VariantMeta {
+    pub fn is_default(&self) -> bool {
+        *self == VariantMeta::default()
+    }
+
+    /// Metadata for a variant created just now by `owner`
+    pub fn created_by(owner: Option<String>) -> Self {
+        Self { owner, created: Some(Utc::now()), uuid: Some(Uuid::new_v4()), ..Self::default() }
+    }
+
+    /// Record an edit made just now
+    pub fn touch(&mut self) {
+        self.modified = Some(Utc::now());
+    }
+
+    /// Identifier `target` emits the variant as: its override, or `usual`
+    pub fn emitted_name(&self, target: CodegenTarget, usual: &str) -> String {
+        self.target_names.get(target.id()).cloned().unwrap_or_else(|| usual.to_string())
+    }
+
+    /// True if the value is the union of other flags
+    pub fn is_composite(&self) -> bool {
+        !self.combines.is_empty()
+    }
+
+    pub fn open_notes(&self) -> usize {
+        self.notes.iter().filter(|note| !note.resolved).count()
+    }
+
+    /// Color packed as `0xRRGGBBAA`, if it parses
+    pub fn color_rgba(&self) -> Option<u32> {
+        let hex = self.color.as_deref()?.trim().trim_start_matches('#');
+        match hex.len() {
+            6 => u32::from_str_radix(hex, 16).ok().map(|rgb| (rgb << 8) | 0xFF),
+            8 => u32::from_str_radix(hex, 16).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// A change made to every selected variant at once
+#[derive(Clone, Debug, PartialEq)]
+pub enum BatchEdit {
+    Deprecated(bool),
+    Stage(Stage),
+    Cfg(Option<String>),
+    Owner(Option<String>),
+}
+
+impl BatchEdit {
+    pub fn apply(&self, meta: &mut VariantMeta) {
+        match self {
+            BatchEdit::Deprecated(deprecated) => meta.deprecated = *deprecated,
+            BatchEdit::Stage(stage) => meta.stage = *stage,
+            BatchEdit::Cfg(cfg) => meta.cfg = cfg.clone(),
+            BatchEdit::Owner(owner) => meta.owner = owner.clone(),
+        }
+    }
+
+    /// What the edit does, e.g. "Stage set to Experimental"
+    pub fn describe(&self) -> String {
+        match self {
+            BatchEdit::Deprecated(true) => "Deprecated".to_string(),
+            BatchEdit::Deprecated(false) => "Undeprecated".to_string(),
+            BatchEdit::Stage(stage) => format!("Stage set to {}", stage.label()),
+            BatchEdit::Cfg(Some(cfg)) => format!("Gated behind `cfg({})`", cfg),
+            BatchEdit::Cfg(None) => "cfg gate removed".to_string(),
+            BatchEdit::Owner(Some(owner)) => format!("Owner set to {}", owner),
+            BatchEdit::Owner(None) => "Owner cleared".to_string(),
+        }
+    }
+}
+
+/// A review note or TODO on a variant. Notes are never emitted to generated code.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub resolved: bool,
+}
+
+/// Why the renames, given as variant index and new name, can't be applied
+/// together: a new name that isn't an identifier, that another variant
+/// would also end up with, or that the published enum has retired, or an
+/// old name kept as an alias while another variant takes it
+pub fn check_renames(asset: &EnumAsset, renames: &[(usize, String)]) -> Result<(), String> {
+    let meta = EnumMeta::from_asset(asset);
+    let mut names: Vec<&str> = asset.variants.iter().map(|variant| variant.name.as_str()).collect();
+    for (index, new_name) in renames {
+        if let Some(name) = names.get_mut(*index) {
+            *name = new_name;
+        }
+    }
+    for (index, new_name) in renames {
+        if !crate::validation::is_identifier(new_name) {
+            return Err(format!("`{}` is not a valid variant name", new_name));
+        }
+        if names.iter().enumerate().any(|(other, name)| other != *index && name == new_name) {
+            return Err(format!(
+                "More than one variant would be named `{}`; `{}` is free",
+                new_name,
+                naming::duplicate_name(new_name, names.iter().copied())
+            ));
+        }
+        if meta.reserved.iter().any(|reserved| reserved.name.as_deref() == Some(new_name.as_str())) {
+            return Err(format!("`{}` was retired from the published enum", new_name));
+        }
+        // The alias would match another variant's data too
+        let Some(old_name) = asset.variants.get(*index).map(|variant| variant.name.as_str()) else {
+            continue;
+        };
+        if meta.alias_on_rename && old_name != new_name && names.contains(&old_name) {
+            return Err(format!(
+                "`{}` would stay an alias of `{}` while another variant is named `{}`; turn off keeping old names as aliases to swap or shift names",
+                old_name, new_name, old_name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Rename variants, given as index and new name, moving their metadata and
+/// the default variant along and keeping old names as aliases if the enum
+/// asks for it. Every rename happens at once, so names can be swapped or
+/// shifted (`Foo` → `FooBar`, `FooBar` → `FooBarBar`) unless old names are
+/// kept as aliases. Check them first with [`check_renames`].
+pub fn rename_variants(asset: &mut EnumAsset, renames: &[(usize, String)]) {
+    let mut meta = EnumMeta::from_asset(asset);
+    let moves: Vec<(usize, String, String)> = renames
+        .iter()
+        .filter_map(|(index, new_name)| {
+            let old_name = &asset.variants.get(*index)?.name;
+            (old_name != new_name).then(|| (*index, old_name.clone(), new_name.clone()))
+        })
+        .collect();
+    let moved: Vec<Option<VariantMeta>> = moves.iter().map(|(_, old_name, _)| meta.variants.remove(old_name)).collect();
+    let default_variant = meta.default_variant.clone();
+    for ((index, old_name, new_name), variant_meta) in moves.iter().zip(moved) {
+        if let Some(variant_meta) = variant_meta {
+            meta.variants.insert(new_name.clone(), variant_meta);
+        }
+        if default_variant.as_deref() == Some(old_name.as_str()) {
+            meta.default_variant = Some(new_name.clone());
+        }
+        if meta.alias_on_rename {
+            meta.add_alias(new_name, old_name);
+        }
+        asset.variants[*index].name = new_name.clone();
+    }
+    meta.write_to(asset);
+}
+
+/// Effective discriminant of every variant, following Rust's rules:
+/// explicit values are used as-is, implicit ones are the previous value plus one.
+///
+/// Expressions are evaluated against `meta.constants`; one that doesn't
+/// evaluate counts as implicit. Composite flags are the union of the plain
+/// flags they name and don't advance the implicit value.
+pub fn variant_values(asset: &EnumAsset, meta: &EnumMeta) -> Vec<i64> {
+    let mut next = 0i64;
+    let mut values: Vec<i64> = asset
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_meta = meta.variant(&variant.name);
+            if variant_meta.is_composite() {
+                return 0;
+            }
+            let explicit = match &variant_meta.expr {
+                Some(expr) => expr::evaluate(expr, &meta.constants).ok(),
+                None => variant_meta.value,
+            };
+            let value = explicit.unwrap_or(next);
+            next = value.wrapping_add(1);
+            value
+        })
+        .collect();
+
+    let plain: BTreeMap<&str, i64> = asset
+        .variants
+        .iter()
+        .zip(&values)
+        .filter(|(variant, _)| !meta.variant(&variant.name).is_composite())
+        .map(|(variant, value)| (variant.name.as_str(), *value))
+        .collect();
+    for (variant, value) in asset.variants.iter().zip(values.iter_mut()) {
+        let combines = meta.variant(&variant.name).combines;
+        if !combines.is_empty() {
+            *value = combines.iter().filter_map(|name| plain.get(name.as_str())).fold(0, |acc, flag| acc | flag);
+        }
+    }
+    values
+}
+
+/// True if every value is at least the one declared before it, so derived
+/// comparisons agree with the values
+pub fn values_ascend(asset: &EnumAsset, meta: &EnumMeta) -> bool {
+    variant_values(asset, meta).windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/// Variant names from least to greatest as the enum's comparisons order
+/// them; equal values keep their declaration order
+pub fn comparison_order<'a>(asset: &'a EnumAsset, meta: &EnumMeta) -> Vec<&'a str> {
+    let mut variants: Vec<(&str, i64)> =
+        asset.variants.iter().map(|v| v.name.as_str()).zip(variant_values(asset, meta)).collect();
+    if meta.order_by == OrderBy::Value {
+        variants.sort_by_key(|(_, value)| *value);
+    }
+    variants.into_iter().map(|(name, _)| name).collect()
+}
+
+/// Variant names ordered by sort key; variants without one take their
+/// position in the declaration, and ties keep declaration order
+pub fn sort_key_order<'a>(asset: &'a EnumAsset, meta: &EnumMeta) -> Vec<(&'a str, i64)> {
+    let mut variants: Vec<(&str, i64)> = asset
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| (variant.name.as_str(), meta.variant(&variant.name).sort_key.unwrap_or(index as i64)))
+        .collect();
+    variants.sort_by_key(|(_, key)| *key);
+    variants
+}
+
+/// Split a combination written as `Read | Write` into flag names
+pub fn parse_combination(text: &str) -> Vec<String> {
+    text.split('|').map(str::trim).filter(|name| !name.is_empty()).map(str::to_string).collect()
+}
+
+/// Split name overrides written as `rust = Self_, swift = me` into names by
+/// target id; entries without both sides are left out
+pub fn parse_target_names(text: &str) -> BTreeMap<String, String> {
+    text.split(',')
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(target, name)| (target.trim().to_string(), name.trim().to_string()))
+        .filter(|(target, name)| !target.is_empty() && !name.is_empty())
+        .collect()
+}
+
+/// Name overrides written the way `parse_target_names` reads them
+pub fn format_target_names(names: &BTreeMap<String, String>) -> String {
+    names.iter().map(|(target, name)| format!("{} = {}", target, name)).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,215 @@
+//! Helpers for generating and converting identifiers
+
+use serde::{Deserialize, Serialize};
+
+/// Naming convention for variant names
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Case {
+    #[default]
+    #[serde(rename = "PascalCase")]
+    Pascal,
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnake,
+    #[serde(rename = "snake_case")]
+    Snake,
+    #[serde(rename = "camelCase")]
+    Camel,
+}
+
+impl Case {
+    pub const ALL: [Case; 4] = [Case::Pascal, Case::ScreamingSnake, Case::Snake, Case::Camel];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Case::Pascal => "PascalCase",
+            Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Case::Snake => "snake_case",
+            Case::Camel => "camelCase",
+        }
+    }
+
+    pub(crate) fn is_pascal(&self) -> bool {
+        *self == Case::Pascal
+    }
+
+    /// Convert `name` to this convention
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            Case::Pascal => pascal_case(name),
+            Case::ScreamingSnake => screaming_snake_case(name),
+            Case::Snake => snake_case(name),
+            Case::Camel => camel_case(name),
+        }
+    }
+
+    /// Whether `name` follows the convention. Acronyms are allowed in the
+    /// mixed-case conventions, so `HTTPServer` counts as PascalCase.
+    pub fn matches(&self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else {
+            return true;
+        };
+        match self {
+            Case::Pascal => !first.is_lowercase() && !name.contains('_'),
+            Case::Camel => !first.is_uppercase() && !name.contains('_'),
+            Case::Snake => !name.chars().any(char::is_uppercase),
+            Case::ScreamingSnake => !name.chars().any(char::is_lowercase),
+        }
+    }
+}
+
+/// Rule turning a variant name into a display name, e.g. `FireBall` into
+/// `Fire Ball`. Acronyms keep their capitals in every style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayStyle {
+    /// `Fire Ball`
+    Title,
+    /// `Fire ball`
+    Sentence,
+    /// `fire ball`
+    Lower,
+}
+
+impl DisplayStyle {
+    pub const ALL: [DisplayStyle; 3] = [DisplayStyle::Title, DisplayStyle::Sentence, DisplayStyle::Lower];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DisplayStyle::Title => "Title Case",
+            DisplayStyle::Sentence => "Sentence case",
+            DisplayStyle::Lower => "lower case",
+        }
+    }
+
+    /// The display name of a variant called `name`. Words of
+    /// SCREAMING_SNAKE_CASE names aren't taken for acronyms.
+    pub fn apply(&self, name: &str) -> String {
+        let shouting = !name.chars().any(char::is_lowercase);
+        let words: Vec<String> = cased_words(name)
+            .into_iter()
+            .enumerate()
+            .map(|(index, word)| {
+                let acronym = !shouting && word.chars().count() > 1 && !word.chars().any(char::is_lowercase);
+                if acronym {
+                    return word;
+                }
+                let word = word.to_lowercase();
+                let capitalized = match self {
+                    DisplayStyle::Title => true,
+                    DisplayStyle::Sentence => index == 0,
+                    DisplayStyle::Lower => false,
+                };
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) if capitalized => first.to_uppercase().chain(chars).collect(),
+                    _ => word,
+                }
+            })
+            .collect();
+        words.join(" ")
+    }
+}
+
+/// Whether `name` is usable as an identifier in the generated code
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Name for a copy of `name` that doesn't collide with `existing`.
+///
+/// A trailing number is incremented (`Fireball2` becomes `Fireball3`),
+/// otherwise a number is appended starting at 2 (`Fireball` becomes `Fireball2`).
+pub fn duplicate_name<'a>(name: &str, existing: impl IntoIterator<Item = &'a str> + Clone) -> String {
+    let digits_start = name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (base, number) = name.split_at(digits_start);
+    let mut number = number.parse::<u64>().map(|n| n + 1).unwrap_or(2);
+
+    loop {
+        let candidate = format!("{}{}", base, number);
+        if !existing.clone().into_iter().any(|name| name == candidate) {
+            return candidate;
+        }
+        number += 1;
+    }
+}
+
+/// Split a PascalCase, camelCase or snake_case name into lowercase words.
+///
+/// Acronyms stay together (`HTTPServer` gives `http`, `server`).
+pub fn words(name: &str) -> Vec<String> {
+    cased_words(name).iter().map(|word| word.to_lowercase()).collect()
+}
+
+/// Like [`words`], keeping each word's case (`HTTP`, `Server`)
+fn cased_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// `fire_ball` becomes `FireBall`
+pub fn pascal_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `FireBall` becomes `FIRE_BALL`
+pub fn screaming_snake_case(name: &str) -> String {
+    words(name).join("_").to_uppercase()
+}
+
+/// `FireBall` becomes `fire_ball`
+pub fn snake_case(name: &str) -> String {
+    words(name).join("_")
+}
+
+/// `FireBall` becomes `fireBall`
+pub fn camel_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) if i > 0 => first.to_uppercase().chain(chars).collect(),
+                _ => word.clone(),
+            }
+        })
+        .collect()
+}
+
+/// `FireBall` becomes `fire-ball`
+pub fn kebab_case(name: &str) -> String {
+    words(name).join("-")
+}
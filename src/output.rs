@@ -0,0 +1,128 @@
+//! Messages shown in the editor's Output panel
+//!
+//! The log is shared between the editor, its panels and background work such
+//! as hooks. Every entry is also forwarded to the `log` crate so it still ends
+//! up in the host's log. Views showing the log subscribe to be told when it
+//! changes, from whichever thread changed it.
+
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// Entries kept before the oldest ones are dropped
+const MAX_ENTRIES: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Info, Severity::Warning, Severity::Error];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+        }
+    }
+}
+
+/// What produced an entry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputSource {
+    Editor,
+    Validation,
+    Hook,
+    Export,
+}
+
+impl OutputSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OutputSource::Editor => "editor",
+            OutputSource::Validation => "validation",
+            OutputSource::Hook => "hook",
+            OutputSource::Export => "export",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OutputEntry {
+    pub severity: Severity,
+    pub source: OutputSource,
+    pub message: String,
+}
+
+/// Shared, thread-safe list of output entries
+#[derive(Clone, Default)]
+pub struct OutputLog {
+    entries: Arc<Mutex<Vec<OutputEntry>>>,
+    subscribers: Arc<Mutex<Vec<UnboundedSender<()>>>>,
+}
+
+impl OutputLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, severity: Severity, source: OutputSource, message: impl Into<String>) {
+        let message = message.into();
+        match severity {
+            Severity::Info => log::info!("[{}] {}", source.label(), message),
+            Severity::Warning => log::warn!("[{}] {}", source.label(), message),
+            Severity::Error => log::error!("[{}] {}", source.label(), message),
+        }
+
+        let mut entries = self.entries.lock();
+        if entries.len() >= MAX_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push(OutputEntry { severity, source, message });
+        drop(entries);
+        self.changed();
+    }
+
+    /// A stream that yields whenever entries are added or cleared. It ends
+    /// when dropped by the subscriber.
+    pub fn subscribe(&self) -> UnboundedReceiver<()> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribers.lock().push(sender);
+        receiver
+    }
+
+    fn changed(&self) {
+        self.subscribers.lock().retain(|sender| sender.unbounded_send(()).is_ok());
+    }
+
+    pub fn info(&self, source: OutputSource, message: impl Into<String>) {
+        self.push(Severity::Info, source, message);
+    }
+
+    pub fn warn(&self, source: OutputSource, message: impl Into<String>) {
+        self.push(Severity::Warning, source, message);
+    }
+
+    pub fn error(&self, source: OutputSource, message: impl Into<String>) {
+        self.push(Severity::Error, source, message);
+    }
+
+    /// Entries at or above the given severity, oldest first
+    pub fn entries(&self, min_severity: Severity) -> Vec<OutputEntry> {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|entry| entry.severity >= min_severity)
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+        self.changed();
+    }
+}
@@ -0,0 +1,125 @@
+//! Changelog panel: release notes since a git revision or the last save
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use crate::changelog::ChangelogBase;
+use crate::markdown;
+
+/// Requests from the Changelog Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum ChangelogPanelEvent {
+    CompareRequested(ChangelogBase),
+}
+
+/// Changelog Panel - Release notes for the changes since a git revision or the last save
+pub struct ChangelogPanel {
+    revision_input: Entity<InputState>,
+    changelog: Option<String>,
+    focus_handle: FocusHandle,
+}
+
+impl ChangelogPanel {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            revision_input: cx.new(|cx| InputState::new(window, cx).placeholder("Git revision, e.g. v1.2.0")),
+            changelog: None,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_changelog(&mut self, changelog: String, cx: &mut Context<Self>) {
+        self.changelog = Some(changelog);
+        cx.notify();
+    }
+
+    fn compare_revision(&mut self, cx: &mut Context<Self>) {
+        let revision = self.revision_input.read(cx).text().trim().to_string();
+        let revision = if revision.is_empty() { "HEAD".to_string() } else { revision };
+        cx.emit(ChangelogPanelEvent::CompareRequested(ChangelogBase::Revision(revision)));
+    }
+}
+
+impl EventEmitter<PanelEvent> for ChangelogPanel {}
+impl EventEmitter<ChangelogPanelEvent> for ChangelogPanel {}
+
+impl Render for ChangelogPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .p_2()
+                    .gap_2()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(TextInput::new(&self.revision_input).flex_1())
+                    .child(
+                        Button::new("changelog-compare")
+                            .primary()
+                            .with_size(ui::Size::Small)
+                            .label("Compare")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.compare_revision(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("changelog-since-save")
+                            .with_size(ui::Size::Small)
+                            .label("Since Save")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(ChangelogPanelEvent::CompareRequested(ChangelogBase::Saved));
+                            }))
+                    )
+                    .when_some(self.changelog.clone(), |this, changelog| {
+                        this.child(
+                            Button::new("changelog-copy")
+                                .ghost()
+                                .with_size(ui::Size::Small)
+                                .icon(IconName::Copy)
+                                .tooltip("Copy changelog")
+                                .on_click(move |_, _window, cx| {
+                                    cx.write_to_clipboard(ClipboardItem::new_string(changelog.clone()));
+                                })
+                        )
+                    })
+            )
+            .child(
+                v_flex()
+                    .id("enum-changelog-content")
+                    .flex_1()
+                    .p_3()
+                    .overflow_scroll()
+                    .child(match &self.changelog {
+                        Some(changelog) => markdown::render(changelog, cx),
+                        None => div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Compare against a git revision (HEAD if empty) or the last save"),
+                    })
+            )
+    }
+}
+
+impl Focusable for ChangelogPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for ChangelogPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_changelog"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Changelog".into_any_element()
+    }
+}
@@ -0,0 +1,585 @@
+//! Code Preview panel: generated code with syntax highlighting, refreshed
+//! after edits
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+    popup_menu::ContextMenuExt as _,
+};
+use ui_types_common::EnumAsset;
+use futures::channel::mpsc::{self, UnboundedSender};
+use futures::future::{self, Either};
+use futures::StreamExt;
+use std::ops::Range;
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::codegen::{self, CodegenTarget, MarkdownColumn, SourceMap, TemplateTarget};
+use crate::metadata::{BuildProfile, EnumMeta};
+use crate::project::{Density, CODE_FONT_SIZES, DEFAULT_CODE_FONT_SIZE};
+
+/// Requests from the Code Preview Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum CodePreviewPanelEvent {
+    ExportRequested(CodegenTarget),
+    ExportTemplateRequested(TemplateTarget),
+    GenerateAllRequested,
+    /// Generate every enum in the project by its export layout
+    ExportAllRequested,
+    /// A line generated from this variant was clicked
+    VariantClicked(String),
+    /// Show the code of the saved definition alongside; answered with `set_saved`
+    CompareRequested,
+}
+
+/// Quiet time after the last edit before the code preview regenerates
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Marks the code preview stale after an edit, from any panel's
+/// modification callback
+#[derive(Clone)]
+pub struct PreviewTrigger(UnboundedSender<()>);
+
+impl PreviewTrigger {
+    pub fn edited(&self) {
+        // Fails only once the preview is gone, when nothing needs updating
+        let _ = self.0.unbounded_send(());
+    }
+}
+
+/// Byte ranges of `old` and `new` that differ, between their common prefix
+/// and suffix, or `None` if the texts are equal
+fn changed_range(old: &str, new: &str) -> Option<(Range<usize>, Range<usize>)> {
+    if old == new {
+        return None;
+    }
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(old.len().min(new.len()), |((index, _), _)| index);
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    Some((prefix..old.len() - suffix, prefix..new.len() - suffix))
+}
+
+/// Byte offset of `utf16` code units into `text`
+fn byte_offset(text: &str, utf16: usize) -> usize {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units >= utf16 {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+/// Byte offset where line `line` (from 0) of `text` starts
+fn line_offset(text: &str, line: usize) -> usize {
+    match line {
+        0 => 0,
+        line => text.match_indices('\n').nth(line - 1).map_or(text.len(), |(index, _)| index + 1),
+    }
+}
+
+actions!(enum_code_preview, [CopyCode]);
+
+/// Show the output of a target in the code preview
+#[derive(Clone, PartialEq, Action)]
+#[action(namespace = enum_code_preview, no_json)]
+pub struct PreviewTarget(pub CodegenTarget);
+
+/// Language the code preview highlights a target's output as
+fn highlight_language(target: CodegenTarget) -> &'static str {
+    match target {
+        CodegenTarget::Rust | CodegenTarget::FfiRust | CodegenTarget::RustImpls | CodegenTarget::ConfigLoader => "rust",
+        CodegenTarget::Markdown | CodegenTarget::MdBook | CodegenTarget::ConfigSamples => "markdown",
+        CodegenTarget::Html => "html",
+        CodegenTarget::SqlCheck | CodegenTarget::SqlLookup => "sql",
+        CodegenTarget::Python => "python",
+        CodegenTarget::Java => "java",
+        CodegenTarget::Kotlin => "kotlin",
+        CodegenTarget::Swift => "swift",
+        CodegenTarget::CHeader => "c",
+        CodegenTarget::GraphQl => "graphql",
+        _ => "text",
+    }
+}
+
+/// Code Preview Panel - Display generated Rust code with syntax highlighting
+pub struct CodePreviewPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    code_input: Entity<InputState>,
+    /// Definition as last saved, shown in a second column while set
+    saved: Option<EnumAsset>,
+    saved_input: Entity<InputState>,
+    templates: Vec<TemplateTarget>,
+    markdown_columns: Vec<MarkdownColumn>,
+    /// Build profile the preview is generated for
+    preview_profile: BuildProfile,
+    /// Target whose output is shown; variants can only be located in Rust
+    preview_target: CodegenTarget,
+    density: Density,
+    /// Font size of the code, in pixels
+    font_size: f32,
+    focus_handle: FocusHandle,
+    needs_update: Arc<parking_lot::Mutex<bool>>,
+    /// Lines of the shown code each variant generated
+    source_map: SourceMap,
+    /// Edits typed in other panels, applied once typing pauses
+    trigger: PreviewTrigger,
+    _debounce_task: Task<()>,
+}
+
+impl CodePreviewPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        use ui::input::TabSize;
+
+        let code_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor("rust")
+                .line_number(true)
+                .minimap(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+        });
+        let saved_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor("rust")
+                .line_number(true)
+                .tab_size(TabSize {
+                    tab_size: 4,
+                    hard_tabs: false,
+                })
+        });
+
+        let preview_profile = EnumMeta::from_asset(&asset.read()).profile;
+        let (sender, mut edits) = mpsc::unbounded();
+        let debounce_task = cx.spawn_in(window, async move |this, cx| {
+            while edits.next().await.is_some() {
+                // Every further edit restarts the wait
+                loop {
+                    let timer = pin!(cx.background_executor().timer(PREVIEW_DEBOUNCE));
+                    match future::select(edits.next(), timer).await {
+                        Either::Left((Some(()), _)) => continue,
+                        _ => break,
+                    }
+                }
+                if this.update_in(cx, |this, window, cx| this.update_code_preview(window, cx)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            asset,
+            code_input,
+            saved: None,
+            saved_input,
+            templates: Vec::new(),
+            markdown_columns: MarkdownColumn::DEFAULT.to_vec(),
+            preview_profile,
+            preview_target: CodegenTarget::Rust,
+            density: Density::default(),
+            font_size: DEFAULT_CODE_FONT_SIZE,
+            focus_handle: cx.focus_handle(),
+            needs_update: Arc::new(parking_lot::Mutex::new(true)),
+            source_map: SourceMap::new(),
+            trigger: PreviewTrigger(sender),
+            _debounce_task: debounce_task,
+        }
+    }
+
+    /// Handle for marking the preview stale as the asset is edited
+    pub fn trigger(&self) -> PreviewTrigger {
+        self.trigger.clone()
+    }
+
+    /// Spacing of the toolbars and the font size of the code
+    pub fn set_appearance(&mut self, density: Density, font_size: f32, cx: &mut Context<Self>) {
+        self.density = density;
+        self.font_size = font_size.clamp(*CODE_FONT_SIZES.start(), *CODE_FONT_SIZES.end());
+        cx.notify();
+    }
+
+    /// User templates offered as additional export targets
+    pub fn set_templates(&mut self, templates: Vec<TemplateTarget>, cx: &mut Context<Self>) {
+        self.templates = templates;
+        cx.notify();
+    }
+
+    fn toggle_markdown_column(&mut self, column: MarkdownColumn, cx: &mut Context<Self>) {
+        if let Some(position) = self.markdown_columns.iter().position(|c| *c == column) {
+            self.markdown_columns.remove(position);
+        } else {
+            // Keep the canonical column order
+            self.markdown_columns.push(column);
+            self.markdown_columns.sort_by_key(|c| MarkdownColumn::ALL.iter().position(|a| a == c));
+        }
+        cx.notify();
+    }
+
+    fn copy_markdown(&self, cx: &mut Context<Self>) {
+        let table = codegen::generate_markdown_table(&self.asset.read(), &self.markdown_columns);
+        cx.write_to_clipboard(ClipboardItem::new_string(table));
+    }
+
+    pub fn request_update(&self) {
+        *self.needs_update.lock() = true;
+    }
+
+    /// Bytes of generated code held by the preview
+    pub fn buffer_len(&self, cx: &App) -> usize {
+        self.code_input.read(cx).text().len()
+    }
+
+    /// Drop the generated code and its highlighting; they are regenerated
+    /// the next time the panel renders. Returns the bytes freed.
+    pub fn trim(&mut self, window: &mut Window, cx: &mut Context<Self>) -> usize {
+        let freed = self.buffer_len(cx);
+        self.code_input.update(cx, |input, cx| input.replace_text_in_range(None, "", window, cx));
+        self.request_update();
+        cx.notify();
+        freed
+    }
+
+    /// Regenerate the code and replace only the part that changed, so big
+    /// enums don't re-lay out every line on each edit
+    fn update_code_preview(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        *self.needs_update.lock() = false;
+        let (code, source_map) = match self.preview_target {
+            CodegenTarget::Rust => codegen::generate_rust_mapped(&codegen::staged(&self.asset.read(), self.preview_profile)),
+            target => (self.preview_text(&self.asset.read(), target), SourceMap::new()),
+        };
+        self.source_map = source_map;
+        let shown = self.code_input.read(cx).text().to_string();
+        let Some((old, new)) = changed_range(&shown, &code) else {
+            return;
+        };
+        // The input addresses text in UTF-16 code units
+        let start = shown[..old.start].encode_utf16().count();
+        let end = start + shown[old].encode_utf16().count();
+        self.code_input.update(cx, |input, cx| {
+            input.replace_text_in_range(Some(start..end), &code[new], window, cx);
+        });
+    }
+
+    pub fn is_comparing(&self) -> bool {
+        self.saved.is_some()
+    }
+
+    /// Show the code of the saved definition next to the current one, or
+    /// stop comparing with `None`
+    pub fn set_saved(&mut self, saved: Option<EnumAsset>, window: &mut Window, cx: &mut Context<Self>) {
+        self.saved = saved;
+        self.update_saved_code(window, cx);
+        cx.notify();
+    }
+
+    fn update_saved_code(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let code = self.saved.as_ref().map(|saved| self.preview_text(saved, self.preview_target)).unwrap_or_default();
+        self.saved_input.update(cx, |input, cx| input.replace_text_in_range(None, &code, window, cx));
+    }
+
+    /// Output of `target` for the definition, or why there is none
+    fn preview_text(&self, asset: &EnumAsset, target: CodegenTarget) -> String {
+        target.preview(asset, self.preview_profile).unwrap_or_else(|message| message)
+    }
+
+    /// Show the output of another target
+    fn set_preview_target(&mut self, target: CodegenTarget, window: &mut Window, cx: &mut Context<Self>) {
+        self.preview_target = target;
+        let language = highlight_language(target);
+        self.code_input.update(cx, |input, cx| input.set_highlighter(language, cx));
+        self.saved_input.update(cx, |input, cx| input.set_highlighter(language, cx));
+        *self.needs_update.lock() = true;
+        self.update_saved_code(window, cx);
+        cx.notify();
+    }
+
+    /// Put the shown code on the clipboard
+    fn copy_code(&mut self, _: &CopyCode, _window: &mut Window, cx: &mut Context<Self>) {
+        let code = self.code_input.read(cx).text().to_string();
+        cx.write_to_clipboard(ClipboardItem::new_string(code));
+    }
+
+    fn switch_target(&mut self, action: &PreviewTarget, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_preview_target(action.0, window, cx);
+    }
+
+    /// Announce the variant that generated the line under the cursor
+    fn reveal_clicked_variant(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(selection) = self.code_input.update(cx, |input, cx| input.selected_text_range(false, window, cx)) else {
+            return;
+        };
+        let text = self.code_input.read(cx).text().to_string();
+        let line = text[..byte_offset(&text, selection.range.start)].matches('\n').count();
+        if let Some((name, _)) = self.source_map.iter().find(|(_, lines)| lines.contains(&line)) {
+            cx.emit(CodePreviewPanelEvent::VariantClicked(name.clone()));
+        }
+    }
+
+    fn column_label(label: &'static str, cx: &App) -> Div {
+        div()
+            .px_3()
+            .py_1()
+            .text_xs()
+            .font_semibold()
+            .text_color(cx.theme().muted_foreground)
+            .child(label)
+    }
+
+    /// Select the lines generated for the variant `name`
+    pub fn highlight_variant(&mut self, name: &str, window: &mut Window, cx: &mut Context<Self>) {
+        if *self.needs_update.lock() {
+            self.update_code_preview(window, cx);
+        }
+        let Some((_, lines)) = self.source_map.iter().find(|(variant, _)| variant == name).cloned() else {
+            return;
+        };
+        let text = self.code_input.read(cx).text().to_string();
+        let (start, end) = (line_offset(&text, lines.start), line_offset(&text, lines.end));
+        let selected = &text[start..end];
+        let start = text[..start].encode_utf16().count();
+        let len = selected.encode_utf16().count();
+        // The input can't be told what to select, but text inserted as marked
+        // text ends up selected, so the lines are put back as marked text
+        self.code_input.update(cx, |input, cx| {
+            input.replace_and_mark_text_in_range(Some(start..start + len), selected, Some(0..len), window, cx);
+            input.unmark_text(window, cx);
+        });
+    }
+}
+
+impl EventEmitter<PanelEvent> for CodePreviewPanel {}
+impl EventEmitter<CodePreviewPanelEvent> for CodePreviewPanel {}
+
+impl Render for CodePreviewPanel {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if *self.needs_update.lock() {
+            self.update_code_preview(window, cx);
+        }
+
+        v_flex()
+            .size_full()
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::copy_code))
+            .on_action(cx.listener(Self::switch_target))
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(match self.preview_target {
+                                CodegenTarget::Rust => "Generated Code".to_string(),
+                                target => format!("Generated Code ({})", target.label()),
+                            })
+                    )
+                    .children(BuildProfile::ALL.into_iter().map(|profile| {
+                        Button::new(SharedString::from(format!("preview-profile-{}", profile.label())))
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .when(self.preview_profile == profile, |this| this.primary())
+                            .label(profile.label())
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.preview_profile = profile;
+                                *this.needs_update.lock() = true;
+                                this.update_saved_code(window, cx);
+                                cx.notify();
+                            }))
+                    }))
+                    .child(
+                        Button::new("compare-saved")
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .when(self.saved.is_some(), |this| this.primary())
+                            .label("Compare Saved")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                if this.saved.is_some() {
+                                    this.set_saved(None, window, cx);
+                                } else {
+                                    cx.emit(CodePreviewPanelEvent::CompareRequested);
+                                }
+                            }))
+                    )
+                    .child(
+                        Button::new("generate-all")
+                            .primary()
+                            .with_size(ui::Size::Small)
+                            .label("Generate All")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(CodePreviewPanelEvent::GenerateAllRequested);
+                            }))
+                    )
+                    .child(
+                        Button::new("export-all")
+                            .with_size(ui::Size::Small)
+                            .label("Export All")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(CodePreviewPanelEvent::ExportAllRequested);
+                            }))
+                    )
+            )
+            // One export button per target; wraps as the list of backends grows
+            .child(
+                h_flex()
+                    .w_full()
+                    .px_3()
+                    .py(px(self.density.spacing(4.0)))
+                    .gap_1()
+                    .flex_wrap()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .children(CodegenTarget::ALL.into_iter().map(|target| {
+                        Button::new(SharedString::from(format!("export-{}", target.id())))
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .label(format!("Export {}", target.label()))
+                            .on_click(cx.listener(move |_this, _, _window, cx| {
+                                cx.emit(CodePreviewPanelEvent::ExportRequested(target));
+                            }))
+                    }))
+                    .children(self.templates.iter().cloned().enumerate().map(|(index, template)| {
+                        Button::new(("export-template", index))
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .label(format!("Export {}", template.label()))
+                            .on_click(cx.listener(move |_this, _, _window, cx| {
+                                cx.emit(CodePreviewPanelEvent::ExportTemplateRequested(template.clone()));
+                            }))
+                    }))
+            )
+            .child(
+                h_flex()
+                    .w_full()
+                    .flex_1()
+                    .text_size(px(self.font_size))
+                    // Saved code on the left, so pending codegen changes read left to right
+                    .when(self.saved.is_some(), |this| {
+                        this.child(
+                            v_flex()
+                                .h_full()
+                                .flex_1()
+                                .border_r_1()
+                                .border_color(cx.theme().border)
+                                .child(Self::column_label("Saved", cx))
+                                .child(TextInput::new(&self.saved_input).w_full().flex_1())
+                        )
+                    })
+                    // Clicking a variant's line selects it in the Variants panel
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .flex_1()
+                            .when(self.saved.is_some(), |this| this.child(Self::column_label("Current", cx)))
+                            .child(
+                                div()
+                                    .id("generated-code")
+                                    .w_full()
+                                    .flex_1()
+                                    .on_mouse_up(MouseButton::Left, cx.listener(|this, _, window, cx| {
+                                        this.reveal_clicked_variant(window, cx);
+                                    }))
+                                    .child(
+                                        TextInput::new(&self.code_input)
+                                            .size_full()
+                                    )
+                                    .on_mouse_down(MouseButton::Right, cx.listener(|this, _, window, _cx| {
+                                        window.focus(&this.focus_handle);
+                                    }))
+                                    .context_menu({
+                                        let current = self.preview_target;
+                                        move |menu, window, cx| {
+                                            menu.menu("Copy", Box::new(CopyCode))
+                                                .separator()
+                                                .submenu("Preview As", window, cx, move |menu, _window, _cx| {
+                                                    CodegenTarget::ALL
+                                                        .into_iter()
+                                                        .filter(|target| *target != CodegenTarget::Binary)
+                                                        .fold(menu, |menu, target| {
+                                                            menu.menu_with_check(
+                                                                target.label(),
+                                                                target == current,
+                                                                Box::new(PreviewTarget(target)),
+                                                            )
+                                                        })
+                                                })
+                                        }
+                                    })
+                            )
+                    )
+            )
+            // Copy as Markdown with a selectable set of columns
+            .child(
+                h_flex()
+                    .w_full()
+                    .px_3()
+                    .py(px(self.density.spacing(8.0)))
+                    .gap_1()
+                    .flex_wrap()
+                    .items_center()
+                    .border_t_1()
+                    .border_color(cx.theme().border)
+                    .children(MarkdownColumn::ALL.into_iter().map(|column| {
+                        Button::new(SharedString::from(format!("markdown-column-{}", column.label())))
+                            .with_size(ui::Size::XSmall)
+                            .when(self.markdown_columns.contains(&column), |this| this.primary())
+                            .label(column.label())
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.toggle_markdown_column(column, cx);
+                            }))
+                    }))
+                    .child(
+                        Button::new("copy-markdown")
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .icon(IconName::Copy)
+                            .label("Copy as Markdown")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.copy_markdown(cx);
+                            }))
+                    )
+            )
+    }
+}
+
+impl Focusable for CodePreviewPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for CodePreviewPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_code_preview"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Code Preview".into_any_element()
+    }
+}
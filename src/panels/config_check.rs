@@ -0,0 +1,201 @@
+//! Config Check panel: values in game config files that don't match the
+//! definition
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use std::path::PathBuf;
+use crate::config_check::ConfigFileCheck;
+use crate::output::Severity;
+
+/// Requests from the Config Check Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum ConfigCheckPanelEvent {
+    /// Check the config files under `dir` (relative to the project root),
+    /// looking for the enum under `keys`; either may be empty for the default
+    CheckRequested { dir: String, keys: Vec<String> },
+}
+
+/// Config Check Panel - Values of the enum in game config files that don't
+/// match the definition
+pub struct ConfigCheckPanel {
+    dir_input: Entity<InputState>,
+    keys_input: Entity<InputState>,
+    root: PathBuf,
+    results: Vec<ConfigFileCheck>,
+    checking: bool,
+    /// Whether a check has completed
+    checked: bool,
+    focus_handle: FocusHandle,
+}
+
+impl ConfigCheckPanel {
+    pub fn new(default_key: &str, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            dir_input: cx.new(|cx| InputState::new(window, cx).placeholder("Config folder, e.g. assets/config")),
+            keys_input: cx.new(|cx| InputState::new(window, cx).placeholder(default_key.to_string())),
+            root: PathBuf::new(),
+            results: Vec::new(),
+            checking: false,
+            checked: false,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Ask the editor to check with the folder and keys typed in
+    pub fn request_check(&mut self, cx: &mut Context<Self>) {
+        let dir = self.dir_input.read(cx).text().trim().to_string();
+        let keys = self
+            .keys_input
+            .read(cx)
+            .text()
+            .split(',')
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty())
+            .collect();
+        cx.emit(ConfigCheckPanelEvent::CheckRequested { dir, keys });
+    }
+
+    pub fn begin(&mut self, root: PathBuf, cx: &mut Context<Self>) {
+        self.root = root;
+        self.results.clear();
+        self.checking = true;
+        self.checked = false;
+        cx.notify();
+    }
+
+    pub fn finish(&mut self, results: Vec<ConfigFileCheck>, cx: &mut Context<Self>) {
+        self.results = results;
+        self.checking = false;
+        self.checked = true;
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for ConfigCheckPanel {}
+impl EventEmitter<ConfigCheckPanelEvent> for ConfigCheckPanel {}
+
+impl Render for ConfigCheckPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let problems: usize = self.results.iter().map(ConfigFileCheck::problems).sum();
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                v_flex()
+                    .w_full()
+                    .p_2()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(TextInput::new(&self.dir_input))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(TextInput::new(&self.keys_input).flex_1())
+                            .child(
+                                Button::new("config-check")
+                                    .primary()
+                                    .with_size(ui::Size::Small)
+                                    .label("Check Configs")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.request_check(cx);
+                                    }))
+                            )
+                    )
+                    .when(self.checking, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Checking…")
+                        )
+                    })
+                    .when(self.checked, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(if problems > 0 { cx.theme().warning } else { cx.theme().muted_foreground })
+                                .child(format!("{} files, {} problems", self.results.len(), problems))
+                        )
+                    })
+            )
+            .child(
+                v_flex()
+                    .id("enum-config-check-content")
+                    .flex_1()
+                    .p_2()
+                    .gap_2()
+                    .overflow_scroll()
+                    .when(!self.checked && !self.checking, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Find unknown and deprecated values of this enum in JSON and RON config files")
+                        )
+                    })
+                    .when(self.checked && problems == 0, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().success)
+                                .child("No problems found")
+                        )
+                    })
+                    .children(self.results.iter().filter(|file| file.problems() > 0).map(|file| {
+                        let path = file.path.strip_prefix(&self.root).unwrap_or(&file.path);
+                        let findings: Vec<(Severity, String)> = match &file.result {
+                            Ok(findings) => findings
+                                .iter()
+                                .map(|finding| (finding.severity, format!("{}: {}", finding.location, finding.message)))
+                                .collect(),
+                            Err(message) => vec![(Severity::Error, message.clone())],
+                        };
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(path.display().to_string())
+                            )
+                            .children(findings.into_iter().map(|(severity, message)| {
+                                let color = match severity {
+                                    Severity::Error => cx.theme().danger,
+                                    Severity::Warning => cx.theme().warning,
+                                    Severity::Info => cx.theme().muted_foreground,
+                                };
+                                div()
+                                    .pl_3()
+                                    .text_xs()
+                                    .text_color(color)
+                                    .child(message)
+                            }))
+                    }))
+            )
+    }
+}
+
+impl Focusable for ConfigCheckPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for ConfigCheckPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_config_check"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Config Check".into_any_element()
+    }
+}
@@ -0,0 +1,126 @@
+//! Docs panel: the enum and variant documentation rendered as markdown
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{v_flex, h_flex, ActiveTheme, StyledExt, dock::{Panel, PanelEvent}};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::metadata::EnumMeta;
+use crate::markdown;
+
+/// Docs Panel - The enum and variant documentation rendered as markdown
+pub struct DocsPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    focus_handle: FocusHandle,
+}
+
+impl DocsPanel {
+    pub fn new(asset: Arc<parking_lot::RwLock<EnumAsset>>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            asset,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+}
+
+impl EventEmitter<PanelEvent> for DocsPanel {}
+
+impl Render for DocsPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let asset = self.asset.read();
+        let meta = EnumMeta::from_asset(&asset);
+        let documented = asset.variants.iter().filter(|v| v.doc.as_deref().is_some_and(|d| !d.trim().is_empty())).count();
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Documentation")
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{} of {} variants documented", documented, asset.variants.len()))
+                    )
+            )
+            .child(
+                v_flex()
+                    .id("enum-docs-content")
+                    .flex_1()
+                    .p_4()
+                    .gap_4()
+                    .overflow_scroll()
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(asset.display_name.clone())
+                    )
+                    .when_some(asset.description.clone().filter(|d| !d.trim().is_empty()), |this, description| {
+                        this.child(markdown::render(&description, cx))
+                    })
+                    .children(asset.variants.iter().map(|variant| {
+                        let display_name = Some(meta.display_name(&variant.name)).filter(|name| *name != variant.name);
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .items_center()
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(variant.name.clone())
+                                    )
+                                    .when_some(display_name, |this, display_name| {
+                                        this.child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(display_name)
+                                        )
+                                    })
+                            )
+                            .child(match variant.doc.as_deref().filter(|d| !d.trim().is_empty()) {
+                                Some(doc) => markdown::render(doc, cx),
+                                None => div()
+                                    .text_xs()
+                                    .text_color(if meta.require_docs { cx.theme().warning } else { cx.theme().muted_foreground })
+                                    .child("Undocumented"),
+                            })
+                    }))
+            )
+    }
+}
+
+impl Focusable for DocsPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for DocsPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_docs"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Docs".into_any_element()
+    }
+}
@@ -0,0 +1,157 @@
+//! Drift panel: differences from the enum's hand-written Rust declaration
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::drift::Drift;
+use crate::metadata::EnumMeta;
+
+/// Requests from the Drift Panel that the owning editor handles. Each carries
+/// the path of the Rust file as entered.
+#[derive(Clone, Debug)]
+pub enum DriftPanelEvent {
+    CheckRequested(String),
+    /// Make the definition match the Rust declaration
+    UpdateDefinitionRequested(String),
+    /// Rewrite the Rust declaration's variants from the definition
+    UpdateSourceRequested(String),
+}
+
+/// Drift Panel - Differences from the enum's hand-written Rust declaration
+pub struct DriftPanel {
+    path_input: Entity<InputState>,
+    /// Result of the last check
+    result: Option<Result<Vec<Drift>, String>>,
+    focus_handle: FocusHandle,
+}
+
+impl DriftPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let path = EnumMeta::from_asset(&asset.read()).rust_source.unwrap_or_default();
+        let path_input = cx.new(|cx| InputState::new(window, cx).placeholder("../src/spells.rs"));
+        path_input.update(cx, |input, cx| input.replace_text_in_range(None, &path, window, cx));
+        Self {
+            path_input,
+            result: None,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_result(&mut self, result: Result<Vec<Drift>, String>, cx: &mut Context<Self>) {
+        self.result = Some(result);
+        cx.notify();
+    }
+
+    fn path(&self, cx: &App) -> Option<String> {
+        let path = self.path_input.read(cx).text().trim().to_string();
+        (!path.is_empty()).then_some(path)
+    }
+}
+
+impl EventEmitter<PanelEvent> for DriftPanel {}
+impl EventEmitter<DriftPanelEvent> for DriftPanel {}
+
+impl Render for DriftPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let drift = match &self.result {
+            Some(Ok(drift)) => drift.clone(),
+            _ => Vec::new(),
+        };
+
+        v_flex()
+            .id("enum-drift-content")
+            .size_full()
+            .p_4()
+            .gap_4()
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(div().flex_1().child(TextInput::new(&self.path_input)))
+                    .child(
+                        Button::new("drift-check")
+                            .primary()
+                            .label("Check")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                if let Some(path) = this.path(cx) {
+                                    cx.emit(DriftPanelEvent::CheckRequested(path));
+                                }
+                            }))
+                    )
+            )
+            .child(match &self.result {
+                None => div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Path of the Rust file declaring the enum, relative to the definition's folder"),
+                Some(Err(message)) => div().text_sm().text_color(cx.theme().danger).child(message.clone()),
+                Some(Ok(drift)) if drift.is_empty() => {
+                    div().text_sm().text_color(cx.theme().success).child("The source matches the definition")
+                }
+                Some(Ok(drift)) => div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().warning)
+                    .child(format!("{} difference(s)", drift.len())),
+            })
+            .children(drift.iter().map(|drift| {
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(drift.describe().replace('`', ""))
+            }))
+            .when(!drift.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new("drift-update-definition")
+                                .label("Update Definition")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    if let Some(path) = this.path(cx) {
+                                        cx.emit(DriftPanelEvent::UpdateDefinitionRequested(path));
+                                    }
+                                }))
+                        )
+                        .child(
+                            Button::new("drift-update-source")
+                                .danger()
+                                .label("Update Source")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    if let Some(path) = this.path(cx) {
+                                        cx.emit(DriftPanelEvent::UpdateSourceRequested(path));
+                                    }
+                                }))
+                        )
+                )
+            })
+    }
+}
+
+impl Focusable for DriftPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for DriftPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_drift"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Drift".into_any_element()
+    }
+}
@@ -0,0 +1,224 @@
+//! Find & Replace panel: search over variant names, docs and display names
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::find_replace::{self, FindOptions, MatchField, Replacement};
+
+/// Requests from the Find & Replace Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum FindReplacePanelEvent {
+    ApplyRequested(Vec<Replacement>),
+    UndoRequested,
+    /// A match was clicked; show its variant
+    RevealRequested(usize),
+}
+
+/// Find & Replace Panel - Search variant names, docs and display names
+pub struct FindReplacePanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    find_input: Entity<InputState>,
+    replace_input: Entity<InputState>,
+    options: FindOptions,
+    matches: Result<Vec<Replacement>, String>,
+    focus_handle: FocusHandle,
+    _subscriptions: Vec<gpui::Subscription>,
+}
+
+impl FindReplacePanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let find_input = cx.new(|cx| InputState::new(window, cx).placeholder("Find..."));
+        let replace_input = cx.new(|cx| InputState::new(window, cx).placeholder("Replace with..."));
+
+        let subscriptions = [&find_input, &replace_input]
+            .into_iter()
+            .map(|input| {
+                cx.subscribe_in(input, window, |this, _state, event: &ui::input::InputEvent, _window, cx| {
+                    if let ui::input::InputEvent::Change = event {
+                        this.refresh(cx);
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            asset,
+            find_input,
+            replace_input,
+            options: FindOptions::default(),
+            matches: Ok(Vec::new()),
+            focus_handle: cx.focus_handle(),
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Recompute the preview from the inputs and the current asset
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.options.pattern = self.find_input.read(cx).text().to_string();
+        self.options.replacement = self.replace_input.read(cx).text().to_string();
+        self.matches = find_replace::find(&self.asset.read(), &self.options);
+        cx.notify();
+    }
+
+    fn toggle_field(&mut self, field: MatchField, cx: &mut Context<Self>) {
+        if let Some(position) = self.options.fields.iter().position(|f| *f == field) {
+            self.options.fields.remove(position);
+        } else {
+            self.options.fields.push(field);
+        }
+        self.refresh(cx);
+    }
+
+    fn apply(&mut self, cx: &mut Context<Self>) {
+        if let Ok(matches) = &self.matches {
+            if !matches.is_empty() {
+                cx.emit(FindReplacePanelEvent::ApplyRequested(matches.clone()));
+            }
+        }
+    }
+}
+
+impl EventEmitter<PanelEvent> for FindReplacePanel {}
+impl EventEmitter<FindReplacePanelEvent> for FindReplacePanel {}
+
+impl Render for FindReplacePanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let match_count = self.matches.as_ref().map(Vec::len).unwrap_or(0);
+
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .bg(cx.theme().sidebar)
+            .child(TextInput::new(&self.find_input))
+            .child(TextInput::new(&self.replace_input))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .child(
+                        Button::new("find-regex")
+                            .with_size(ui::Size::Small)
+                            .when(self.options.regex, |this| this.primary())
+                            .label(".*")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.options.regex = !this.options.regex;
+                                this.refresh(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("find-case")
+                            .with_size(ui::Size::Small)
+                            .when(self.options.case_sensitive, |this| this.primary())
+                            .label("Aa")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.options.case_sensitive = !this.options.case_sensitive;
+                                this.refresh(cx);
+                            }))
+                    )
+                    .children(MatchField::ALL.into_iter().map(|field| {
+                        Button::new(SharedString::from(format!("find-field-{}", field.label())))
+                            .with_size(ui::Size::Small)
+                            .when(self.options.fields.contains(&field), |this| this.primary())
+                            .label(field.label())
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.toggle_field(field, cx);
+                            }))
+                    }))
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("replace-all")
+                            .primary()
+                            .label(format!("Replace All ({})", match_count))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.apply(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("replace-undo")
+                            .ghost()
+                            .label("Undo")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(FindReplacePanelEvent::UndoRequested);
+                            }))
+                    )
+            )
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .id("find-replace-matches")
+                    .flex_1()
+                    .gap_1()
+                    .overflow_scroll()
+                    .map(|this| match &self.matches {
+                        Err(e) => this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().danger)
+                                .child(e.clone())
+                        ),
+                        Ok(matches) => this.children(matches.iter().enumerate().map(|(match_index, replacement)| {
+                            let variant_index = replacement.variant_index;
+                            v_flex()
+                                .id(("find-replace-match", match_index))
+                                .cursor_pointer()
+                                .on_click(cx.listener(move |_this, _, _window, cx| {
+                                    cx.emit(FindReplacePanelEvent::RevealRequested(variant_index));
+                                }))
+                                .p_2()
+                                .gap_1()
+                                .rounded(px(4.0))
+                                .bg(cx.theme().secondary.opacity(0.2))
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(format!("{} · {}", replacement.variant_name, replacement.field.label()))
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().danger)
+                                        .child(format!("- {}", replacement.before))
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().success)
+                                        .child(format!("+ {}", replacement.after))
+                                )
+                        })),
+                    })
+            )
+    }
+}
+
+impl Focusable for FindReplacePanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for FindReplacePanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_find_replace"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Find & Replace".into_any_element()
+    }
+}
@@ -0,0 +1,164 @@
+//! Generate Report panel: results of the last Generate All
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+};
+use std::collections::HashSet;
+use crate::codegen::{FileChange, TargetReport};
+use super::render_diff;
+
+/// Generate Report Panel - Results of the last "Generate All"
+pub struct GenerateReportPanel {
+    reports: Vec<TargetReport>,
+    /// Reports whose diff is shown
+    expanded: HashSet<usize>,
+    focus_handle: FocusHandle,
+}
+
+impl GenerateReportPanel {
+    pub fn new(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            reports: Vec::new(),
+            expanded: HashSet::new(),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_reports(&mut self, reports: Vec<TargetReport>, cx: &mut Context<Self>) {
+        self.reports = reports;
+        self.expanded.clear();
+        cx.notify();
+    }
+
+    fn toggle_expanded(&mut self, index: usize, cx: &mut Context<Self>) {
+        if !self.expanded.remove(&index) {
+            self.expanded.insert(index);
+        }
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for GenerateReportPanel {}
+
+impl Render for GenerateReportPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let failed = self.reports.iter().filter(|r| r.result.is_err()).count();
+        let written = self
+            .reports
+            .iter()
+            .filter(|r| matches!(r.result, Ok(FileChange::Created | FileChange::Changed { .. })))
+            .count();
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Generate All")
+                    )
+                    .when(!self.reports.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(if failed > 0 { cx.theme().danger } else { cx.theme().muted_foreground })
+                                .child(format!("{} written, {} failed", written, failed))
+                        )
+                    })
+            )
+            .child(
+                v_flex()
+                    .id("enum-generate-report-content")
+                    .flex_1()
+                    .p_2()
+                    .gap_2()
+                    .overflow_scroll()
+                    .when(self.reports.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Enable targets under Codegen Targets in Properties, then run Generate All")
+                        )
+                    })
+                    .children(self.reports.iter().enumerate().map(|(index, report)| {
+                        let (status, color) = match &report.result {
+                            Ok(change) => (change.summary(), cx.theme().foreground),
+                            Err(message) => (message.clone(), cx.theme().danger),
+                        };
+                        let diff = match &report.result {
+                            Ok(FileChange::Changed { diff, .. }) if !diff.is_empty() => Some(diff.clone()),
+                            _ => None,
+                        };
+                        let expanded = self.expanded.contains(&index);
+
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .text_xs()
+                                    .items_center()
+                                    .child(
+                                        div()
+                                            .w(px(96.0))
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(report.target.label())
+                                    )
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(report.path.display().to_string())
+                                    )
+                                    .child(div().text_color(color).child(status))
+                                    .when(diff.is_some(), |this| {
+                                        this.child(
+                                            Button::new(("toggle-report-diff", index))
+                                                .ghost()
+                                                .with_size(ui::Size::XSmall)
+                                                .label(if expanded { "Hide Diff" } else { "Diff" })
+                                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                                    this.toggle_expanded(index, cx);
+                                                }))
+                                        )
+                                    })
+                            )
+                            .when_some(diff.filter(|_| expanded), |this, diff| {
+                                this.child(render_diff(&diff, cx))
+                            })
+                    }))
+            )
+    }
+}
+
+impl Focusable for GenerateReportPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for GenerateReportPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_generate_report"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Generate Report".into_any_element()
+    }
+}
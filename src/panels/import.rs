@@ -0,0 +1,163 @@
+//! Import panel: enums read from Rust, C/C++, protobuf, TypeScript or JSON
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use crate::import::SourceEnum;
+
+/// Requests from the Import Panel that the owning editor handles. Paths are
+/// as entered.
+#[derive(Clone, Debug)]
+pub enum ImportPanelEvent {
+    /// List the enums declared in a file; answered with `set_enums`
+    ScanRequested(String),
+    /// Merge one of them into the open definition
+    ImportRequested { path: String, source: SourceEnum },
+    /// Write enums as new `.enum` folders next to the open definition
+    CreateRequested(Vec<SourceEnum>),
+}
+
+/// Import Panel - Bring enums declared in Rust, C/C++, protobuf, TypeScript or
+/// JSON source into the definition or into new definitions
+pub struct ImportPanel {
+    path_input: Entity<InputState>,
+    /// Path the listed enums were read from
+    scanned: String,
+    /// Enums declared in the scanned file, or why it couldn't be read
+    enums: Option<Result<Vec<SourceEnum>, String>>,
+    focus_handle: FocusHandle,
+}
+
+impl ImportPanel {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            path_input: cx.new(|cx| InputState::new(window, cx).placeholder("../sdk/include/spells.h")),
+            scanned: String::new(),
+            enums: None,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_enums(&mut self, path: String, enums: Result<Vec<SourceEnum>, String>, cx: &mut Context<Self>) {
+        self.scanned = path;
+        self.enums = Some(enums);
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for ImportPanel {}
+impl EventEmitter<ImportPanelEvent> for ImportPanel {}
+
+impl Render for ImportPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .id("enum-import-content")
+            .size_full()
+            .p_4()
+            .gap_3()
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(div().flex_1().child(TextInput::new(&self.path_input)))
+                    .child(
+                        Button::new("import-scan")
+                            .label("Scan")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                let path = this.path_input.read(cx).text().trim().to_string();
+                                if !path.is_empty() {
+                                    cx.emit(ImportPanelEvent::ScanRequested(path));
+                                }
+                            }))
+                    )
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Rust (.rs), C/C++ (.h, .hpp), protobuf (.proto), TypeScript (.ts) or JSON (.json) file, relative to the definition's folder. Importing replaces the variants; ones missing from the source go to the trash.")
+            )
+            .when_some(self.enums.as_ref(), |this, enums| match enums {
+                Err(message) => this.child(div().text_sm().text_color(cx.theme().danger).child(message.clone())),
+                Ok(enums) if enums.is_empty() => this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("No enums are declared in this file")
+                ),
+                Ok(enums) => this
+                    .child(
+                        Button::new("import-create-all")
+                            .with_size(ui::Size::Small)
+                            .label(format!("Create {} Definitions", enums.len()))
+                            .on_click({
+                                let enums = enums.clone();
+                                cx.listener(move |_this, _, _window, cx| {
+                                    cx.emit(ImportPanelEvent::CreateRequested(enums.clone()));
+                                })
+                            })
+                    )
+                    .children(enums.iter().enumerate().map(|(index, source)| {
+                    let event = ImportPanelEvent::ImportRequested { path: self.scanned.clone(), source: source.clone() };
+                    let create = ImportPanelEvent::CreateRequested(vec![source.clone()]);
+                    h_flex()
+                        .gap_2()
+                        .items_center()
+                        .child(
+                            v_flex()
+                                .flex_1()
+                                .child(div().text_sm().font_semibold().text_color(cx.theme().foreground).child(source.name.clone()))
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(match &source.repr {
+                                            Some(repr) => format!("{} variants, {}", source.variants.len(), repr),
+                                            None => format!("{} variants", source.variants.len()),
+                                        })
+                                )
+                        )
+                        .child(
+                            Button::new(("import-create", index))
+                                .ghost()
+                                .with_size(ui::Size::Small)
+                                .label("New Definition")
+                                .on_click(cx.listener(move |_this, _, _window, cx| {
+                                    cx.emit(create.clone());
+                                }))
+                        )
+                        .child(
+                            Button::new(("import-enum", index))
+                                .primary()
+                                .with_size(ui::Size::Small)
+                                .label("Import")
+                                .on_click(cx.listener(move |_this, _, _window, cx| {
+                                    cx.emit(event.clone());
+                                }))
+                        )
+                })),
+            })
+    }
+}
+
+impl Focusable for ImportPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for ImportPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_import"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Import".into_any_element()
+    }
+}
@@ -0,0 +1,241 @@
+//! Inspector panel: the fields of the variants selected in the compact list
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use crate::metadata::{BatchEdit, Stage, VariantMeta};
+use crate::variant_editor::VariantEditorView;
+
+/// Requests from the Inspector Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum InspectorPanelEvent {
+    /// Make the same change to every named variant
+    BatchEditRequested { names: Vec<String>, edit: BatchEdit },
+}
+
+/// The value all items share, if they do
+fn common<T: PartialEq>(mut values: impl Iterator<Item = T>) -> Option<T> {
+    let first = values.next()?;
+    values.all(|value| value == first).then_some(first)
+}
+
+/// Inspector Panel - Every field of the variant selected in the compact
+/// Variants list, or the fields several selected variants share. The editor
+/// shown belongs to the Variants panel, which handles its edits.
+pub struct InspectorPanel {
+    variants: Vec<WeakEntity<VariantEditorView>>,
+    cfg_input: Entity<InputState>,
+    owner_input: Entity<InputState>,
+    focus_handle: FocusHandle,
+}
+
+impl InspectorPanel {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            variants: Vec::new(),
+            cfg_input: cx.new(|cx| InputState::new(window, cx).placeholder("feature = \"debug_tools\"")),
+            owner_input: cx.new(|cx| InputState::new(window, cx).placeholder("Owner")),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_variants(&mut self, variants: Vec<Entity<VariantEditorView>>, cx: &mut Context<Self>) {
+        self.variants = variants.iter().map(Entity::downgrade).collect();
+        cx.notify();
+    }
+
+    fn request(&mut self, edit: BatchEdit, cx: &mut Context<Self>) {
+        let names: Vec<String> = self
+            .variants
+            .iter()
+            .filter_map(WeakEntity::upgrade)
+            .map(|editor| editor.read(cx).variant.name.clone())
+            .collect();
+        if !names.is_empty() {
+            cx.emit(InspectorPanelEvent::BatchEditRequested { names, edit });
+        }
+    }
+
+    /// Set the field from its input on every selected variant; an empty
+    /// input clears it
+    fn apply_input(&mut self, cfg: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let input = if cfg { self.cfg_input.clone() } else { self.owner_input.clone() };
+        let text = input.read(cx).text().trim().to_string();
+        let value = (!text.is_empty()).then_some(text);
+        self.request(if cfg { BatchEdit::Cfg(value) } else { BatchEdit::Owner(value) }, cx);
+        input.update(cx, |input, cx| input.replace_text_in_range(None, "", window, cx));
+    }
+
+    /// Common value of a text field, "Mixed" or "None"
+    fn common_text(values: impl Iterator<Item = Option<String>>) -> String {
+        match common(values) {
+            Some(Some(value)) => value,
+            Some(None) => "None".to_string(),
+            None => "Mixed".to_string(),
+        }
+    }
+
+    fn field(label: &str, current: String, cx: &App) -> Div {
+        h_flex()
+            .gap_2()
+            .child(div().text_sm().font_semibold().text_color(cx.theme().foreground).child(label.to_string()))
+            .child(div().text_xs().text_color(cx.theme().muted_foreground).child(current))
+    }
+
+    /// Fields shared by several selected variants, set on all of them at once
+    fn batch_view(&self, variants: &[Entity<VariantEditorView>], cx: &mut Context<Self>) -> Div {
+        let metas: Vec<&VariantMeta> = variants.iter().map(|editor| &editor.read(cx).meta).collect();
+        let deprecated = common(metas.iter().map(|meta| meta.deprecated));
+        let stage = common(metas.iter().map(|meta| meta.stage));
+        let cfg = Self::common_text(metas.iter().map(|meta| meta.cfg.clone()));
+        let owner = Self::common_text(metas.iter().map(|meta| meta.owner.clone()));
+
+        v_flex()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(format!("{} variants selected", variants.len()))
+            )
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(Self::field(
+                        "Deprecated",
+                        match deprecated {
+                            Some(true) => "Yes".to_string(),
+                            Some(false) => "No".to_string(),
+                            None => "Mixed".to_string(),
+                        },
+                        cx,
+                    ))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                Button::new("batch-deprecate")
+                                    .with_size(ui::Size::Small)
+                                    .label("Deprecate")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.request(BatchEdit::Deprecated(true), cx);
+                                    }))
+                            )
+                            .child(
+                                Button::new("batch-undeprecate")
+                                    .with_size(ui::Size::Small)
+                                    .label("Undeprecate")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.request(BatchEdit::Deprecated(false), cx);
+                                    }))
+                            )
+                    )
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(Self::field("Stage", stage.map_or("Mixed", |stage| stage.label()).to_string(), cx))
+                    .child(h_flex().gap_2().children(Stage::ALL.into_iter().map(|option| {
+                        Button::new(SharedString::from(format!("batch-stage-{}", option.label())))
+                            .with_size(ui::Size::Small)
+                            .when(stage == Some(option), |this| this.primary())
+                            .label(option.label())
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.request(BatchEdit::Stage(option), cx);
+                            }))
+                    })))
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(Self::field("cfg", cfg, cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(div().flex_1().child(TextInput::new(&self.cfg_input)))
+                            .child(
+                                Button::new("batch-cfg")
+                                    .with_size(ui::Size::Small)
+                                    .label("Apply")
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.apply_input(true, window, cx);
+                                    }))
+                            )
+                    )
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(Self::field("Owner", owner, cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(div().flex_1().child(TextInput::new(&self.owner_input)))
+                            .child(
+                                Button::new("batch-owner")
+                                    .with_size(ui::Size::Small)
+                                    .label("Apply")
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.apply_input(false, window, cx);
+                                    }))
+                            )
+                    )
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Applying an empty cfg or owner clears it")
+            )
+    }
+}
+
+impl EventEmitter<PanelEvent> for InspectorPanel {}
+impl EventEmitter<InspectorPanelEvent> for InspectorPanel {}
+
+impl Render for InspectorPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // The Variants panel drops its editors when it rebuilds the list
+        let variants: Vec<Entity<VariantEditorView>> = self.variants.iter().filter_map(WeakEntity::upgrade).collect();
+
+        v_flex()
+            .id("enum-inspector-content")
+            .size_full()
+            .p_3()
+            .overflow_y_scroll()
+            .bg(cx.theme().sidebar)
+            .map(|this| match variants.as_slice() {
+                [] => this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("Select a variant in the compact Variants list to edit it here. Ctrl-click (Cmd-click on macOS) or Shift-click to select several.")
+                ),
+                [variant] => this.child(variant.clone()),
+                variants => this.child(self.batch_view(variants, cx)),
+            })
+    }
+}
+
+impl Focusable for InspectorPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for InspectorPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_inspector"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Inspector".into_any_element()
+    }
+}
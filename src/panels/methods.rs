@@ -0,0 +1,219 @@
+//! Methods panel: helper methods emitted in the enum's `impl` block
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::metadata::{EnumMeta, MethodSnippet};
+
+/// Requests from the Methods Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum MethodsPanelEvent {
+    /// Replace the enum's methods
+    MethodsChanged(Vec<MethodSnippet>),
+}
+
+/// Methods Panel - Helper methods emitted in the enum's `impl` block
+pub struct MethodsPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    /// Method loaded into the inputs; `None` while writing a new one
+    editing: Option<usize>,
+    signature_input: Entity<InputState>,
+    body_input: Entity<InputState>,
+    doc_input: Entity<InputState>,
+    focus_handle: FocusHandle,
+}
+
+impl MethodsPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let signature_input = cx.new(|cx| InputState::new(window, cx).placeholder("pub fn is_magic(&self) -> bool"));
+        let body_input = cx.new(|cx| InputState::new(window, cx).multi_line().placeholder("matches!(self, Self::Fireball | Self::Frostbolt)"));
+        let doc_input = cx.new(|cx| InputState::new(window, cx).placeholder("Documentation..."));
+        Self {
+            asset,
+            editing: None,
+            signature_input,
+            body_input,
+            doc_input,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    fn methods(&self) -> Vec<MethodSnippet> {
+        EnumMeta::from_asset(&self.asset.read()).methods
+    }
+
+    /// Load a method into the inputs, or clear them for a new one
+    fn edit(&mut self, index: Option<usize>, window: &mut Window, cx: &mut Context<Self>) {
+        let method = index.and_then(|index| self.methods().get(index).cloned()).unwrap_or_default();
+        self.editing = index;
+        let doc = method.doc.unwrap_or_default();
+        for (input, text) in [(&self.signature_input, &method.signature), (&self.body_input, &method.body), (&self.doc_input, &doc)] {
+            input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, text, window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    fn save(&mut self, cx: &mut Context<Self>) {
+        let doc = self.doc_input.read(cx).text().trim().to_string();
+        let method = MethodSnippet {
+            signature: self.signature_input.read(cx).text().trim().to_string(),
+            body: self.body_input.read(cx).text().to_string(),
+            doc: (!doc.is_empty()).then_some(doc),
+        };
+        if method.signature.is_empty() {
+            return;
+        }
+        let mut methods = self.methods();
+        match self.editing.filter(|index| *index < methods.len()) {
+            Some(index) => methods[index] = method,
+            None => {
+                methods.push(method);
+                self.editing = Some(methods.len() - 1);
+            }
+        }
+        cx.emit(MethodsPanelEvent::MethodsChanged(methods));
+        cx.notify();
+    }
+
+    fn remove(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let mut methods = self.methods();
+        if index >= methods.len() {
+            return;
+        }
+        methods.remove(index);
+        cx.emit(MethodsPanelEvent::MethodsChanged(methods));
+        if self.editing == Some(index) {
+            self.edit(None, window, cx);
+        }
+    }
+}
+
+impl EventEmitter<PanelEvent> for MethodsPanel {}
+impl EventEmitter<MethodsPanelEvent> for MethodsPanel {}
+
+impl Render for MethodsPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let methods = self.methods();
+
+        v_flex()
+            .id("enum-methods-content")
+            .size_full()
+            .p_4()
+            .gap_4()
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            // Existing methods
+            .child(
+                v_flex()
+                    .gap_1()
+                    .when(methods.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No methods yet")
+                        )
+                    })
+                    .children(methods.iter().enumerate().map(|(i, method)| {
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                Button::new(("edit-method", i))
+                                    .ghost()
+                                    .when(self.editing == Some(i), |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(method.name().unwrap_or("(unnamed)").to_string())
+                                    .on_click(cx.listener(move |this, _, window, cx| {
+                                        this.edit(Some(i), window, cx);
+                                    }))
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(method.signature.clone())
+                            )
+                            .child(
+                                Button::new(("remove-method", i))
+                                    .ghost()
+                                    .with_size(ui::Size::XSmall)
+                                    .icon(IconName::Delete)
+                                    .tooltip("Remove method")
+                                    .on_click(cx.listener(move |this, _, window, cx| {
+                                        this.remove(i, window, cx);
+                                    }))
+                            )
+                    }))
+            )
+            // Editor for the selected or new method
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(if self.editing.is_some() { "Edit Method" } else { "New Method" })
+                    )
+                    .child(TextInput::new(&self.doc_input))
+                    .child(TextInput::new(&self.signature_input))
+                    .child(TextInput::new(&self.body_input).h(px(160.0)))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                Button::new("save-method")
+                                    .primary()
+                                    .label(if self.editing.is_some() { "Save" } else { "Add Method" })
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.save(cx);
+                                    }))
+                            )
+                            .when(self.editing.is_some(), |this| {
+                                this.child(
+                                    Button::new("new-method")
+                                        .ghost()
+                                        .label("New")
+                                        .icon(IconName::Plus)
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.edit(None, window, cx);
+                                        }))
+                                )
+                            })
+                    )
+            )
+    }
+}
+
+impl Focusable for MethodsPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for MethodsPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_methods"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Methods".into_any_element()
+    }
+}
@@ -0,0 +1,62 @@
+//! Panels of the enum editor's dock workspace, one module per panel
+
+mod changelog;
+mod code_preview;
+mod config_check;
+mod docs;
+mod drift;
+mod find_replace;
+mod generate_report;
+mod import;
+mod inspector;
+mod methods;
+mod output;
+mod properties;
+mod renumber;
+mod review;
+mod samples;
+mod settings;
+mod type_rename;
+mod validation_report;
+mod variants;
+
+pub use changelog::{ChangelogPanel, ChangelogPanelEvent};
+pub use code_preview::{CodePreviewPanel, CodePreviewPanelEvent};
+pub use config_check::{ConfigCheckPanel, ConfigCheckPanelEvent};
+pub use docs::DocsPanel;
+pub use drift::{DriftPanel, DriftPanelEvent};
+pub use find_replace::{FindReplacePanel, FindReplacePanelEvent};
+pub use generate_report::GenerateReportPanel;
+pub use import::{ImportPanel, ImportPanelEvent};
+pub use inspector::{InspectorPanel, InspectorPanelEvent};
+pub use methods::{MethodsPanel, MethodsPanelEvent};
+pub use output::OutputPanel;
+pub use properties::{PropertiesPanel, PropertiesPanelEvent};
+pub use renumber::{RenumberPanel, RenumberPanelEvent};
+pub use review::{ReviewPanel, ReviewPanelEvent};
+pub use samples::SamplesPanel;
+pub use settings::{SettingsPanel, SettingsPanelEvent};
+pub use type_rename::{TypeRenamePanel, TypeRenamePanelEvent};
+pub use validation_report::{ValidationReportPanel, ValidationReportPanelEvent};
+pub use variants::{bind_keys, VariantsPanel, VariantsPanelEvent};
+
+use gpui::*;
+use ui::{v_flex, ActiveTheme};
+
+/// Unified diff with added and removed lines colored
+pub(crate) fn render_diff(diff: &str, cx: &App) -> Div {
+    v_flex()
+        .p_2()
+        .rounded(px(4.0))
+        .bg(cx.theme().background)
+        .text_xs()
+        .children(diff.lines().map(|line| {
+            let color = match line.chars().next() {
+                Some('+') => cx.theme().success,
+                Some('-') => cx.theme().danger,
+                Some('@') => cx.theme().muted_foreground,
+                _ => cx.theme().foreground,
+            };
+            div().text_color(color).child(line.to_string())
+        }))
+}
@@ -0,0 +1,148 @@
+//! Output panel: plugin log lines, hook output, validation and export results
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+};
+use futures::StreamExt;
+use crate::output::{OutputLog, Severity};
+
+/// Output Panel - Plugin log lines, hook output, validation and export results
+pub struct OutputPanel {
+    log: OutputLog,
+    min_severity: Severity,
+    focus_handle: FocusHandle,
+    _log_changes: Task<()>,
+}
+
+impl OutputPanel {
+    pub fn new(log: OutputLog, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let mut changes = log.subscribe();
+        // Entries arrive from background work too, so redraw on every change
+        let log_changes = cx.spawn(async move |this, cx| {
+            while changes.next().await.is_some() {
+                if this.update(cx, |_, cx| cx.notify()).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            log,
+            min_severity: Severity::Info,
+            focus_handle: cx.focus_handle(),
+            _log_changes: log_changes,
+        }
+    }
+
+    fn set_min_severity(&mut self, severity: Severity, cx: &mut Context<Self>) {
+        self.min_severity = severity;
+        cx.notify();
+    }
+
+    fn clear(&mut self, cx: &mut Context<Self>) {
+        self.log.clear();
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for OutputPanel {}
+
+impl Render for OutputPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let entries = self.log.entries(self.min_severity);
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .gap_2()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(format!("Output ({})", entries.len()))
+                    )
+                    .children(Severity::ALL.into_iter().map(|severity| {
+                        Button::new(SharedString::from(format!("output-filter-{}", severity.label())))
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .when(self.min_severity == severity, |this| this.primary())
+                            .label(match severity {
+                                Severity::Info => "All",
+                                Severity::Warning => "Warnings",
+                                Severity::Error => "Errors",
+                            })
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.set_min_severity(severity, cx);
+                            }))
+                    }))
+                    .child(
+                        Button::new("output-clear")
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .icon(IconName::Delete)
+                            .tooltip("Clear output")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.clear(cx);
+                            }))
+                    )
+            )
+            .child(
+                v_flex()
+                    .id("enum-output-content")
+                    .flex_1()
+                    .p_2()
+                    .gap_1()
+                    .overflow_scroll()
+                    .children(entries.into_iter().map(|entry| {
+                        let color = match entry.severity {
+                            Severity::Info => cx.theme().foreground,
+                            Severity::Warning => cx.theme().warning,
+                            Severity::Error => cx.theme().danger,
+                        };
+                        h_flex()
+                            .gap_2()
+                            .text_xs()
+                            .child(
+                                div()
+                                    .w(px(72.0))
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(entry.source.label())
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_color(color)
+                                    .child(entry.message)
+                            )
+                    }))
+            )
+    }
+}
+
+impl Focusable for OutputPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for OutputPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_output"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Output".into_any_element()
+    }
+}
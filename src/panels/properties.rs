@@ -0,0 +1,1444 @@
+//! Properties panel: the enum's name, docs, derives, serde representation
+//! and codegen targets
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use ui_types_common::{EnumAsset, Visibility};
+use std::sync::Arc;
+use crate::codegen::{self, CodegenTarget, KeywordCollision, MarkdownColumn};
+use crate::expr;
+use crate::metadata::{
+    values_ascend, AssociatedConst, BuildProfile, EnumMeta, EnumMode, OrderBy, SerdeRepr, TargetConfig,
+};
+use crate::naming::DisplayStyle;
+use crate::samples;
+use crate::output::Severity;
+use crate::project::Density;
+use crate::storage::{EnumFileFormat, SerializationFormat};
+use crate::type_check;
+use crate::validation;
+
+/// Requests from the Properties Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum PropertiesPanelEvent {
+    ConvertFormatRequested,
+    ModeChanged(EnumMode),
+    SerializationChangeRequested(SerializationFormat),
+    /// Derives, the default variant or the serde representation changed, so
+    /// validation is stale
+    DerivesChanged,
+    /// Metadata of a variant was edited here, so the variant editors are stale
+    VariantMetaChanged,
+}
+
+/// Properties Panel - Edit enum metadata (name, display name, description, visibility)
+pub struct PropertiesPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    name_input: Entity<InputState>,
+    display_name_input: Entity<InputState>,
+    description_input: Entity<InputState>,
+    category_input: Entity<InputState>,
+    constant_name_input: Entity<InputState>,
+    constant_value_input: Entity<InputState>,
+    associated_name_input: Entity<InputState>,
+    associated_type_input: Entity<InputState>,
+    associated_value_input: Entity<InputState>,
+    default_variant_input: Entity<InputState>,
+    serde_tag_input: Entity<InputState>,
+    serde_content_input: Entity<InputState>,
+    /// Output path input of every codegen target
+    target_output_inputs: Vec<(CodegenTarget, Entity<InputState>)>,
+    file_format: Option<(EnumFileFormat, SerializationFormat)>,
+    density: Density,
+    focus_handle: FocusHandle,
+    on_modified: Arc<parking_lot::Mutex<Option<Box<dyn Fn() + Send + Sync>>>>,
+    _subscriptions: Vec<gpui::Subscription>,
+}
+
+impl PropertiesPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("EnumName"));
+        let display_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Display Name"));
+        let description_input = cx.new(|cx| InputState::new(window, cx).placeholder("Enum description..."));
+        let category_input = cx.new(|cx| InputState::new(window, cx).placeholder("Add category..."));
+        let constant_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("BASE"));
+        let constant_value_input = cx.new(|cx| InputState::new(window, cx).placeholder("100"));
+        let associated_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("MAX"));
+        let associated_type_input = cx.new(|cx| InputState::new(window, cx).placeholder("u32"));
+        let associated_value_input = cx.new(|cx| InputState::new(window, cx).placeholder("64"));
+        let default_variant_input = cx.new(|cx| InputState::new(window, cx).placeholder("Default variant"));
+        let serde_tag_input = cx.new(|cx| InputState::new(window, cx).placeholder("type"));
+        let serde_content_input = cx.new(|cx| InputState::new(window, cx).placeholder("c"));
+
+        // Initialize inputs with current asset values
+        let asset_read = asset.read();
+        name_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &asset_read.name, window, cx);
+        });
+        display_name_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &asset_read.display_name, window, cx);
+        });
+        if let Some(desc) = &asset_read.description {
+            description_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, desc, window, cx);
+            });
+        }
+
+        let meta = EnumMeta::from_asset(&asset_read);
+        if let Some(default_variant) = &meta.default_variant {
+            default_variant_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, default_variant, window, cx);
+            });
+        }
+        let (tag, content) = serde_tag_names(&meta.serde_repr);
+        serde_tag_input.update(cx, |input, cx| input.replace_text_in_range(None, &tag, window, cx));
+        serde_content_input.update(cx, |input, cx| input.replace_text_in_range(None, &content, window, cx));
+        let target_output_inputs: Vec<_> = CodegenTarget::ALL
+            .into_iter()
+            .map(|target| {
+                let placeholder = target.default_file_name(&asset_read);
+                let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+                if let Some(output) = meta.target(target.id()).output {
+                    input.update(cx, |input, cx| {
+                        input.replace_text_in_range(None, &output, window, cx);
+                    });
+                }
+                (target, input)
+            })
+            .collect();
+        drop(asset_read);
+
+        let mut subscriptions: Vec<gpui::Subscription> = target_output_inputs
+            .iter()
+            .map(|(target, input)| {
+                let target = *target;
+                cx.subscribe_in(input, window, move |this, state, event: &ui::input::InputEvent, _window, cx| {
+                    if let ui::input::InputEvent::Change = event {
+                        let text = state.read(cx).text().to_string();
+                        this.set_target_output(target, &text, cx);
+                    }
+                })
+            })
+            .collect();
+        subscriptions.push(cx.subscribe_in(
+            &default_variant_input,
+            window,
+            |this, state, event: &ui::input::InputEvent, _window, cx| {
+                if let ui::input::InputEvent::Change = event {
+                    let text = state.read(cx).text().to_string();
+                    this.set_default_variant(&text, cx);
+                }
+            },
+        ));
+        for input in [&serde_tag_input, &serde_content_input] {
+            subscriptions.push(cx.subscribe_in(input, window, |this, _, event: &ui::input::InputEvent, _window, cx| {
+                if let ui::input::InputEvent::Change = event {
+                    let repr = EnumMeta::from_asset(&this.asset.read()).serde_repr;
+                    this.set_serde_repr(repr, cx);
+                }
+            }));
+        }
+
+        Self {
+            asset,
+            name_input,
+            display_name_input,
+            description_input,
+            category_input,
+            constant_name_input,
+            constant_value_input,
+            associated_name_input,
+            associated_type_input,
+            associated_value_input,
+            default_variant_input,
+            serde_tag_input,
+            serde_content_input,
+            target_output_inputs,
+            file_format: None,
+            density: Density::default(),
+            focus_handle: cx.focus_handle(),
+            on_modified: Arc::new(parking_lot::Mutex::new(None)),
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn set_on_modified<F>(&mut self, callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.on_modified.lock() = Some(Box::new(callback));
+    }
+
+    fn notify_modified(&self) {
+        if let Some(ref callback) = *self.on_modified.lock() {
+            callback();
+        }
+    }
+
+    pub fn set_file_format(
+        &mut self,
+        layout: EnumFileFormat,
+        serialization: SerializationFormat,
+        cx: &mut Context<Self>,
+    ) {
+        self.file_format = Some((layout, serialization));
+        cx.notify();
+    }
+
+    pub fn set_density(&mut self, density: Density, cx: &mut Context<Self>) {
+        self.density = density;
+        cx.notify();
+    }
+
+    /// Reset the inputs after the asset was replaced (reload, undo, bulk edits)
+    pub fn reload(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let asset = self.asset.read();
+        let name = asset.name.clone();
+        let display_name = asset.display_name.clone();
+        let description = asset.description.clone().unwrap_or_default();
+        let meta = EnumMeta::from_asset(&asset);
+        drop(asset);
+
+        for (target, input) in &self.target_output_inputs {
+            let output = meta.target(target.id()).output.unwrap_or_default();
+            input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &output, window, cx);
+            });
+        }
+        let default_variant = meta.default_variant.unwrap_or_default();
+        self.default_variant_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &default_variant, window, cx);
+        });
+        let (tag, content) = serde_tag_names(&meta.serde_repr);
+        self.serde_tag_input.update(cx, |input, cx| input.replace_text_in_range(None, &tag, window, cx));
+        self.serde_content_input.update(cx, |input, cx| input.replace_text_in_range(None, &content, window, cx));
+
+        self.name_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &name, window, cx);
+        });
+        self.display_name_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &display_name, window, cx);
+        });
+        self.description_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &description, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn sync_inputs_to_asset(&self, cx: &App) {
+        let name = self.name_input.read(cx).text().to_string();
+        let display_name = self.display_name_input.read(cx).text().to_string();
+        let description = self.description_input.read(cx).text().to_string();
+
+        let mut asset = self.asset.write();
+        asset.name = name;
+        asset.display_name = display_name;
+        asset.description = Some(description);
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+        self.asset.write().visibility = visibility;
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn add_category(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let category = self.category_input.read(cx).text().to_string();
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if !meta.add_category(&category) {
+            return;
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.category_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, "", window, cx);
+        });
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Define or redefine a constant for discriminant expressions
+    fn add_constant(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let name = self.constant_name_input.read(cx).text().trim().to_string();
+        let Some(value) = expr::parse_value(&self.constant_value_input.read(cx).text()) else {
+            return;
+        };
+        if name.is_empty() {
+            return;
+        }
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.constants.insert(name, value);
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        for input in [&self.constant_name_input, &self.constant_value_input] {
+            input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, "", window, cx);
+            });
+        }
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn remove_constant(&mut self, name: &str, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.constants.remove(name);
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Add an associated constant, replacing one of the same name
+    fn add_associated_const(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let constant = AssociatedConst {
+            name: self.associated_name_input.read(cx).text().trim().to_string(),
+            ty: self.associated_type_input.read(cx).text().trim().to_string(),
+            value: self.associated_value_input.read(cx).text().trim().to_string(),
+            doc: None,
+        };
+        if constant.name.is_empty() || constant.ty.is_empty() || constant.value.is_empty() {
+            return;
+        }
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        match meta.associated_consts.iter_mut().find(|c| c.name == constant.name) {
+            Some(existing) => *existing = AssociatedConst { doc: existing.doc.take(), ..constant },
+            None => meta.associated_consts.push(constant),
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        for input in [&self.associated_name_input, &self.associated_type_input, &self.associated_value_input] {
+            input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, "", window, cx);
+            });
+        }
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn remove_associated_const(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if index < meta.associated_consts.len() {
+            meta.associated_consts.remove(index);
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn set_mode(&mut self, mode: EnumMode, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.mode = mode;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::ModeChanged(mode));
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Add `derive` to the Rust declaration, or remove it
+    fn toggle_derive(&mut self, derive: &str, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        let name = type_check::derive_name(derive);
+        match meta.derives.iter().position(|d| type_check::derive_name(d) == name) {
+            Some(position) => {
+                meta.derives.remove(position);
+            }
+            None => meta.derives.push(derive.to_string()),
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn set_display_style(&mut self, style: Option<DisplayStyle>, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if meta.display_style == style {
+            return;
+        }
+        meta.display_style = style;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn set_order_by(&mut self, order_by: OrderBy, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if meta.order_by == order_by {
+            return;
+        }
+        meta.order_by = order_by;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.notify();
+    }
+
+    fn set_default_variant(&mut self, text: &str, cx: &mut Context<Self>) {
+        let default_variant = Some(text.trim()).filter(|t| !t.is_empty()).map(str::to_string);
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if meta.default_variant == default_variant {
+            return;
+        }
+        meta.default_variant = default_variant;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.notify();
+    }
+
+    /// Switch to the kind of representation `kind` is, with the tag names
+    /// typed in the inputs or the usual ones
+    fn set_serde_repr(&mut self, kind: SerdeRepr, cx: &mut Context<Self>) {
+        let name = |input: &Entity<InputState>, usual: &str| {
+            let text = input.read(cx).text().trim().to_string();
+            if text.is_empty() { usual.to_string() } else { text }
+        };
+        let repr = match &kind {
+            SerdeRepr::Internal { tag } => SerdeRepr::Internal { tag: name(&self.serde_tag_input, tag) },
+            SerdeRepr::Adjacent { tag, content } => SerdeRepr::Adjacent {
+                tag: name(&self.serde_tag_input, tag),
+                content: name(&self.serde_content_input, content),
+            },
+            SerdeRepr::External | SerdeRepr::Untagged => kind,
+        };
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if meta.serde_repr == repr {
+            return;
+        }
+        meta.serde_repr = repr;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn toggle_alias_on_rename(&mut self, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.alias_on_rename = !meta.alias_on_rename;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Update a target's settings in the enum metadata
+    fn update_target(&mut self, target: CodegenTarget, cx: &mut Context<Self>, apply: impl FnOnce(&mut TargetConfig)) {
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        let mut config = meta.target(target.id());
+        let before = config.clone();
+        apply(&mut config);
+        if config == before {
+            return;
+        }
+        meta.set_target(target.id(), config);
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn set_export_profile(&mut self, profile: BuildProfile, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.profile = profile;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn toggle_published(&mut self, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.published = !meta.published;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn unreserve(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        if index < meta.reserved.len() {
+            meta.reserved.remove(index);
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Turning stable IDs on assigns them right away, so they show up in
+    /// the generated code
+    fn toggle_stable_ids(&mut self, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.stable_ids = !meta.stable_ids;
+        if meta.stable_ids {
+            meta.assign_stable_ids(&asset);
+        }
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn toggle_require_docs(&mut self, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.require_docs = !meta.require_docs;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn toggle_regenerate_on_save(&mut self, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.regenerate_on_save = !meta.regenerate_on_save;
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn set_target_output(&mut self, target: CodegenTarget, text: &str, cx: &mut Context<Self>) {
+        let output = Some(text.trim()).filter(|t| !t.is_empty()).map(str::to_string);
+        self.update_target(target, cx, |config| config.output = output);
+    }
+
+    fn toggle_target(&mut self, target: CodegenTarget, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+        self.update_target(target, cx, |config| config.enabled = !config.enabled);
+        // Enabled targets decide which keywords are checked
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+    }
+
+    /// Emit the variant under the collision's suggested name in its target
+    fn use_suggested_name(&mut self, collision: KeywordCollision, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let Some(name) = asset.variants.get(collision.variant).map(|v| v.name.clone()) else {
+            return;
+        };
+        let mut meta = EnumMeta::from_asset(&asset);
+        let mut variant_meta = meta.variant(&name);
+        variant_meta.target_names.insert(collision.target.id().to_string(), collision.suggestion);
+        variant_meta.touch();
+        meta.set_variant(&name, variant_meta);
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PropertiesPanelEvent::VariantMetaChanged);
+        cx.emit(PropertiesPanelEvent::DerivesChanged);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn toggle_markdown_column(&mut self, column: MarkdownColumn, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+        self.update_target(CodegenTarget::Markdown, cx, |config| {
+            let mut columns = MarkdownColumn::from_options(&config.options);
+            if let Some(position) = columns.iter().position(|c| *c == column) {
+                columns.remove(position);
+            } else {
+                columns.push(column);
+                columns.sort_by_key(|c| MarkdownColumn::ALL.iter().position(|a| a == c));
+            }
+            MarkdownColumn::write_options(&columns, &mut config.options);
+        });
+    }
+
+    fn remove_category(&mut self, category: &str, cx: &mut Context<Self>) {
+        self.sync_inputs_to_asset(cx);
+
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.remove_category(category);
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+}
+
+/// Tag and content names of a representation, empty where it has none
+fn serde_tag_names(repr: &SerdeRepr) -> (String, String) {
+    match repr {
+        SerdeRepr::Internal { tag } => (tag.clone(), String::new()),
+        SerdeRepr::Adjacent { tag, content } => (tag.clone(), content.clone()),
+        SerdeRepr::External | SerdeRepr::Untagged => (String::new(), String::new()),
+    }
+}
+
+impl EventEmitter<PanelEvent> for PropertiesPanel {}
+impl EventEmitter<PropertiesPanelEvent> for PropertiesPanel {}
+
+impl Render for PropertiesPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let asset = self.asset.read();
+        let meta = EnumMeta::from_asset(&asset);
+        let categories = meta.categories.clone();
+
+        v_flex()
+            .id("enum-properties-content")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .p(px(self.density.spacing(16.0)))
+            .gap(px(self.density.spacing(16.0)))
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            // Name
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Name")
+                    )
+                    .child(TextInput::new(&self.name_input))
+            )
+            // Display Name
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Display Name")
+                    )
+                    .child(TextInput::new(&self.display_name_input))
+            )
+            // Description
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Description")
+                    )
+                    .child(TextInput::new(&self.description_input))
+            )
+            // Categories
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Categories")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children(categories.into_iter().enumerate().map(|(index, category)| {
+                                h_flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .pl_2()
+                                    .rounded(cx.theme().radius)
+                                    .bg(cx.theme().secondary.opacity(0.4))
+                                    .border_1()
+                                    .border_color(cx.theme().border)
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().foreground)
+                                            .child(category.clone())
+                                    )
+                                    .child(
+                                        Button::new(("remove-category", index))
+                                            .ghost()
+                                            .with_size(ui::Size::XSmall)
+                                            .icon(IconName::Close)
+                                            .tooltip("Remove category")
+                                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                                this.remove_category(&category, cx);
+                                            }))
+                                    )
+                            }))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .child(TextInput::new(&self.category_input))
+                            )
+                            .child(
+                                Button::new("add-category")
+                                    .icon(IconName::Plus)
+                                    .tooltip("Add category")
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.add_category(window, cx);
+                                    }))
+                            )
+                    )
+            )
+            // Visibility
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Visibility")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .child(
+                                Button::new("visibility-public")
+                                    .when(asset.visibility == Visibility::Public, |this| this.primary())
+                                    .label("Public")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.set_visibility(Visibility::Public, cx);
+                                    }))
+                            )
+                            .child(
+                                Button::new("visibility-private")
+                                    .when(asset.visibility == Visibility::Private, |this| this.primary())
+                                    .label("Private")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.set_visibility(Visibility::Private, cx);
+                                    }))
+                            )
+                            .child(
+                                Button::new("visibility-crate")
+                                    .when(asset.visibility == Visibility::Crate, |this| this.primary())
+                                    .label("Crate")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.set_visibility(Visibility::Crate, cx);
+                                    }))
+                            )
+                            .child(
+                                Button::new("visibility-super")
+                                    .when(asset.visibility == Visibility::Super, |this| this.primary())
+                                    .label("Super")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.set_visibility(Visibility::Super, cx);
+                                    }))
+                            )
+                    )
+            )
+            // Mode
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Mode")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .children(EnumMode::ALL.into_iter().map(|mode| {
+                                Button::new(SharedString::from(format!("mode-{}", mode.label())))
+                                    .when(meta.mode == mode, |this| this.primary())
+                                    .label(mode.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.set_mode(mode, cx);
+                                    }))
+                            }))
+                    )
+            )
+            // Constants for discriminant expressions
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Constants")
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Usable in variant values, e.g. `BASE + 5` or `1 << SHIFT`")
+                    )
+                    .children(meta.constants.iter().enumerate().map(|(i, (name, value))| {
+                        let name = name.clone();
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_sm()
+                                    .text_color(cx.theme().foreground)
+                                    .child(format!("{} = {}", name, value))
+                            )
+                            .child(
+                                Button::new(("remove-constant", i))
+                                    .ghost()
+                                    .with_size(ui::Size::XSmall)
+                                    .icon(IconName::Close)
+                                    .tooltip("Remove constant")
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.remove_constant(&name, cx);
+                                    }))
+                            )
+                    }))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(div().flex_1().child(TextInput::new(&self.constant_name_input)))
+                            .child(div().w(px(80.0)).child(TextInput::new(&self.constant_value_input)))
+                            .child(
+                                Button::new("add-constant")
+                                    .icon(IconName::Plus)
+                                    .tooltip("Add constant")
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.add_constant(window, cx);
+                                    }))
+                            )
+                    )
+            )
+            // Associated constants of the generated `impl` block
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Associated Constants")
+                    )
+                    .children(meta.associated_consts.iter().enumerate().map(|(i, constant)| {
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_sm()
+                                    .text_color(cx.theme().foreground)
+                                    .child(format!("const {}", constant.label()))
+                            )
+                            .child(
+                                Button::new(("remove-associated-const", i))
+                                    .ghost()
+                                    .with_size(ui::Size::XSmall)
+                                    .icon(IconName::Close)
+                                    .tooltip("Remove associated constant")
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.remove_associated_const(i, cx);
+                                    }))
+                            )
+                    }))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(div().flex_1().child(TextInput::new(&self.associated_name_input)))
+                            .child(div().w(px(60.0)).child(TextInput::new(&self.associated_type_input)))
+                            .child(div().w(px(80.0)).child(TextInput::new(&self.associated_value_input)))
+                            .child(
+                                Button::new("add-associated-const")
+                                    .icon(IconName::Plus)
+                                    .tooltip("Add associated constant")
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.add_associated_const(window, cx);
+                                    }))
+                            )
+                    )
+            )
+            // Publishing
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Publishing")
+                    )
+                    .child(
+                        Button::new("toggle-published")
+                            .when(meta.published, |this| this.primary())
+                            .label("Published (API frozen)")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_published(cx);
+                            }))
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Saving removals, renames without an alias, or value changes asks for confirmation")
+                    )
+                    .when(!meta.reserved.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .font_semibold()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Reserved")
+                        )
+                        .children(meta.reserved.iter().enumerate().map(|(i, reserved)| {
+                            h_flex()
+                                .gap_2()
+                                .items_center()
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .child(reserved.label())
+                                )
+                                .child(
+                                    Button::new(("unreserve", i))
+                                        .ghost()
+                                        .with_size(ui::Size::XSmall)
+                                        .label("Unreserve")
+                                        .on_click(cx.listener(move |this, _, _window, cx| {
+                                            this.unreserve(i, cx);
+                                        }))
+                                )
+                        }))
+                    })
+            )
+            // Documentation
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Documentation")
+                    )
+                    .child(
+                        Button::new("require-docs")
+                            .when(meta.require_docs, |this| this.primary())
+                            .label("Require docs on public variants")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_require_docs(cx);
+                            }))
+                    )
+            )
+            // Display names
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Display Names")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .flex_wrap()
+                            .children([None].into_iter().chain(DisplayStyle::ALL.map(Some)).map(|style| {
+                                Button::new(SharedString::from(format!("display-style-{:?}", style)))
+                                    .with_size(ui::Size::Small)
+                                    .when(meta.display_style == style, |this| this.primary())
+                                    .label(style.map_or("As written", |style| style.label()))
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.set_display_style(style, cx);
+                                    }))
+                            }))
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(match asset.variants.first() {
+                                Some(variant) if meta.display_style.is_some() => format!(
+                                    "`{}` is shown as \"{}\"; a variant's own display name overrides the rule",
+                                    variant.name,
+                                    meta.display_name(&variant.name)
+                                ),
+                                _ => "Variants without a display name are shown by their name".to_string(),
+                            })
+                    )
+            )
+            // Derives
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Derives")
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Debug and Clone are always derived")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .flex_wrap()
+                            .children(type_check::DERIVE_OPTIONS.into_iter().map(|derive| {
+                                let derived = meta.derives.iter().any(|d| type_check::derive_name(d) == type_check::derive_name(derive));
+                                Button::new(SharedString::from(format!("derive-{}", derive)))
+                                    .with_size(ui::Size::Small)
+                                    .when(derived, |this| this.primary())
+                                    .label(type_check::derive_name(derive))
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.toggle_derive(derive, cx);
+                                    }))
+                            }))
+                    )
+                    .when(meta.derives.iter().any(|d| type_check::derive_name(d) == "PartialOrd"), |this| {
+                        let manual = meta.order_by == OrderBy::Value && !values_ascend(&asset, &meta);
+                        this.child(
+                            h_flex()
+                                .gap_1()
+                                .items_center()
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("Compare")
+                                )
+                                .children(OrderBy::ALL.into_iter().map(|order_by| {
+                                    Button::new(SharedString::from(format!("order-by-{}", order_by.label())))
+                                        .with_size(ui::Size::Small)
+                                        .when(meta.order_by == order_by, |this| this.primary())
+                                        .label(order_by.label())
+                                        .on_click(cx.listener(move |this, _, _window, cx| {
+                                            this.set_order_by(order_by, cx);
+                                        }))
+                                }))
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(if manual {
+                                    "The values don't ascend in declaration order, so PartialOrd and Ord are implemented by hand"
+                                } else if meta.order_by == OrderBy::Declaration {
+                                    "Reordering variants changes how they compare"
+                                } else {
+                                    "Changing values changes how variants compare"
+                                })
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child("#[default]")
+                            )
+                            .child(div().flex_1().child(TextInput::new(&self.default_variant_input)))
+                    )
+                    .children(validation::derive_issues(&asset, &meta).into_iter().map(|issue| {
+                        div()
+                            .text_xs()
+                            .text_color(if issue.severity == Severity::Error { cx.theme().danger } else { cx.theme().warning })
+                            .child(issue.message)
+                    }))
+            )
+            // Serde representation
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Serde Representation")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .flex_wrap()
+                            .children(SerdeRepr::all().into_iter().map(|repr| {
+                                Button::new(SharedString::from(format!("serde-repr-{}", repr.label())))
+                                    .with_size(ui::Size::Small)
+                                    .when(meta.serde_repr.same_kind(&repr), |this| this.primary())
+                                    .label(repr.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.set_serde_repr(repr.clone(), cx);
+                                    }))
+                            }))
+                    )
+                    .when(matches!(meta.serde_repr, SerdeRepr::Internal { .. } | SerdeRepr::Adjacent { .. }), |this| {
+                        this.child(
+                            h_flex()
+                                .gap_2()
+                                .items_center()
+                                .child(div().text_xs().text_color(cx.theme().muted_foreground).child("Tag"))
+                                .child(div().flex_1().child(TextInput::new(&self.serde_tag_input)))
+                                .when(matches!(meta.serde_repr, SerdeRepr::Adjacent { .. }), |this| {
+                                    this.child(div().text_xs().text_color(cx.theme().muted_foreground).child("Content"))
+                                        .child(div().flex_1().child(TextInput::new(&self.serde_content_input)))
+                                })
+                        )
+                    })
+                    .child(
+                        v_flex()
+                            .p_2()
+                            .rounded(cx.theme().radius)
+                            .bg(cx.theme().background)
+                            .text_xs()
+                            .text_color(cx.theme().foreground)
+                            .children(
+                                samples::json_examples(&asset, &samples::representative_variants(&asset))
+                                    .lines()
+                                    .map(|line| div().child(line.to_string()))
+                                    .collect::<Vec<_>>()
+                            )
+                    )
+            )
+            // Renaming
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Renaming")
+                    )
+                    .child(
+                        Button::new("stable-ids")
+                            .when(meta.stable_ids, |this| this.primary())
+                            .label("Stable IDs for analytics")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_stable_ids(cx);
+                            }))
+                    )
+                    .when(meta.stable_ids, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Each variant keeps the ID it was first given, whatever it is renamed to")
+                        )
+                    })
+                    .child(
+                        Button::new("alias-on-rename")
+                            .when(meta.alias_on_rename, |this| this.primary())
+                            .label("Keep old names as serde aliases")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_alias_on_rename(cx);
+                            }))
+                    )
+            )
+            // File format
+            .when_some(self.file_format, |this, (layout, serialization)| {
+                this.child(Divider::horizontal())
+                    .child(
+                        v_flex()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child("File Format")
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(layout.label())
+                            )
+                            .child(
+                                Button::new("convert-format")
+                                    .label(format!("Convert to {}", layout.other().label()))
+                                    .on_click(cx.listener(|_this, _, _window, cx| {
+                                        cx.emit(PropertiesPanelEvent::ConvertFormatRequested);
+                                    }))
+                            )
+                            // The host only recognises folder assets by their JSON marker
+                            .when(layout == EnumFileFormat::Flat || serialization != SerializationFormat::Json, |this| {
+                                this.child(
+                                    h_flex()
+                                        .gap_2()
+                                        .children(SerializationFormat::ALL.into_iter().map(|format| {
+                                            Button::new(SharedString::from(format!("serialization-{}", format.extension())))
+                                                .when(format == serialization, |this| this.primary())
+                                                .label(format.label())
+                                                .on_click(cx.listener(move |_this, _, _window, cx| {
+                                                    cx.emit(PropertiesPanelEvent::SerializationChangeRequested(format));
+                                                }))
+                                        }))
+                                )
+                            })
+                            .when(layout == EnumFileFormat::Folder, |this| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("Folder assets are stored as enum.json; use a single file for YAML or TOML")
+                                )
+                            })
+                    )
+            })
+            // Codegen targets
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Codegen Targets")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child("Export profile")
+                            )
+                            .children(BuildProfile::ALL.into_iter().map(|profile| {
+                                Button::new(SharedString::from(format!("export-profile-{}", profile.label())))
+                                    .with_size(ui::Size::Small)
+                                    .when(meta.profile == profile, |this| this.primary())
+                                    .label(profile.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.set_export_profile(profile, cx);
+                                    }))
+                            }))
+                    )
+                    .child(
+                        Button::new("regenerate-on-save")
+                            .when(meta.regenerate_on_save, |this| this.primary())
+                            .label("Regenerate enabled targets on save")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_regenerate_on_save(cx);
+                            }))
+                    )
+                    .children(self.target_output_inputs.iter().map(|(target, input)| {
+                        let target = *target;
+                        let config = meta.target(target.id());
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .items_center()
+                                    .child(
+                                        Button::new(SharedString::from(format!("target-enabled-{}", target.id())))
+                                            .w(px(130.0))
+                                            .with_size(ui::Size::Small)
+                                            .when(config.enabled, |this| this.primary().icon(IconName::Check))
+                                            .label(target.label())
+                                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                                this.toggle_target(target, cx);
+                                            }))
+                                    )
+                                    .child(div().flex_1().child(TextInput::new(input)))
+                            )
+                            .when(target == CodegenTarget::Markdown && config.enabled, |this| {
+                                let columns = MarkdownColumn::from_options(&config.options);
+                                this.child(
+                                    h_flex()
+                                        .gap_1()
+                                        .flex_wrap()
+                                        .children(MarkdownColumn::ALL.into_iter().map(|column| {
+                                            Button::new(SharedString::from(format!("target-markdown-column-{}", column.id())))
+                                                .with_size(ui::Size::XSmall)
+                                                .when(columns.contains(&column), |this| this.primary())
+                                                .label(column.label())
+                                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                                    this.toggle_markdown_column(column, cx);
+                                                }))
+                                        }))
+                                )
+                            })
+                    }))
+                    .children(codegen::keyword_collisions(&asset, &meta).into_iter().enumerate().map(|(i, collision)| {
+                        let message = format!(
+                            "`{}` is emitted as `{}`, a keyword in {}",
+                            asset.variants[collision.variant].name,
+                            collision.identifier,
+                            collision.target.label()
+                        );
+                        let label = format!("Emit `{}`", collision.suggestion);
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().flex_1().text_xs().text_color(cx.theme().warning).child(message))
+                            .child(
+                                Button::new(("keyword-collision-fix", i))
+                                    .with_size(ui::Size::XSmall)
+                                    .label(label)
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.use_suggested_name(collision.clone(), cx);
+                                    }))
+                            )
+                    }))
+            )
+    }
+}
+
+impl Focusable for PropertiesPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for PropertiesPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_properties"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Properties".into_any_element()
+    }
+}
@@ -0,0 +1,202 @@
+//! Renumber panel: gaps and collisions in the values, and renumbering
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::metadata::EnumMeta;
+use crate::renumber::{self, Strategy, ValueChange};
+
+/// Requests from the Renumber Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum RenumberPanelEvent {
+    ApplyRequested(Vec<ValueChange>),
+}
+
+/// Renumber Panel - Gaps and collisions in the values, and renumbering strategies
+pub struct RenumberPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    strategy: Strategy,
+    step_input: Entity<InputState>,
+    focus_handle: FocusHandle,
+}
+
+impl RenumberPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let step_input = cx.new(|cx| InputState::new(window, cx).placeholder("10"));
+        step_input.update(cx, |input, cx| input.replace_text_in_range(None, "10", window, cx));
+        Self {
+            asset,
+            strategy: Strategy::Compact,
+            step_input,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// The selected strategy with the step from the input
+    fn strategy(&self, cx: &App) -> Strategy {
+        match self.strategy {
+            Strategy::Align(_) => {
+                let step = self.step_input.read(cx).text().trim().parse::<i64>().unwrap_or(10);
+                Strategy::Align(step.max(1))
+            }
+            strategy => strategy,
+        }
+    }
+}
+
+impl EventEmitter<PanelEvent> for RenumberPanel {}
+impl EventEmitter<RenumberPanelEvent> for RenumberPanel {}
+
+impl Render for RenumberPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let strategy = self.strategy(cx);
+        let asset = self.asset.read();
+        let published = EnumMeta::from_asset(&asset).published;
+        let analysis = renumber::analyze(&asset);
+        let changes = renumber::plan(&asset, strategy);
+        drop(asset);
+
+        v_flex()
+            .id("enum-renumber-content")
+            .size_full()
+            .p_4()
+            .gap_4()
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            // Analysis
+            .child(
+                v_flex()
+                    .gap_1()
+                    .text_sm()
+                    .child(
+                        div()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(match (analysis.min, analysis.max) {
+                                (Some(min), Some(max)) => format!("Values {} to {}", min, max),
+                                _ => "No variants".to_string(),
+                            })
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(if analysis.gaps.is_empty() {
+                                "No gaps".to_string()
+                            } else {
+                                let gaps: Vec<String> = analysis
+                                    .gaps
+                                    .iter()
+                                    .map(|(start, end)| if start == end { start.to_string() } else { format!("{}–{}", start, end) })
+                                    .collect();
+                                format!("Gaps: {}", gaps.join(", "))
+                            })
+                    )
+                    .children(analysis.collisions.iter().map(|(value, names)| {
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().danger)
+                            .child(format!("{} is used by {}", value, names.join(", ")))
+                    }))
+            )
+            // Strategy
+            .child(Divider::horizontal())
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .children([Strategy::Compact, Strategy::Preserve, Strategy::Align(0)].into_iter().map(|option| {
+                        let selected = std::mem::discriminant(&option) == std::mem::discriminant(&self.strategy);
+                        let label = match option {
+                            Strategy::Align(_) => "Align to Multiples".to_string(),
+                            option => option.label(),
+                        };
+                        Button::new(SharedString::from(format!("renumber-{}", label)))
+                            .when(selected, |this| this.primary())
+                            .with_size(ui::Size::Small)
+                            .label(label)
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.strategy = option;
+                                cx.notify();
+                            }))
+                    }))
+                    .when(matches!(self.strategy, Strategy::Align(_)), |this| {
+                        this.child(div().w(px(60.0)).child(TextInput::new(&self.step_input)))
+                    })
+            )
+            // Preview
+            .child(
+                v_flex()
+                    .gap_1()
+                    .when(changes.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No values change")
+                        )
+                    })
+                    .children(changes.iter().map(|change| {
+                        h_flex()
+                            .gap_2()
+                            .text_sm()
+                            .child(div().flex_1().text_color(cx.theme().foreground).child(change.name.clone()))
+                            .child(div().text_color(cx.theme().danger).child(change.old.to_string()))
+                            .child(div().text_color(cx.theme().muted_foreground).child("→"))
+                            .child(div().text_color(cx.theme().success).child(change.new.to_string()))
+                    }))
+            )
+            .when(!changes.is_empty(), |this| {
+                this.child(
+                    div()
+                        .p_2()
+                        .rounded(px(4.0))
+                        .bg(cx.theme().warning.opacity(0.1))
+                        .border_1()
+                        .border_color(cx.theme().warning.opacity(0.4))
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(if published {
+                            "This enum is published. Changing values breaks saved data and code that stores raw values; saving will ask for confirmation."
+                        } else {
+                            "Changing values breaks saved data and code that stores raw values."
+                        })
+                )
+                .child(
+                    Button::new("renumber-apply")
+                        .danger()
+                        .label(format!("Renumber {} Variants", changes.len()))
+                        .on_click(cx.listener(move |_this, _, _window, cx| {
+                            cx.emit(RenumberPanelEvent::ApplyRequested(changes.clone()));
+                        }))
+                )
+            })
+    }
+}
+
+impl Focusable for RenumberPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for RenumberPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_renumber"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Renumber".into_any_element()
+    }
+}
@@ -0,0 +1,220 @@
+//! Review panel: the whole definition on one read-only page
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::Button,
+};
+use ui_types_common::{EnumAsset, Visibility};
+use std::sync::Arc;
+use crate::codegen;
+use crate::metadata::{EnumMeta, Stage};
+use crate::markdown;
+
+/// Requests from the Review Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum ReviewPanelEvent {
+    ExportRequested,
+}
+
+/// Review Panel - The whole definition on one read-only page for design reviews
+pub struct ReviewPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    focus_handle: FocusHandle,
+}
+
+impl ReviewPanel {
+    pub fn new(asset: Arc<parking_lot::RwLock<EnumAsset>>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            asset,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Label and value rows, muted labels in a fixed-width column
+    fn rows(rows: Vec<(&'static str, String)>, cx: &App) -> Div {
+        v_flex().gap_0p5().text_xs().children(rows.into_iter().map(|(label, value)| {
+            h_flex()
+                .gap_2()
+                .items_start()
+                .child(div().w(px(110.0)).flex_shrink_0().text_color(cx.theme().muted_foreground).child(label))
+                .child(div().flex_1().text_color(cx.theme().foreground).child(value))
+        }))
+    }
+}
+
+impl EventEmitter<PanelEvent> for ReviewPanel {}
+impl EventEmitter<ReviewPanelEvent> for ReviewPanel {}
+
+impl Render for ReviewPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let asset = self.asset.read();
+        let meta = EnumMeta::from_asset(&asset);
+        let model = codegen::EnumModel::from_asset(&asset);
+
+        let mut definition = vec![
+            ("Visibility", model.visibility.to_string()),
+            ("Mode", meta.mode.label().to_string()),
+        ];
+        if let Some(repr) = &meta.repr {
+            definition.push(("Representation", repr.clone()));
+        }
+        if !meta.derives.is_empty() {
+            definition.push(("Derives", meta.derives.join(", ")));
+        }
+        definition.push(("Serialization", meta.serde_repr.label().to_string()));
+        definition.push(("Naming", meta.naming.label().to_string()));
+        definition.push(("Export profile", meta.profile.label().to_string()));
+        definition.push(("Published", if meta.published { "Yes" } else { "No" }.to_string()));
+        if let Some(default) = &meta.default_variant {
+            definition.push(("Default", default.clone()));
+        }
+        if !model.categories.is_empty() {
+            definition.push(("Categories", model.categories.join(", ")));
+        }
+        if !meta.reserved.is_empty() {
+            definition.push(("Reserved", meta.reserved.iter().map(|reserved| reserved.label()).collect::<Vec<_>>().join(", ")));
+        }
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .gap_2()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child("Review")
+                    )
+                    .child(
+                        Button::new("review-export")
+                            .with_size(ui::Size::Small)
+                            .label("Export HTML")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(ReviewPanelEvent::ExportRequested);
+                            }))
+                    )
+            )
+            .child(
+                v_flex()
+                    .id("enum-review-content")
+                    .flex_1()
+                    .p_4()
+                    .gap_4()
+                    .overflow_scroll()
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(format!("{} ({})", model.display_name, model.name))
+                    )
+                    .when_some(model.description.clone().filter(|d| !d.trim().is_empty()), |this, description| {
+                        this.child(markdown::render(&description, cx))
+                    })
+                    .child(Self::rows(definition, cx))
+                    .child(Divider::horizontal())
+                    .children(model.variants.iter().map(|variant| {
+                        let variant_meta = meta.variant(&variant.name);
+                        let value = match (variant.combines.as_slice(), &variant.expr) {
+                            ([], Some(expr)) => format!("{} = {}", expr, variant.value),
+                            ([], None) if variant.has_explicit_value => variant.value.to_string(),
+                            ([], None) => format!("{} (implicit)", variant.value),
+                            (flags, _) => flags.join(" | "),
+                        };
+                        let mut stage = variant_meta.stage.label().to_string();
+                        if variant.deprecated {
+                            stage.push_str(", deprecated");
+                        }
+                        let mut rows = vec![
+                            ("Value", value),
+                            ("Display name", variant.display_name.clone()),
+                            ("Stage", stage),
+                        ];
+                        for field in &variant.fields {
+                            let default = field.default.as_deref().map(|default| format!(" = {}", default)).unwrap_or_default();
+                            rows.push(("Field", format!("{}: {}{}", field.name, field.type_name, default)));
+                        }
+                        if let Some(owner) = &variant_meta.owner {
+                            rows.push(("Owner", owner.clone()));
+                        }
+                        if let Some(cfg) = &variant.cfg {
+                            rows.push(("Only with", format!("cfg({})", cfg)));
+                        }
+                        if !variant_meta.aliases.is_empty() {
+                            rows.push(("Old names", variant_meta.aliases.join(", ")));
+                        }
+                        if !variant.target_names.is_empty() {
+                            let names: Vec<String> =
+                                variant.target_names.iter().map(|(target, name)| format!("{} {}", target, name)).collect();
+                            rows.push(("Emitted as", names.join(", ")));
+                        }
+                        if let Some(stable_id) = &variant_meta.stable_id {
+                            rows.push(("Stable ID", stable_id.clone()));
+                        }
+                        v_flex()
+                            .gap_1()
+                            .p_2()
+                            .rounded(px(4.0))
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .when(variant_meta.stage == Stage::Removed, |this| this.opacity(0.6))
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .items_center()
+                                    .when_some(variant_meta.color_rgba(), |this, color| {
+                                        this.child(div().size(px(10.0)).rounded(px(2.0)).bg(rgba(color)))
+                                    })
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(format!("{}{}", variant.name, codegen::payload_signature(variant)))
+                                    )
+                            )
+                            .when_some(variant.doc.clone().filter(|d| !d.trim().is_empty()), |this, doc| {
+                                this.child(markdown::render(&doc, cx))
+                            })
+                            .child(Self::rows(rows, cx))
+                            .children(variant_meta.notes.iter().map(|note| {
+                                let author = note.author.as_deref().map(|author| format!(" — {}", author)).unwrap_or_default();
+                                div()
+                                    .text_xs()
+                                    .text_color(if note.resolved { cx.theme().muted_foreground } else { cx.theme().warning })
+                                    .when(note.resolved, |this| this.line_through())
+                                    .child(format!("• {}{}", note.text, author))
+                            }))
+                    }))
+            )
+    }
+}
+
+impl Focusable for ReviewPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for ReviewPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_review"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Review".into_any_element()
+    }
+}
@@ -0,0 +1,122 @@
+//! Samples panel: how each variant is written in data files
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+};
+use ui_types_common::EnumAsset;
+use std::sync::Arc;
+use crate::metadata::EnumMeta;
+use crate::samples::{self, SampleFormat};
+
+/// Samples Panel - How each variant is written in data files
+pub struct SamplesPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    format: SampleFormat,
+    focus_handle: FocusHandle,
+}
+
+impl SamplesPanel {
+    pub fn new(asset: Arc<parking_lot::RwLock<EnumAsset>>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            asset,
+            format: SampleFormat::Json,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    fn set_format(&mut self, format: SampleFormat, cx: &mut Context<Self>) {
+        self.format = format;
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for SamplesPanel {}
+
+impl Render for SamplesPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let asset = self.asset.read();
+        let meta = EnumMeta::from_asset(&asset);
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .gap_2()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .children(SampleFormat::ALL.into_iter().map(|format| {
+                        Button::new(SharedString::from(format!("sample-format-{}", format.label())))
+                            .with_size(ui::Size::Small)
+                            .when(self.format == format, |this| this.primary())
+                            .label(format.label())
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.set_format(format, cx);
+                            }))
+                    }))
+                    .child(div().flex_1())
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(meta.serde_repr.label())
+                    )
+            )
+            .child(
+                v_flex()
+                    .id("enum-samples-content")
+                    .flex_1()
+                    .p_4()
+                    .gap_3()
+                    .overflow_scroll()
+                    .children(asset.variants.iter().map(|variant| {
+                        let sample = samples::variant_text(&asset, variant, &meta, self.format);
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(variant.name.clone())
+                            )
+                            .child(match sample {
+                                Ok(text) => v_flex()
+                                    .p_2()
+                                    .rounded(px(4.0))
+                                    .bg(cx.theme().background)
+                                    .text_xs()
+                                    .text_color(cx.theme().foreground)
+                                    .children(text.lines().map(|line| div().child(line.to_string())).collect::<Vec<_>>()),
+                                Err(e) => v_flex()
+                                    .text_xs()
+                                    .text_color(cx.theme().warning)
+                                    .child(e),
+                            })
+                    }))
+            )
+    }
+}
+
+impl Focusable for SamplesPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for SamplesPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_samples"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Samples".into_any_element()
+    }
+}
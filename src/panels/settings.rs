@@ -0,0 +1,517 @@
+//! Settings panel: the project's `pulsar-enum.toml`
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use std::path::PathBuf;
+use crate::codegen::CodegenTarget;
+use crate::naming::Case;
+use crate::project::{Density, EnumDefaults, ExportLayout, PanelDensity, ProjectConfig, CODE_FONT_SIZES};
+use crate::storage::SerializationFormat;
+
+/// Requests from the Settings Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum SettingsPanelEvent {
+    SaveRequested(ProjectConfig),
+    ApplyDefaultsRequested(EnumDefaults),
+}
+
+/// Panels with a density setting, and where it is kept
+const DENSITY_PANELS: [(&str, fn(&mut PanelDensity) -> &mut Density); 3] = [
+    ("Properties", |density| &mut density.properties),
+    ("Variants", |density| &mut density.variants),
+    ("Code Preview", |density| &mut density.code_preview),
+];
+
+/// Settings Panel - Edit the project's `pulsar-enum.toml`
+pub struct SettingsPanel {
+    config: ProjectConfig,
+    /// Where the configuration is saved
+    config_path: Option<PathBuf>,
+    template_input: Entity<InputState>,
+    repr_input: Entity<InputState>,
+    derives_input: Entity<InputState>,
+    autosave_delay_input: Entity<InputState>,
+    indent_width_input: Entity<InputState>,
+    payload_types_input: Entity<InputState>,
+    code_font_size_input: Entity<InputState>,
+    strip_prefixes_input: Entity<InputState>,
+    strip_suffixes_input: Entity<InputState>,
+    export_dir_input: Entity<InputState>,
+    source_root_input: Entity<InputState>,
+    focus_handle: FocusHandle,
+}
+
+impl SettingsPanel {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            config: ProjectConfig::default(),
+            config_path: None,
+            template_input: cx.new(|cx| InputState::new(window, cx).placeholder("templates/Base.enum.json")),
+            repr_input: cx.new(|cx| InputState::new(window, cx).placeholder("u8")),
+            derives_input: cx.new(|cx| InputState::new(window, cx).placeholder("PartialEq, Eq, Hash")),
+            autosave_delay_input: cx.new(|cx| InputState::new(window, cx).placeholder("30")),
+            indent_width_input: cx.new(|cx| InputState::new(window, cx).placeholder("4")),
+            payload_types_input: cx.new(|cx| InputState::new(window, cx).placeholder("EntityId, glam::Vec3")),
+            code_font_size_input: cx.new(|cx| InputState::new(window, cx).placeholder("13")),
+            strip_prefixes_input: cx.new(|cx| InputState::new(window, cx).placeholder("EVT_, k")),
+            strip_suffixes_input: cx.new(|cx| InputState::new(window, cx).placeholder("_E")),
+            export_dir_input: cx.new(|cx| InputState::new(window, cx).placeholder("next to each enum")),
+            source_root_input: cx.new(|cx| InputState::new(window, cx).placeholder("project root")),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Show a loaded configuration
+    pub fn set_config(&mut self, config: ProjectConfig, config_path: Option<PathBuf>, window: &mut Window, cx: &mut Context<Self>) {
+        let template = config.defaults.template.clone().unwrap_or_default();
+        let repr = config.defaults.repr.clone().unwrap_or_default();
+        let derives = config.defaults.derives.join(", ");
+        let delay = config.editor.autosave_delay_secs.to_string();
+        let indent = config.defaults.style.indent_width.to_string();
+        let payload_types = config.editor.payload_types.join(", ");
+        let code_font_size = config.editor.code_font_size.to_string();
+        let strip_prefixes = config.import.strip_prefixes.join(", ");
+        let strip_suffixes = config.import.strip_suffixes.join(", ");
+        let export_dir = config.export.dir.clone().unwrap_or_default();
+        let source_root = config.export.source_root.clone().unwrap_or_default();
+        self.template_input.update(cx, |input, cx| input.replace_text_in_range(None, &template, window, cx));
+        self.repr_input.update(cx, |input, cx| input.replace_text_in_range(None, &repr, window, cx));
+        self.derives_input.update(cx, |input, cx| input.replace_text_in_range(None, &derives, window, cx));
+        self.autosave_delay_input.update(cx, |input, cx| input.replace_text_in_range(None, &delay, window, cx));
+        self.indent_width_input.update(cx, |input, cx| input.replace_text_in_range(None, &indent, window, cx));
+        self.payload_types_input.update(cx, |input, cx| input.replace_text_in_range(None, &payload_types, window, cx));
+        self.code_font_size_input.update(cx, |input, cx| input.replace_text_in_range(None, &code_font_size, window, cx));
+        self.strip_prefixes_input.update(cx, |input, cx| input.replace_text_in_range(None, &strip_prefixes, window, cx));
+        self.strip_suffixes_input.update(cx, |input, cx| input.replace_text_in_range(None, &strip_suffixes, window, cx));
+        self.export_dir_input.update(cx, |input, cx| input.replace_text_in_range(None, &export_dir, window, cx));
+        self.source_root_input.update(cx, |input, cx| input.replace_text_in_range(None, &source_root, window, cx));
+        self.config = config;
+        self.config_path = config_path;
+        cx.notify();
+    }
+
+    /// The configuration with the text inputs applied
+    fn edited_config(&self, cx: &App) -> ProjectConfig {
+        let mut config = self.config.clone();
+        let optional = |input: &Entity<InputState>| {
+            let text = input.read(cx).text().trim().to_string();
+            (!text.is_empty()).then_some(text)
+        };
+        let list = |input: &Entity<InputState>| -> Vec<String> {
+            input
+                .read(cx)
+                .text()
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        config.defaults.template = optional(&self.template_input);
+        config.defaults.repr = optional(&self.repr_input);
+        config.defaults.derives = list(&self.derives_input);
+        config.editor.payload_types = list(&self.payload_types_input);
+        config.import.strip_prefixes = list(&self.strip_prefixes_input);
+        config.import.strip_suffixes = list(&self.strip_suffixes_input);
+        config.export.dir = optional(&self.export_dir_input);
+        config.export.source_root = optional(&self.source_root_input);
+        if let Ok(delay) = self.autosave_delay_input.read(cx).text().trim().parse::<u64>() {
+            config.editor.autosave_delay_secs = delay.max(1);
+        }
+        if let Ok(width) = self.indent_width_input.read(cx).text().trim().parse::<usize>() {
+            config.defaults.style.indent_width = width.clamp(1, 8);
+        }
+        if let Ok(size) = self.code_font_size_input.read(cx).text().trim().parse::<f32>() {
+            config.editor.code_font_size = size.clamp(*CODE_FONT_SIZES.start(), *CODE_FONT_SIZES.end());
+        }
+        config
+    }
+
+    fn toggle_default_target(&mut self, target: CodegenTarget, cx: &mut Context<Self>) {
+        let targets = &mut self.config.defaults.targets;
+        match targets.iter().position(|id| id == target.id()) {
+            Some(index) => {
+                targets.remove(index);
+            }
+            None => targets.push(target.id().to_string()),
+        }
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for SettingsPanel {}
+impl EventEmitter<SettingsPanelEvent> for SettingsPanel {}
+
+impl Render for SettingsPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let config = &self.config;
+        let heading = |label: &'static str, cx: &App| {
+            div()
+                .text_sm()
+                .font_semibold()
+                .text_color(cx.theme().foreground)
+                .child(label)
+        };
+
+        v_flex()
+            .id("enum-settings-content")
+            .size_full()
+            .p_4()
+            .gap_4()
+            .overflow_scroll()
+            .bg(cx.theme().sidebar)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(match &self.config_path {
+                        Some(path) => format!("Project settings in {}", path.display()),
+                        None => "Project settings".to_string(),
+                    })
+            )
+            // New enums
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Template", cx))
+                    .child(TextInput::new(&self.template_input))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Definition new enums are copied from, relative to the project root")
+                    )
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Repr", cx))
+                    .child(TextInput::new(&self.repr_input))
+            )
+            // Naming
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Variant Naming", cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children(Case::ALL.into_iter().map(|case| {
+                                Button::new(SharedString::from(format!("naming-{}", case.label())))
+                                    .when(config.defaults.naming == case, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(case.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.config.defaults.naming = case;
+                                        cx.notify();
+                                    }))
+                            }))
+                    )
+            )
+            // Serialization of new single-file enums
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Serialization", cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children(std::iter::once(None).chain(SerializationFormat::ALL.into_iter().map(Some)).map(|format| {
+                                Button::new(SharedString::from(format!(
+                                    "default-serialization-{}",
+                                    format.map_or("file", |format| format.extension())
+                                )))
+                                .when(config.defaults.serialization == format, |this| this.primary())
+                                .with_size(ui::Size::Small)
+                                .label(format.map_or("As created", |format| format.label()))
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    this.config.defaults.serialization = format;
+                                    cx.notify();
+                                }))
+                            }))
+                    )
+            )
+            // Derives
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Default Derives", cx))
+                    .child(TextInput::new(&self.derives_input))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Added to the Rust declaration besides Debug and Clone")
+                    )
+            )
+            // Targets
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Default Targets", cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children(CodegenTarget::ALL.into_iter().map(|target| {
+                                let enabled = config.defaults.targets.iter().any(|id| id == target.id());
+                                Button::new(SharedString::from(format!("default-target-{}", target.id())))
+                                    .when(enabled, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(target.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.toggle_default_target(target, cx);
+                                    }))
+                            }))
+                    )
+            )
+            // Codegen style
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Codegen Style", cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("Indent width"))
+                            .child(div().w(px(60.0)).child(TextInput::new(&self.indent_width_input)))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                Button::new("style-tabs")
+                                    .when(config.defaults.style.tabs, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label("Indent with tabs")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.config.defaults.style.tabs = !this.config.defaults.style.tabs;
+                                        cx.notify();
+                                    }))
+                            )
+                            .child(
+                                Button::new("style-crlf")
+                                    .when(config.defaults.style.crlf, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label("CRLF line endings")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.config.defaults.style.crlf = !this.config.defaults.style.crlf;
+                                        cx.notify();
+                                    }))
+                            )
+                    )
+            )
+            // Import
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Import", cx))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Renaming of variants imported from C headers and protobuf files")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("Strip prefixes"))
+                            .child(div().flex_1().child(TextInput::new(&self.strip_prefixes_input)))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("Strip suffixes"))
+                            .child(div().flex_1().child(TextInput::new(&self.strip_suffixes_input)))
+                    )
+                    .child(
+                        Button::new("import-strip-enum-name")
+                            .when(config.import.strip_enum_name, |this| this.primary())
+                            .with_size(ui::Size::Small)
+                            .label("Strip the enum's name (COLOR_RED in Color)")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.config.import.strip_enum_name = !this.config.import.strip_enum_name;
+                                cx.notify();
+                            }))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children([None].into_iter().chain(Case::ALL.map(Some)).map(|case| {
+                                Button::new(SharedString::from(format!("import-case-{:?}", case)))
+                                    .when(config.import.case == case, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(case.map_or("Keep case", |case| case.label()))
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.config.import.case = case;
+                                        cx.notify();
+                                    }))
+                            }))
+                    )
+            )
+            // Export All
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Export All", cx))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Where the enabled targets of every enum are written; folders under the source root become modules")
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("Output folder"))
+                            .child(div().flex_1().child(TextInput::new(&self.export_dir_input)))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("Source root"))
+                            .child(div().flex_1().child(TextInput::new(&self.source_root_input)))
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .flex_wrap()
+                            .children(ExportLayout::ALL.into_iter().map(|layout| {
+                                Button::new(SharedString::from(format!("export-layout-{:?}", layout)))
+                                    .when(config.export.layout == layout, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(layout.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        this.config.export.layout = layout;
+                                        cx.notify();
+                                    }))
+                            }))
+                    )
+            )
+            // Autosave
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Autosave", cx))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                Button::new("autosave")
+                                    .when(config.editor.autosave, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label("Autosave")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.config.editor.autosave = !this.config.editor.autosave;
+                                        cx.notify();
+                                    }))
+                            )
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("every"))
+                            .child(div().w(px(60.0)).child(TextInput::new(&self.autosave_delay_input)))
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("seconds"))
+                    )
+            )
+            // Panel spacing and the code preview's font size
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Appearance", cx))
+                    .children(DENSITY_PANELS.into_iter().map(|(panel, field)| {
+                        let current = *field(&mut config.editor.density.clone());
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().w(px(100.0)).text_sm().text_color(cx.theme().foreground).child(panel))
+                            .children(Density::ALL.into_iter().map(|density| {
+                                Button::new(SharedString::from(format!("density-{}-{}", panel, density.label())))
+                                    .when(current == density, |this| this.primary())
+                                    .with_size(ui::Size::Small)
+                                    .label(density.label())
+                                    .on_click(cx.listener(move |this, _, _window, cx| {
+                                        *field(&mut this.config.editor.density) = density;
+                                        cx.notify();
+                                    }))
+                            }))
+                    }))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(div().w(px(100.0)).text_sm().text_color(cx.theme().foreground).child("Code font size"))
+                            .child(div().w(px(60.0)).child(TextInput::new(&self.code_font_size_input)))
+                            .child(div().text_sm().text_color(cx.theme().foreground).child("px"))
+                    )
+            )
+            // Types offered by the payload type picker
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(heading("Payload Types", cx))
+                    .child(TextInput::new(&self.payload_types_input))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("Suggested with primitives, std types and the workspace's enums")
+                    )
+            )
+            .child(Divider::horizontal())
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("settings-save")
+                            .primary()
+                            .label("Save Settings")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                let config = this.edited_config(cx);
+                                cx.emit(SettingsPanelEvent::SaveRequested(config));
+                            }))
+                    )
+                    .child(
+                        Button::new("settings-apply-defaults")
+                            .label("Apply Defaults to This Enum")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                let defaults = this.edited_config(cx).defaults;
+                                cx.emit(SettingsPanelEvent::ApplyDefaultsRequested(defaults));
+                            }))
+                    )
+            )
+    }
+}
+
+impl Focusable for SettingsPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for SettingsPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_settings"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Settings".into_any_element()
+    }
+}
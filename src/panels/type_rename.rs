@@ -0,0 +1,172 @@
+//! Rename Type panel: renames a payload type across the project
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+    input::{InputState, TextInput},
+};
+use std::path::PathBuf;
+use crate::workspace_scan::TypeRenamePlan;
+
+/// Requests from the Rename Type Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum TypeRenamePanelEvent {
+    PreviewRequested { old: String, new: String },
+    ApplyRequested { old: String, new: String, plans: Vec<TypeRenamePlan> },
+}
+
+/// Rename Type Panel - Rename a payload type across every enum in the project
+pub struct TypeRenamePanel {
+    old_input: Entity<InputState>,
+    new_input: Entity<InputState>,
+    root: PathBuf,
+    /// Old and new name of the previewed rename
+    renaming: Option<(String, String)>,
+    /// Planned files, and whether each is included
+    plans: Vec<(TypeRenamePlan, bool)>,
+    previewed: bool,
+    focus_handle: FocusHandle,
+}
+
+impl TypeRenamePanel {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            old_input: cx.new(|cx| InputState::new(window, cx).placeholder("Type, e.g. EntityId")),
+            new_input: cx.new(|cx| InputState::new(window, cx).placeholder("New name")),
+            root: PathBuf::new(),
+            renaming: None,
+            plans: Vec::new(),
+            previewed: false,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn set_plans(&mut self, root: PathBuf, plans: Vec<TypeRenamePlan>, cx: &mut Context<Self>) {
+        self.root = root;
+        self.plans = plans.into_iter().map(|plan| (plan, true)).collect();
+        self.previewed = true;
+        cx.notify();
+    }
+
+    fn preview(&mut self, cx: &mut Context<Self>) {
+        let old = self.old_input.read(cx).text().trim().to_string();
+        let new = self.new_input.read(cx).text().trim().to_string();
+        if !old.is_empty() && !new.is_empty() && old != new {
+            self.renaming = Some((old.clone(), new.clone()));
+            cx.emit(TypeRenamePanelEvent::PreviewRequested { old, new });
+        }
+    }
+
+    fn apply(&mut self, cx: &mut Context<Self>) {
+        let Some((old, new)) = self.renaming.take() else {
+            return;
+        };
+        let plans: Vec<_> = std::mem::take(&mut self.plans)
+            .into_iter()
+            .filter_map(|(plan, included)| included.then_some(plan))
+            .collect();
+        self.previewed = false;
+        if !plans.is_empty() {
+            cx.emit(TypeRenamePanelEvent::ApplyRequested { old, new, plans });
+        }
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for TypeRenamePanel {}
+impl EventEmitter<TypeRenamePanelEvent> for TypeRenamePanel {}
+
+impl Render for TypeRenamePanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let included = self.plans.iter().filter(|(_, included)| *included).count();
+
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .bg(cx.theme().sidebar)
+            .child(TextInput::new(&self.old_input))
+            .child(TextInput::new(&self.new_input))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("type-rename-preview")
+                            .label("Preview")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.preview(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("type-rename-apply")
+                            .primary()
+                            .label(format!("Rename in {} Files", included))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.apply(cx);
+                            }))
+                    )
+            )
+            .child(
+                v_flex()
+                    .id("enum-type-rename-preview")
+                    .flex_1()
+                    .gap_2()
+                    .overflow_scroll()
+                    .when(self.previewed && self.plans.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("No enum in the project uses this type")
+                        )
+                    })
+                    .children(self.plans.iter().enumerate().map(|(index, (plan, included))| {
+                        let path = plan.path.strip_prefix(&self.root).unwrap_or(&plan.path);
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .items_center()
+                                    .child(
+                                        Button::new(("type-rename-include", index))
+                                            .with_size(ui::Size::XSmall)
+                                            .when(*included, |this| this.primary().icon(IconName::Check))
+                                            .label(path.display().to_string())
+                                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                                if let Some((_, included)) = this.plans.get_mut(index) {
+                                                    *included = !*included;
+                                                }
+                                                cx.notify();
+                                            }))
+                                    )
+                            )
+                            .children(plan.changes.iter().map(|change| {
+                                div()
+                                    .pl_6()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(change.replace('`', ""))
+                            }))
+                    }))
+            )
+    }
+}
+
+impl Focusable for TypeRenamePanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for TypeRenamePanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_type_rename"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Rename Type".into_any_element()
+    }
+}
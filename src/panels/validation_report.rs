@@ -0,0 +1,219 @@
+//! Validation Report panel: problems in every enum of the workspace
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    button::{Button, ButtonVariants},
+};
+use std::path::PathBuf;
+use crate::output::Severity;
+use crate::workspace_scan::{self, FileValidation};
+
+/// Requests from the Validation Report Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum ValidationReportPanelEvent {
+    ValidateAllRequested,
+    /// An issue about a variant was clicked
+    IssueSelected { path: PathBuf, variant: usize },
+}
+
+/// Validation Report Panel - Problems in every enum of the workspace
+pub struct ValidationReportPanel {
+    root: PathBuf,
+    results: Vec<FileValidation>,
+    /// Whether a run has completed
+    validated: bool,
+    /// Number of definitions being checked, while a run is in progress
+    checking: Option<usize>,
+    focus_handle: FocusHandle,
+}
+
+impl ValidationReportPanel {
+    pub fn new(_window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            root: PathBuf::new(),
+            results: Vec::new(),
+            validated: false,
+            checking: None,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    /// Clear the report for a run over `total` definitions under `root`
+    pub fn begin(&mut self, root: PathBuf, total: usize, cx: &mut Context<Self>) {
+        self.root = root;
+        self.results.clear();
+        self.validated = false;
+        self.checking = Some(total);
+        cx.notify();
+    }
+
+    /// Show results as they come in
+    pub fn append(&mut self, results: Vec<FileValidation>, cx: &mut Context<Self>) {
+        self.results.extend(results);
+        cx.notify();
+    }
+
+    /// Replace the results of a definition already in the report, e.g.
+    /// after it was edited
+    pub fn update_file(&mut self, validation: FileValidation, cx: &mut Context<Self>) {
+        if let Some(existing) = self.results.iter_mut().find(|result| result.path == validation.path) {
+            *existing = validation;
+            cx.notify();
+        }
+    }
+
+    pub fn finish(&mut self, cx: &mut Context<Self>) {
+        self.checking = None;
+        self.validated = true;
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for ValidationReportPanel {}
+impl EventEmitter<ValidationReportPanelEvent> for ValidationReportPanel {}
+
+impl Render for ValidationReportPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let errors: usize = self.results.iter().map(FileValidation::errors).sum();
+        let warnings: usize = self.results.iter().map(FileValidation::warnings).sum();
+        let report = workspace_scan::report_to_text(&self.root, &self.results);
+
+        v_flex()
+            .size_full()
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.0))
+                    .px_3()
+                    .gap_2()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        Button::new("validate-all")
+                            .primary()
+                            .with_size(ui::Size::Small)
+                            .label("Validate All Enums")
+                            .on_click(cx.listener(|_this, _, _window, cx| {
+                                cx.emit(ValidationReportPanelEvent::ValidateAllRequested);
+                            }))
+                    )
+                    .child(div().flex_1())
+                    .when_some(self.checking, |this, total| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("Checking {} of {}…", self.results.len(), total))
+                        )
+                    })
+                    .when(self.validated, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(if errors > 0 { cx.theme().danger } else { cx.theme().muted_foreground })
+                                .child(format!("{} enums, {} errors, {} warnings", self.results.len(), errors, warnings))
+                        )
+                        .child(
+                            Button::new("validation-report-copy")
+                                .ghost()
+                                .with_size(ui::Size::Small)
+                                .icon(IconName::Copy)
+                                .tooltip("Copy report")
+                                .on_click(move |_, _window, cx| {
+                                    cx.write_to_clipboard(ClipboardItem::new_string(report.clone()));
+                                })
+                        )
+                    })
+            )
+            .child(
+                v_flex()
+                    .id("enum-validation-report-content")
+                    .flex_1()
+                    .p_2()
+                    .gap_2()
+                    .overflow_scroll()
+                    .when(!self.validated && self.checking.is_none(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("Check every .enum in the project before committing")
+                        )
+                    })
+                    .when(self.validated && errors + warnings == 0, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().success)
+                                .child("No problems found")
+                        )
+                    })
+                    .children(
+                        self.results
+                            .iter()
+                            .filter(|file| file.errors() + file.warnings() > 0)
+                            .enumerate()
+                            .map(|(file_index, file)| {
+                                let path = file.path.strip_prefix(&self.root).unwrap_or(&file.path);
+                                let issues: Vec<(Severity, String, Option<usize>)> = match &file.result {
+                                    Ok(issues) => issues.iter().map(|i| (i.severity, i.message.clone(), i.variant)).collect(),
+                                    Err(message) => vec![(Severity::Error, message.clone(), None)],
+                                };
+                                v_flex()
+                                    .id(("validation-file", file_index))
+                                    .gap_1()
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .font_semibold()
+                                            .text_color(cx.theme().foreground)
+                                            .child(path.display().to_string())
+                                    )
+                                    .children(issues.into_iter().enumerate().map(|(issue_index, (severity, message, variant))| {
+                                        let color = match severity {
+                                            Severity::Error => cx.theme().danger,
+                                            Severity::Warning => cx.theme().warning,
+                                            Severity::Info => cx.theme().muted_foreground,
+                                        };
+                                        let path = file.path.clone();
+                                        div()
+                                            .id(("validation-issue", issue_index))
+                                            .pl_3()
+                                            .text_xs()
+                                            .text_color(color)
+                                            .child(format!("{}: {}", severity.label(), message))
+                                            // Issues about a variant jump to it
+                                            .when_some(variant, |this, variant| {
+                                                this.cursor_pointer().on_click(cx.listener(move |_this, _, _window, cx| {
+                                                    cx.emit(ValidationReportPanelEvent::IssueSelected {
+                                                        path: path.clone(),
+                                                        variant,
+                                                    });
+                                                }))
+                                            })
+                                    }))
+                            })
+                    )
+            )
+    }
+}
+
+impl Focusable for ValidationReportPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for ValidationReportPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_validation_report"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Validation".into_any_element()
+    }
+}
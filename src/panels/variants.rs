@@ -0,0 +1,1899 @@
+//! Variants panel: the variant list, with its columns, selection, context
+//! menu and paste
+
+use gpui::{*, prelude::FluentBuilder};
+use ui::{
+    v_flex, h_flex, ActiveTheme, StyledExt, IconName, Sizable,
+    dock::{Panel, PanelEvent},
+    divider::Divider,
+    button::{Button, ButtonVariants},
+    popup_menu::ContextMenuExt as _,
+    ContextModal as _,
+};
+use ui_types_common::{EnumAsset, EnumVariant, VariantPayload};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use crate::bits;
+use crate::columns::{ColumnLayout, VariantColumn};
+use crate::codegen;
+use crate::drift::Drift;
+use crate::editor::{ExportAll, GenerateAll, RevealInFolder, ValidateAll};
+use crate::import::{paste::{self, PasteFormat}, SourceEnum};
+use crate::git::Commit;
+use crate::metadata::{self, variant_values, EnumMeta, EnumMode, Note, Stage, VariantMeta};
+use crate::markdown;
+use crate::naming;
+use crate::references::{self, RenameImpact};
+use crate::samples::{self, SampleFormat};
+use crate::project::Density;
+use crate::spellcheck::SpellChecker;
+use crate::type_suggestions::TypeSuggestions;
+use crate::variant_editor::{ValueFormat, VariantEditorView, VariantEditorEvent};
+
+/// Requests from the Variants Panel that the owning editor handles
+#[derive(Clone, Debug)]
+pub enum VariantsPanelEvent {
+    /// The blame gutter was turned on; answered with `set_blame`
+    BlameRequested,
+    /// Merge the changed Rust source into the definition
+    ReimportRequested,
+    /// Add pasted variants, or replace the variants with them
+    PasteRequested { source: SourceEnum, replace: bool },
+    /// Load the unsaved edits a previous session left behind
+    RestoreRecoveryRequested,
+    /// Delete the unsaved edits a previous session left behind
+    DiscardRecoveryRequested,
+    /// A variant was clicked or revealed
+    VariantSelected(String),
+    /// The variant to show in the inspector changed; see `inspected`
+    InspectedChanged,
+    /// Columns of the compact list were changed
+    ColumnsChanged(ColumnLayout),
+    /// List the uses of the named variant in the project's sources
+    FindReferencesRequested(String),
+}
+
+actions!(enum_variants, [SelectPrevious, SelectNext, SelectFirst, SelectLast]);
+
+// Context menu of a variant row; each acts on the last variant selected
+actions!(enum_variants, [
+    RenameVariant,
+    DuplicateVariant,
+    DeleteVariant,
+    CopyAsRust,
+    CopyAsJson,
+    CopyAsRon,
+    CopyAsToml,
+    FindReferences,
+]);
+
+/// Key context of the Variants panel, where the list's bindings apply
+const VARIANTS_KEY_CONTEXT: &str = "EnumVariants";
+
+/// Bind the arrow, Home and End keys of the variants list. Inputs inside the
+/// panel have their own bindings for these keys, which take precedence.
+pub fn bind_keys(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrevious, Some(VARIANTS_KEY_CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(VARIANTS_KEY_CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(VARIANTS_KEY_CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(VARIANTS_KEY_CONTEXT)),
+    ]);
+}
+
+/// Width of the name column of the compact list
+const NAME_COLUMN_WIDTH: f32 = 180.0;
+
+/// Pixels a column grows or shrinks by per click
+const COLUMN_WIDTH_STEP: f32 = 20.0;
+
+/// Variants listed before the A–Z jump bar appears
+const JUMP_BAR_MIN_VARIANTS: usize = 30;
+
+/// Letters of the jump bar; `#` stands for names that don't start with one
+const JUMP_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ#";
+
+/// Jump bar letter of a variant name, ignoring leading underscores
+fn initial(name: &str) -> char {
+    match name.trim_start_matches('_').chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase(),
+        _ => '#',
+    }
+}
+
+/// Everything about a variant shown when hovering its row in the compact list
+struct VariantTooltip {
+    signature: String,
+    /// Label and value rows, e.g. `("Value", "3")`
+    details: Vec<(&'static str, String)>,
+    doc: Option<String>,
+    /// Its lines of the Rust declaration
+    code: Option<String>,
+}
+
+impl VariantTooltip {
+    fn new(asset: &EnumAsset, index: usize) -> Option<Self> {
+        let model = codegen::EnumModel::from_asset(asset);
+        let variant = model.variants.get(index)?;
+        let meta = EnumMeta::from_asset(asset).variant(&variant.name);
+        let mut details = vec![
+            ("Value", match variant.combines.as_slice() {
+                [] => variant.value.to_string(),
+                flags => flags.join(" | "),
+            }),
+            ("Display name", variant.display_name.clone()),
+        ];
+        let mut stage = meta.stage.label().to_string();
+        if meta.deprecated {
+            stage.push_str(", deprecated");
+        }
+        details.push(("Stage", stage));
+        if let Some(owner) = &meta.owner {
+            details.push(("Owner", owner.clone()));
+        }
+        if let Some(cfg) = &meta.cfg {
+            details.push(("Only with", format!("cfg({})", cfg)));
+        }
+        if !meta.aliases.is_empty() {
+            details.push(("Old names", meta.aliases.join(", ")));
+        }
+        if meta.open_notes() > 0 {
+            details.push(("Open notes", meta.open_notes().to_string()));
+        }
+        Some(Self {
+            signature: format!("{}{}", variant.name, codegen::payload_signature(variant)),
+            details,
+            doc: variant.doc.clone().filter(|doc| !doc.trim().is_empty()),
+            code: codegen::generate_rust_variant(asset, &variant.name),
+        })
+    }
+}
+
+impl Render for VariantTooltip {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .max_w(px(420.0))
+            .p_2()
+            .gap_2()
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .shadow_md()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .text_color(cx.theme().foreground)
+                    .child(self.signature.clone())
+            )
+            .children(self.details.iter().map(|(label, value)| {
+                h_flex()
+                    .gap_2()
+                    .text_xs()
+                    .child(div().w(px(80.0)).flex_none().text_color(cx.theme().muted_foreground).child(*label))
+                    .child(div().text_color(cx.theme().foreground).child(value.clone()))
+            }))
+            .when_some(self.doc.clone(), |this, doc| this.child(markdown::render(&doc, cx)))
+            .when_some(self.code.clone(), |this, code| {
+                this.child(
+                    div()
+                        .p_2()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().background)
+                        .font_family(cx.theme().mono_font_family.clone())
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(code)
+                )
+            })
+    }
+}
+
+/// Variants Panel - Manage enum variants (add, remove, edit)
+pub struct VariantsPanel {
+    asset: Arc<parking_lot::RwLock<EnumAsset>>,
+    variant_editors: Vec<Entity<VariantEditorView>>,
+    show_trash: bool,
+    /// Hide variants gated behind a `cfg`
+    hide_gated: bool,
+    /// Show only variants with unresolved notes
+    only_open_notes: bool,
+    /// Last commit per variant, shown in a gutter while set
+    blame: Option<HashMap<String, Commit>>,
+    /// Variants no project source references; only these are listed while set
+    unused: Option<HashSet<String>>,
+    /// Scans the project for references in the background
+    unused_task: Option<Task<()>>,
+    /// Counts the references a requested rename affects
+    rename_task: Option<Task<()>>,
+    value_format: ValueFormat,
+    /// Name of the enum's Rust source and how it differs, while it has
+    /// changed since it was last imported
+    source_changes: Option<(String, Vec<Drift>)>,
+    /// Variants read from the clipboard, waiting to be added
+    pasted: Option<Result<(PasteFormat, SourceEnum), String>>,
+    /// Describes unsaved edits found from a previous session, until they are
+    /// restored or discarded
+    recovery: Option<String>,
+    /// Directory scanned for references when previewing renames
+    search_root: Option<PathBuf>,
+    /// Recorded as the owner of variants added in this panel
+    user: Option<String>,
+    /// Selected variants, shown highlighted. The last one clicked without
+    /// Shift is at the end; Shift-clicks select the range from it.
+    selected: Vec<Entity<VariantEditorView>>,
+    /// Names of the variants kept at the top of the panel. Only the editor
+    /// remembers them; they aren't saved with the definition.
+    pinned: Vec<String>,
+    /// List variants as one-line summaries and edit the selected one in the
+    /// Inspector panel
+    compact: bool,
+    /// Columns of the compact list
+    columns: ColumnLayout,
+    density: Density,
+    /// Show the column settings under the header
+    show_columns: bool,
+    scroll_handle: ScrollHandle,
+    spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+    /// Shared with the variant editors' type pickers
+    type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
+    focus_handle: FocusHandle,
+    on_modified: Arc<parking_lot::Mutex<Option<Box<dyn Fn() + Send + Sync>>>>,
+}
+
+impl VariantsPanel {
+    pub fn new(
+        asset: Arc<parking_lot::RwLock<EnumAsset>>,
+        spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+        type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let asset_read = asset.read();
+        let meta = EnumMeta::from_asset(&asset_read);
+        let mut variant_editors = Vec::new();
+
+        for (index, variant) in asset_read.variants.iter().enumerate() {
+            let variant_meta = meta.variant(&variant.name);
+            variant_editors.push(Self::create_variant_editor(
+                variant.clone(),
+                variant_meta,
+                index,
+                spell_checker.clone(),
+                type_suggestions.clone(),
+                ValueFormat::default(),
+                window,
+                cx,
+            ));
+        }
+        drop(asset_read);
+
+        let panel = Self {
+            asset,
+            variant_editors,
+            show_trash: false,
+            hide_gated: false,
+            only_open_notes: false,
+            blame: None,
+            unused: None,
+            unused_task: None,
+            rename_task: None,
+            value_format: ValueFormat::default(),
+            source_changes: None,
+            pasted: None,
+            recovery: None,
+            search_root: None,
+            user: None,
+            selected: Vec::new(),
+            pinned: Vec::new(),
+            compact: true,
+            columns: ColumnLayout::default(),
+            density: Density::default(),
+            show_columns: false,
+            scroll_handle: ScrollHandle::new(),
+            spell_checker,
+            type_suggestions,
+            focus_handle: cx.focus_handle(),
+            on_modified: Arc::new(parking_lot::Mutex::new(None)),
+        };
+        panel.sync_flags_mode(cx);
+        panel
+    }
+
+    pub fn set_on_modified<F>(&mut self, callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        *self.on_modified.lock() = Some(Box::new(callback));
+    }
+
+    fn notify_modified(&self) {
+        if let Some(ref callback) = *self.on_modified.lock() {
+            callback();
+        }
+    }
+
+    /// Tell the variant editors whether the enum is in bitflags mode
+    pub fn sync_flags_mode(&self, cx: &mut Context<Self>) {
+        let flags = EnumMeta::from_asset(&self.asset.read()).mode == EnumMode::Flags;
+        for editor in &self.variant_editors {
+            editor.update(cx, |editor, cx| editor.set_flags_mode(flags, cx));
+        }
+        cx.notify();
+    }
+
+    pub fn set_search_root(&mut self, root: Option<PathBuf>) {
+        self.search_root = root;
+    }
+
+    pub fn set_user(&mut self, user: Option<String>) {
+        self.user = user;
+    }
+
+    pub fn set_blame(&mut self, blame: Option<HashMap<String, Commit>>, cx: &mut Context<Self>) {
+        self.blame = blame;
+        cx.notify();
+    }
+
+    /// Show or clear the banner offering to re-import the changed Rust source
+    pub fn set_source_changes(&mut self, changes: Option<(String, Vec<Drift>)>, cx: &mut Context<Self>) {
+        self.source_changes = changes;
+        cx.notify();
+    }
+
+    /// Show or clear the banner offering to restore unsaved edits
+    pub fn set_recovery(&mut self, recovery: Option<String>, cx: &mut Context<Self>) {
+        self.recovery = recovery;
+        cx.notify();
+    }
+
+    /// Read variants from the clipboard and offer to add them
+    pub fn paste(&mut self, cx: &mut Context<Self>) {
+        let text = cx.read_from_clipboard().and_then(|item| item.text());
+        self.pasted = Some(match text {
+            Some(text) => paste::detect(&text).ok_or_else(|| "Nothing in the clipboard reads as variants".to_string()),
+            None => Err("The clipboard holds no text".to_string()),
+        });
+        cx.notify();
+    }
+
+    fn apply_paste(&mut self, replace: bool, cx: &mut Context<Self>) {
+        if let Some(Ok((_, source))) = self.pasted.take() {
+            cx.emit(VariantsPanelEvent::PasteRequested { source, replace });
+        }
+        cx.notify();
+    }
+
+    /// Select the listed variant at `position` in the list, or the last one,
+    /// and scroll to it
+    fn select_listed(&mut self, position: usize, cx: &mut Context<Self>) {
+        let listed: Vec<usize> = self
+            .variant_editors
+            .iter()
+            .map(|editor| editor.read(cx))
+            .filter(|editor| self.is_listed(editor))
+            .map(|editor| editor.index)
+            .collect();
+        let Some(last) = listed.len().checked_sub(1) else {
+            return;
+        };
+        let position = position.min(last);
+        self.select_variant(listed[position], cx);
+        self.scroll_handle.scroll_to_item(position);
+    }
+
+    /// Position in the list of the variant the arrow keys move from: the
+    /// last one selected
+    fn cursor(&self, cx: &App) -> Option<usize> {
+        let current = self.selected.last()?;
+        self.variant_editors.iter().filter(|editor| self.is_listed(editor.read(cx))).position(|editor| editor == current)
+    }
+
+    fn select_previous(&mut self, _: &SelectPrevious, _window: &mut Window, cx: &mut Context<Self>) {
+        let position = self.cursor(cx).map_or(0, |position| position.saturating_sub(1));
+        self.select_listed(position, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, _window: &mut Window, cx: &mut Context<Self>) {
+        let position = self.cursor(cx).map_or(0, |position| position + 1);
+        self.select_listed(position, cx);
+    }
+
+    fn select_first(&mut self, _: &SelectFirst, _window: &mut Window, cx: &mut Context<Self>) {
+        self.select_listed(0, cx);
+    }
+
+    fn select_last(&mut self, _: &SelectLast, _window: &mut Window, cx: &mut Context<Self>) {
+        self.select_listed(usize::MAX, cx);
+    }
+
+    /// Select the row a context menu is opening on, unless it is already
+    /// part of the selection, and take focus so the menu's actions reach
+    /// the panel
+    fn open_row_menu(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let selected = self.variant_editors.get(index).is_some_and(|editor| self.selected.contains(editor));
+        if !selected {
+            self.select_variant(index, cx);
+        }
+        window.focus(&self.focus_handle);
+    }
+
+    fn rename_selected(&mut self, _: &RenameVariant, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(editor) = self.selected.last().cloned() {
+            editor.update(cx, |editor, cx| editor.start_rename(window, cx));
+        }
+    }
+
+    fn duplicate_selected(&mut self, _: &DuplicateVariant, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(index) = self.selected.last().map(|editor| editor.read(cx).index) {
+            self.duplicate_variant(index, window, cx);
+        }
+    }
+
+    fn delete_selected(&mut self, _: &DeleteVariant, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(editor) = self.selected.last().cloned() {
+            editor.update(cx, |editor, cx| editor.request_remove(cx));
+        }
+    }
+
+    /// Put the last selected variant on the clipboard as Rust, or as a
+    /// config value in `format`
+    fn copy_selected(&mut self, format: Option<SampleFormat>, cx: &mut Context<Self>) {
+        let Some(name) = self.selected.last().map(|editor| editor.read(cx).variant.name.clone()) else {
+            return;
+        };
+        let asset = self.asset.read();
+        let text = match format {
+            None => codegen::generate_rust_variant(&asset, &name),
+            Some(format) => asset.variants.iter().find(|variant| variant.name == name).map(|variant| {
+                samples::variant_text(&asset, variant, &EnumMeta::from_asset(&asset), format).unwrap_or_else(|message| message)
+            }),
+        };
+        drop(asset);
+        if let Some(text) = text {
+            cx.write_to_clipboard(ClipboardItem::new_string(text));
+        }
+    }
+
+    fn copy_as_rust(&mut self, _: &CopyAsRust, _window: &mut Window, cx: &mut Context<Self>) {
+        self.copy_selected(None, cx);
+    }
+
+    fn copy_as_json(&mut self, _: &CopyAsJson, _window: &mut Window, cx: &mut Context<Self>) {
+        self.copy_selected(Some(SampleFormat::Json), cx);
+    }
+
+    fn copy_as_ron(&mut self, _: &CopyAsRon, _window: &mut Window, cx: &mut Context<Self>) {
+        self.copy_selected(Some(SampleFormat::Ron), cx);
+    }
+
+    fn copy_as_toml(&mut self, _: &CopyAsToml, _window: &mut Window, cx: &mut Context<Self>) {
+        self.copy_selected(Some(SampleFormat::Toml), cx);
+    }
+
+    fn find_selected_references(&mut self, _: &FindReferences, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(name) = self.selected.last().map(|editor| editor.read(cx).variant.name.clone()) {
+            cx.emit(VariantsPanelEvent::FindReferencesRequested(name));
+        }
+    }
+
+    /// True if the variant is in the scrolling list: it isn't pinned and it
+    /// passes the list's filters
+    fn is_listed(&self, editor: &VariantEditorView) -> bool {
+        !self.pinned.contains(&editor.variant.name)
+            && !(self.hide_gated && editor.meta.cfg.is_some())
+            && (!self.only_open_notes || editor.meta.open_notes() > 0)
+            && self.unused.as_ref().map_or(true, |unused| unused.contains(&editor.variant.name))
+    }
+
+    /// Scroll to the variant at `index`, highlight it and focus the panel,
+    /// turning off filters that hide it. False if there is no such variant.
+    pub fn reveal_variant(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(editor) = self.variant_editors.get(index).cloned() else {
+            return false;
+        };
+        // Pinned variants are always shown
+        if !self.pinned.contains(&editor.read(cx).variant.name) {
+            if !self.is_listed(editor.read(cx)) {
+                self.hide_gated = false;
+                self.only_open_notes = false;
+                self.unused = None;
+            }
+            let position = self.variant_editors[..index].iter().filter(|e| self.is_listed(e.read(cx))).count();
+            self.scroll_handle.scroll_to_item(position);
+        }
+        self.select_variant(index, cx);
+        window.focus(&self.focus_handle);
+        true
+    }
+
+    pub fn pinned(&self) -> &[String] {
+        &self.pinned
+    }
+
+    fn toggle_pin(&mut self, name: String, cx: &mut Context<Self>) {
+        match self.pinned.iter().position(|pinned| *pinned == name) {
+            Some(position) => {
+                self.pinned.remove(position);
+            }
+            None => self.pinned.push(name),
+        }
+        cx.notify();
+    }
+
+    /// A variant editor with its pin button, blame gutter, flag bits and
+    /// context menu. The variant the arrow keys move from gets a focus ring
+    /// while `focused`.
+    fn variant_row(
+        &self,
+        editor: &Entity<VariantEditorView>,
+        flag_overlap: Option<&(Vec<usize>, Vec<i64>)>,
+        values: Option<&[i64]>,
+        focused: bool,
+        cx: &Context<Self>,
+    ) -> impl IntoElement {
+        let index = editor.read(cx).index;
+        let name = editor.read(cx).variant.name.clone();
+        let pinned = self.pinned.contains(&name);
+        let asset = self.asset.clone();
+        let cursor = focused && self.selected.last() == Some(editor);
+        h_flex()
+            .id(("variant-row", index))
+            .w_full()
+            .items_start()
+            .gap_2()
+            .rounded(cx.theme().radius)
+            .border_1()
+            .border_color(if cursor { cx.theme().ring } else { transparent_black() })
+            // The full editor already shows everything; summaries show the rest on hover
+            .when(values.is_some(), |this| {
+                this.tooltip(move |_window, cx| match VariantTooltip::new(&asset.read(), index) {
+                    Some(tooltip) => cx.new(|_| tooltip).into(),
+                    None => cx.new(|_| Empty).into(),
+                })
+            })
+            .on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+                let modifiers = event.modifiers();
+                if modifiers.shift {
+                    this.select_range(index, cx);
+                } else if modifiers.secondary() {
+                    this.toggle_selected(index, cx);
+                } else {
+                    this.select_variant(index, cx);
+                }
+                // Summaries have no inputs, so the list takes the arrow keys
+                if this.compact {
+                    window.focus(&this.focus_handle);
+                }
+            }))
+            .child(
+                Button::new(("pin-variant", index))
+                    .ghost()
+                    .with_size(ui::Size::XSmall)
+                    .mt_3()
+                    .when(pinned, |this| this.primary())
+                    .label(if pinned { "Unpin" } else { "Pin" })
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.toggle_pin(name.clone(), cx);
+                    }))
+            )
+            .when(self.blame.is_some(), |this| {
+                this.child(self.blame_gutter(&editor.read(cx).variant.name, cx))
+            })
+            .when_some(flag_overlap, |this, (overlap, values)| {
+                let editor = editor.read(cx);
+                let value = values.get(editor.index).copied().unwrap_or(0);
+                this.child(Self::bit_grid(value, editor.meta.is_composite(), overlap, cx))
+            })
+            .child(match values {
+                Some(values) => {
+                    let editor = editor.read(cx);
+                    self.summary(editor, values.get(editor.index).copied(), cx)
+                }
+                None => div().flex_1().child(editor.clone()),
+            })
+            .on_mouse_down(MouseButton::Right, cx.listener(move |this, _, window, cx| {
+                this.open_row_menu(index, window, cx);
+            }))
+            .context_menu(|menu, window, cx| {
+                menu.menu("Rename", Box::new(RenameVariant))
+                    .menu("Duplicate", Box::new(DuplicateVariant))
+                    .menu("Delete", Box::new(DeleteVariant))
+                    .separator()
+                    .submenu("Copy As", window, cx, |menu, _window, _cx| {
+                        menu.menu("Rust", Box::new(CopyAsRust))
+                            .menu("JSON", Box::new(CopyAsJson))
+                            .menu("RON", Box::new(CopyAsRon))
+                            .menu("TOML", Box::new(CopyAsToml))
+                    })
+                    .separator()
+                    .menu("Find References", Box::new(FindReferences))
+            })
+    }
+
+    /// Scroll to the first listed variant whose name starts with `letter`
+    fn jump_to_letter(&mut self, letter: char, cx: &mut Context<Self>) {
+        let position = self
+            .variant_editors
+            .iter()
+            .filter(|editor| self.is_listed(editor.read(cx)))
+            .position(|editor| initial(&editor.read(cx).variant.name) == letter);
+        if let Some(position) = position {
+            self.scroll_handle.scroll_to_item(position);
+            cx.notify();
+        }
+    }
+
+    /// A–Z column for long lists, given the initials of the listed variants.
+    /// The letter at the top of the view is highlighted, with the position
+    /// in the list below.
+    fn jump_bar(&self, initials: &[char], cx: &mut Context<Self>) -> Div {
+        let top = self.scroll_handle.top_item().min(initials.len().saturating_sub(1));
+        let current = initials.get(top).copied();
+        v_flex()
+            .w(px(40.0))
+            .h_full()
+            .py_1()
+            .items_center()
+            .border_l_1()
+            .border_color(cx.theme().border)
+            .children(JUMP_LETTERS.chars().map(|letter| {
+                let present = initials.contains(&letter);
+                let color = if current == Some(letter) {
+                    cx.theme().primary
+                } else if present {
+                    cx.theme().foreground
+                } else {
+                    cx.theme().muted_foreground.opacity(0.4)
+                };
+                div()
+                    .id(SharedString::from(format!("jump-{}", letter)))
+                    .text_xs()
+                    .text_color(color)
+                    .when(current == Some(letter), |this| this.font_semibold())
+                    .when(present, |this| {
+                        this.cursor_pointer().on_click(cx.listener(move |this, _, _window, cx| {
+                            this.jump_to_letter(letter, cx);
+                        }))
+                    })
+                    .child(letter.to_string())
+            }))
+            .child(div().flex_1())
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("{}/{}", top + 1, initials.len()))
+            )
+    }
+
+    /// Select only the variant at `index` and announce it
+    fn select_variant(&mut self, index: usize, cx: &mut Context<Self>) {
+        let Some(editor) = self.variant_editors.get(index).cloned() else {
+            return;
+        };
+        cx.emit(VariantsPanelEvent::VariantSelected(editor.read(cx).variant.name.clone()));
+        self.set_selection(vec![editor], cx);
+    }
+
+    /// Add the variant at `index` to the selection, or take it out
+    fn toggle_selected(&mut self, index: usize, cx: &mut Context<Self>) {
+        let Some(editor) = self.variant_editors.get(index).cloned() else {
+            return;
+        };
+        let mut selection = self.selected.clone();
+        match selection.iter().position(|selected| *selected == editor) {
+            Some(position) => {
+                selection.remove(position);
+            }
+            None => selection.push(editor),
+        }
+        self.set_selection(selection, cx);
+    }
+
+    /// Select the listed variants between the last one clicked and the one at
+    /// `index`
+    fn select_range(&mut self, index: usize, cx: &mut Context<Self>) {
+        let Some(anchor) = self.selected.last().cloned() else {
+            return self.select_variant(index, cx);
+        };
+        let from = anchor.read(cx).index;
+        let mut selection: Vec<Entity<VariantEditorView>> = self
+            .variant_editors
+            .get(from.min(index)..=from.max(index))
+            .unwrap_or_default()
+            .iter()
+            .filter(|editor| **editor != anchor && (self.is_listed(editor.read(cx)) || editor.read(cx).index == index))
+            .cloned()
+            .collect();
+        selection.push(anchor);
+        self.set_selection(selection, cx);
+    }
+
+    fn set_selection(&mut self, selection: Vec<Entity<VariantEditorView>>, cx: &mut Context<Self>) {
+        for editor in &self.selected {
+            if !selection.contains(editor) {
+                editor.update(cx, |editor, cx| editor.set_highlighted(false, cx));
+            }
+        }
+        for editor in &selection {
+            editor.update(cx, |editor, cx| editor.set_highlighted(true, cx));
+        }
+        self.selected = selection;
+        cx.emit(VariantsPanelEvent::InspectedChanged);
+        cx.notify();
+    }
+
+    /// Editors to show in the inspector: the selected variant while the list
+    /// is compact, or any selection of several variants
+    pub fn inspected(&self) -> Vec<Entity<VariantEditorView>> {
+        if self.compact || self.selected.len() > 1 {
+            self.selected.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    pub fn set_columns(&mut self, columns: ColumnLayout, cx: &mut Context<Self>) {
+        self.columns = columns;
+        cx.notify();
+    }
+
+    pub fn set_density(&mut self, density: Density, cx: &mut Context<Self>) {
+        self.density = density;
+        cx.notify();
+    }
+
+    fn update_columns(&mut self, update: impl FnOnce(&mut ColumnLayout), cx: &mut Context<Self>) {
+        update(&mut self.columns);
+        cx.emit(VariantsPanelEvent::ColumnsChanged(self.columns.clone()));
+        cx.notify();
+    }
+
+    /// Which columns the compact list shows, their order and widths
+    fn column_settings(&self, cx: &Context<Self>) -> Div {
+        v_flex()
+            .mx_3()
+            .p_2()
+            .gap_1()
+            .rounded(cx.theme().radius)
+            .border_1()
+            .border_color(cx.theme().border)
+            .children(VariantColumn::ALL.into_iter().map(|kind| {
+                let shown = self.columns.columns.iter().find(|column| column.kind == kind);
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        Button::new(SharedString::from(format!("column-{}", kind.label())))
+                            .ghost()
+                            .with_size(ui::Size::XSmall)
+                            .when(shown.is_some(), |this| this.primary())
+                            .label(kind.label())
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.update_columns(|columns| columns.toggle(kind), cx);
+                            }))
+                    )
+                    .when_some(shown, |this, column| {
+                        let step = |id: &str, label: &'static str, update: fn(&mut ColumnLayout, VariantColumn)| {
+                            Button::new(SharedString::from(format!("column-{}-{}", id, kind.label())))
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label(label)
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    this.update_columns(|columns| update(columns, kind), cx);
+                                }))
+                        };
+                        this.child(div().flex_1())
+                            .child(step("earlier", "Earlier", |columns, kind| columns.move_column(kind, -1)))
+                            .child(step("later", "Later", |columns, kind| columns.move_column(kind, 1)))
+                            .child(step("narrower", "−", |columns, kind| columns.resize(kind, -COLUMN_WIDTH_STEP)))
+                            .child(
+                                div()
+                                    .w(px(48.0))
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(format!("{}px", column.width))
+                            )
+                            .child(step("wider", "+", |columns, kind| columns.resize(kind, COLUMN_WIDTH_STEP)))
+                    })
+            }))
+    }
+
+    /// Text of a column for one variant
+    fn cell_text(kind: VariantColumn, editor: &VariantEditorView, value: Option<i64>) -> String {
+        let meta = &editor.meta;
+        match kind {
+            VariantColumn::Value => value.map(|value| editor.value_format().format(value)).unwrap_or_default(),
+            VariantColumn::Payload => match &editor.variant.payload {
+                VariantPayload::Unit => String::new(),
+                VariantPayload::Single(type_ref) => format!("({})", codegen::type_ref_to_string(type_ref)),
+                VariantPayload::Struct(fields) => format!("{{ {} fields }}", fields.len()),
+            },
+            VariantColumn::Docs => editor.variant.doc.as_deref().and_then(|doc| doc.lines().next()).unwrap_or_default().to_string(),
+            VariantColumn::Flags => meta.combines.join(" | "),
+            VariantColumn::DisplayName => meta.display_name.clone().unwrap_or_default(),
+            VariantColumn::Color => meta.color.clone().unwrap_or_default(),
+            VariantColumn::Stage => meta.stage.label().to_string(),
+            VariantColumn::Cfg => meta.cfg.clone().unwrap_or_default(),
+            VariantColumn::Owner => meta.owner.clone().unwrap_or_default(),
+            VariantColumn::Aliases => meta.aliases.join(", "),
+            VariantColumn::Modified => meta.modified.map(|time| time.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+        }
+    }
+
+    /// One-line summary of a variant for the compact list, with the chosen
+    /// columns after the name
+    fn summary(&self, editor: &VariantEditorView, value: Option<i64>, cx: &App) -> Div {
+        h_flex()
+            .w_full()
+            .px_3()
+            .py(px(self.density.spacing(6.0)))
+            .gap_3()
+            .items_center()
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().secondary.opacity(0.4))
+            .border_1()
+            .border_color(if editor.is_highlighted() { cx.theme().primary } else { cx.theme().border })
+            .child(
+                h_flex()
+                    .w(px(NAME_COLUMN_WIDTH))
+                    .flex_none()
+                    .gap_2()
+                    .overflow_hidden()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .whitespace_nowrap()
+                            .text_color(if editor.meta.deprecated { cx.theme().muted_foreground } else { cx.theme().foreground })
+                            .child(editor.variant.name.clone())
+                    )
+                    .when(editor.meta.deprecated, |this| {
+                        this.child(div().text_xs().text_color(cx.theme().warning).child("deprecated"))
+                    })
+            )
+            .children(self.columns.columns.iter().map(|column| {
+                h_flex()
+                    .w(px(column.width))
+                    .flex_none()
+                    .gap_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .when(column.kind == VariantColumn::Color, |this| {
+                        this.when_some(editor.meta.color_rgba(), |this, color| {
+                            this.child(div().size(px(12.0)).flex_none().rounded(px(2.0)).bg(rgba(color)))
+                        })
+                    })
+                    .child(Self::cell_text(column.kind, editor, value))
+            }))
+    }
+
+    pub fn is_blame_shown(&self) -> bool {
+        self.blame.is_some()
+    }
+
+    fn toggle_blame(&mut self, cx: &mut Context<Self>) {
+        if self.blame.take().is_none() {
+            cx.emit(VariantsPanelEvent::BlameRequested);
+        }
+        cx.notify();
+    }
+
+    /// Scan the project for variants that are never referenced, or stop
+    /// showing them. The scan runs in the background; toggling again while
+    /// it runs cancels it.
+    fn toggle_unused(&mut self, cx: &mut Context<Self>) {
+        let was_shown = self.unused.take().is_some() || self.unused_task.take().is_some();
+        if let Some(root) = self.search_root.clone().filter(|_| !was_shown) {
+            let asset = self.asset.read();
+            let name = asset.name.clone();
+            let names: Vec<String> = asset.variants.iter().map(|v| v.name.clone()).collect();
+            drop(asset);
+            self.unused_task = Some(cx.spawn(async move |this, cx| {
+                let unused = cx
+                    .background_executor()
+                    .spawn(async move { references::unreferenced_variants(&root, &name, &names) })
+                    .await;
+                this.update(cx, |this, cx| {
+                    this.unused = Some(unused);
+                    this.unused_task = None;
+                    cx.notify();
+                })
+                .ok();
+            }));
+        }
+        cx.notify();
+    }
+
+    /// Show discriminants in the next format (decimal, hex, binary)
+    fn cycle_value_format(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let format = self.value_format.next();
+        self.value_format = format;
+        for editor in &self.variant_editors {
+            editor.update(cx, |editor, cx| editor.set_value_format(format, window, cx));
+        }
+        cx.notify();
+    }
+
+    /// Bits of a flag value, most significant first and grouped in bytes.
+    /// Bits another variant also sets are highlighted, except for composite
+    /// flags, which share their bits by design.
+    fn bit_grid(value: i64, composite: bool, overlap: &[usize], cx: &App) -> Div {
+        let width = overlap.len() as u32;
+        h_flex().w(px(160.0)).pt_3().flex_wrap().gap_1().children((0..width / 8).rev().map(|byte| {
+            h_flex().gap_0p5().children((0..8).rev().map(|offset| {
+                let bit = byte * 8 + offset;
+                let color = match (bits::is_set(value, bit), overlap[bit as usize]) {
+                    (false, _) => cx.theme().secondary,
+                    (true, _) if composite => cx.theme().accent,
+                    (true, count) if count > 1 => cx.theme().danger,
+                    (true, _) => cx.theme().primary,
+                };
+                div().size(px(8.0)).rounded(px(1.0)).bg(color)
+            }))
+        }))
+    }
+
+    /// Combined bit usage: unused bits, bits set by one variant and bits
+    /// several variants share
+    fn overlap_map(overlap: &[usize], cx: &App) -> Div {
+        let conflicts = overlap.iter().filter(|count| **count > 1).count();
+        let unused = overlap.iter().filter(|count| **count == 0).count();
+        h_flex()
+            .mx_3()
+            .p_2()
+            .gap_3()
+            .items_center()
+            .rounded(cx.theme().radius)
+            .border_1()
+            .border_color(cx.theme().border)
+            .child(
+                h_flex().gap_1().flex_wrap().children((0..overlap.len() / 8).rev().map(|byte| {
+                    h_flex().gap_0p5().children((0..8).rev().map(|offset| {
+                        let count = overlap[byte * 8 + offset];
+                        let color = match count {
+                            0 => cx.theme().secondary,
+                            1 => cx.theme().success,
+                            _ => cx.theme().danger,
+                        };
+                        div().size(px(10.0)).rounded(px(1.0)).bg(color)
+                    }))
+                }))
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(if conflicts > 0 { cx.theme().danger } else { cx.theme().muted_foreground })
+                    .child(format!("{} bits unused, {} shared by several variants", unused, conflicts))
+            )
+    }
+
+    /// Gutter cell with the last commit that touched the variant
+    fn blame_gutter(&self, name: &str, cx: &App) -> Div {
+        let cell = v_flex().w(px(120.0)).pt_3().gap_0p5().text_xs().text_color(cx.theme().muted_foreground);
+        match self.blame.as_ref().and_then(|blame| blame.get(name)) {
+            Some(commit) => cell
+                .child(div().font_semibold().text_color(cx.theme().foreground).child(commit.short_hash().to_string()))
+                .child(commit.author.clone())
+                .child(commit.date.clone()),
+            None => cell.child("Not committed"),
+        }
+    }
+
+    /// Recreate the variant editors after the asset was replaced (reload, undo, bulk edits)
+    pub fn rebuild(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let selected: Vec<String> = self.selected.drain(..).map(|editor| editor.read(cx).variant.name.clone()).collect();
+        let asset = self.asset.read().clone();
+        let meta = EnumMeta::from_asset(&asset);
+        self.variant_editors = asset
+            .variants
+            .into_iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                let variant_meta = meta.variant(&variant.name);
+                Self::create_variant_editor(variant, variant_meta, index, self.spell_checker.clone(), self.type_suggestions.clone(), self.value_format, window, cx)
+            })
+            .collect();
+        // Keep the selection on the variants that are still there
+        let selection = self
+            .variant_editors
+            .iter()
+            .filter(|editor| selected.contains(&editor.read(cx).variant.name))
+            .cloned()
+            .collect();
+        self.set_selection(selection, cx);
+        self.sync_flags_mode(cx);
+    }
+
+    fn create_variant_editor(
+        variant: EnumVariant,
+        meta: VariantMeta,
+        index: usize,
+        spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+        type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
+        value_format: ValueFormat,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<VariantEditorView> {
+        let editor = cx.new(|cx| {
+            VariantEditorView::new(variant, meta, index, spell_checker, type_suggestions, value_format, window, cx)
+        });
+        cx.subscribe_in(&editor, window, Self::on_variant_editor_event).detach();
+        editor
+    }
+
+    /// Update the index every editor reports in its events
+    fn reindex_editors(&self, cx: &mut Context<Self>) {
+        for (i, editor) in self.variant_editors.iter().enumerate() {
+            editor.update(cx, |ed, _cx| {
+                ed.index = i;
+            });
+        }
+    }
+
+    fn on_variant_editor_event(
+        &mut self,
+        editor: &Entity<VariantEditorView>,
+        event: &VariantEditorEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event {
+            VariantEditorEvent::VariantChanged(index, variant) => {
+                let mut asset = self.asset.write();
+                if *index < asset.variants.len() {
+                    // Keep per-variant metadata attached across renames
+                    let old_name = asset.variants[*index].name.clone();
+                    let mut meta = EnumMeta::from_asset(&asset);
+                    meta.rename_variant(&old_name, &variant.name);
+                    let mut variant_meta = meta.variant(&variant.name);
+                    variant_meta.touch();
+                    meta.set_variant(&variant.name, variant_meta.clone());
+                    meta.write_to(&mut asset);
+                    asset.variants[*index] = variant.clone();
+                    drop(asset);
+                    editor.update(cx, |editor, cx| editor.set_meta(variant_meta, cx));
+                    self.notify_modified();
+                    cx.emit(PanelEvent::LayoutChanged);
+                    cx.notify();
+                }
+            }
+            VariantEditorEvent::RenameRequested(index, new_name) => {
+                let asset = self.asset.read();
+                let Some(old_name) = asset.variants.get(*index).map(|v| v.name.clone()) else {
+                    return;
+                };
+                let checked = metadata::check_renames(&asset, &[(*index, new_name.clone())]);
+                let adds_alias = EnumMeta::from_asset(&asset).alias_on_rename;
+                let enum_name = asset.name.clone();
+                drop(asset);
+                if let Err(message) = checked {
+                    editor.update(cx, |editor, cx| editor.reject_rename(&old_name, message, window, cx));
+                    return;
+                }
+                let Some(root) = self.search_root.clone() else {
+                    let impact = RenameImpact {
+                        old_name,
+                        new_name: new_name.clone(),
+                        references: 0,
+                        files: 0,
+                        adds_alias,
+                    };
+                    editor.update(cx, |editor, cx| editor.set_rename_impact(impact, cx));
+                    return;
+                };
+                // Counting references reads the project's sources, so it runs
+                // in the background; a newer rename replaces the scan
+                let new_name = new_name.clone();
+                let editor = editor.downgrade();
+                self.rename_task = Some(cx.spawn(async move |this, cx| {
+                    let impact = cx
+                        .background_executor()
+                        .spawn(async move { references::rename_impact(&root, &enum_name, &old_name, &new_name, adds_alias) })
+                        .await;
+                    editor.update(cx, |editor, cx| editor.set_rename_impact(impact, cx)).ok();
+                    this.update(cx, |this, _cx| this.rename_task = None).ok();
+                }));
+            }
+            VariantEditorEvent::RenameConfirmed(index, new_name) => {
+                let mut asset = self.asset.write();
+                let Some(old_name) = asset.variants.get(*index).map(|v| v.name.clone()) else {
+                    return;
+                };
+                // Another variant may have taken the name while the rename was pending
+                let renames = [(*index, new_name.clone())];
+                if let Err(message) = metadata::check_renames(&asset, &renames) {
+                    drop(asset);
+                    editor.update(cx, |editor, cx| editor.reject_rename(&old_name, message, window, cx));
+                    return;
+                }
+                metadata::rename_variants(&mut asset, &renames);
+                drop(asset);
+                for pinned in self.pinned.iter_mut().filter(|pinned| **pinned == old_name) {
+                    *pinned = new_name.clone();
+                }
+                self.notify_modified();
+                cx.emit(PanelEvent::LayoutChanged);
+                cx.notify();
+            }
+            VariantEditorEvent::MetaChanged(index, variant_meta) => {
+                let mut asset = self.asset.write();
+                if let Some(name) = asset.variants.get(*index).map(|v| v.name.clone()) {
+                    let mut variant_meta = variant_meta.clone();
+                    variant_meta.touch();
+                    let mut meta = EnumMeta::from_asset(&asset);
+                    // Stable IDs and UUIDs are never edited
+                    variant_meta.stable_id = meta.variant(&name).stable_id;
+                    variant_meta.uuid = meta.variant(&name).uuid;
+                    // Values of a published enum only change once confirmed
+                    let committed = meta.variant(&name).value;
+                    // Expressions are checked for breaking changes on save instead
+                    let pending = meta.published && variant_meta.expr.is_none() && variant_meta.value != committed;
+                    if pending {
+                        variant_meta.value = committed;
+                    }
+                    meta.set_variant(&name, variant_meta.clone());
+                    meta.write_to(&mut asset);
+                    drop(asset);
+                    editor.update(cx, |editor, cx| {
+                        editor.set_pending_value(pending.then_some(committed), cx);
+                        editor.set_meta(variant_meta, cx);
+                    });
+                    self.notify_modified();
+                    cx.emit(PanelEvent::LayoutChanged);
+                    cx.notify();
+                }
+            }
+            VariantEditorEvent::NoteAdded(index, text) => {
+                let mut asset = self.asset.write();
+                if let Some(name) = asset.variants.get(*index).map(|v| v.name.clone()) {
+                    let mut meta = EnumMeta::from_asset(&asset);
+                    let mut variant_meta = meta.variant(&name);
+                    variant_meta.notes.push(Note {
+                        text: text.clone(),
+                        author: self.user.clone(),
+                        created: Some(chrono::Utc::now()),
+                        resolved: false,
+                    });
+                    meta.set_variant(&name, variant_meta.clone());
+                    meta.write_to(&mut asset);
+                    drop(asset);
+                    editor.update(cx, |editor, cx| editor.set_meta(variant_meta, cx));
+                    self.notify_modified();
+                    cx.emit(PanelEvent::LayoutChanged);
+                    cx.notify();
+                }
+            }
+            VariantEditorEvent::ValueChangeConfirmed(index, value) => {
+                let mut asset = self.asset.write();
+                if let Some(name) = asset.variants.get(*index).map(|v| v.name.clone()) {
+                    let mut meta = EnumMeta::from_asset(&asset);
+                    let old_value = variant_values(&asset, &meta)[*index];
+                    meta.reserve(None, Some(old_value));
+                    let mut variant_meta = meta.variant(&name);
+                    variant_meta.value = *value;
+                    variant_meta.touch();
+                    meta.set_variant(&name, variant_meta.clone());
+                    meta.write_to(&mut asset);
+                    drop(asset);
+                    editor.update(cx, |editor, cx| editor.set_meta(variant_meta, cx));
+                    self.notify_modified();
+                    cx.emit(PanelEvent::LayoutChanged);
+                    cx.notify();
+                }
+            }
+            VariantEditorEvent::RemoveRequested(index) => {
+                if EnumMeta::from_asset(&self.asset.read()).published {
+                    self.confirm_published_removal(*index, window, cx);
+                } else {
+                    self.remove_variant(*index, cx);
+                }
+            }
+            VariantEditorEvent::DuplicateRequested(index) => {
+                self.duplicate_variant(*index, window, cx);
+            }
+            VariantEditorEvent::AddFieldRequested(index) => {
+                tracing::info!("Add field requested for variant {}", index);
+            }
+        }
+    }
+
+    fn add_variant(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        // `Variant<count>` can still be taken after a deletion
+        let name = format!("Variant{}", self.variant_editors.len());
+        let name = {
+            let asset = self.asset.read();
+            let names = asset.variants.iter().map(|v| v.name.as_str());
+            if names.clone().any(|existing| existing == name) {
+                naming::duplicate_name(&name, names)
+            } else {
+                name
+            }
+        };
+        let new_variant = EnumVariant {
+            name,
+            payload: VariantPayload::Unit,
+            doc: None,
+        };
+
+        let index = self.variant_editors.len();
+        let variant_meta = VariantMeta::created_by(self.user.clone());
+        let editor = Self::create_variant_editor(new_variant.clone(), variant_meta.clone(), index, self.spell_checker.clone(), self.type_suggestions.clone(), self.value_format, window, cx);
+
+        self.variant_editors.push(editor);
+        self.sync_flags_mode(cx);
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.set_variant(&new_variant.name, variant_meta);
+        meta.write_to(&mut asset);
+        asset.variants.push(new_variant);
+        drop(asset);
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Copy a variant, including payload, docs and metadata, under a fresh name.
+    /// The copy is appended so the implicit values of existing variants don't shift.
+    fn duplicate_variant(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let mut asset = self.asset.write();
+        let Some(source) = asset.variants.get(index).cloned() else {
+            return;
+        };
+        let mut meta = EnumMeta::from_asset(&asset);
+
+        let mut copy = source.clone();
+        copy.name = naming::duplicate_name(&source.name, asset.variants.iter().map(|v| v.name.as_str()));
+
+        // Keep the copy's discriminant unique
+        let values = variant_values(&asset, &meta);
+        let mut copy_meta = meta.variant(&source.name);
+        copy_meta.owner = self.user.clone().or(copy_meta.owner);
+        copy_meta.created = Some(chrono::Utc::now());
+        copy_meta.modified = None;
+        copy_meta.expr = None;
+        // The copy is a new variant and gets its own ID on save
+        copy_meta.stable_id = None;
+        copy_meta.uuid = Some(uuid::Uuid::new_v4());
+        let implicit = values.last().map_or(0, |last| last.wrapping_add(1));
+        copy_meta.value = if values.contains(&implicit) {
+            values.iter().max().map(|max| max.wrapping_add(1))
+        } else {
+            None
+        };
+
+        meta.set_variant(&copy.name, copy_meta.clone());
+        meta.write_to(&mut asset);
+        asset.variants.push(copy.clone());
+        let new_index = asset.variants.len() - 1;
+        drop(asset);
+
+        let editor = Self::create_variant_editor(copy, copy_meta, new_index, self.spell_checker.clone(), self.type_suggestions.clone(), self.value_format, window, cx);
+        self.variant_editors.push(editor);
+        self.sync_flags_mode(cx);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Ask before deleting a variant of a published enum
+    fn confirm_published_removal(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(name) = self.asset.read().variants.get(index).map(|v| v.name.clone()) else {
+            return;
+        };
+        let panel = cx.entity().downgrade();
+        window.open_modal(cx, move |modal, _window, _cx| {
+            let panel = panel.clone();
+            modal
+                .title(format!("Delete {}?", name))
+                .child(format!(
+                    "This enum is published. `{}` and its value will be reserved so they can't be reused.",
+                    name
+                ))
+                .confirm()
+                .on_ok(move |_, _window, cx| {
+                    panel.update(cx, |panel, cx| panel.remove_published_variant(index, cx)).ok();
+                    true
+                })
+        });
+    }
+
+    /// Delete a variant, reserving its name and value
+    fn remove_published_variant(&mut self, index: usize, cx: &mut Context<Self>) {
+        let mut asset = self.asset.write();
+        let Some(name) = asset.variants.get(index).map(|v| v.name.clone()) else {
+            return;
+        };
+        let mut meta = EnumMeta::from_asset(&asset);
+        let value = variant_values(&asset, &meta)[index];
+        meta.reserve(Some(name), Some(value));
+        meta.write_to(&mut asset);
+        drop(asset);
+        self.remove_variant(index, cx);
+    }
+
+    fn remove_variant(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index < self.variant_editors.len() {
+            // Move to the trash, along with its metadata
+            let mut asset = self.asset.write();
+            let removed = asset.variants.remove(index);
+            let mut meta = EnumMeta::from_asset(&asset);
+            meta.trash_variant(removed, index);
+            meta.write_to(&mut asset);
+            drop(asset);
+
+            // Remove editor
+            self.variant_editors.remove(index);
+            self.reindex_editors(cx);
+
+            self.notify_modified();
+            cx.emit(PanelEvent::LayoutChanged);
+            cx.notify();
+        }
+    }
+
+    /// Put a trashed variant back at its original position
+    fn restore_variant(&mut self, trash_index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        let Some(trashed) = meta.restore_variant(trash_index) else {
+            return;
+        };
+        if asset.variants.iter().any(|v| v.name == trashed.variant.name) {
+            // Restoring would create a duplicate; leave it in the trash
+            return;
+        }
+        let index = trashed.index.min(asset.variants.len());
+        asset.variants.insert(index, trashed.variant.clone());
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        let editor = Self::create_variant_editor(trashed.variant, trashed.meta, index, self.spell_checker.clone(), self.type_suggestions.clone(), self.value_format, window, cx);
+        self.variant_editors.insert(index, editor);
+        self.reindex_editors(cx);
+        self.sync_flags_mode(cx);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn empty_trash(&mut self, cx: &mut Context<Self>) {
+        let mut asset = self.asset.write();
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.trash.clear();
+        meta.write_to(&mut asset);
+        drop(asset);
+
+        self.notify_modified();
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for VariantsPanel {}
+impl EventEmitter<VariantsPanelEvent> for VariantsPanel {}
+
+impl Render for VariantsPanel {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(window);
+        let asset = self.asset.read();
+        let meta = EnumMeta::from_asset(&asset);
+        let gated = meta.variants.values().filter(|v| v.cfg.is_some()).count();
+        let with_open_notes = meta.variants.values().filter(|v| v.open_notes() > 0).count();
+        let flag_overlap = (meta.mode == EnumMode::Flags).then(|| {
+            let values = variant_values(&asset, &meta);
+            let plain: Vec<i64> = asset
+                .variants
+                .iter()
+                .zip(&values)
+                .filter(|(variant, _)| !meta.variant(&variant.name).is_composite())
+                .map(|(_, value)| *value)
+                .collect();
+            (bits::overlap(&plain, bits::grid_width(&values)), values)
+        });
+        let trash = meta.trash;
+        let initials: Vec<char> = self
+            .variant_editors
+            .iter()
+            .filter(|editor| self.is_listed(editor.read(cx)))
+            .map(|editor| initial(&editor.read(cx).variant.name))
+            .collect();
+        let show_jump_bar = initials.len() >= JUMP_BAR_MIN_VARIANTS;
+        // Compact rows show values; the full editors show their own
+        let values = self.compact.then(|| variant_values(&asset, &meta));
+        let pinned: Vec<Entity<VariantEditorView>> = self
+            .variant_editors
+            .iter()
+            .filter(|editor| self.pinned.contains(&editor.read(cx).variant.name))
+            .cloned()
+            .collect();
+
+        v_flex()
+            .size_full()
+            .key_context(VARIANTS_KEY_CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_previous))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::select_first))
+            .on_action(cx.listener(Self::select_last))
+            .on_action(cx.listener(Self::rename_selected))
+            .on_action(cx.listener(Self::duplicate_selected))
+            .on_action(cx.listener(Self::delete_selected))
+            .on_action(cx.listener(Self::copy_as_rust))
+            .on_action(cx.listener(Self::copy_as_json))
+            .on_action(cx.listener(Self::copy_as_ron))
+            .on_action(cx.listener(Self::copy_as_toml))
+            .on_action(cx.listener(Self::find_selected_references))
+            .gap(px(self.density.spacing(12.0)))
+            .bg(cx.theme().sidebar)
+            .child(
+                h_flex()
+                    .w_full()
+                    .p(px(self.density.spacing(12.0)))
+                    .items_center()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    // The enum's title, with its project-wide actions on right click
+                    .child(
+                        div()
+                            .id("variants-header")
+                            .flex_1()
+                            .text_sm()
+                            .font_semibold()
+                            .text_color(cx.theme().foreground)
+                            .child(match self.selected.len() {
+                                0 | 1 => format!("Variants ({})", asset.variants.len()),
+                                selected => format!("Variants ({}, {} selected)", asset.variants.len(), selected),
+                            })
+                            .on_mouse_down(MouseButton::Right, cx.listener(|this, _, window, _cx| {
+                                window.focus(&this.focus_handle);
+                            }))
+                            .context_menu(|menu, _window, _cx| {
+                                menu.menu("Generate All", Box::new(GenerateAll))
+                                    .menu("Export All", Box::new(ExportAll))
+                                    .separator()
+                                    .menu("Validate All", Box::new(ValidateAll))
+                                    .separator()
+                                    .menu("Open Containing Folder", Box::new(RevealInFolder))
+                            })
+                    )
+                    .child(
+                        Button::new("toggle-compact")
+                            .ghost()
+                            .when(self.compact, |this| this.primary())
+                            .label("Compact")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.compact = !this.compact;
+                                cx.emit(VariantsPanelEvent::InspectedChanged);
+                                cx.notify();
+                            }))
+                    )
+                    .when(self.compact, |this| {
+                        this.child(
+                            Button::new("toggle-columns")
+                                .ghost()
+                                .when(self.show_columns, |this| this.primary())
+                                .label("Columns")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.show_columns = !this.show_columns;
+                                    cx.notify();
+                                }))
+                        )
+                    })
+                    .when(gated > 0, |this| {
+                        this.child(
+                            Button::new("toggle-gated")
+                                .ghost()
+                                .when(self.hide_gated, |this| this.primary())
+                                .label(if self.hide_gated {
+                                    format!("{} gated hidden", gated)
+                                } else {
+                                    format!("Hide gated ({})", gated)
+                                })
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.hide_gated = !this.hide_gated;
+                                    cx.notify();
+                                }))
+                        )
+                    })
+                    .child(
+                        Button::new("cycle-value-format")
+                            .ghost()
+                            .label(format!("Values: {}", self.value_format.label()))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.cycle_value_format(window, cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("toggle-unused")
+                            .ghost()
+                            .when(self.unused.is_some(), |this| this.primary())
+                            .label(match &self.unused {
+                                Some(unused) => format!("Unused ({})", unused.len()),
+                                None if self.unused_task.is_some() => "Scanning…".to_string(),
+                                None => "Find Unused".to_string(),
+                            })
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_unused(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("toggle-blame")
+                            .ghost()
+                            .when(self.blame.is_some(), |this| this.primary())
+                            .label("Blame")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.toggle_blame(cx);
+                            }))
+                    )
+                    .when(with_open_notes > 0 || self.only_open_notes, |this| {
+                        this.child(
+                            Button::new("toggle-open-notes")
+                                .ghost()
+                                .when(self.only_open_notes, |this| this.primary())
+                                .label(format!("With open notes ({})", with_open_notes))
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.only_open_notes = !this.only_open_notes;
+                                    cx.notify();
+                                }))
+                        )
+                    })
+                    .when(!trash.is_empty(), |this| {
+                        this.child(
+                            Button::new("toggle-trash")
+                                .ghost()
+                                .when(self.show_trash, |this| this.primary())
+                                .label(format!("Trash ({})", trash.len()))
+                                .icon(IconName::Delete)
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.show_trash = !this.show_trash;
+                                    cx.notify();
+                                }))
+                        )
+                    })
+                    .child(
+                        Button::new("paste-variants")
+                            .ghost()
+                            .label("Paste")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.paste(cx);
+                            }))
+                    )
+                    .child(
+                        Button::new("add-variant")
+                            .label("Add")
+                            .icon(IconName::Plus)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.add_variant(window, cx);
+                            }))
+                    )
+            )
+            .when(self.compact && self.show_columns, |this| this.child(self.column_settings(cx)))
+            // Unsaved edits of a session that didn't close cleanly
+            .when_some(self.recovery.as_ref(), |this, message| {
+                this.child(
+                    h_flex()
+                        .mx_3()
+                        .p_2()
+                        .gap_2()
+                        .items_center()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().warning.opacity(0.1))
+                        .border_1()
+                        .border_color(cx.theme().warning.opacity(0.4))
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(message.clone())
+                        )
+                        .child(
+                            Button::new("recovery-restore")
+                                .primary()
+                                .with_size(ui::Size::XSmall)
+                                .label("Restore")
+                                .on_click(cx.listener(|_this, _, _window, cx| {
+                                    cx.emit(VariantsPanelEvent::RestoreRecoveryRequested);
+                                }))
+                        )
+                        .child(
+                            Button::new("recovery-discard")
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label("Discard")
+                                .on_click(cx.listener(|_this, _, _window, cx| {
+                                    cx.emit(VariantsPanelEvent::DiscardRecoveryRequested);
+                                }))
+                        )
+                )
+            })
+            // The Rust source changed since it was imported
+            .when_some(self.source_changes.as_ref(), |this, (file_name, changes)| {
+                this.child(
+                    h_flex()
+                        .mx_3()
+                        .p_2()
+                        .gap_2()
+                        .items_center()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().warning.opacity(0.1))
+                        .border_1()
+                        .border_color(cx.theme().warning.opacity(0.4))
+                        .child(
+                            v_flex()
+                                .flex_1()
+                                .gap_0p5()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .child(format!("{} changed and differs from the definition", file_name))
+                                )
+                                .children(changes.iter().take(3).map(|change| {
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(change.describe().replace('`', ""))
+                                }))
+                                .when(changes.len() > 3, |this| {
+                                    this.child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(format!("and {} more", changes.len() - 3))
+                                    )
+                                })
+                        )
+                        .child(
+                            Button::new("source-reimport")
+                                .primary()
+                                .with_size(ui::Size::XSmall)
+                                .label("Re-import")
+                                .on_click(cx.listener(|_this, _, _window, cx| {
+                                    cx.emit(VariantsPanelEvent::ReimportRequested);
+                                }))
+                        )
+                        .child(
+                            Button::new("source-dismiss")
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label("Dismiss")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.source_changes = None;
+                                    cx.notify();
+                                }))
+                        )
+                )
+            })
+            // Variants read from the clipboard
+            .when_some(self.pasted.as_ref(), |this, pasted| {
+                let (message, names) = match pasted {
+                    Ok((format, source)) => {
+                        let names: Vec<&str> = source.variants.iter().take(6).map(|v| v.variant.name.as_str()).collect();
+                        let more = source.variants.len().saturating_sub(names.len());
+                        let names = if more > 0 { format!("{} and {} more", names.join(", "), more) } else { names.join(", ") };
+                        (format!("Pasted a {} with {} variants", format.label(), source.variants.len()), Some(names))
+                    }
+                    Err(message) => (message.clone(), None),
+                };
+                this.child(
+                    h_flex()
+                        .mx_3()
+                        .p_2()
+                        .gap_2()
+                        .items_center()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().secondary.opacity(0.2))
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .child(
+                            v_flex()
+                                .flex_1()
+                                .gap_0p5()
+                                .child(div().text_sm().text_color(cx.theme().foreground).child(message))
+                                .when_some(names, |this, names| {
+                                    this.child(div().text_xs().text_color(cx.theme().muted_foreground).child(names))
+                                })
+                        )
+                        .when(pasted.is_ok(), |this| {
+                            this.child(
+                                Button::new("paste-add")
+                                    .primary()
+                                    .with_size(ui::Size::XSmall)
+                                    .label("Add Variants")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.apply_paste(false, cx);
+                                    }))
+                            )
+                            .child(
+                                Button::new("paste-replace")
+                                    .ghost()
+                                    .with_size(ui::Size::XSmall)
+                                    .label("Replace All")
+                                    .on_click(cx.listener(|this, _, _window, cx| {
+                                        this.apply_paste(true, cx);
+                                    }))
+                            )
+                        })
+                        .child(
+                            Button::new("paste-dismiss")
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label("Dismiss")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.pasted = None;
+                                    cx.notify();
+                                }))
+                        )
+                )
+            })
+            .when_some(flag_overlap.as_ref(), |this, (overlap, _)| {
+                this.child(Self::overlap_map(overlap, cx))
+            })
+            // Recently deleted variants
+            .when(self.show_trash && !trash.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .mx_3()
+                        .p_2()
+                        .gap_1()
+                        .rounded(cx.theme().radius)
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .bg(cx.theme().secondary.opacity(0.2))
+                        .child(
+                            h_flex()
+                                .items_center()
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .text_xs()
+                                        .font_semibold()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("Recently Deleted")
+                                )
+                                .child(
+                                    Button::new("empty-trash")
+                                        .ghost()
+                                        .with_size(ui::Size::XSmall)
+                                        .label("Empty")
+                                        .on_click(cx.listener(|this, _, _window, cx| {
+                                            this.empty_trash(cx);
+                                        }))
+                                )
+                        )
+                        .children(trash.iter().enumerate().rev().map(|(trash_index, trashed)| {
+                            h_flex()
+                                .items_center()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .child(trashed.variant.name.clone())
+                                )
+                                .child(
+                                    Button::new(("restore-variant", trash_index))
+                                        .ghost()
+                                        .with_size(ui::Size::XSmall)
+                                        .label("Restore")
+                                        .on_click(cx.listener(move |this, _, window, cx| {
+                                            this.restore_variant(trash_index, window, cx);
+                                        }))
+                                )
+                        }))
+                )
+            })
+            // Pinned variants stay above the scrolling list
+            .when(!pinned.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .id("enum-variants-pinned")
+                        .mx_3()
+                        .gap_2()
+                        .max_h(px(320.0))
+                        .overflow_y_scroll()
+                        .child(
+                            div()
+                                .text_xs()
+                                .font_semibold()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("Pinned ({})", pinned.len()))
+                        )
+                        .children(pinned.iter().map(|editor| self.variant_row(editor, flag_overlap.as_ref(), values.as_deref(), focused, cx)))
+                        .child(Divider::horizontal())
+                )
+            })
+            .child(
+                h_flex()
+                    .w_full()
+                    .flex_1()
+                    .items_start()
+                    .child(
+                        v_flex()
+                            .id("enum-variants-content")
+                            .px_3()
+                            .gap(px(self.density.spacing(8.0)))
+                            .flex_1()
+                            .h_full()
+                            .overflow_scroll()
+                            .track_scroll(&self.scroll_handle)
+                            // The jump bar follows the scroll position
+                            .when(show_jump_bar, |this| {
+                                this.on_scroll_wheel(cx.listener(|_this, _, _window, cx| cx.notify()))
+                            })
+                            .children(
+                                self.variant_editors
+                                    .iter()
+                                    .filter(|editor| self.is_listed(editor.read(cx)))
+                                    .map(|editor| self.variant_row(editor, flag_overlap.as_ref(), values.as_deref(), focused, cx))
+                            )
+                            .when(self.unused.as_ref().is_some_and(HashSet::is_empty), |this| {
+                                this.child(
+                                    div()
+                                        .p_2()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("Every variant is referenced in the project's sources")
+                                )
+                            })
+                            .when(self.variant_editors.is_empty(), |this| {
+                                this.child(
+                                    div()
+                                        .w_full()
+                                        .p_8()
+                                        .flex()
+                                        .flex_col()
+                                        .items_center()
+                                        .justify_center()
+                                        .gap_2()
+                                        .child(
+                                            div()
+                                                .text_size(rems(2.0))
+                                                .child("🏷️")
+                                        )
+                                        .child(
+                                            div()
+                                                .text_sm()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child("No variants yet")
+                                        )
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(cx.theme().muted_foreground.opacity(0.7))
+                                                .child("Click 'Add' to create a variant")
+                                        )
+                                )
+                            })
+                    )
+                    .when(show_jump_bar, |this| this.child(self.jump_bar(&initials, cx)))
+            )
+    }
+}
+
+impl Focusable for VariantsPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Panel for VariantsPanel {
+    fn panel_name(&self) -> &'static str {
+        "enum_variants"
+    }
+
+    fn title(&self, _window: &Window, _cx: &App) -> AnyElement {
+        "Variants".into_any_element()
+    }
+}
@@ -0,0 +1,313 @@
+//! Project-level plugin configuration
+//!
+//! The plugin looks for a `pulsar-enum.toml` in the asset's directory or any of
+//! its parents; the directory containing it is treated as the project root.
+
+use crate::error::{EnumEditorError, Result};
+use crate::metadata::{CodegenStyle, EnumMeta, TargetConfig};
+use crate::naming::Case;
+use crate::storage::{self, SerializationFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use ui_types_common::EnumAsset;
+
+/// Name of the project configuration file
+pub const CONFIG_FILE: &str = "pulsar-enum.toml";
+
+/// Contents of `pulsar-enum.toml`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectConfig {
+    pub editor: EditorConfig,
+    pub defaults: EnumDefaults,
+    pub hooks: HooksConfig,
+    pub spellcheck: SpellcheckConfig,
+    pub import: ImportConfig,
+    pub export: ExportConfig,
+}
+
+/// Editor behavior
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorConfig {
+    /// Save modified enums in the background
+    pub autosave: bool,
+    /// Seconds between autosaves
+    pub autosave_delay_secs: u64,
+    /// Types the payload type picker offers besides the built-in ones and
+    /// the workspace's enums, e.g. `EntityId` or `glam::Vec3`
+    pub payload_types: Vec<String>,
+    /// Spacing of the Properties, Variants and Code Preview panels
+    pub density: PanelDensity,
+    /// Font size of the code preview, in pixels
+    pub code_font_size: f32,
+}
+
+impl Default for EditorConfig {
+    fn default() -> Self {
+        Self {
+            autosave: false,
+            autosave_delay_secs: 30,
+            payload_types: Vec::new(),
+            density: PanelDensity::default(),
+            code_font_size: DEFAULT_CODE_FONT_SIZE,
+        }
+    }
+}
+
+/// Font size of the code preview unless configured, in pixels
+pub const DEFAULT_CODE_FONT_SIZE: f32 = 13.0;
+
+/// Smallest and largest font size the code preview can be set to, in pixels
+pub const CODE_FONT_SIZES: std::ops::RangeInclusive<f32> = 8.0..=32.0;
+
+/// How tightly a panel lays out its rows and sections
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+}
+
+impl Density {
+    pub const ALL: [Density; 2] = [Density::Compact, Density::Comfortable];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Comfortable => "Comfortable",
+        }
+    }
+
+    /// A gap or padding that is `comfortable` pixels at comfortable density
+    pub fn spacing(&self, comfortable: f32) -> f32 {
+        match self {
+            Density::Compact => (comfortable / 2.0).round(),
+            Density::Comfortable => comfortable,
+        }
+    }
+}
+
+/// Density of each panel that has one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PanelDensity {
+    pub properties: Density,
+    pub variants: Density,
+    pub code_preview: Density,
+}
+
+/// Settings every new enum starts with
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnumDefaults {
+    /// Definition new enums are copied from, relative to the project root
+    pub template: Option<String>,
+    /// Integer representation, e.g. `u8`
+    pub repr: Option<String>,
+    /// Naming convention for variants
+    pub naming: Case,
+    /// Derives added to the Rust declaration
+    pub derives: Vec<String>,
+    /// Codegen targets enabled for "Generate All", by id
+    pub targets: Vec<String>,
+    /// Indentation and line endings of generated code
+    pub style: CodegenStyle,
+    /// Serialization new single-file enums are saved in; folder assets are
+    /// always JSON
+    pub serialization: Option<SerializationFormat>,
+}
+
+impl EnumDefaults {
+    /// Apply the defaults to an enum's metadata, keeping target settings
+    /// other than the enabled flag
+    pub fn apply(&self, asset: &mut EnumAsset) {
+        let mut meta = EnumMeta::from_asset(asset);
+        self.apply_to_meta(&mut meta);
+        meta.write_to(asset);
+    }
+
+    fn apply_to_meta(&self, meta: &mut EnumMeta) {
+        if self.repr.is_some() {
+            meta.repr = self.repr.clone();
+        }
+        meta.naming = self.naming;
+        meta.derives = self.derives.clone();
+        meta.style = self.style.clone();
+        for id in &self.targets {
+            let config = TargetConfig { enabled: true, ..meta.target(id) };
+            meta.set_target(id, config);
+        }
+    }
+
+    /// Contents of a newly created definition: the template if there is one,
+    /// otherwise an empty enum with the defaults applied. A template's own
+    /// metadata takes precedence over the defaults.
+    pub fn new_definition(&self, root: &Path) -> Result<serde_json::Value> {
+        if let Some(template) = &self.template {
+            let path = storage::resolve_definition_path(&root.join(template));
+            let mut asset = storage::load_asset(&path)?;
+            if asset.meta.as_object().map_or(true, |meta| meta.is_empty()) {
+                self.apply(&mut asset);
+            }
+            return serde_json::to_value(&asset).map_err(|e| EnumEditorError::Serialize {
+                path,
+                message: e.to_string(),
+            });
+        }
+
+        let mut meta = EnumMeta::default();
+        self.apply_to_meta(&mut meta);
+        Ok(json!({
+            "name": "NewEnum",
+            "variants": [],
+            "meta": meta,
+        }))
+    }
+}
+
+/// Renaming of variants imported from C headers and protobuf files, so they
+/// follow the project's conventions
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportConfig {
+    /// Prefixes removed from variant names, e.g. `EVT_`; the first that
+    /// matches is removed
+    pub strip_prefixes: Vec<String>,
+    /// Suffixes removed from variant names, e.g. `_E`
+    pub strip_suffixes: Vec<String>,
+    /// Remove the enum's own name as a prefix, e.g. `COLOR_` from `COLOR_RED`
+    /// in `Color`, as protobuf style asks for
+    pub strip_enum_name: bool,
+    /// Convention variant names are converted to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub case: Option<Case>,
+}
+
+impl ImportConfig {
+    pub fn is_empty(&self) -> bool {
+        *self == ImportConfig::default()
+    }
+}
+
+/// Where "Export All" writes the generated files of every enum in the project
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// Output folder relative to the project root, e.g. `src/generated`;
+    /// unset writes each enum's files next to it, as "Generate All" does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+    /// Folder relative to the project root whose subfolders become module
+    /// paths, e.g. `assets/enums`; the project root if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_root: Option<String>,
+    pub layout: ExportLayout,
+}
+
+/// How generated files are organized in the output folder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportLayout {
+    /// One file per enum, in folders mirroring the definitions', e.g.
+    /// `combat/damage_kind.rs`
+    #[default]
+    PerEnum,
+    /// One file per enum, all directly in the output folder
+    Flat,
+    /// One file per definition folder with all of its enums, e.g.
+    /// `combat.rs`, for targets whose outputs can be joined
+    PerModule,
+}
+
+impl ExportLayout {
+    pub const ALL: [ExportLayout; 3] = [ExportLayout::PerEnum, ExportLayout::Flat, ExportLayout::PerModule];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportLayout::PerEnum => "File per enum",
+            ExportLayout::Flat => "Flat",
+            ExportLayout::PerModule => "File per module",
+        }
+    }
+}
+
+/// Shell commands run around saves and exports, from the project root
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run before saving; a failing command aborts the save
+    pub pre_save: Vec<String>,
+    /// Run in the background after a successful save
+    pub post_save: Vec<String>,
+    /// Run in the background after a successful export
+    pub post_export: Vec<String>,
+}
+
+/// Spell checking of docs and display names
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellcheckConfig {
+    pub enabled: bool,
+    /// Word lists relative to the project root; the system dictionary if empty
+    pub dictionaries: Vec<String>,
+}
+
+impl Default for SpellcheckConfig {
+    fn default() -> Self {
+        Self { enabled: true, dictionaries: Vec::new() }
+    }
+}
+
+/// Name recorded as the owner of new variants: `$PULSAR_ENUM_USER`, or else
+/// git's `user.name` as configured for `root`
+pub fn current_user(root: &Path) -> Option<String> {
+    if let Some(user) = std::env::var("PULSAR_ENUM_USER").ok().filter(|u| !u.trim().is_empty()) {
+        return Some(user.trim().to_string());
+    }
+    let output = std::process::Command::new("git")
+        .args(["config", "user.name"])
+        .current_dir(root)
+        .output()
+        .ok()?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
+
+/// A loaded configuration and the project root it applies to
+#[derive(Clone, Debug)]
+pub struct Project {
+    pub root: PathBuf,
+    pub config: ProjectConfig,
+}
+
+impl Project {
+    /// Find and load the configuration that applies to `path`.
+    /// Returns `Ok(None)` if no configuration file exists.
+    pub fn find(path: &Path) -> Result<Option<Self>> {
+        let Some(root) = path.ancestors().find(|dir| dir.join(CONFIG_FILE).is_file()) else {
+            return Ok(None);
+        };
+        let config_path = root.join(CONFIG_FILE);
+        let content = std::fs::read_to_string(&config_path).map_err(|e| EnumEditorError::io(&config_path, e))?;
+        let config = toml::from_str(&content).map_err(|e| EnumEditorError::Parse {
+            path: config_path.clone(),
+            message: e.to_string(),
+        })?;
+        Ok(Some(Self { root: root.to_path_buf(), config }))
+    }
+
+    /// Write the configuration to `pulsar-enum.toml` in the project root.
+    /// Comments in an existing file are not preserved.
+    pub fn save(&self) -> Result<PathBuf> {
+        let config_path = self.root.join(CONFIG_FILE);
+        let content = toml::to_string_pretty(&self.config).map_err(|e| EnumEditorError::Serialize {
+            path: config_path.clone(),
+            message: e.to_string(),
+        })?;
+        std::fs::write(&config_path, content).map_err(|e| EnumEditorError::io(&config_path, e))?;
+        Ok(config_path)
+    }
+}
@@ -0,0 +1,154 @@
+//! Scans project sources for references to enum variants
+//!
+//! References are found textually: `Enum::Variant` in Rust and C++, and
+//! `Enum.Variant` in C#, TypeScript and similar languages. Build output and VCS
+//! directories are skipped.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Extensions of files that are scanned
+pub const SOURCE_EXTENSIONS: &[&str] = &["rs", "cs", "ts", "js", "cpp", "hpp", "h", "c", "py", "java", "kt", "swift"];
+
+/// Directories never descended into
+pub(crate) const SKIPPED_DIRS: &[&str] = &["target", ".git", "node_modules", "build", "dist"];
+
+/// Files scanned before giving up, so huge trees don't stall the editor
+const MAX_FILES: usize = 20_000;
+
+/// A line referencing a variant
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    pub path: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    pub text: String,
+}
+
+/// What renaming a variant would affect
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenameImpact {
+    pub old_name: String,
+    pub new_name: String,
+    pub references: usize,
+    pub files: usize,
+    /// The old name will be kept as a serde alias
+    pub adds_alias: bool,
+}
+
+/// Every source file under `root` that is scanned for references
+pub fn source_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if path.is_dir() {
+                if !SKIPPED_DIRS.contains(&name.as_str()) {
+                    pending.push(path);
+                }
+            } else if path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| SOURCE_EXTENSIONS.contains(&e))
+            {
+                files.push(path);
+                if files.len() >= MAX_FILES {
+                    return files;
+                }
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+pub(crate) fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// True if `line` contains `Enum::Variant` or `Enum.Variant` as whole words
+pub fn line_references(line: &str, enum_name: &str, variant_name: &str) -> bool {
+    [format!("{}::{}", enum_name, variant_name), format!("{}.{}", enum_name, variant_name)]
+        .iter()
+        .any(|needle| {
+            line.match_indices(needle.as_str()).any(|(start, _)| {
+                let before = line[..start].chars().next_back();
+                let after = line[start + needle.len()..].chars().next();
+                !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char)
+            })
+        })
+}
+
+/// Find references to a variant in every source file under `root`
+pub fn find_references(root: &Path, enum_name: &str, variant_name: &str) -> Vec<Reference> {
+    let mut references = Vec::new();
+    for path in source_files(root) {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        for (index, line) in content.lines().enumerate() {
+            if line_references(line, enum_name, variant_name) {
+                references.push(Reference {
+                    path: path.clone(),
+                    line: index + 1,
+                    text: line.trim().to_string(),
+                });
+            }
+        }
+    }
+    references
+}
+
+/// True if the file starts with an `@generated` header
+fn is_generated(content: &str) -> bool {
+    content.lines().take(2).any(|line| line.contains("@generated"))
+}
+
+/// Variants that no source file under `root` references. Generated files
+/// are skipped since they mention every variant; data files are not scanned.
+pub fn unreferenced_variants(root: &Path, enum_name: &str, variant_names: &[String]) -> HashSet<String> {
+    let mut unreferenced: HashSet<String> = variant_names.iter().cloned().collect();
+    for path in source_files(root) {
+        if unreferenced.is_empty() {
+            break;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if is_generated(&content) || !content.contains(enum_name) {
+            continue;
+        }
+        for line in content.lines() {
+            unreferenced.retain(|name| !line_references(line, enum_name, name));
+        }
+    }
+    unreferenced
+}
+
+/// Summarize the effect of renaming a variant
+pub fn rename_impact(
+    root: &Path,
+    enum_name: &str,
+    old_name: &str,
+    new_name: &str,
+    adds_alias: bool,
+) -> RenameImpact {
+    let references = find_references(root, enum_name, old_name);
+    let mut files: Vec<_> = references.iter().map(|r| &r.path).collect();
+    files.dedup();
+
+    RenameImpact {
+        old_name: old_name.to_string(),
+        new_name: new_name.to_string(),
+        references: references.len(),
+        files: files.len(),
+        adds_alias,
+    }
+}
@@ -0,0 +1,243 @@
+//! Discriminant gap analysis and renumbering
+//!
+//! Works on the effective values of the variants (explicit, or one more than
+//! the previous variant). Renumbering only writes explicit values where the
+//! new value doesn't follow from the previous one.
+
+use crate::metadata::{variant_values, EnumMeta, EnumMode};
+use std::collections::{BTreeMap, HashSet};
+use ui_types_common::EnumAsset;
+
+/// Holes and duplicates in the current values
+#[derive(Clone, Debug, Default)]
+pub struct Analysis {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    /// Unused ranges between the smallest and largest value, inclusive
+    pub gaps: Vec<(i64, i64)>,
+    /// Values used by more than one variant, with the variants' names
+    pub collisions: Vec<(i64, Vec<String>)>,
+}
+
+/// Gaps are not reported for bitflags, where they are expected
+pub fn analyze(asset: &EnumAsset) -> Analysis {
+    let meta = EnumMeta::from_asset(asset);
+    let values = variant_values(asset, &meta);
+
+    let mut by_value: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+    for (variant, value) in asset.variants.iter().zip(&values) {
+        by_value.entry(*value).or_default().push(variant.name.clone());
+    }
+
+    let mut gaps = Vec::new();
+    if meta.mode == EnumMode::Standard {
+        let mut previous: Option<i64> = None;
+        for value in by_value.keys() {
+            if let Some(previous) = previous {
+                if *value > previous + 1 {
+                    gaps.push((previous + 1, value - 1));
+                }
+            }
+            previous = Some(*value);
+        }
+    }
+
+    Analysis {
+        min: by_value.keys().next().copied(),
+        max: by_value.keys().next_back().copied(),
+        gaps,
+        collisions: by_value.into_iter().filter(|(_, names)| names.len() > 1).collect(),
+    }
+}
+
+/// How new values are assigned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Consecutive values in declaration order, from the smallest value
+    /// (successive bits for bitflags)
+    Compact,
+    /// Keep every value that is unique and move duplicates to free values
+    /// (unused bits for bitflags)
+    Preserve,
+    /// Multiples of the step in declaration order, leaving room for new variants
+    Align(i64),
+}
+
+impl Strategy {
+    pub fn label(&self) -> String {
+        match self {
+            Strategy::Compact => "Compact".to_string(),
+            Strategy::Preserve => "Preserve".to_string(),
+            Strategy::Align(step) => format!("Multiples of {}", step),
+        }
+    }
+}
+
+/// A variant whose value changes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueChange {
+    pub index: usize,
+    pub name: String,
+    pub old: i64,
+    pub new: i64,
+}
+
+/// New values for every variant. Reserved values are skipped, and composite
+/// flags keep their value since it follows from the flags they combine.
+fn new_values(asset: &EnumAsset, meta: &EnumMeta, strategy: Strategy) -> Vec<i64> {
+    let all_values = variant_values(asset, meta);
+    let composite: Vec<bool> = asset.variants.iter().map(|v| meta.variant(&v.name).is_composite()).collect();
+    let values: Vec<i64> = all_values
+        .iter()
+        .zip(&composite)
+        .filter(|(_, composite)| !**composite)
+        .map(|(value, _)| *value)
+        .collect();
+    let mut renumbered = renumber_plain(&values, meta, strategy).into_iter();
+    all_values
+        .iter()
+        .zip(composite)
+        .map(|(value, composite)| if composite { *value } else { renumbered.next().unwrap_or(*value) })
+        .collect()
+}
+
+fn renumber_plain(values: &[i64], meta: &EnumMeta, strategy: Strategy) -> Vec<i64> {
+    let start = values.iter().copied().min().unwrap_or(0);
+    let is_free = |value: &i64| !meta.reserved.iter().any(|r| r.reserves_value(*value));
+
+    match (strategy, meta.mode) {
+        (Strategy::Compact, EnumMode::Flags) => {
+            (0..63).map(|bit| 1i64 << bit).filter(is_free).take(values.len()).collect()
+        }
+        (Strategy::Compact, EnumMode::Standard) => (start..).filter(is_free).take(values.len()).collect(),
+        (Strategy::Align(step), _) => {
+            let step = step.max(1);
+            let first = start.div_euclid(step) * step;
+            (0..).map(|i| first + i * step).filter(is_free).take(values.len()).collect()
+        }
+        (Strategy::Preserve, mode) => {
+            let mut used = HashSet::new();
+            let keep: Vec<bool> = values.iter().map(|value| is_free(value) && used.insert(*value)).collect();
+            let candidates: Box<dyn Iterator<Item = i64>> = match mode {
+                EnumMode::Flags => Box::new((0..63).map(|bit| 1i64 << bit)),
+                EnumMode::Standard => Box::new(start..),
+            };
+            let mut free = candidates.filter(|value| is_free(value) && !used.contains(value));
+            values
+                .iter()
+                .zip(keep)
+                .map(|(value, keep)| if keep { *value } else { free.next().unwrap_or(*value) })
+                .collect()
+        }
+    }
+}
+
+/// Value changes a strategy would make, in declaration order
+pub fn plan(asset: &EnumAsset, strategy: Strategy) -> Vec<ValueChange> {
+    let meta = EnumMeta::from_asset(asset);
+    let old_values = variant_values(asset, &meta);
+    let new_values = new_values(asset, &meta, strategy);
+    asset
+        .variants
+        .iter()
+        .zip(old_values.into_iter().zip(new_values))
+        .enumerate()
+        .filter(|(_, (_, (old, new)))| old != new)
+        .map(|(index, (variant, (old, new)))| ValueChange { index, name: variant.name.clone(), old, new })
+        .collect()
+}
+
+/// Apply planned changes, writing explicit values only where needed.
+/// Existing explicit values that still hold are kept.
+pub fn apply(asset: &mut EnumAsset, changes: &[ValueChange]) {
+    let mut meta = EnumMeta::from_asset(asset);
+    let mut values = variant_values(asset, &meta);
+    for change in changes {
+        if let Some(value) = values.get_mut(change.index) {
+            *value = change.new;
+        }
+    }
+
+    let mut next = 0i64;
+    for (index, (variant, value)) in asset.variants.iter().zip(values).enumerate() {
+        let mut variant_meta = meta.variant(&variant.name);
+        if variant_meta.is_composite() {
+            continue;
+        }
+        // Expressions are kept unless the variant is renumbered
+        let renumbered = changes.iter().any(|change| change.index == index);
+        if variant_meta.expr.is_some() && !renumbered {
+            next = value.wrapping_add(1);
+            continue;
+        }
+        if variant_meta.value != Some(value) || variant_meta.expr.is_some() {
+            variant_meta.expr = None;
+            variant_meta.value = (value != next).then_some(value);
+            meta.set_variant(&variant.name, variant_meta);
+        }
+        next = value.wrapping_add(1);
+    }
+    meta.write_to(asset);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::synthetic_enum;
+    use crate::metadata::Reserved;
+
+    /// A fixture enum whose variants have the given explicit values
+    fn with_values(values: &[i64], mode: EnumMode, reserved: &[i64]) -> EnumAsset {
+        let mut asset = synthetic_enum("Values", values.len());
+        let mut meta = EnumMeta::from_asset(&asset);
+        meta.mode = mode;
+        for (index, value) in values.iter().enumerate() {
+            let name = format!("Variant{}", index);
+            let mut variant = meta.variant(&name);
+            variant.value = Some(*value);
+            meta.set_variant(&name, variant);
+        }
+        meta.reserved = reserved.iter().map(|value| Reserved { name: None, value: Some(*value), end: None }).collect();
+        meta.write_to(&mut asset);
+        asset
+    }
+
+    fn changed_values(changes: &[ValueChange]) -> Vec<(usize, i64)> {
+        changes.iter().map(|change| (change.index, change.new)).collect()
+    }
+
+    #[test]
+    fn preserve_moves_duplicate_flags_to_unused_bits() {
+        let asset = with_values(&[1, 2, 2, 8], EnumMode::Flags, &[]);
+        let changes = plan(&asset, Strategy::Preserve);
+        assert_eq!(changed_values(&changes), vec![(2, 4)]);
+        assert_eq!(changes[0].old, 2);
+    }
+
+    #[test]
+    fn preserve_moves_values_off_reserved_ones() {
+        let asset = with_values(&[0, 1, 2], EnumMode::Standard, &[1]);
+        let changes = plan(&asset, Strategy::Preserve);
+        assert_eq!(changed_values(&changes), vec![(1, 3)]);
+    }
+
+    #[test]
+    fn compact_skips_reserved_values() {
+        let asset = with_values(&[0, 1, 2], EnumMode::Standard, &[1]);
+        let changes = plan(&asset, Strategy::Compact);
+        assert_eq!(changed_values(&changes), vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn apply_writes_only_values_that_do_not_follow() {
+        let mut asset = with_values(&[0, 5, 6, 9], EnumMode::Standard, &[]);
+        let changes = plan(&asset, Strategy::Compact);
+        apply(&mut asset, &changes);
+
+        let meta = EnumMeta::from_asset(&asset);
+        assert_eq!(variant_values(&asset, &meta), vec![0, 1, 2, 3]);
+        // The first value still holds, so it stays explicit
+        let explicit: Vec<Option<i64>> = asset.variants.iter().map(|variant| meta.variant(&variant.name).value).collect();
+        assert_eq!(explicit, vec![Some(0), None, None, None]);
+    }
+}
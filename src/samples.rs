@@ -0,0 +1,238 @@
+//! Example serialized values of each variant
+//!
+//! Payload values are placeholders picked from the field types: zeros,
+//! empty strings and collections, or a field's default when it is a JSON
+//! literal. Types the editor doesn't know are written as `"<Type>"`.
+//!
+//! TOML samples show the value under a key named after the enum, as it
+//! would appear in a config file.
+
+use crate::codegen;
+use crate::metadata::{EnumMeta, SerdeRepr};
+use crate::naming;
+use crate::type_check;
+use serde_json::{Map, Value};
+use ui_types_common::{EnumAsset, EnumVariant, TypeRef, VariantPayload};
+
+/// Placeholder value of a payload type
+pub fn placeholder(type_ref: &TypeRef) -> Value {
+    let text = codegen::type_ref_to_string(type_ref);
+    match type_check::parse_type(&text) {
+        Ok(ty) => type_placeholder(&ty),
+        Err(_) => Value::String(format!("<{}>", text)),
+    }
+}
+
+fn type_placeholder(ty: &syn::Type) -> Value {
+    match ty {
+        syn::Type::Path(path) => {
+            let Some(last) = path.path.segments.last() else {
+                return Value::Null;
+            };
+            let name = last.ident.to_string();
+            let first_argument = match &last.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => arguments.args.iter().find_map(|argument| match argument {
+                    syn::GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                }),
+                _ => None,
+            };
+            match name.as_str() {
+                "bool" => Value::Bool(false),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => {
+                    Value::from(0)
+                }
+                "f32" | "f64" => Value::from(0.0),
+                "char" => Value::String("a".to_string()),
+                "String" | "str" | "PathBuf" | "Cow" => Value::String(String::new()),
+                "Option" => Value::Null,
+                "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => Value::Array(Vec::new()),
+                "HashMap" | "BTreeMap" => Value::Object(Map::new()),
+                "Box" | "Rc" | "Arc" => first_argument.map_or(Value::Null, type_placeholder),
+                "Duration" => serde_json::json!({ "secs": 0, "nanos": 0 }),
+                _ => Value::String(format!("<{}>", name)),
+            }
+        }
+        syn::Type::Tuple(tuple) => Value::Array(tuple.elems.iter().map(type_placeholder).collect()),
+        syn::Type::Array(array) => Value::Array(vec![type_placeholder(&array.elem)]),
+        syn::Type::Slice(slice) => Value::Array(vec![type_placeholder(&slice.elem)]),
+        syn::Type::Reference(reference) => type_placeholder(&reference.elem),
+        syn::Type::Paren(paren) => type_placeholder(&paren.elem),
+        syn::Type::Group(group) => type_placeholder(&group.elem),
+        _ => Value::Null,
+    }
+}
+
+/// The payload as serde writes it without a tag, or `None` for unit variants
+fn payload_value(variant: &EnumVariant, meta: &EnumMeta) -> Option<Value> {
+    match &variant.payload {
+        VariantPayload::Unit => None,
+        VariantPayload::Single(type_ref) => Some(placeholder(type_ref)),
+        VariantPayload::Struct(fields) => {
+            let defaults = meta.variant(&variant.name).field_defaults;
+            let object = fields
+                .iter()
+                .map(|field| {
+                    let value = defaults
+                        .get(&field.name)
+                        .and_then(|default| serde_json::from_str(default).ok())
+                        .unwrap_or_else(|| placeholder(&field.type_ref));
+                    (field.name.clone(), value)
+                })
+                .collect();
+            Some(Value::Object(object))
+        }
+    }
+}
+
+/// How the variant is serialized under `repr`, or why serde can't write it
+pub fn variant_sample(variant: &EnumVariant, meta: &EnumMeta, repr: &SerdeRepr) -> Result<Value, String> {
+    let name = Value::String(variant.name.clone());
+    let payload = payload_value(variant, meta);
+    Ok(match (repr, payload) {
+        (SerdeRepr::External, None) => name,
+        (SerdeRepr::External, Some(payload)) => Value::Object(Map::from_iter([(variant.name.clone(), payload)])),
+        (SerdeRepr::Internal { tag }, None) => Value::Object(Map::from_iter([(tag.clone(), name)])),
+        (SerdeRepr::Internal { tag }, Some(Value::Object(fields))) => {
+            if fields.contains_key(tag) {
+                return Err(format!("`{}` has a field named like the tag `{}`", variant.name, tag));
+            }
+            let mut object = Map::from_iter([(tag.clone(), name)]);
+            object.extend(fields);
+            Value::Object(object)
+        }
+        // A type the editor doesn't know may well be a struct
+        (SerdeRepr::Internal { tag }, Some(Value::String(opaque))) if is_opaque(&opaque) => {
+            Value::Object(Map::from_iter([(tag.clone(), name), ("..".to_string(), Value::String(format!("fields of {}", opaque)))]))
+        }
+        (SerdeRepr::Internal { .. }, Some(_)) => {
+            return Err(format!("`{}` holds a value that isn't a map, which internal tagging can't write", variant.name));
+        }
+        (SerdeRepr::Adjacent { tag, .. }, None) => Value::Object(Map::from_iter([(tag.clone(), name)])),
+        (SerdeRepr::Adjacent { tag, content }, Some(payload)) => {
+            Value::Object(Map::from_iter([(tag.clone(), name), (content.clone(), payload)]))
+        }
+        (SerdeRepr::Untagged, None) => Value::Null,
+        (SerdeRepr::Untagged, Some(payload)) => payload,
+    })
+}
+
+/// True for the placeholder of a type the editor doesn't know
+fn is_opaque(value: &str) -> bool {
+    value.starts_with('<') && value.ends_with('>')
+}
+
+/// Variants showing each payload shape the enum uses: its first unit,
+/// single-value and struct variant
+pub fn representative_variants(asset: &EnumAsset) -> Vec<&EnumVariant> {
+    let unit = asset.variants.iter().find(|v| matches!(v.payload, VariantPayload::Unit));
+    let single = asset.variants.iter().find(|v| matches!(v.payload, VariantPayload::Single(_)));
+    let fields = asset.variants.iter().find(|v| matches!(v.payload, VariantPayload::Struct(_)));
+    [unit, single, fields].into_iter().flatten().collect()
+}
+
+/// Pretty JSON of `variants` under the enum's representation, with a
+/// comment in place of those serde can't write
+pub fn json_examples(asset: &EnumAsset, variants: &[&EnumVariant]) -> String {
+    let meta = EnumMeta::from_asset(asset);
+    variants
+        .iter()
+        .map(|variant| match variant_sample(variant, &meta, &meta.serde_repr) {
+            Ok(value) => serde_json::to_string_pretty(&value).unwrap_or_default(),
+            Err(e) => format!("// {}", e),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a sample is written in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleFormat {
+    Json,
+    Ron,
+    Toml,
+}
+
+impl SampleFormat {
+    pub const ALL: [SampleFormat; 3] = [SampleFormat::Json, SampleFormat::Ron, SampleFormat::Toml];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SampleFormat::Json => "JSON",
+            SampleFormat::Ron => "RON",
+            SampleFormat::Toml => "TOML",
+        }
+    }
+}
+
+/// The variant written in `format` under the enum's representation, or why
+/// it can't be
+pub fn variant_text(asset: &EnumAsset, variant: &EnumVariant, meta: &EnumMeta, format: SampleFormat) -> Result<String, String> {
+    let value = variant_sample(variant, meta, &meta.serde_repr)?;
+    match format {
+        SampleFormat::Json => serde_json::to_string_pretty(&value).map_err(|e| e.to_string()),
+        SampleFormat::Ron => Ok(ron_sample(variant, meta, &meta.serde_repr)),
+        SampleFormat::Toml => {
+            let key = naming::snake_case(&asset.name);
+            let value = without_nulls(value).ok_or_else(|| "TOML has no null, so the key is left out".to_string())?;
+            toml::to_string(&Value::Object(Map::from_iter([(key, value)]))).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// The value with null map entries removed, as TOML leaves out `None`
+fn without_nulls(value: Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::Array(items) => Some(Value::Array(items.into_iter().filter_map(without_nulls).collect())),
+        Value::Object(entries) => {
+            Some(Value::Object(entries.into_iter().filter_map(|(k, v)| without_nulls(v).map(|v| (k, v))).collect()))
+        }
+        value => Some(value),
+    }
+}
+
+/// A placeholder in RON; maps stay maps, `null` is `None`
+fn ron_value(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Array(items) => format!("[{}]", items.iter().map(ron_value).collect::<Vec<_>>().join(", ")),
+        Value::Object(entries) => format!(
+            "{{{}}}",
+            entries.iter().map(|(k, v)| format!("{:?}: {}", k, ron_value(v))).collect::<Vec<_>>().join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+/// Struct fields in RON, `(x: 0, y: 0)`, led by `tag` if there is one
+fn ron_struct(tag: Option<(&str, &str)>, fields: &Map<String, Value>) -> String {
+    let tag = tag.map(|(key, name)| format!("{}: {:?}", key, name));
+    let fields = fields.iter().map(|(k, v)| format!("{}: {}", k, ron_value(v)));
+    format!("({})", tag.into_iter().chain(fields).collect::<Vec<_>>().join(", "))
+}
+
+/// The variant in RON, where struct payloads are written as structs
+fn ron_sample(variant: &EnumVariant, meta: &EnumMeta, repr: &SerdeRepr) -> String {
+    let name = variant.name.as_str();
+    let payload = payload_value(variant, meta);
+    let content = |payload: &Value| match payload {
+        Value::Object(fields) if matches!(variant.payload, VariantPayload::Struct(_)) => ron_struct(None, fields),
+        payload => ron_value(payload),
+    };
+    match (repr, &payload) {
+        (SerdeRepr::External, None) => name.to_string(),
+        (SerdeRepr::External, Some(Value::Object(fields))) if matches!(variant.payload, VariantPayload::Struct(_)) => {
+            format!("{}{}", name, ron_struct(None, fields))
+        }
+        (SerdeRepr::External, Some(payload)) => format!("{}({})", name, ron_value(payload)),
+        (SerdeRepr::Internal { tag }, Some(Value::Object(fields))) => ron_struct(Some((tag, name)), fields),
+        (SerdeRepr::Internal { tag }, _) => ron_struct(Some((tag, name)), &Map::new()),
+        (SerdeRepr::Adjacent { tag, .. }, None) => ron_struct(Some((tag, name)), &Map::new()),
+        (SerdeRepr::Adjacent { tag, content: key }, Some(payload)) => {
+            format!("({}: {:?}, {}: {})", tag, name, key, content(payload))
+        }
+        (SerdeRepr::Untagged, None) => "()".to_string(),
+        (SerdeRepr::Untagged, Some(payload)) => content(payload),
+    }
+}
@@ -0,0 +1,148 @@
+//! Lightweight spell checking for docs and display names
+//!
+//! Words are looked up in plain word lists (one word per line, as in
+//! `/usr/share/dict/words`; hunspell `.dic` files work too). Words a project
+//! uses on purpose are kept in an ignore list, [`IGNORE_FILE`] in the project
+//! root, so the whole team shares it.
+
+use crate::error::{EnumEditorError, Result};
+use crate::project::SpellcheckConfig;
+use std::collections::{BTreeSet, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Per-project list of words that are spelled correctly on purpose
+pub const IGNORE_FILE: &str = ".pulsar-enum-words";
+
+/// Tried in order when the project doesn't configure any dictionaries
+const SYSTEM_DICTIONARIES: &[&str] = &[
+    "/usr/share/dict/words",
+    "/usr/share/dict/american-english",
+    "/usr/share/dict/british-english",
+];
+
+/// A word that is neither in the dictionary nor ignored
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misspelling {
+    /// Byte range of the word in the checked text
+    pub range: Range<usize>,
+    pub word: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SpellChecker {
+    words: HashSet<String>,
+    ignored: BTreeSet<String>,
+    /// Where the ignore list is saved
+    ignore_path: Option<PathBuf>,
+}
+
+fn read_words(path: &Path) -> std::io::Result<impl Iterator<Item = String>> {
+    let content = std::fs::read_to_string(path)?;
+    let words: Vec<String> = content
+        .lines()
+        // Drop hunspell affix flags (`word/FLAGS`) and comments
+        .filter_map(|line| line.split('/').next())
+        .map(|word| word.trim().to_lowercase())
+        .filter(|word| !word.is_empty() && !word.starts_with('#'))
+        .collect();
+    Ok(words.into_iter())
+}
+
+impl SpellChecker {
+    /// Load the dictionaries and ignore list for the project rooted at `root`
+    pub fn load(root: &Path, config: &SpellcheckConfig) -> Self {
+        let ignore_path = root.join(IGNORE_FILE);
+        let mut checker = Self { ignore_path: Some(ignore_path.clone()), ..Self::default() };
+        if let Ok(words) = read_words(&ignore_path) {
+            checker.ignored.extend(words);
+        }
+        if !config.enabled {
+            return checker;
+        }
+
+        let dictionaries: Vec<PathBuf> = if config.dictionaries.is_empty() {
+            SYSTEM_DICTIONARIES.iter().map(PathBuf::from).filter(|path| path.is_file()).take(1).collect()
+        } else {
+            config.dictionaries.iter().map(|path| root.join(path)).collect()
+        };
+        for path in dictionaries {
+            match read_words(&path) {
+                Ok(words) => checker.words.extend(words),
+                Err(e) => log::warn!("Failed to read dictionary {}: {}", path.display(), e),
+            }
+        }
+        checker
+    }
+
+    /// False if spell checking is off or no dictionary could be loaded
+    pub fn is_enabled(&self) -> bool {
+        !self.words.is_empty()
+    }
+
+    fn is_known(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        let word = word.strip_suffix("'s").or_else(|| word.strip_suffix("’s")).unwrap_or(&word);
+        self.words.contains(word) || self.ignored.contains(word)
+    }
+
+    /// Words in `text` that aren't in the dictionary. Code spans, URLs,
+    /// acronyms and identifier-like words (`FireBall`) are skipped.
+    pub fn check(&self, text: &str) -> Vec<Misspelling> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+        let code = code_ranges(text);
+        let offset = |part: &str| part.as_ptr() as usize - text.as_ptr() as usize;
+
+        let mut misspellings = Vec::new();
+        for chunk in text.split_whitespace() {
+            if chunk.contains("://") || chunk.starts_with("www.") {
+                continue;
+            }
+            for word in chunk.split(|c: char| !(c.is_alphabetic() || c == '\'' || c == '’')) {
+                let word = word.trim_matches(|c| c == '\'' || c == '’');
+                let start = offset(word);
+                if word.chars().count() < 2
+                    || word.chars().skip(1).any(char::is_uppercase)
+                    || code.iter().any(|range| range.contains(&start))
+                    || self.is_known(word)
+                {
+                    continue;
+                }
+                misspellings.push(Misspelling { range: start..start + word.len(), word: word.to_string() });
+            }
+        }
+        misspellings
+    }
+
+    /// Add a word to the project's ignore list and save it
+    pub fn ignore(&mut self, word: &str) -> Result<()> {
+        if !self.ignored.insert(word.to_lowercase()) {
+            return Ok(());
+        }
+        let Some(path) = &self.ignore_path else {
+            return Ok(());
+        };
+        let content: String = self.ignored.iter().map(|word| format!("{}\n", word)).collect();
+        std::fs::write(path, content).map_err(|e| EnumEditorError::io(path, e))
+    }
+}
+
+/// Byte ranges of inline code spans and fenced code blocks
+fn code_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = text[rest..].find('`') {
+        let start = rest + open;
+        let ticks = text[start..].chars().take_while(|c| *c == '`').count();
+        let fence = &text[start..start + ticks];
+        let Some(close) = text[start + ticks..].find(fence) else {
+            break;
+        };
+        let end = start + ticks + close + ticks;
+        ranges.push(start..end);
+        rest = end;
+    }
+    ranges
+}
@@ -0,0 +1,411 @@
+//! On-disk layout of enum definitions
+//!
+//! Enums are stored either folder-based (`Foo.enum/enum.json`) or as a flat
+//! single file (`Foo.enum.json`). Both contain the same `EnumAsset`, serialized
+//! as JSON, YAML or TOML depending on the extension of the definition file.
+//! The host recognises folder assets by their `enum.json` marker, so those
+//! are always written as JSON; YAML and TOML are for single files.
+
+use crate::columns::ColumnLayout;
+use crate::error::{EnumEditorError, Result};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use ui_types_common::EnumAsset;
+
+/// Stem of the marker file inside a folder-based `.enum` asset
+pub const MARKER_STEM: &str = "enum";
+
+/// Marker file inside a folder-based `.enum` asset using the default serialization
+pub const MARKER_FILE: &str = "enum.json";
+
+/// Extension of folder-based assets
+pub const FOLDER_EXTENSION: &str = "enum";
+
+/// Scratch file inside a folder-based asset holding edits that weren't saved
+pub const RECOVERY_FILE: &str = ".recovery.json";
+
+/// File inside a folder-based asset holding the editor's variant list columns
+pub const COLUMNS_FILE: &str = ".columns.json";
+
+/// How an enum definition is laid out on disk
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumFileFormat {
+    /// `Foo.enum/enum.json`; `enum.yaml` and `enum.toml` are read but not written
+    Folder,
+    /// `Foo.enum.json` (or `.enum.yaml` / `.enum.toml`)
+    Flat,
+}
+
+impl EnumFileFormat {
+    /// Detect the format from the path of the definition file
+    pub fn detect(definition_path: &Path) -> Self {
+        let is_marker = definition_path.file_stem().and_then(|n| n.to_str()) == Some(MARKER_STEM)
+            && SerializationFormat::from_path(definition_path).is_some();
+        let in_enum_folder = definition_path
+            .parent()
+            .and_then(|p| p.extension())
+            .and_then(|e| e.to_str())
+            == Some(FOLDER_EXTENSION);
+
+        if is_marker && in_enum_folder {
+            EnumFileFormat::Folder
+        } else {
+            EnumFileFormat::Flat
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnumFileFormat::Folder => "Folder (.enum)",
+            EnumFileFormat::Flat => "Single file",
+        }
+    }
+
+    pub fn other(&self) -> Self {
+        match self {
+            EnumFileFormat::Folder => EnumFileFormat::Flat,
+            EnumFileFormat::Flat => EnumFileFormat::Folder,
+        }
+    }
+}
+
+/// Serialization used for the definition file
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SerializationFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl SerializationFormat {
+    pub const ALL: [SerializationFormat; 3] = [
+        SerializationFormat::Json,
+        SerializationFormat::Yaml,
+        SerializationFormat::Toml,
+    ];
+
+    /// Detect the serialization from the file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(SerializationFormat::Json),
+            "yaml" | "yml" => Some(SerializationFormat::Yaml),
+            "toml" => Some(SerializationFormat::Toml),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SerializationFormat::Json => "json",
+            SerializationFormat::Yaml => "yaml",
+            SerializationFormat::Toml => "toml",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SerializationFormat::Json => "JSON",
+            SerializationFormat::Yaml => "YAML",
+            SerializationFormat::Toml => "TOML",
+        }
+    }
+
+    pub fn parse(&self, content: &str) -> Result<EnumAsset, String> {
+        match self {
+            SerializationFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+            SerializationFormat::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+            SerializationFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+        }
+    }
+
+    pub fn serialize(&self, asset: &EnumAsset) -> Result<String, String> {
+        match self {
+            SerializationFormat::Json => serde_json::to_string_pretty(asset).map_err(|e| e.to_string()),
+            SerializationFormat::Yaml => serde_yaml::to_string(asset).map_err(|e| e.to_string()),
+            SerializationFormat::Toml => match null_at(&asset.meta, "meta") {
+                Some(at) => Err(format!("TOML has no null, which `{}` is", at)),
+                None => toml::to_string_pretty(asset).map_err(|e| e.to_string()),
+            },
+        }
+    }
+}
+
+/// Path of the first null inside `value`, e.g. `meta.variants.Fire.note`
+fn null_at(value: &serde_json::Value, path: &str) -> Option<String> {
+    match value {
+        serde_json::Value::Null => Some(path.to_string()),
+        serde_json::Value::Array(items) => {
+            items.iter().enumerate().find_map(|(index, item)| null_at(item, &format!("{}[{}]", path, index)))
+        }
+        serde_json::Value::Object(entries) => {
+            entries.iter().find_map(|(key, item)| null_at(item, &format!("{}.{}", path, key)))
+        }
+        _ => None,
+    }
+}
+
+/// Serialization of a definition file, defaulting to JSON for unknown extensions
+pub fn serialization_of(definition_path: &Path) -> SerializationFormat {
+    SerializationFormat::from_path(definition_path).unwrap_or(SerializationFormat::Json)
+}
+
+/// Resolve the path the host opened (asset folder or flat file) to the definition file
+pub fn resolve_definition_path(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        SerializationFormat::ALL
+            .iter()
+            .map(|format| path.join(format!("{}.{}", MARKER_STEM, format.extension())))
+            .find(|candidate| candidate.exists())
+            .unwrap_or_else(|| path.join(MARKER_FILE))
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Path of the asset a definition belongs to, as the file drawer shows it:
+/// the `.enum` folder, or the flat file itself
+pub fn asset_path(definition_path: &Path) -> PathBuf {
+    match EnumFileFormat::detect(definition_path) {
+        EnumFileFormat::Folder => definition_path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        EnumFileFormat::Flat => definition_path.to_path_buf(),
+    }
+}
+
+/// Asset stem shared by both layouts (`Foo` for `Foo.enum/enum.json` and `Foo.enum.json`)
+pub fn asset_stem(definition_path: &Path) -> Option<String> {
+    match EnumFileFormat::detect(definition_path) {
+        EnumFileFormat::Folder => definition_path
+            .parent()
+            .and_then(|p| p.file_stem())
+            .map(|s| s.to_string_lossy().to_string()),
+        EnumFileFormat::Flat => {
+            let name = definition_path.file_name()?.to_string_lossy().to_string();
+            let suffix = format!(".{}.{}", FOLDER_EXTENSION, serialization_of(definition_path).extension());
+            Some(name.strip_suffix(&suffix).map(str::to_string).unwrap_or(name))
+        }
+    }
+}
+
+/// Directory that contains the asset (the `.enum` folder or the flat file)
+pub fn asset_container_dir(definition_path: &Path) -> PathBuf {
+    let container = match EnumFileFormat::detect(definition_path) {
+        EnumFileFormat::Folder => definition_path.parent().and_then(Path::parent),
+        EnumFileFormat::Flat => definition_path.parent(),
+    };
+    container.map(Path::to_path_buf).unwrap_or_default()
+}
+
+/// Path of a definition in the given layout and serialization, next to the
+/// current one. Folder assets always use the JSON marker.
+pub fn definition_path_for(
+    definition_path: &Path,
+    layout: EnumFileFormat,
+    serialization: SerializationFormat,
+) -> Option<PathBuf> {
+    let stem = asset_stem(definition_path)?;
+    let container = asset_container_dir(definition_path);
+    let extension = serialization.extension();
+
+    Some(match layout {
+        EnumFileFormat::Folder => container.join(format!("{}.{}", stem, FOLDER_EXTENSION)).join(MARKER_FILE),
+        EnumFileFormat::Flat => container.join(format!("{}.{}.{}", stem, FOLDER_EXTENSION, extension)),
+    })
+}
+
+/// Path the definition would have in the other layout
+pub fn converted_path(definition_path: &Path) -> Option<PathBuf> {
+    let layout = EnumFileFormat::detect(definition_path).other();
+    definition_path_for(definition_path, layout, serialization_of(definition_path))
+}
+
+/// Load an enum definition, using the serialization its extension implies
+pub fn load_asset(definition_path: &Path) -> Result<EnumAsset> {
+    let content = std::fs::read_to_string(definition_path)
+        .map_err(|e| EnumEditorError::io(definition_path, e))?;
+    serialization_of(definition_path)
+        .parse(&content)
+        .map_err(|message| EnumEditorError::Parse {
+            path: definition_path.to_path_buf(),
+            message,
+        })
+}
+
+/// Serialize an enum definition for the given path
+pub fn serialize_asset(definition_path: &Path, asset: &EnumAsset) -> Result<String> {
+    serialization_of(definition_path)
+        .serialize(asset)
+        .map_err(|message| EnumEditorError::Serialize {
+            path: definition_path.to_path_buf(),
+            message,
+        })
+}
+
+/// Serialize an enum definition and write it to the given path
+pub fn save_asset(definition_path: &Path, asset: &EnumAsset) -> Result<()> {
+    let content = serialize_asset(definition_path, asset)?;
+    std::fs::write(definition_path, content).map_err(|e| EnumEditorError::io(definition_path, e))
+}
+
+/// `file` inside a folder-based asset, or `.Foo.enum` followed by `file`
+/// next to a flat one
+fn sidecar_path(definition_path: &Path, file: &str) -> PathBuf {
+    match EnumFileFormat::detect(definition_path) {
+        EnumFileFormat::Folder => definition_path.with_file_name(file),
+        EnumFileFormat::Flat => {
+            let stem = asset_stem(definition_path).unwrap_or_default();
+            asset_container_dir(definition_path).join(format!(".{}.{}{}", stem, FOLDER_EXTENSION, file))
+        }
+    }
+}
+
+/// Where unsaved edits of a definition are kept until it is saved:
+/// `Foo.enum/.recovery.json`, or `.Foo.enum.recovery.json` next to a flat file
+pub fn recovery_path(definition_path: &Path) -> PathBuf {
+    sidecar_path(definition_path, RECOVERY_FILE)
+}
+
+/// Where the columns of the definition's variant list are kept:
+/// `Foo.enum/.columns.json`, or `.Foo.enum.columns.json` next to a flat file
+pub fn columns_path(definition_path: &Path) -> PathBuf {
+    sidecar_path(definition_path, COLUMNS_FILE)
+}
+
+/// Column layout last used for the definition, if one was saved
+pub fn load_columns(definition_path: &Path) -> Result<Option<ColumnLayout>> {
+    let path = columns_path(definition_path);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(EnumEditorError::io(&path, e)),
+    };
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| EnumEditorError::Parse { path, message: e.to_string() })
+}
+
+/// Remember the column layout of the definition's variant list
+pub fn save_columns(definition_path: &Path, layout: &ColumnLayout) -> Result<()> {
+    let path = columns_path(definition_path);
+    let content = serde_json::to_string_pretty(layout)
+        .map_err(|e| EnumEditorError::Serialize { path: path.clone(), message: e.to_string() })?;
+    std::fs::write(&path, content).map_err(|e| EnumEditorError::io(&path, e))
+}
+
+/// Write unsaved edits to the definition's recovery file, always as JSON
+pub fn save_recovery(definition_path: &Path, asset: &EnumAsset) -> Result<()> {
+    let path = recovery_path(definition_path);
+    let content = SerializationFormat::Json
+        .serialize(asset)
+        .map_err(|message| EnumEditorError::Serialize { path: path.clone(), message })?;
+    std::fs::write(&path, content).map_err(|e| EnumEditorError::io(&path, e))
+}
+
+/// Unsaved edits left behind by an editor that didn't close cleanly
+pub fn load_recovery(definition_path: &Path) -> Result<Option<EnumAsset>> {
+    let path = recovery_path(definition_path);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(EnumEditorError::io(&path, e)),
+    };
+    SerializationFormat::Json
+        .parse(&content)
+        .map(Some)
+        .map_err(|message| EnumEditorError::Parse { path, message })
+}
+
+/// Remove the recovery file, if there is one
+pub fn discard_recovery(definition_path: &Path) -> Result<()> {
+    let path = recovery_path(definition_path);
+    match std::fs::remove_file(&path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(EnumEditorError::io(&path, e)),
+        _ => Ok(()),
+    }
+}
+
+/// Move a definition to a new path, taking its recovery and column files
+/// along, and remove the original and an emptied `.enum` folder
+fn relocate(definition_path: &Path, target: &Path, content: &[u8]) -> io::Result<()> {
+    if target.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", target.display()),
+        ));
+    }
+
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(target, content)?;
+    std::fs::remove_file(definition_path)?;
+    for file in [RECOVERY_FILE, COLUMNS_FILE] {
+        let (from, to) = (sidecar_path(definition_path, file), sidecar_path(target, file));
+        if from != to && from.exists() {
+            std::fs::rename(&from, &to)?;
+        }
+    }
+
+    let original_dir = definition_path.parent();
+    if EnumFileFormat::detect(definition_path) == EnumFileFormat::Folder && original_dir != target.parent() {
+        if let Some(asset_dir) = original_dir {
+            // Only succeeds if nothing else lives in the folder
+            let _ = std::fs::remove_dir(asset_dir);
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert a definition between the folder-based and flat layouts.
+///
+/// The original file is removed once the new one is written; an emptied
+/// `.enum` folder is removed as well. A YAML or TOML file becomes JSON when
+/// it moves into a folder. Returns the new definition path.
+pub fn convert(definition_path: &Path) -> Result<PathBuf> {
+    let target = converted_path(definition_path).ok_or_else(|| cannot_convert(definition_path))?;
+
+    let content = if serialization_of(definition_path) == serialization_of(&target) {
+        std::fs::read(definition_path).map_err(|e| EnumEditorError::io(definition_path, e))?
+    } else {
+        serialize_asset(&target, &load_asset(definition_path)?)?.into_bytes()
+    };
+    relocate(definition_path, &target, &content).map_err(|e| EnumEditorError::io(&target, e))?;
+    Ok(target)
+}
+
+/// Write a definition using another serialization, keeping its layout, and
+/// remove the old file. Returns the new definition path.
+pub fn convert_serialization(
+    definition_path: &Path,
+    asset: &EnumAsset,
+    serialization: SerializationFormat,
+) -> Result<PathBuf> {
+    let layout = EnumFileFormat::detect(definition_path);
+    if layout == EnumFileFormat::Folder && serialization != SerializationFormat::Json {
+        return Err(EnumEditorError::io(
+            definition_path,
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Folder assets are stored as enum.json; convert to a single file to use YAML or TOML",
+            ),
+        ));
+    }
+    let target = definition_path_for(definition_path, layout, serialization)
+        .ok_or_else(|| cannot_convert(definition_path))?;
+    if target == definition_path {
+        return Ok(target);
+    }
+
+    let content = serialize_asset(&target, asset)?;
+    relocate(definition_path, &target, content.as_bytes()).map_err(|e| EnumEditorError::io(&target, e))?;
+    Ok(target)
+}
+
+fn cannot_convert(definition_path: &Path) -> EnumEditorError {
+    EnumEditorError::io(
+        definition_path,
+        io::Error::new(io::ErrorKind::InvalidInput, "Cannot determine the converted path"),
+    )
+}
@@ -0,0 +1,116 @@
+//! Checks on the Rust types written for payloads
+//!
+//! Types are parsed with `syn`, so anything Rust accepts in a field type is
+//! valid. Derive compatibility is judged from the names the type mentions:
+//! it catches the common mistakes, like deriving `Eq` with an `f32` field,
+//! without knowing the project's own types.
+
+/// Derives offered for the Rust declaration besides `Debug` and `Clone`
+pub const DERIVE_OPTIONS: [&str; 9] = [
+    "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash", "Default", "serde::Serialize", "serde::Deserialize",
+];
+
+/// Derives that only compile alongside others
+const PREREQUISITES: &[(&str, &[&str])] = &[
+    ("Eq", &["PartialEq"]),
+    ("PartialOrd", &["PartialEq"]),
+    ("Ord", &["PartialOrd", "Eq"]),
+];
+
+/// Derives a type can't satisfy, and why
+const UNSATISFIED: &[(&str, &[&str], &str)] = &[
+    ("f32", &["Eq", "Ord", "Hash"], "floats are neither totally ordered nor hashable"),
+    ("f64", &["Eq", "Ord", "Hash"], "floats are neither totally ordered nor hashable"),
+    ("HashMap", &["Hash", "PartialOrd", "Ord", "Copy"], "hash maps are unordered and live on the heap"),
+    ("HashSet", &["Hash", "PartialOrd", "Ord", "Copy"], "hash sets are unordered and live on the heap"),
+    ("String", &["Copy"], "heap types can only be cloned"),
+    ("Vec", &["Copy"], "heap types can only be cloned"),
+    ("VecDeque", &["Copy"], "heap types can only be cloned"),
+    ("Box", &["Copy"], "heap types can only be cloned"),
+    ("BTreeMap", &["Copy"], "heap types can only be cloned"),
+    ("BTreeSet", &["Copy"], "heap types can only be cloned"),
+    ("Rc", &["Copy"], "heap types can only be cloned"),
+    ("Arc", &["Copy"], "heap types can only be cloned"),
+    ("PathBuf", &["Copy"], "heap types can only be cloned"),
+];
+
+/// The type, or why it isn't one
+pub fn parse_type(text: &str) -> Result<syn::Type, String> {
+    if text.trim().is_empty() {
+        return Err("the type is empty".to_string());
+    }
+    syn::parse_str::<syn::Type>(text).map_err(|e| e.to_string())
+}
+
+/// Last segment of every path the type mentions, generic arguments included,
+/// e.g. `Vec`, `Option` and `f32` for `Vec<Option<f32>>`
+pub fn type_names(ty: &syn::Type) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_names(ty, &mut names);
+    names
+}
+
+fn collect_names(ty: &syn::Type, names: &mut Vec<String>) {
+    match ty {
+        syn::Type::Path(path) => {
+            if let Some(qself) = &path.qself {
+                collect_names(&qself.ty, names);
+            }
+            for segment in &path.path.segments {
+                if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    for argument in &arguments.args {
+                        if let syn::GenericArgument::Type(ty) = argument {
+                            collect_names(ty, names);
+                        }
+                    }
+                }
+            }
+            if let Some(last) = path.path.segments.last() {
+                names.push(last.ident.to_string());
+            }
+        }
+        syn::Type::Array(array) => collect_names(&array.elem, names),
+        syn::Type::Slice(slice) => collect_names(&slice.elem, names),
+        syn::Type::Reference(reference) => collect_names(&reference.elem, names),
+        syn::Type::Ptr(pointer) => collect_names(&pointer.elem, names),
+        syn::Type::Paren(paren) => collect_names(&paren.elem, names),
+        syn::Type::Group(group) => collect_names(&group.elem, names),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().for_each(|ty| collect_names(ty, names)),
+        _ => {}
+    }
+}
+
+/// Derives, by last path segment, that `ty` can't satisfy, with the reason
+pub fn unsatisfied_derives(ty: &syn::Type, derives: &[String]) -> Vec<(String, &'static str)> {
+    let names = type_names(ty);
+    let mut unsatisfied: Vec<(String, &'static str)> = Vec::new();
+    for derive in derives {
+        let derive = derive_name(derive);
+        let reason = UNSATISFIED
+            .iter()
+            .find(|(name, blocked, _)| names.iter().any(|n| n == name) && blocked.contains(&derive))
+            .map(|(_, _, reason)| *reason);
+        if let Some(reason) = reason {
+            if !unsatisfied.iter().any(|(d, _)| d == derive) {
+                unsatisfied.push((derive.to_string(), reason));
+            }
+        }
+    }
+    unsatisfied
+}
+
+/// Last path segment of a derive, e.g. `Serialize` for `serde::Serialize`
+pub fn derive_name(derive: &str) -> &str {
+    derive.rsplit("::").next().unwrap_or(derive)
+}
+
+/// Derives in `derives` missing another derive they need, with the first
+/// one missing
+pub fn missing_prerequisites(derives: &[String]) -> Vec<(&'static str, &'static str)> {
+    let has = |name: &str| derives.iter().any(|d| derive_name(d) == name);
+    PREREQUISITES
+        .iter()
+        .filter(|(derive, _)| has(derive))
+        .filter_map(|(derive, needs)| needs.iter().find(|need| !has(need)).map(|need| (*derive, *need)))
+        .collect()
+}
@@ -0,0 +1,136 @@
+//! Type names offered while editing payload types
+//!
+//! Suggestions come from Rust's primitive types, common `std` types, the
+//! other enums defined in the workspace and the project's own list
+//! (`editor.payload_types` in `pulsar-enum.toml`).
+
+use crate::cache::DefinitionCache;
+use crate::references::is_ident_char;
+use crate::workspace_scan;
+use std::path::Path;
+use ui_types_common::TypeRef;
+
+/// Types written as `TypeRef::Primitive` rather than as paths
+pub const PRIMITIVE_TYPES: [&str; 18] = [
+    "bool", "char", "str", "String", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+/// `std` types common in payloads
+pub const STD_TYPES: [&str; 14] = [
+    "Option", "Vec", "Box", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "VecDeque", "Rc", "Arc", "Cow", "PathBuf",
+    "Duration", "SystemTime",
+];
+
+/// Where a suggested type comes from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypeSource {
+    Project,
+    Workspace,
+    Primitive,
+    Std,
+}
+
+impl TypeSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TypeSource::Project => "project",
+            TypeSource::Workspace => "enum",
+            TypeSource::Primitive => "primitive",
+            TypeSource::Std => "std",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeSuggestion {
+    pub name: String,
+    pub source: TypeSource,
+}
+
+/// Types the project knows besides the built-in ones
+#[derive(Clone, Debug, Default)]
+pub struct TypeSuggestions {
+    project: Vec<String>,
+    workspace: Vec<String>,
+}
+
+impl TypeSuggestions {
+    /// The enums defined under `root` other than `current`, and the types
+    /// the project configures
+    pub fn load(definitions: &DefinitionCache, root: &Path, project_types: &[String], current: &str) -> Self {
+        let mut workspace: Vec<String> = workspace_scan::enum_files(root)
+            .iter()
+            .filter_map(|path| definitions.load(path).ok())
+            .map(|asset| asset.name.clone())
+            .filter(|name| name != current)
+            .collect();
+        workspace.sort();
+        workspace.dedup();
+        Self { project: project_types.to_vec(), workspace }
+    }
+
+    /// Every known type, the project's first
+    fn candidates(&self) -> impl Iterator<Item = (&str, TypeSource)> {
+        let project = self.project.iter().map(|name| (name.as_str(), TypeSource::Project));
+        let workspace = self.workspace.iter().map(|name| (name.as_str(), TypeSource::Workspace));
+        let primitive = PRIMITIVE_TYPES.into_iter().map(|name| (name, TypeSource::Primitive));
+        let std = STD_TYPES.into_iter().map(|name| (name, TypeSource::Std));
+        project.chain(workspace).chain(primitive).chain(std)
+    }
+
+    /// Types completing the name being typed at the end of `text`, e.g.
+    /// `Ent` in `Vec<Ent`. Names starting with it come before names that
+    /// only contain it; case is ignored.
+    pub fn suggest(&self, text: &str, limit: usize) -> Vec<TypeSuggestion> {
+        let partial = partial_name(text).to_lowercase();
+        let mut starting = Vec::new();
+        let mut containing = Vec::new();
+        for (name, source) in self.candidates() {
+            // Paths are matched by their last segment as well
+            let lower = name.to_lowercase();
+            let last = lower.rsplit("::").next().unwrap_or_default();
+            let suggestion = TypeSuggestion { name: name.to_string(), source };
+            if lower.starts_with(&partial) || last.starts_with(&partial) {
+                starting.push(suggestion);
+            } else if lower.contains(&partial) {
+                containing.push(suggestion);
+            }
+        }
+        let mut suggestions: Vec<TypeSuggestion> = Vec::new();
+        for suggestion in starting.into_iter().chain(containing) {
+            if suggestion.name != partial_name(text) && !suggestions.iter().any(|s| s.name == suggestion.name) {
+                suggestions.push(suggestion);
+            }
+        }
+        suggestions.truncate(limit);
+        suggestions
+    }
+}
+
+/// The name at the end of `text`, with its path
+fn partial_name(text: &str) -> &str {
+    let start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_ident_char(*c) || *c == ':')
+        .last()
+        .map_or(text.len(), |(index, _)| index);
+    &text[start..]
+}
+
+/// `text` with the name at its end replaced by `name`
+pub fn complete(text: &str, name: &str) -> String {
+    let partial = partial_name(text);
+    format!("{}{}", &text[..text.len() - partial.len()], name)
+}
+
+/// A payload type as written, e.g. `u32` or `Vec<EntityId>`
+pub fn type_ref(text: &str) -> TypeRef {
+    let name = text.trim().to_string();
+    if PRIMITIVE_TYPES.contains(&name.as_str()) {
+        TypeRef::Primitive { name }
+    } else {
+        TypeRef::Path { path: name }
+    }
+}
@@ -0,0 +1,434 @@
+//! Checks run on an enum definition before it is saved or exported
+
+use crate::codegen;
+use crate::error::{EnumEditorError, Result};
+use crate::expr;
+use crate::naming;
+use crate::metadata::{repr_range, variant_values, EnumMeta, EnumMode, OrderBy, SerdeRepr};
+use crate::output::Severity;
+use crate::samples;
+use crate::spellcheck::SpellChecker;
+use crate::type_check;
+use std::collections::{HashMap, HashSet};
+use ui_types_common::{EnumAsset, EnumVariant, TypeRef, VariantPayload, Visibility};
+
+/// A problem found in an enum definition
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    /// Index of the offending variant, if the issue is about one
+    pub variant: Option<usize>,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(variant: Option<usize>, message: String) -> Self {
+        Self { severity: Severity::Error, variant, message }
+    }
+
+    fn warning(variant: Option<usize>, message: String) -> Self {
+        Self { severity: Severity::Warning, variant, message }
+    }
+}
+
+/// True if `name` can be used as an identifier in the generated code
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Check the definition, returning every issue found
+pub fn validate(asset: &EnumAsset) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if asset.name.trim().is_empty() {
+        issues.push(Issue::error(None, "Enum name is empty".to_string()));
+    } else if !is_identifier(&asset.name) {
+        issues.push(Issue::error(None, format!("Enum name `{}` is not a valid identifier", asset.name)));
+    }
+
+    if asset.variants.is_empty() {
+        issues.push(Issue::warning(None, "Enum has no variants".to_string()));
+    }
+
+    let meta = EnumMeta::from_asset(asset);
+    let mut seen = HashSet::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        if variant.name.trim().is_empty() {
+            issues.push(Issue::error(Some(index), format!("Variant {} has no name", index + 1)));
+        } else if !seen.insert(variant.name.as_str()) {
+            issues.push(Issue::error(Some(index), format!("Duplicate variant name `{}`", variant.name)));
+        } else if !is_identifier(&variant.name) {
+            issues.push(Issue::error(Some(index), format!("Variant `{}` is not a valid identifier", variant.name)));
+        } else if !meta.naming.matches(&variant.name) {
+            issues.push(Issue::warning(Some(index), format!("Variant `{}` should be {}", variant.name, meta.naming.label())));
+        }
+    }
+
+    let mut stable_ids = HashSet::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        match meta.variant(&variant.name).stable_id {
+            Some(id) if id.trim().is_empty() => {
+                issues.push(Issue::error(Some(index), format!("Variant `{}` has an empty stable ID", variant.name)));
+            }
+            Some(id) if !stable_ids.insert(id.clone()) => {
+                issues.push(Issue::error(Some(index), format!("Stable ID `{}` of `{}` is already used", id, variant.name)));
+            }
+            Some(_) => {}
+            None if meta.stable_ids => {
+                issues.push(Issue::warning(Some(index), format!("Variant `{}` gets its stable ID when saved", variant.name)));
+            }
+            None => {}
+        }
+    }
+
+    let mut uuids = HashMap::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        let Some(uuid) = meta.variant(&variant.name).uuid else {
+            continue;
+        };
+        match uuids.get(&uuid) {
+            Some(first) => issues.push(Issue::warning(
+                Some(index),
+                format!("Variant `{}` has the same UUID as `{}` and gets a new one when saved", variant.name, first),
+            )),
+            None => {
+                uuids.insert(uuid, variant.name.as_str());
+            }
+        }
+    }
+
+    for (index, variant) in asset.variants.iter().enumerate() {
+        if let Some(cfg) = &meta.variant(&variant.name).cfg {
+            if let Err(e) = codegen::validate_cfg(cfg) {
+                issues.push(Issue::error(Some(index), format!("Variant `{}` has an invalid cfg `{}`: {}", variant.name, cfg, e)));
+            }
+        }
+    }
+
+    for (index, variant) in asset.variants.iter().enumerate() {
+        for (location, type_ref) in payload_types(variant) {
+            let text = codegen::type_ref_to_string(type_ref);
+            if let Err(e) = type_check::parse_type(&text) {
+                issues.push(Issue::error(Some(index), format!("Type `{}` of {} is not a valid type: {}", text, location, e)));
+            }
+        }
+    }
+    for collision in codegen::keyword_collisions(asset, &meta) {
+        issues.push(Issue::warning(
+            Some(collision.variant),
+            format!(
+                "`{}` is emitted as `{}`, a keyword in {}; give it another name there, e.g. `{} = {}` under Emitted Names",
+                asset.variants[collision.variant].name,
+                collision.identifier,
+                collision.target.label(),
+                collision.target.id(),
+                collision.suggestion
+            ),
+        ));
+    }
+    issues.extend(derive_issues(asset, &meta));
+    issues.extend(serde_repr_issues(asset, &meta));
+
+    for (index, variant) in asset.variants.iter().enumerate() {
+        let fields: Vec<&str> = match &variant.payload {
+            VariantPayload::Struct(fields) => fields.iter().map(|field| field.name.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        for (field, default) in &meta.variant(&variant.name).field_defaults {
+            if !fields.contains(&field.as_str()) {
+                issues.push(Issue::warning(Some(index), format!("Variant `{}` has a default for unknown field `{}`", variant.name, field)));
+            } else if default.trim().is_empty() {
+                issues.push(Issue::error(Some(index), format!("Default of `{}::{}` is empty", variant.name, field)));
+            }
+        }
+    }
+
+    if meta.require_docs && asset.visibility == Visibility::Public {
+        if asset.description.as_deref().map_or(true, |d| d.trim().is_empty()) {
+            issues.push(Issue::warning(None, format!("Enum `{}` has no description", asset.name)));
+        }
+        for (index, variant) in asset.variants.iter().enumerate() {
+            if variant.doc.as_deref().map_or(true, |d| d.trim().is_empty()) {
+                issues.push(Issue::warning(Some(index), format!("Variant `{}` is undocumented", variant.name)));
+            }
+        }
+    }
+
+    for (index, (variant, value)) in asset.variants.iter().zip(variant_values(asset, &meta)).enumerate() {
+        if meta.reserved.iter().any(|r| r.name.as_deref() == Some(variant.name.as_str())) {
+            issues.push(Issue::error(Some(index), format!("Variant name `{}` is reserved", variant.name)));
+        }
+        if meta.reserved.iter().any(|r| r.reserves_value(value)) {
+            issues.push(Issue::error(Some(index), format!("Variant `{}` uses the reserved value {}", variant.name, value)));
+        }
+    }
+
+    for name in meta.constants.keys() {
+        if !naming::is_identifier(name) {
+            issues.push(Issue::error(None, format!("Constant `{}` is not a valid identifier", name)));
+        }
+    }
+    let mut associated = HashSet::new();
+    for constant in &meta.associated_consts {
+        if !naming::is_identifier(&constant.name) {
+            issues.push(Issue::error(None, format!("Associated constant `{}` is not a valid identifier", constant.name)));
+        } else if !associated.insert(constant.name.as_str()) {
+            issues.push(Issue::error(None, format!("Associated constant `{}` is defined twice", constant.name)));
+        }
+        if constant.ty.trim().is_empty() || constant.value.trim().is_empty() {
+            issues.push(Issue::error(None, format!("Associated constant `{}` needs a type and a value", constant.name)));
+        }
+    }
+    let mut methods = HashSet::new();
+    for method in &meta.methods {
+        match method.name() {
+            None => issues.push(Issue::error(None, format!("`{}` is not a method signature", method.signature))),
+            Some(name) if !methods.insert(name) => {
+                issues.push(Issue::error(None, format!("Method `{}` is defined twice", name)));
+            }
+            Some(_) => {}
+        }
+    }
+    for (index, variant) in asset.variants.iter().enumerate() {
+        if let Some(text) = &meta.variant(&variant.name).expr {
+            if let Err(e) = expr::evaluate(text, &meta.constants) {
+                issues.push(Issue::error(Some(index), format!("Value of `{}` doesn't evaluate: {}", variant.name, e)));
+            }
+        }
+    }
+
+    for (index, variant) in asset.variants.iter().enumerate() {
+        let variant_meta = meta.variant(&variant.name);
+        if !variant_meta.is_composite() {
+            continue;
+        }
+        if meta.mode != EnumMode::Flags {
+            issues.push(Issue::error(
+                Some(index),
+                format!("Variant `{}` combines other flags, which needs bitflags mode", variant.name),
+            ));
+        }
+        if variant_meta.value.is_some() || variant_meta.expr.is_some() {
+            issues.push(Issue::warning(
+                Some(index),
+                format!("The explicit value of `{}` is ignored because it combines other flags", variant.name),
+            ));
+        }
+        for name in &variant_meta.combines {
+            if !asset.variants.iter().any(|other| other.name == *name) {
+                issues.push(Issue::error(Some(index), format!("Variant `{}` combines unknown flag `{}`", variant.name, name)));
+            } else if meta.variant(name).is_composite() {
+                issues.push(Issue::error(
+                    Some(index),
+                    format!("Variant `{}` can only combine plain flags, but `{}` is itself a combination", variant.name, name),
+                ));
+            }
+        }
+    }
+
+    if let Some(repr) = &meta.repr {
+        match repr_range(repr) {
+            Some((min, max)) => {
+                for (index, (variant, value)) in asset.variants.iter().zip(variant_values(asset, &meta)).enumerate() {
+                    if value < min || value > max {
+                        issues.push(Issue::error(
+                            Some(index),
+                            format!("Value {} of `{}` doesn't fit in `{}`", value, variant.name, repr),
+                        ));
+                    }
+                }
+            }
+            None => issues.push(Issue::error(None, format!("`{}` is not an integer type usable as a repr", repr))),
+        }
+    }
+
+    // Rust rejects equal discriminants, but payload-only enums emit none
+    let duplicate_value = if codegen::has_discriminants(asset, &meta) { Issue::error } else { Issue::warning };
+    let mut by_value: HashMap<i64, &str> = HashMap::new();
+    for (index, (variant, value)) in asset.variants.iter().zip(variant_values(asset, &meta)).enumerate() {
+        if let Some(other) = by_value.insert(value, &variant.name) {
+            issues.push(duplicate_value(
+                Some(index),
+                format!("Variant `{}` has the same value ({}) as `{}`", variant.name, value, other),
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Every payload type of a variant, with where it appears, e.g. `` `Move::x` ``
+fn payload_types(variant: &EnumVariant) -> Vec<(String, &TypeRef)> {
+    match &variant.payload {
+        VariantPayload::Unit => Vec::new(),
+        VariantPayload::Single(type_ref) => vec![(format!("`{}`", variant.name), type_ref)],
+        VariantPayload::Struct(fields) => fields
+            .iter()
+            .map(|field| (format!("`{}::{}`", variant.name, field.name), &field.type_ref))
+            .collect(),
+    }
+}
+
+/// Derives of the Rust declaration that won't compile with the current
+/// payloads, each with what to change
+pub fn derive_issues(asset: &EnumAsset, meta: &EnumMeta) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (index, variant) in asset.variants.iter().enumerate() {
+        for (location, type_ref) in payload_types(variant) {
+            let text = codegen::type_ref_to_string(type_ref);
+            let Ok(ty) = type_check::parse_type(&text) else {
+                continue;
+            };
+            for (derive, reason) in type_check::unsatisfied_derives(&ty, &meta.derives) {
+                issues.push(Issue::warning(
+                    Some(index),
+                    format!(
+                        "`{}` can't be derived: {} is `{}`, and {}; remove `{}` or change the type",
+                        derive, location, text, reason, derive
+                    ),
+                ));
+            }
+        }
+    }
+
+    for (derive, needs) in type_check::missing_prerequisites(&meta.derives) {
+        issues.push(Issue::warning(None, format!("`{}` needs `{}`; derive `{}` as well or remove `{}`", derive, needs, needs, derive)));
+    }
+
+    let compared = meta.derives.iter().any(|d| type_check::derive_name(d) == "PartialOrd");
+    if compared && meta.order_by == OrderBy::Declaration {
+        let values = variant_values(asset, meta);
+        if let Some(index) = values.windows(2).position(|pair| pair[0] > pair[1]) {
+            let (first, second) = (&asset.variants[index], &asset.variants[index + 1]);
+            issues.push(Issue::warning(
+                Some(index + 1),
+                format!(
+                    "`{}` (= {}) compares greater than `{}` (= {}) since comparisons follow declaration order; order by value or reorder them",
+                    second.name, values[index + 1], first.name, values[index]
+                ),
+            ));
+        }
+    }
+
+    let derives_default = meta.derives.iter().any(|d| type_check::derive_name(d) == "Default");
+    match meta.default_variant.as_deref() {
+        None if derives_default => {
+            issues.push(Issue::warning(None, "`Default` needs a default variant; pick one or remove `Default`".to_string()));
+        }
+        None => {}
+        Some(name) => match asset.variants.iter().position(|v| v.name == name) {
+            None => issues.push(Issue::error(None, format!("Default variant `{}` does not exist", name))),
+            Some(index) if !matches!(asset.variants[index].payload, VariantPayload::Unit) => issues.push(Issue::error(
+                Some(index),
+                format!("Default variant `{}` has a payload; `#[default]` only works on unit variants", name),
+            )),
+            Some(index) if !derives_default => issues.push(Issue::warning(
+                Some(index),
+                format!("`{}` is the default variant but `Default` isn't derived", name),
+            )),
+            Some(_) => {}
+        },
+    }
+    issues
+}
+
+/// Possible misspellings in the player-facing strings: docs and display names
+/// Problems with the serde representation: empty tag names, variants the
+/// tagging can't write and untagged variants that read back ambiguously
+fn serde_repr_issues(asset: &EnumAsset, meta: &EnumMeta) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let names: Vec<&String> = match &meta.serde_repr {
+        SerdeRepr::Internal { tag } => vec![tag],
+        SerdeRepr::Adjacent { tag, content } => vec![tag, content],
+        SerdeRepr::External | SerdeRepr::Untagged => Vec::new(),
+    };
+    if names.iter().any(|name| name.trim().is_empty()) {
+        issues.push(Issue::error(None, format!("{} needs non-empty tag names", meta.serde_repr.label())));
+    }
+    for (index, variant) in asset.variants.iter().enumerate() {
+        if let Err(e) = samples::variant_sample(variant, meta, &meta.serde_repr) {
+            issues.push(Issue::error(Some(index), format!("{}; use a struct payload or another representation", e)));
+        }
+    }
+    if meta.serde_repr == SerdeRepr::Untagged {
+        let units: Vec<usize> = asset
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| matches!(v.payload, VariantPayload::Unit))
+            .map(|(index, _)| index)
+            .collect();
+        if let [_, rest @ ..] = units.as_slice() {
+            for index in rest {
+                issues.push(Issue::warning(
+                    Some(*index),
+                    format!("Untagged unit variant `{}` reads back as the first unit variant", asset.variants[*index].name),
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Stable IDs changed since `saved`: a variant kept its name but its ID is
+/// gone, and didn't follow a rename. IDs must never change, or analytics
+/// would lose the variant's history.
+pub fn stable_id_changes(saved: &EnumAsset, current: &EnumAsset) -> Vec<String> {
+    let (saved_meta, meta) = (EnumMeta::from_asset(saved), EnumMeta::from_asset(current));
+    let ids: HashSet<String> = meta.variants.values().filter_map(|v| v.stable_id.clone()).collect();
+    current
+        .variants
+        .iter()
+        .filter_map(|variant| {
+            let before = saved_meta.variants.get(&variant.name)?.stable_id.clone()?;
+            if ids.contains(&before) {
+                return None;
+            }
+            let after = meta.variant(&variant.name).stable_id;
+            Some(match after {
+                Some(after) => format!("Stable ID of `{}` changed from `{}` to `{}`", variant.name, before, after),
+                None => format!("Stable ID `{}` of `{}` was removed", before, variant.name),
+            })
+        })
+        .collect()
+}
+
+pub fn check_spelling(asset: &EnumAsset, checker: &SpellChecker) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut check = |variant: Option<usize>, text: &str, location: String| {
+        for misspelling in checker.check(text) {
+            issues.push(Issue::warning(variant, format!("Possible misspelling `{}` in {}", misspelling.word, location)));
+        }
+    };
+
+    check(None, &asset.display_name, "the enum display name".to_string());
+    if let Some(description) = &asset.description {
+        check(None, description, "the enum description".to_string());
+    }
+    let meta = EnumMeta::from_asset(asset);
+    for (index, variant) in asset.variants.iter().enumerate() {
+        if let Some(display_name) = &meta.variant(&variant.name).display_name {
+            check(Some(index), display_name, format!("the display name of `{}`", variant.name));
+        }
+        if let Some(doc) = &variant.doc {
+            check(Some(index), doc, format!("the docs of `{}`", variant.name));
+        }
+    }
+    issues
+}
+
+/// Fail with a validation error if the definition has any error-level issues
+pub fn ensure_valid(asset: &EnumAsset) -> Result<()> {
+    let messages: Vec<String> = validate(asset)
+        .into_iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .map(|issue| issue.message)
+        .collect();
+    if messages.is_empty() {
+        Ok(())
+    } else {
+        Err(EnumEditorError::Validation { messages })
+    }
+}
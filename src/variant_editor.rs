@@ -1,19 +1,113 @@
 use gpui::{prelude::*, InteractiveElement as _, StatefulInteractiveElement as _, *};
 use ui::{v_flex, h_flex, ActiveTheme, StyledExt, IconName, Icon, Sizable, button::{Button, ButtonVariants}, input::{InputState, TextInput}};
 use ui_types_common::{EnumVariant, TypeRef, VariantPayload, StructField, Visibility};
+use crate::expr::parse_value;
+use crate::metadata::{format_target_names, parse_combination, parse_target_names, Stage, VariantMeta};
+use crate::references::RenameImpact;
+use crate::spellcheck::SpellChecker;
+use crate::type_suggestions::{self, TypeSuggestions};
+use std::sync::Arc;
+
+/// How discriminants are shown in the value inputs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValueFormat {
+    #[default]
+    Decimal,
+    Hex,
+    Binary,
+}
+
+impl ValueFormat {
+    pub const ALL: [ValueFormat; 3] = [ValueFormat::Decimal, ValueFormat::Hex, ValueFormat::Binary];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValueFormat::Decimal => "Dec",
+            ValueFormat::Hex => "Hex",
+            ValueFormat::Binary => "Bin",
+        }
+    }
+
+    /// The format after this one, wrapping around
+    pub fn next(&self) -> ValueFormat {
+        match self {
+            ValueFormat::Decimal => ValueFormat::Hex,
+            ValueFormat::Hex => ValueFormat::Binary,
+            ValueFormat::Binary => ValueFormat::Decimal,
+        }
+    }
+
+    /// `255` becomes `255`, `0xFF` or `0b1111_1111`
+    pub fn format(&self, value: i64) -> String {
+        let sign = if value < 0 { "-" } else { "" };
+        let magnitude = value.unsigned_abs();
+        match self {
+            ValueFormat::Decimal => value.to_string(),
+            ValueFormat::Hex => format!("{}0x{:X}", sign, magnitude),
+            ValueFormat::Binary => {
+                // Group the digits in nibbles, counted from the right
+                let digits = format!("{:b}", magnitude);
+                let mut grouped = String::new();
+                for (i, digit) in digits.chars().enumerate() {
+                    if i > 0 && (digits.len() - i) % 4 == 0 {
+                        grouped.push('_');
+                    }
+                    grouped.push(digit);
+                }
+                format!("{}0b{}", sign, grouped)
+            }
+        }
+    }
+}
+
+/// Suggestions listed under the type input
+const TYPE_SUGGESTIONS: usize = 8;
 
 /// Component for editing a single enum variant
 pub struct VariantEditorView {
     pub variant: EnumVariant,
+    pub meta: VariantMeta,
     pub index: usize,
 
     // Input states
     name_input: Entity<InputState>,
     doc_input: Entity<InputState>,
+    value_input: Entity<InputState>,
+    combines_input: Entity<InputState>,
+    display_name_input: Entity<InputState>,
+    color_input: Entity<InputState>,
+    cfg_input: Entity<InputState>,
+    owner_input: Entity<InputState>,
+    sort_key_input: Entity<InputState>,
+    target_names_input: Entity<InputState>,
+    note_input: Entity<InputState>,
+    field_doc_input: Entity<InputState>,
+    field_default_input: Entity<InputState>,
+    type_input: Entity<InputState>,
+
+    /// Shared with the other variant editors of the enum
+    spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+    type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
 
     // Editing state
     editing_name: bool,
     editing_doc: bool,
+    show_notes: bool,
+    confirming_remove: bool,
+    pending_rename: Option<RenameImpact>,
+    /// Why the last rename was refused
+    rename_error: Option<String>,
+    /// Value in effect while a value change of a published enum awaits confirmation
+    committed_value: Option<Option<i64>>,
+    value_format: ValueFormat,
+    /// The enum is in bitflags mode, so variants can combine other flags
+    flags_mode: bool,
+    /// Revealed from another panel, e.g. a validation issue
+    highlighted: bool,
+    /// Struct payload field whose type, doc and default are being edited
+    editing_field: Option<usize>,
+    /// The type of a single payload is being edited
+    editing_type: bool,
     
     // Subscriptions
     _subscriptions: Vec<gpui::Subscription>,
@@ -22,15 +116,44 @@ pub struct VariantEditorView {
 #[derive(Clone, Debug)]
 pub enum VariantEditorEvent {
     VariantChanged(usize, EnumVariant),
+    /// The name was edited; the owner answers with `set_rename_impact`
+    RenameRequested(usize, String),
+    RenameConfirmed(usize, String),
+    MetaChanged(usize, VariantMeta),
     RemoveRequested(usize),
-    TypePickerRequested(usize),
+    DuplicateRequested(usize),
+    /// A note was written; the owner records its author and time
+    NoteAdded(usize, String),
+    /// A value change of a published enum was confirmed
+    ValueChangeConfirmed(usize, Option<i64>),
     AddFieldRequested(usize),
 }
 
 impl VariantEditorView {
-    pub fn new(variant: EnumVariant, index: usize, window: &mut Window, cx: &mut Context<Self>) -> Self {
+    pub fn new(
+        variant: EnumVariant,
+        meta: VariantMeta,
+        index: usize,
+        spell_checker: Arc<parking_lot::RwLock<SpellChecker>>,
+        type_suggestions: Arc<parking_lot::RwLock<TypeSuggestions>>,
+        value_format: ValueFormat,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
         let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("variant_name"));
-        let doc_input = cx.new(|cx| InputState::new(window, cx).placeholder("Variant documentation..."));
+        let doc_input = cx.new(|cx| InputState::new(window, cx).multi_line().placeholder("Variant documentation (markdown)..."));
+        let value_input = cx.new(|cx| InputState::new(window, cx).placeholder("auto"));
+        let combines_input = cx.new(|cx| InputState::new(window, cx).placeholder("Read | Write"));
+        let display_name_input = cx.new(|cx| InputState::new(window, cx).placeholder(variant.name.clone()));
+        let color_input = cx.new(|cx| InputState::new(window, cx).placeholder("#RRGGBB"));
+        let owner_input = cx.new(|cx| InputState::new(window, cx).placeholder("Unassigned"));
+        let sort_key_input = cx.new(|cx| InputState::new(window, cx).placeholder("by position"));
+        let target_names_input = cx.new(|cx| InputState::new(window, cx).placeholder("rust = Self_, swift = me"));
+        let note_input = cx.new(|cx| InputState::new(window, cx).placeholder("Add a note..."));
+        let cfg_input = cx.new(|cx| InputState::new(window, cx).placeholder("always"));
+        let field_doc_input = cx.new(|cx| InputState::new(window, cx).placeholder("Field documentation..."));
+        let field_default_input = cx.new(|cx| InputState::new(window, cx).placeholder("required"));
+        let type_input = cx.new(|cx| InputState::new(window, cx).placeholder("u32, Vec<EntityId>..."));
 
         // Initialize inputs
         name_input.update(cx, |input, cx| {
@@ -43,29 +166,64 @@ impl VariantEditorView {
             });
         }
 
+        if let Some(expr) = &meta.expr {
+            value_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, expr, window, cx);
+            });
+        } else if let Some(value) = meta.value {
+            value_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &value_format.format(value), window, cx);
+            });
+        }
+        if meta.is_composite() {
+            combines_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &meta.combines.join(" | "), window, cx);
+            });
+        }
+        if let Some(display_name) = &meta.display_name {
+            display_name_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, display_name, window, cx);
+            });
+        }
+        if let Some(color) = &meta.color {
+            color_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, color, window, cx);
+            });
+        }
+        if let Some(cfg) = &meta.cfg {
+            cfg_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, cfg, window, cx);
+            });
+        }
+        if let Some(owner) = &meta.owner {
+            owner_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, owner, window, cx);
+            });
+        }
+        if let Some(sort_key) = meta.sort_key {
+            sort_key_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &sort_key.to_string(), window, cx);
+            });
+        }
+        if !meta.target_names.is_empty() {
+            target_names_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &format_target_names(&meta.target_names), window, cx);
+            });
+        }
+
         // Subscribe to input events
+        // Renames are only applied once the impact preview is confirmed
         let sub1 = cx.subscribe_in(&name_input, window, |this, _state, event: &ui::input::InputEvent, _window, cx| {
-            match event {
-                ui::input::InputEvent::Change => {
-                    if this.editing_name {
-                        this.name_input.update(cx, |input, _cx| {
-                            this.variant.name = input.text().to_string();
-                        });
-                        cx.emit(VariantEditorEvent::VariantChanged(this.index, this.variant.clone()));
-                        cx.notify();
-                    }
-                }
-                ui::input::InputEvent::Blur => {
-                    if this.editing_name {
-                        this.editing_name = false;
-                        this.name_input.update(cx, |input, _cx| {
-                            this.variant.name = input.text().to_string();
-                        });
-                        cx.emit(VariantEditorEvent::VariantChanged(this.index, this.variant.clone()));
-                        cx.notify();
+            if let ui::input::InputEvent::Blur = event {
+                if this.editing_name {
+                    this.editing_name = false;
+                    this.rename_error = None;
+                    let new_name = this.name_input.read(cx).text().trim().to_string();
+                    if !new_name.is_empty() && new_name != this.variant.name {
+                        cx.emit(VariantEditorEvent::RenameRequested(this.index, new_name));
                     }
+                    cx.notify();
                 }
-                _ => {}
             }
         });
 
@@ -96,17 +254,552 @@ impl VariantEditorView {
             }
         });
 
+        // Anything but a plain number is kept as an expression
+        let sub3 = Self::subscribe_meta_input(&value_input, window, cx, |meta, text| {
+            if text.trim().is_empty() {
+                meta.value = None;
+                meta.expr = None;
+            } else if let Some(value) = parse_value(&text) {
+                meta.value = Some(value);
+                meta.expr = None;
+            } else {
+                meta.value = None;
+                meta.expr = Some(text.trim().to_string());
+            }
+        });
+        let sub8 = Self::subscribe_meta_input(&combines_input, window, cx, |meta, text| {
+            meta.combines = parse_combination(&text);
+        });
+        let sub4 = Self::subscribe_meta_input(&display_name_input, window, cx, |meta, text| {
+            meta.display_name = if text.is_empty() { None } else { Some(text) };
+        });
+        let sub5 = Self::subscribe_meta_input(&color_input, window, cx, |meta, text| {
+            meta.color = if text.trim().is_empty() { None } else { Some(text.trim().to_string()) };
+        });
+        let sub6 = Self::subscribe_meta_input(&cfg_input, window, cx, |meta, text| {
+            meta.cfg = if text.trim().is_empty() { None } else { Some(text.trim().to_string()) };
+        });
+        let sub7 = Self::subscribe_meta_input(&owner_input, window, cx, |meta, text| {
+            meta.owner = if text.trim().is_empty() { None } else { Some(text.trim().to_string()) };
+        });
+        // Text that isn't a number leaves the key unset
+        let sub12 = Self::subscribe_meta_input(&sort_key_input, window, cx, |meta, text| {
+            meta.sort_key = text.trim().parse().ok();
+        });
+        let sub13 = Self::subscribe_meta_input(&target_names_input, window, cx, |meta, text| {
+            meta.target_names = parse_target_names(&text);
+        });
+
+        let sub9 = cx.subscribe_in(&field_doc_input, window, |this, state, event: &ui::input::InputEvent, _window, cx| {
+            if let ui::input::InputEvent::Change = event {
+                let text = state.read(cx).text().to_string();
+                let doc = if text.trim().is_empty() { None } else { Some(text) };
+                if let (Some(field_idx), VariantPayload::Struct(fields)) = (this.editing_field, &mut this.variant.payload) {
+                    match fields.get_mut(field_idx) {
+                        Some(field) if field.doc != doc => field.doc = doc,
+                        _ => return,
+                    }
+                    cx.emit(VariantEditorEvent::VariantChanged(this.index, this.variant.clone()));
+                    cx.notify();
+                }
+            }
+        });
+        // An empty default makes the field required again
+        let sub10 = cx.subscribe_in(&field_default_input, window, |this, state, event: &ui::input::InputEvent, _window, cx| {
+            if let ui::input::InputEvent::Change = event {
+                let Some(name) = this.editing_field_name() else {
+                    return;
+                };
+                let text = state.read(cx).text().trim().to_string();
+                let before = this.meta.clone();
+                if text.is_empty() {
+                    this.meta.field_defaults.remove(&name);
+                } else {
+                    this.meta.field_defaults.insert(name, text);
+                }
+                if this.meta != before {
+                    cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                    cx.notify();
+                }
+            }
+        });
+
+        // Suggestions follow the text; the type is set when the input loses focus
+        let sub11 = cx.subscribe_in(&type_input, window, |this, state, event: &ui::input::InputEvent, _window, cx| {
+            match event {
+                ui::input::InputEvent::Change => cx.notify(),
+                ui::input::InputEvent::Blur => {
+                    let text = state.read(cx).text().to_string();
+                    this.set_type(&text, cx);
+                }
+                _ => {}
+            }
+        });
+
         Self {
             variant,
+            meta,
             index,
             name_input,
             doc_input,
+            value_input,
+            combines_input,
+            display_name_input,
+            color_input,
+            cfg_input,
+            owner_input,
+            sort_key_input,
+            target_names_input,
+            note_input,
+            field_doc_input,
+            field_default_input,
+            type_input,
+            spell_checker,
+            type_suggestions,
             editing_name: false,
             editing_doc: false,
-            _subscriptions: vec![sub1, sub2],
+            show_notes: false,
+            confirming_remove: false,
+            pending_rename: None,
+            rename_error: None,
+            committed_value: None,
+            value_format,
+            flags_mode: false,
+            highlighted: false,
+            editing_field: None,
+            editing_type: false,
+            _subscriptions: vec![sub1, sub2, sub3, sub4, sub5, sub6, sub7, sub8, sub9, sub10, sub11, sub12, sub13],
+        }
+    }
+
+    /// Apply edits of an always-visible metadata input to `meta` as the user types
+    fn subscribe_meta_input(
+        input: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+        apply: fn(&mut VariantMeta, String),
+    ) -> gpui::Subscription {
+        cx.subscribe_in(input, window, move |this, state, event: &ui::input::InputEvent, _window, cx| {
+            if let ui::input::InputEvent::Change = event {
+                let text = state.read(cx).text().to_string();
+                let before = this.meta.clone();
+                apply(&mut this.meta, text);
+                // Reformatting a value leaves the metadata as it was
+                if this.meta == before {
+                    return;
+                }
+                cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                cx.notify();
+            }
+        })
+    }
+
+    fn editing_field_name(&self) -> Option<String> {
+        match &self.variant.payload {
+            VariantPayload::Struct(fields) => Some(fields.get(self.editing_field?)?.name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Show the doc and default of a struct payload field for editing, or
+    /// hide them if they are shown
+    fn edit_field(&mut self, field_idx: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_field == Some(field_idx) {
+            self.editing_field = None;
+            cx.notify();
+            return;
+        }
+        let VariantPayload::Struct(fields) = &self.variant.payload else {
+            return;
+        };
+        let Some(field) = fields.get(field_idx) else {
+            return;
+        };
+        let doc = field.doc.clone().unwrap_or_default();
+        let default = self.meta.field_defaults.get(&field.name).cloned().unwrap_or_default();
+        let type_str = Self::type_ref_to_string(&field.type_ref);
+        // Filled while no field is edited, so filling them changes nothing
+        self.editing_field = None;
+        self.field_doc_input.update(cx, |input, cx| input.replace_text_in_range(None, &doc, window, cx));
+        self.field_default_input.update(cx, |input, cx| input.replace_text_in_range(None, &default, window, cx));
+        self.type_input.update(cx, |input, cx| input.replace_text_in_range(None, &type_str, window, cx));
+        self.editing_field = Some(field_idx);
+        cx.notify();
+    }
+
+    /// Show the type picker of a single payload, or hide it
+    fn toggle_type_picker(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.editing_type = !self.editing_type;
+        if let (true, VariantPayload::Single(type_ref)) = (self.editing_type, &self.variant.payload) {
+            let type_str = Self::type_ref_to_string(type_ref);
+            self.type_input.update(cx, |input, cx| input.replace_text_in_range(None, &type_str, window, cx));
+        }
+        cx.notify();
+    }
+
+    /// Set the type being edited: the single payload's, or the edited field's
+    fn set_type(&mut self, text: &str, cx: &mut Context<Self>) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let type_ref = match (&mut self.variant.payload, self.editing_field) {
+            (VariantPayload::Single(type_ref), _) if self.editing_type => type_ref,
+            (VariantPayload::Struct(fields), Some(field_idx)) => match fields.get_mut(field_idx) {
+                Some(field) => &mut field.type_ref,
+                None => return,
+            },
+            _ => return,
+        };
+        if Self::type_ref_to_string(type_ref) == text {
+            return;
+        }
+        *type_ref = type_suggestions::type_ref(text);
+        cx.emit(VariantEditorEvent::VariantChanged(self.index, self.variant.clone()));
+        cx.notify();
+    }
+
+    /// Complete the name being typed with a suggestion and use the type
+    fn pick_type(&mut self, name: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let text = type_suggestions::complete(&self.type_input.read(cx).text(), name);
+        self.type_input.update(cx, |input, cx| input.replace_text_in_range(None, &text, window, cx));
+        self.set_type(&text, cx);
+    }
+
+    /// Type input with the known types matching what is being typed
+    fn type_picker(&self, cx: &Context<Self>) -> Div {
+        let text = self.type_input.read(cx).text().to_string();
+        let suggestions = self.type_suggestions.read().suggest(&text, TYPE_SUGGESTIONS);
+        v_flex()
+            .gap_1()
+            .child(Self::meta_field("Type", &self.type_input, cx))
+            .child(h_flex().flex_wrap().gap_1().children(suggestions.into_iter().enumerate().map(|(i, suggestion)| {
+                let name = suggestion.name.clone();
+                Button::new(SharedString::from(format!("type-suggestion-{}-{}", self.index, i)))
+                    .ghost()
+                    .with_size(ui::Size::XSmall)
+                    .label(format!("{} · {}", suggestion.name, suggestion.source.label()))
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        this.pick_type(&name, window, cx);
+                    }))
+            })))
+    }
+
+    /// Move a struct payload field `offset` places, stopping at either end
+    fn move_field(&mut self, field_idx: usize, offset: isize, cx: &mut Context<Self>) {
+        let VariantPayload::Struct(fields) = &mut self.variant.payload else {
+            return;
+        };
+        let target = field_idx.saturating_add_signed(offset).min(fields.len().saturating_sub(1));
+        if field_idx >= fields.len() || target == field_idx {
+            return;
+        }
+        let field = fields.remove(field_idx);
+        fields.insert(target, field);
+        self.editing_field = match self.editing_field {
+            Some(editing) if editing == field_idx => Some(target),
+            Some(editing) if editing == target => Some(field_idx),
+            editing => editing,
+        };
+        cx.emit(VariantEditorEvent::VariantChanged(self.index, self.variant.clone()));
+        cx.notify();
+    }
+
+    fn remove_field(&mut self, field_idx: usize, cx: &mut Context<Self>) {
+        let VariantPayload::Struct(fields) = &mut self.variant.payload else {
+            return;
+        };
+        if field_idx >= fields.len() {
+            return;
+        }
+        let field = fields.remove(field_idx);
+        self.editing_field = None;
+        cx.emit(VariantEditorEvent::VariantChanged(self.index, self.variant.clone()));
+        if self.meta.field_defaults.remove(&field.name).is_some() {
+            cx.emit(VariantEditorEvent::MetaChanged(self.index, self.meta.clone()));
+        }
+        cx.notify();
+    }
+
+    fn meta_field(label: &'static str, input: &Entity<InputState>, cx: &App) -> Div {
+        v_flex()
+            .flex_1()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(label)
+            )
+            .child(TextInput::new(input))
+    }
+
+    /// `text` with misspelled words underlined and a button to ignore each,
+    /// or `None` if it is spelled correctly
+    fn spelling(&self, id: &str, text: &str, cx: &mut Context<Self>) -> Option<Div> {
+        let misspellings = self.spell_checker.read().check(text);
+        if misspellings.is_empty() {
+            return None;
+        }
+
+        let squiggle = HighlightStyle {
+            underline: Some(UnderlineStyle {
+                thickness: px(1.0),
+                color: Some(cx.theme().danger),
+                wavy: true,
+            }),
+            ..Default::default()
+        };
+        let highlights: Vec<_> = misspellings.iter().map(|m| (m.range.clone(), squiggle)).collect();
+        let mut words: Vec<String> = misspellings.into_iter().map(|m| m.word).collect();
+        words.dedup();
+
+        Some(
+            v_flex()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(StyledText::new(text.to_string()).with_highlights(highlights))
+                )
+                .child(
+                    h_flex()
+                        .flex_wrap()
+                        .gap_1()
+                        .children(words.into_iter().map(|word| {
+                            Button::new(SharedString::from(format!("ignore-word-{}-{}-{}", id, self.index, word)))
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label(format!("Ignore \"{}\"", word))
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    if let Err(e) = this.spell_checker.write().ignore(&word) {
+                                        log::warn!("Failed to save ignored word: {}", e);
+                                    }
+                                    cx.notify();
+                                }))
+                        }))
+                )
+        )
+    }
+
+    /// Show what a requested rename would affect, awaiting confirmation
+    pub fn set_rename_impact(&mut self, impact: RenameImpact, cx: &mut Context<Self>) {
+        self.pending_rename = Some(impact);
+        cx.notify();
+    }
+
+    /// Refuse a requested or confirmed rename, putting `name` back
+    pub fn reject_rename(&mut self, name: &str, message: String, window: &mut Window, cx: &mut Context<Self>) {
+        self.variant.name = name.to_string();
+        self.rename_error = Some(message);
+        self.cancel_rename(window, cx);
+    }
+
+    fn cancel_rename(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.pending_rename = None;
+        let name = self.variant.name.clone();
+        self.name_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &name, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn confirm_rename(&mut self, cx: &mut Context<Self>) {
+        if let Some(impact) = self.pending_rename.take() {
+            self.variant.name = impact.new_name.clone();
+            cx.emit(VariantEditorEvent::RenameConfirmed(self.index, impact.new_name));
+            cx.notify();
+        }
+    }
+
+    fn add_note(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.note_input.read(cx).text().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        self.note_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, "", window, cx);
+        });
+        cx.emit(VariantEditorEvent::NoteAdded(self.index, text));
+    }
+
+    fn render_notes(&self, cx: &mut Context<Self>) -> Div {
+        let index = self.index;
+        v_flex()
+            .gap_2()
+            .child(
+                div()
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(cx.theme().muted_foreground)
+                    .child("Notes")
+            )
+            .children(self.meta.notes.iter().enumerate().map(|(i, note)| {
+                let byline = match (&note.author, &note.created) {
+                    (Some(author), Some(created)) => format!("{}, {}", author, created.with_timezone(&chrono::Local).format("%Y-%m-%d")),
+                    (Some(author), None) => author.clone(),
+                    (None, Some(created)) => created.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string(),
+                    (None, None) => String::new(),
+                };
+                h_flex()
+                    .gap_2()
+                    .items_start()
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(if note.resolved { cx.theme().muted_foreground } else { cx.theme().foreground })
+                                    .when(note.resolved, |this| this.line_through())
+                                    .child(note.text.clone())
+                            )
+                            .when(!byline.is_empty(), |this| {
+                                this.child(div().text_xs().text_color(cx.theme().muted_foreground).child(byline))
+                            })
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("resolve-note-{}-{}", index, i)))
+                            .ghost()
+                            .with_size(ui::Size::XSmall)
+                            .label(if note.resolved { "Reopen" } else { "Resolve" })
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                if let Some(note) = this.meta.notes.get_mut(i) {
+                                    note.resolved = !note.resolved;
+                                    cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                                    cx.notify();
+                                }
+                            }))
+                    )
+                    .child(
+                        Button::new(SharedString::from(format!("delete-note-{}-{}", index, i)))
+                            .ghost()
+                            .with_size(ui::Size::XSmall)
+                            .icon(IconName::Delete)
+                            .tooltip("Delete note")
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                if i < this.meta.notes.len() {
+                                    this.meta.notes.remove(i);
+                                    cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                                    cx.notify();
+                                }
+                            }))
+                    )
+            }))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(TextInput::new(&self.note_input).flex_1())
+                    .child(
+                        Button::new(("add-note", index))
+                            .with_size(ui::Size::Small)
+                            .label("Add Note")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.add_note(window, cx);
+                            }))
+                    )
+            )
+    }
+
+    /// e.g. "Added 2026-03-01 14:20 · Modified 2026-03-04 09:12"
+    fn timestamps(meta: &VariantMeta) -> String {
+        let format = |time: &chrono::DateTime<chrono::Utc>| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string();
+        let mut parts = Vec::new();
+        if let Some(created) = &meta.created {
+            parts.push(format!("Added {}", format(created)));
+        }
+        if let Some(modified) = &meta.modified {
+            parts.push(format!("Modified {}", format(modified)));
+        }
+        if parts.is_empty() {
+            "No history recorded".to_string()
+        } else {
+            parts.join(" · ")
         }
     }
 
+    /// Take metadata updated by the owner, e.g. a new modification time
+    pub fn set_meta(&mut self, meta: VariantMeta, cx: &mut Context<Self>) {
+        let typed_value = self.meta.value;
+        self.meta = meta;
+        // Keep showing an unconfirmed value change
+        if self.committed_value.is_some() {
+            self.meta.value = typed_value;
+        }
+        cx.notify();
+    }
+
+    /// Hold the typed value for confirmation; `committed` is the value in effect
+    pub fn set_pending_value(&mut self, committed: Option<Option<i64>>, cx: &mut Context<Self>) {
+        self.committed_value = committed;
+        cx.notify();
+    }
+
+    fn confirm_value_change(&mut self, cx: &mut Context<Self>) {
+        if self.committed_value.take().is_some() {
+            cx.emit(VariantEditorEvent::ValueChangeConfirmed(self.index, self.meta.value));
+            cx.notify();
+        }
+    }
+
+    fn cancel_value_change(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(committed) = self.committed_value.take() else {
+            return;
+        };
+        self.meta.value = committed;
+        let text = committed.map(|value| self.value_format.format(value)).unwrap_or_default();
+        self.value_input.update(cx, |input, cx| {
+            input.replace_text_in_range(None, &text, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Show an explicit value in `format`; hex and binary input is accepted
+    /// whichever format is shown
+    pub fn set_value_format(&mut self, format: ValueFormat, window: &mut Window, cx: &mut Context<Self>) {
+        self.value_format = format;
+        if let Some(value) = self.meta.value {
+            self.value_input.update(cx, |input, cx| {
+                input.replace_text_in_range(None, &format.format(value), window, cx);
+            });
+        }
+        cx.notify();
+    }
+
+    /// Offer the combination field of bitflags enums
+    pub fn set_flags_mode(&mut self, flags_mode: bool, cx: &mut Context<Self>) {
+        self.flags_mode = flags_mode;
+        cx.notify();
+    }
+
+    pub fn is_highlighted(&self) -> bool {
+        self.highlighted
+    }
+
+    pub fn value_format(&self) -> ValueFormat {
+        self.value_format
+    }
+
+    pub fn set_highlighted(&mut self, highlighted: bool, cx: &mut Context<Self>) {
+        self.highlighted = highlighted;
+        cx.notify();
+    }
+
+    /// Show the name input, as the rename button does
+    pub fn start_rename(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.editing_name = true;
+        window.focus(&self.name_input.focus_handle(cx));
+        cx.notify();
+    }
+
+    /// Ask to confirm deleting the variant, as the delete button does
+    pub fn request_remove(&mut self, cx: &mut Context<Self>) {
+        self.confirming_remove = true;
+        cx.notify();
+    }
+
     pub fn update_variant(&mut self, variant: EnumVariant, cx: &mut Context<Self>) {
         self.variant = variant.clone();
         cx.notify();
@@ -133,8 +826,8 @@ impl Render for VariantEditorView {
             .gap_3()
             .bg(cx.theme().secondary.opacity(0.4))
             .border_1()
-            .border_color(cx.theme().border)
-            .rounded(px(8.0))
+            .border_color(if self.highlighted { cx.theme().primary } else { cx.theme().border })
+            .rounded(cx.theme().radius)
             .child(
                 // Header row with name and actions
                 h_flex()
@@ -162,6 +855,7 @@ impl Render for VariantEditorView {
                                         .ghost()
                                         .with_size(ui::Size::XSmall)
                                         .icon(IconName::Edit)
+                                        .tooltip("Rename variant")
                                         .ml_2()
                                         .on_click(cx.listener(|this, _, _window, cx| {
                                             this.editing_name = true;
@@ -171,17 +865,244 @@ impl Render for VariantEditorView {
                                 .into_any_element()
                         }
                     )
+                    .child({
+                        // Notes indicator; highlighted while notes are open
+                        let open = self.meta.open_notes();
+                        Button::new(("toggle-notes", index))
+                            .ghost()
+                            .with_size(ui::Size::Small)
+                            .when(open > 0, |this| this.primary())
+                            .label(match (open, self.meta.notes.len()) {
+                                (0, 0) => "Notes".to_string(),
+                                (0, total) => format!("Notes ({})", total),
+                                (open, _) => format!("{} open", open),
+                            })
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.show_notes = !this.show_notes;
+                                cx.notify();
+                            }))
+                    })
                     .child(
-                        // Remove button
-                        Button::new(("remove", index))
+                        Button::new(("duplicate", index))
                             .ghost()
                             .with_size(ui::Size::Small)
-                            .icon(IconName::Delete)
+                            .icon(IconName::Copy)
+                            .tooltip("Duplicate variant")
+                            .on_click(cx.listener(move |_this, _, _window, cx| {
+                                cx.emit(VariantEditorEvent::DuplicateRequested(index));
+                            }))
+                    )
+                    .child(
+                        // Remove button, asking for confirmation first
+                        if self.confirming_remove {
+                            h_flex()
+                                .gap_1()
+                                .child(
+                                    Button::new(("confirm-remove", index))
+                                        .danger()
+                                        .with_size(ui::Size::Small)
+                                        .label("Delete")
+                                        .on_click(cx.listener(move |this, _, _window, cx| {
+                                            this.confirming_remove = false;
+                                            cx.emit(VariantEditorEvent::RemoveRequested(index));
+                                        }))
+                                )
+                                .child(
+                                    Button::new(("cancel-remove", index))
+                                        .ghost()
+                                        .with_size(ui::Size::Small)
+                                        .label("Cancel")
+                                        .on_click(cx.listener(|this, _, _window, cx| {
+                                            this.confirming_remove = false;
+                                            cx.notify();
+                                        }))
+                                )
+                                .into_any_element()
+                        } else {
+                            Button::new(("remove", index))
+                                .ghost()
+                                .with_size(ui::Size::Small)
+                                .icon(IconName::Delete)
+                                .tooltip("Delete variant")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.confirming_remove = true;
+                                    cx.notify();
+                                }))
+                                .into_any_element()
+                        }
+                    )
+            )
+            // Value change of a published enum awaiting confirmation
+            .when_some(self.committed_value, |this, committed| {
+                let format = self.value_format;
+                let describe = |value: Option<i64>| value.map(|v| format.format(v)).unwrap_or_else(|| "auto".to_string());
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .p_2()
+                        .items_center()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().warning.opacity(0.1))
+                        .border_1()
+                        .border_color(cx.theme().warning.opacity(0.4))
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_xs()
+                                .text_color(cx.theme().foreground)
+                                .child(format!(
+                                    "This enum is published. Changing the value from {} to {} reserves the old value.",
+                                    describe(committed),
+                                    describe(self.meta.value)
+                                ))
+                        )
+                        .child(
+                            Button::new(("confirm-value-change", index))
+                                .danger()
+                                .with_size(ui::Size::XSmall)
+                                .label("Change Value")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.confirm_value_change(cx);
+                                }))
+                        )
+                        .child(
+                            Button::new(("cancel-value-change", index))
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label("Cancel")
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.cancel_value_change(window, cx);
+                                }))
+                        )
+                )
+            })
+            .when_some(self.rename_error.clone(), |this, message| {
+                this.child(div().text_xs().text_color(cx.theme().danger).child(message))
+            })
+            // Impact of a pending rename
+            .when_some(self.pending_rename.clone(), |this, impact| {
+                let mut summary = format!(
+                    "Rename `{}` → `{}`: {} reference{} in {} file{}",
+                    impact.old_name,
+                    impact.new_name,
+                    impact.references,
+                    if impact.references == 1 { "" } else { "s" },
+                    impact.files,
+                    if impact.files == 1 { "" } else { "s" },
+                );
+                if impact.adds_alias {
+                    summary.push_str(&format!(", `{}` kept as serde alias", impact.old_name));
+                }
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .p_2()
+                        .items_center()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().accent.opacity(0.1))
+                        .border_1()
+                        .border_color(cx.theme().accent.opacity(0.4))
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_xs()
+                                .text_color(cx.theme().foreground)
+                                .child(summary)
+                        )
+                        .child(
+                            Button::new(("confirm-rename", index))
+                                .primary()
+                                .with_size(ui::Size::XSmall)
+                                .label("Rename")
+                                .on_click(cx.listener(|this, _, _window, cx| {
+                                    this.confirm_rename(cx);
+                                }))
+                        )
+                        .child(
+                            Button::new(("cancel-rename", index))
+                                .ghost()
+                                .with_size(ui::Size::XSmall)
+                                .label("Cancel")
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.cancel_rename(window, cx);
+                                }))
+                        )
+                )
+            })
+            // Value and runtime metadata
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_end()
+                    .child(Self::meta_field("Value", &self.value_input, cx))
+                    // Kept visible outside bitflags mode so a stale combination can be cleared
+                    .when(self.flags_mode || self.meta.is_composite(), |this| {
+                        this.child(Self::meta_field("Combines", &self.combines_input, cx))
+                    })
+                    .child(Self::meta_field("Display Name", &self.display_name_input, cx))
+                    .child(Self::meta_field("Color", &self.color_input, cx))
+                    .child(Self::meta_field("Cfg", &self.cfg_input, cx))
+                    .when_some(self.meta.color_rgba(), |this, color| {
+                        this.child(
+                            div()
+                                .size(px(24.0))
+                                .rounded(px(4.0))
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .bg(rgba(color))
+                        )
+                    })
+                    .children(Stage::ALL.into_iter().map(|stage| {
+                        Button::new(SharedString::from(format!("stage-{}-{}", stage.label(), index)))
+                            .with_size(ui::Size::Small)
+                            .when(self.meta.stage == stage, |this| this.primary())
+                            .label(stage.label())
                             .on_click(cx.listener(move |this, _, _window, cx| {
-                                cx.emit(VariantEditorEvent::RemoveRequested(index));
+                                this.meta.stage = stage;
+                                cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                                cx.notify();
+                            }))
+                    }))
+                    .child(
+                        Button::new(("toggle-deprecated", index))
+                            .with_size(ui::Size::Small)
+                            .when(self.meta.deprecated, |this| this.primary())
+                            .label("Deprecated")
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.meta.deprecated = !this.meta.deprecated;
+                                cx.emit(VariantEditorEvent::MetaChanged(this.index, this.meta.clone()));
+                                cx.notify();
                             }))
                     )
             )
+            .children(self.meta.display_name.clone().and_then(|name| self.spelling("display-name", &name, cx)))
+            .when(self.show_notes, |this| this.child(self.render_notes(cx)))
+            // Details: who owns the variant and when it was added and last edited
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_end()
+                    .child(Self::meta_field("Owner", &self.owner_input, cx))
+                    .child(Self::meta_field("Sort Key", &self.sort_key_input, cx))
+                    .child(Self::meta_field("Emitted Names", &self.target_names_input, cx))
+                    .child(
+                        div()
+                            .flex_1()
+                            .pb_1()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(Self::timestamps(&self.meta))
+                    )
+                    .when_some(self.meta.stable_id.clone(), |this, stable_id| {
+                        this.child(
+                            div()
+                                .pb_1()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("ID {}", stable_id))
+                        )
+                    })
+            )
             // Render payload based on type
             .child(
                 match &self.variant.payload {
@@ -264,6 +1185,7 @@ impl Render for VariantEditorView {
                                                     .ghost()
                                                     .with_size(ui::Size::XSmall)
                                                     .icon(IconName::Close)
+                                                    .tooltip("Remove payload")
                                                     .on_click(cx.listener(move |this, _, _window, cx| {
                                                         this.variant.payload = VariantPayload::Unit;
                                                         cx.emit(VariantEditorEvent::VariantChanged(index, this.variant.clone()));
@@ -276,15 +1198,15 @@ impl Render for VariantEditorView {
                                 Button::new(("variant-type-picker", index))
                                     .w_full()
                                     .ghost()
-                                    .on_click(cx.listener(move |this, _, _window, cx| {
-                                        cx.emit(VariantEditorEvent::TypePickerRequested(index));
+                                    .on_click(cx.listener(move |this, _, window, cx| {
+                                        this.toggle_type_picker(window, cx);
                                     }))
                                     .child(
                                         h_flex()
                                             .items_center()
                                             .gap_2()
                                             .p_2()
-                                            .rounded(px(4.0))
+                                            .rounded(cx.theme().radius)
                                             .bg(cx.theme().secondary.opacity(0.3))
                                             .border_1()
                                             .border_color(cx.theme().border.opacity(0.5))
@@ -309,6 +1231,7 @@ impl Render for VariantEditorView {
                                             )
                                     )
                             )
+                            .when(self.editing_type, |this| this.child(self.type_picker(cx)))
                             .into_any_element()
                     }
                     VariantPayload::Struct(fields) => {
@@ -334,6 +1257,7 @@ impl Render for VariantEditorView {
                                                     .ghost()
                                                     .with_size(ui::Size::XSmall)
                                                     .icon(IconName::Plus)
+                                                    .tooltip("Add field")
                                                     .on_click(cx.listener(move |this, _, _window, cx| {
                                                         if let VariantPayload::Struct(ref mut fields) = this.variant.payload {
                                                             fields.push(StructField {
@@ -352,6 +1276,7 @@ impl Render for VariantEditorView {
                                                     .ghost()
                                                     .with_size(ui::Size::XSmall)
                                                     .icon(IconName::Close)
+                                                    .tooltip("Remove payload")
                                                     .on_click(cx.listener(move |this, _, _window, cx| {
                                                         this.variant.payload = VariantPayload::Unit;
                                                         cx.emit(VariantEditorEvent::VariantChanged(index, this.variant.clone()));
@@ -365,35 +1290,81 @@ impl Render for VariantEditorView {
                                     .gap_2()
                                     .children(
                                         fields.iter().enumerate().map(|(field_idx, field)| {
-                                            h_flex()
+                                            let default = self.meta.field_defaults.get(&field.name);
+                                            let doc = field.doc.as_deref().and_then(|doc| doc.lines().next());
+                                            let editing = self.editing_field == Some(field_idx);
+                                            let field_button = |id: &str| SharedString::from(format!("{}-{}-{}", id, index, field_idx));
+                                            v_flex()
                                                 .gap_2()
                                                 .p_2()
-                                                .rounded(px(4.0))
+                                                .rounded(cx.theme().radius)
                                                 .bg(cx.theme().secondary.opacity(0.2))
                                                 .border_1()
-                                                .border_color(cx.theme().border.opacity(0.3))
-                                                .child(
-                                                    div()
-                                                        .flex_1()
-                                                        .text_sm()
-                                                        .text_color(cx.theme().foreground)
-                                                        .child(format!("{}: {}", field.name, Self::type_ref_to_string(&field.type_ref)))
-                                                )
+                                                .border_color(if editing { cx.theme().primary } else { cx.theme().border.opacity(0.3) })
                                                 .child(
-                                                    Button::new(SharedString::from(format!("remove-field-{}-{}", index, field_idx)))
-                                                        .ghost()
-                                                        .with_size(ui::Size::XSmall)
-                                                        .icon(IconName::Close)
-                                                        .on_click(cx.listener(move |this, _, _window, cx| {
-                                                            if let VariantPayload::Struct(ref mut fields) = this.variant.payload {
-                                                                if field_idx < fields.len() {
-                                                                    fields.remove(field_idx);
-                                                                    cx.emit(VariantEditorEvent::VariantChanged(index, this.variant.clone()));
-                                                                    cx.notify();
-                                                                }
-                                                            }
-                                                        }))
+                                                    h_flex()
+                                                        .gap_2()
+                                                        .child(
+                                                            div()
+                                                                .flex_1()
+                                                                .text_sm()
+                                                                .text_color(cx.theme().foreground)
+                                                                .child(match default {
+                                                                    Some(default) => format!("{}: {} = {}", field.name, Self::type_ref_to_string(&field.type_ref), default),
+                                                                    None => format!("{}: {}", field.name, Self::type_ref_to_string(&field.type_ref)),
+                                                                })
+                                                        )
+                                                        .child(
+                                                            Button::new(field_button("field-up"))
+                                                                .ghost()
+                                                                .with_size(ui::Size::XSmall)
+                                                                .label("↑")
+                                                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                                                    this.move_field(field_idx, -1, cx);
+                                                                }))
+                                                        )
+                                                        .child(
+                                                            Button::new(field_button("field-down"))
+                                                                .ghost()
+                                                                .with_size(ui::Size::XSmall)
+                                                                .label("↓")
+                                                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                                                    this.move_field(field_idx, 1, cx);
+                                                                }))
+                                                        )
+                                                        .child(
+                                                            Button::new(field_button("edit-field"))
+                                                                .ghost()
+                                                                .with_size(ui::Size::XSmall)
+                                                                .icon(IconName::Edit)
+                                                                .tooltip("Edit field")
+                                                                .when(editing, |this| this.primary())
+                                                                .on_click(cx.listener(move |this, _, window, cx| {
+                                                                    this.edit_field(field_idx, window, cx);
+                                                                }))
+                                                        )
+                                                        .child(
+                                                            Button::new(field_button("remove-field"))
+                                                                .ghost()
+                                                                .with_size(ui::Size::XSmall)
+                                                                .icon(IconName::Close)
+                                                                .tooltip("Remove field")
+                                                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                                                    this.remove_field(field_idx, cx);
+                                                                }))
+                                                        )
                                                 )
+                                                .when_some(doc.filter(|_| !editing), |this, doc| {
+                                                    this.child(div().text_xs().text_color(cx.theme().muted_foreground).child(doc.to_string()))
+                                                })
+                                                .when(editing, |this| {
+                                                    this.child(self.type_picker(cx)).child(
+                                                        h_flex()
+                                                            .gap_2()
+                                                            .child(Self::meta_field("Doc", &self.field_doc_input, cx))
+                                                            .child(Self::meta_field("Default", &self.field_default_input, cx))
+                                                    )
+                                                })
                                         })
                                     )
                             )
@@ -432,6 +1403,7 @@ impl Render for VariantEditorView {
                                             .ghost()
                                             .with_size(ui::Size::XSmall)
                                             .icon(IconName::Edit)
+                                            .tooltip("Edit docs")
                                             .on_click(cx.listener(|this, _, _window, cx| {
                                                 this.editing_doc = true;
                                                 cx.notify();
@@ -439,21 +1411,20 @@ impl Render for VariantEditorView {
                                     )
                                 })
                         )
-                        .child(
-                            if self.editing_doc {
-                                TextInput::new(&self.doc_input)
-                                    .into_any_element()
-                            } else {
+                        .when(self.editing_doc, |this| {
+                            this.child(TextInput::new(&self.doc_input).h(px(96.0)))
+                        })
+                        .children(self.variant.doc.clone().and_then(|doc| self.spelling("doc", &doc, cx)))
+                        // Rendered preview, kept up to date while typing
+                        .when_some(self.variant.doc.clone(), |this, doc| {
+                            this.child(
                                 div()
                                     .p_2()
-                                    .rounded(px(4.0))
+                                    .rounded(cx.theme().radius)
                                     .bg(cx.theme().secondary.opacity(0.2))
-                                    .text_sm()
-                                    .text_color(cx.theme().muted_foreground)
-                                    .child(format!("/// {}", self.variant.doc.as_ref().unwrap_or(&String::from("Click to add documentation"))))
-                                    .into_any_element()
-                            }
-                        )
+                                    .child(crate::markdown::render(&doc, cx))
+                            )
+                        })
                 )
             })
     }
@@ -0,0 +1,322 @@
+//! Operations over every enum definition in the workspace
+//!
+//! Definitions are found by walking the project root: `.enum` folders and
+//! flat `.enum.json` / `.enum.yaml` / `.enum.toml` files. They are loaded
+//! headlessly, without opening an editor.
+
+use crate::cache::DefinitionCache;
+use crate::codegen;
+use crate::error::Result;
+use crate::output::Severity;
+use crate::references::{is_ident_char, SKIPPED_DIRS};
+use crate::storage::{self, SerializationFormat, FOLDER_EXTENSION};
+use crate::type_check;
+use crate::validation::{self, Issue};
+use std::path::{Path, PathBuf};
+use ui_types_common::{EnumAsset, TypeRef, VariantPayload};
+
+/// Definition file of every enum under `root`, sorted by path
+pub fn enum_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if path.is_dir() {
+                if path.extension().and_then(|e| e.to_str()) == Some(FOLDER_EXTENSION) {
+                    let definition = storage::resolve_definition_path(&path);
+                    if definition.is_file() {
+                        files.push(definition);
+                    }
+                } else if !SKIPPED_DIRS.contains(&name.as_str()) {
+                    pending.push(path);
+                }
+            } else if SerializationFormat::ALL
+                .iter()
+                .any(|format| name.ends_with(&format!(".{}.{}", FOLDER_EXTENSION, format.extension())))
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Validation outcome of one definition
+#[derive(Clone, Debug)]
+pub struct FileValidation {
+    pub path: PathBuf,
+    /// Issues found, or why the definition couldn't be loaded
+    pub result: Result<Vec<Issue>, String>,
+}
+
+impl FileValidation {
+    pub fn errors(&self) -> usize {
+        match &self.result {
+            Ok(issues) => issues.iter().filter(|i| i.severity == Severity::Error).count(),
+            Err(_) => 1,
+        }
+    }
+
+    pub fn warnings(&self) -> usize {
+        match &self.result {
+            Ok(issues) => issues.iter().filter(|i| i.severity == Severity::Warning).count(),
+            Err(_) => 0,
+        }
+    }
+}
+
+/// Load and validate one definition
+pub fn validate_file(definitions: &DefinitionCache, path: PathBuf) -> FileValidation {
+    let result = definitions
+        .load(&path)
+        .map(|asset| validation::validate(&asset))
+        .map_err(|e| e.to_string());
+    FileValidation { path, result }
+}
+
+/// Load and validate every enum under `root`
+pub fn validate_all(root: &Path) -> Vec<FileValidation> {
+    let definitions = DefinitionCache::default();
+    enum_files(root).into_iter().map(|path| validate_file(&definitions, path)).collect()
+}
+
+/// Plain-text report, one line per issue, for terminals and pre-commit hooks
+pub fn report_to_text(root: &Path, results: &[FileValidation]) -> String {
+    let mut out = String::new();
+    for file in results {
+        let path = file.path.strip_prefix(root).unwrap_or(&file.path).display();
+        match &file.result {
+            Ok(issues) => {
+                for issue in issues {
+                    out.push_str(&format!("{}: {}: {}\n", path, issue.severity.label().to_lowercase(), issue.message));
+                }
+            }
+            Err(message) => out.push_str(&format!("{}: error: {}\n", path, message)),
+        }
+    }
+    let errors: usize = results.iter().map(FileValidation::errors).sum();
+    let warnings: usize = results.iter().map(FileValidation::warnings).sum();
+    out.push_str(&format!("{} enums checked, {} errors, {} warnings\n", results.len(), errors, warnings));
+    out
+}
+
+/// Replace whole-word occurrences of `old` in a type string, so renaming
+/// `EntityId` also updates `ids::EntityId` and `Vec<EntityId>` but not `EntityIds`
+pub fn rename_in_type(ty: &str, old: &str, new: &str) -> String {
+    if old.is_empty() {
+        return ty.to_string();
+    }
+    let mut out = String::with_capacity(ty.len());
+    let mut rest = 0;
+    for (start, _) in ty.match_indices(old) {
+        if start < rest {
+            continue;
+        }
+        let before = ty[..start].chars().next_back();
+        let after = ty[start + old.len()..].chars().next();
+        if !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char) {
+            out.push_str(&ty[rest..start]);
+            out.push_str(new);
+            rest = start + old.len();
+        }
+    }
+    out.push_str(&ty[rest..]);
+    out
+}
+
+fn type_string_mut(type_ref: &mut TypeRef) -> &mut String {
+    match type_ref {
+        TypeRef::Primitive { name } => name,
+        TypeRef::Path { path } => path,
+        TypeRef::AliasRef { alias } => alias,
+    }
+}
+
+/// Rename a type in every payload of the enum. Returns a description of
+/// each changed payload, e.g. ``Spawn.entity: `EntityId` → `ActorId` ``.
+pub fn rename_type(asset: &mut EnumAsset, old: &str, new: &str) -> Vec<String> {
+    let mut changes = Vec::new();
+    let mut rename = |type_ref: &mut TypeRef, location: String| {
+        let ty = type_string_mut(type_ref);
+        let renamed = rename_in_type(ty, old, new);
+        if renamed != *ty {
+            changes.push(format!("{}: `{}` → `{}`", location, ty, renamed));
+            *ty = renamed;
+        }
+    };
+    for variant in &mut asset.variants {
+        match &mut variant.payload {
+            VariantPayload::Unit => {}
+            VariantPayload::Single(type_ref) => rename(type_ref, variant.name.clone()),
+            VariantPayload::Struct(fields) => {
+                for field in fields {
+                    rename(&mut field.type_ref, format!("{}.{}", variant.name, field.name));
+                }
+            }
+        }
+    }
+    changes
+}
+
+/// A definition with a type renamed, not yet written
+#[derive(Clone, Debug)]
+pub struct TypeRenamePlan {
+    pub path: PathBuf,
+    pub asset: EnumAsset,
+    /// Changed payloads, for the preview
+    pub changes: Vec<String>,
+}
+
+impl TypeRenamePlan {
+    pub fn write(&self) -> Result<()> {
+        storage::save_asset(&self.path, &self.asset)
+    }
+}
+
+/// Rename a type in every enum under `root` that uses it, without writing
+/// anything. Definitions that fail to load are skipped.
+pub fn plan_type_rename(definitions: &DefinitionCache, root: &Path, old: &str, new: &str) -> Vec<TypeRenamePlan> {
+    enum_files(root)
+        .into_iter()
+        .filter_map(|path| {
+            let mut asset = (*definitions.load(&path).ok()?).clone();
+            let changes = rename_type(&mut asset, old, new);
+            (!changes.is_empty()).then_some(TypeRenamePlan { path, asset, changes })
+        })
+        .collect()
+}
+
+/// Types that hold their contents behind a pointer or on the heap, so a
+/// type may contain itself through them
+const INDIRECTIONS: [&str; 9] = ["Box", "Rc", "Arc", "Vec", "VecDeque", "HashMap", "BTreeMap", "HashSet", "BTreeSet"];
+
+/// Last segment of every path `ty` mentions, and whether it is held by value
+/// rather than behind a pointer or collection
+fn contained_names(ty: &syn::Type, by_value: bool, names: &mut Vec<(String, bool)>) {
+    match ty {
+        syn::Type::Path(path) => {
+            let Some(last) = path.path.segments.last() else {
+                return;
+            };
+            let name = last.ident.to_string();
+            let inner = by_value && !INDIRECTIONS.contains(&name.as_str());
+            if let syn::PathArguments::AngleBracketed(arguments) = &last.arguments {
+                for argument in &arguments.args {
+                    if let syn::GenericArgument::Type(ty) = argument {
+                        contained_names(ty, inner, names);
+                    }
+                }
+            }
+            names.push((name, by_value));
+        }
+        syn::Type::Array(array) => contained_names(&array.elem, by_value, names),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().for_each(|ty| contained_names(ty, by_value, names)),
+        syn::Type::Paren(paren) => contained_names(&paren.elem, by_value, names),
+        syn::Type::Group(group) => contained_names(&group.elem, by_value, names),
+        syn::Type::Slice(slice) => contained_names(&slice.elem, false, names),
+        syn::Type::Reference(reference) => contained_names(&reference.elem, false, names),
+        syn::Type::Ptr(pointer) => contained_names(&pointer.elem, false, names),
+        _ => {}
+    }
+}
+
+/// Enums among `enums` that each one's payloads mention, by index, with
+/// whether it holds them by value
+fn dependencies(enums: &[(PathBuf, EnumAsset)]) -> Vec<Vec<(usize, bool)>> {
+    enums
+        .iter()
+        .map(|(_, asset)| {
+            let mut names = Vec::new();
+            for variant in &asset.variants {
+                let type_refs: Vec<&TypeRef> = match &variant.payload {
+                    VariantPayload::Unit => Vec::new(),
+                    VariantPayload::Single(type_ref) => vec![type_ref],
+                    VariantPayload::Struct(fields) => fields.iter().map(|field| &field.type_ref).collect(),
+                };
+                for type_ref in type_refs {
+                    if let Ok(ty) = type_check::parse_type(&codegen::type_ref_to_string(type_ref)) {
+                        contained_names(&ty, true, &mut names);
+                    }
+                }
+            }
+            let mut found: Vec<(usize, bool)> = Vec::new();
+            for (name, by_value) in names {
+                let Some(index) = enums.iter().position(|(_, other)| other.name == name) else {
+                    continue;
+                };
+                match found.iter_mut().find(|(other, _)| *other == index) {
+                    Some((_, held)) => *held |= by_value,
+                    None => found.push((index, by_value)),
+                }
+            }
+            found
+        })
+        .collect()
+}
+
+/// Order to export `enums` in, by index: every enum after the enums it
+/// mentions, otherwise as given. Enums that hold each other by value in a
+/// cycle have no finite size in any language; they are left out of the
+/// order and returned as cycles instead. Enums that mention a left-out enum,
+/// directly or through others, would refer to a type that is never
+/// generated, so they are left out too and returned with the enum they
+/// mention.
+pub fn export_order(enums: &[(PathBuf, EnumAsset)]) -> (Vec<usize>, Vec<Vec<usize>>, Vec<(usize, usize)>) {
+    let dependencies = dependencies(enums);
+    // Enums reachable from each one through values it holds
+    let reachable: Vec<Vec<bool>> = (0..enums.len())
+        .map(|start| {
+            let mut seen = vec![false; enums.len()];
+            let mut pending = vec![start];
+            while let Some(index) = pending.pop() {
+                for &(next, by_value) in &dependencies[index] {
+                    if by_value && !seen[next] {
+                        seen[next] = true;
+                        pending.push(next);
+                    }
+                }
+            }
+            seen
+        })
+        .collect();
+
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+    for index in (0..enums.len()).filter(|&index| reachable[index][index]) {
+        if !cycles.iter().any(|cycle| cycle.contains(&index)) {
+            cycles.push((0..enums.len()).filter(|&other| reachable[index][other] && reachable[other][index]).collect());
+        }
+    }
+
+    let mut excluded: Vec<bool> = (0..enums.len()).map(|index| reachable[index][index]).collect();
+    let mut blocked: Vec<(usize, usize)> = Vec::new();
+    while let Some((index, dependency)) = (0..enums.len()).filter(|&index| !excluded[index]).find_map(|index| {
+        dependencies[index]
+            .iter()
+            .find(|&&(dependency, _)| excluded[dependency])
+            .map(|&(dependency, _)| (index, dependency))
+    }) {
+        excluded[index] = true;
+        blocked.push((index, dependency));
+    }
+
+    let mut order = Vec::new();
+    let mut done = excluded;
+    while let Some(next) = (0..enums.len())
+        .filter(|&index| !done[index])
+        .find(|&index| dependencies[index].iter().all(|&(dependency, _)| done[dependency] || dependency == index))
+        // Only cycles through pointers are left; break them in the given order
+        .or_else(|| (0..enums.len()).find(|&index| !done[index]))
+    {
+        done[next] = true;
+        order.push(next);
+    }
+    (order, cycles, blocked)
+}
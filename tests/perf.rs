@@ -0,0 +1,62 @@
+//! Time budgets for parsing, serializing and generating code from a
+//! synthetic enum of 10,000 variants
+//!
+//! | Operation                       | Budget |
+//! |---------------------------------|--------|
+//! | Parse a JSON definition         | 100 ms |
+//! | Serialize a JSON definition     | 100 ms |
+//! | Generate any codegen target     | 250 ms |
+//!
+//! Budgets are deliberately loose; they catch accidental quadratic work, not
+//! small regressions, which the criterion reports of `cargo bench` show.
+//! Timings are only meaningful in an optimized build, so the check is
+//! ignored by default; run it with
+//! `cargo test --release --test perf -- --ignored`.
+
+use enum_editor_plugin::{synthetic_enum, CodegenTarget, SerializationFormat};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const PARSE_BUDGET: Duration = Duration::from_millis(100);
+const SERIALIZE_BUDGET: Duration = Duration::from_millis(100);
+const CODEGEN_BUDGET: Duration = Duration::from_millis(250);
+
+/// Fixture the budgets are checked against
+const BUDGET_VARIANTS: usize = 10_000;
+
+/// Runs each operation is timed over; the median is compared to the budget
+const RUNS: usize = 5;
+
+fn median_time<T>(mut run: impl FnMut() -> T) -> Duration {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let started = Instant::now();
+            black_box(run());
+            started.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[RUNS / 2]
+}
+
+#[test]
+#[ignore = "timings need an optimized build; run with --release -- --ignored"]
+fn operations_fit_their_budgets() {
+    let asset = synthetic_enum("Bench", BUDGET_VARIANTS);
+    let text = SerializationFormat::Json.serialize(&asset).unwrap();
+
+    let mut checks = vec![
+        ("parse json".to_string(), median_time(|| SerializationFormat::Json.parse(&text)), PARSE_BUDGET),
+        ("serialize json".to_string(), median_time(|| SerializationFormat::Json.serialize(&asset)), SERIALIZE_BUDGET),
+    ];
+    for target in CodegenTarget::ALL {
+        checks.push((format!("codegen {}", target.id()), median_time(|| target.generate(&asset)), CODEGEN_BUDGET));
+    }
+
+    let over: Vec<String> = checks
+        .into_iter()
+        .filter(|(_, elapsed, budget)| elapsed > budget)
+        .map(|(name, elapsed, budget)| format!("{} took {:?} (budget {:?})", name, elapsed, budget))
+        .collect();
+    assert!(over.is_empty(), "over budget at {} variants:\n{}", BUDGET_VARIANTS, over.join("\n"));
+}